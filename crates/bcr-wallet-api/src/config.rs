@@ -2,22 +2,57 @@ use std::path::PathBuf;
 
 use crate::error::Result;
 use bcr_common::cashu::MintUrl;
+use bcr_wallet_core::{
+    types::{Redacted, Seed},
+    util::{keypair_from_mnemonic, seed_from_mnemonic},
+};
 use nostr_sdk::{Keys, RelayUrl, nips::nip06::FromMnemonic, nips::nip19::Nip19Profile};
 
 pub const LOCK_REDUCTION_SECONDS_PER_HOP: u64 = 600;
 pub const MAX_INTERMINT_ATTEMPTS: u64 = 3;
+pub const DEFAULT_KEYSETS_TTL_SECONDS: i64 = 300;
+// a restore request failing on the network is retried, with the backoff doubling each time
+pub const RESTORE_RETRY_ATTEMPTS: u32 = 3;
+pub const RESTORE_RETRY_BACKOFF_MILLIS: u64 = 200;
+// how often the proofs of a NUT-18 payment are checked while waiting for the receiver
+pub const NUT18_DELIVERY_POLL_MILLIS: u64 = 2000;
+// ys checked per post_check_state request, mints may reject bigger ones
+pub const CHECK_STATE_BATCH_SIZE: usize = 500;
+// a prepared send left unsent for longer has most likely been abandoned
+pub const STALE_RESERVATION_SECONDS: u64 = 600;
 
 #[derive(Debug, Clone)]
 pub struct AppStateConfig {
     pub db_path: PathBuf,
     pub network: bitcoin::Network,
     pub nostr_relays: Vec<RelayUrl>,
-    pub mnemonic: bip39::Mnemonic,
+    pub mnemonic: Redacted<bip39::Mnemonic>,
+    // the BIP39 passphrase, never stored, the wallets can only be loaded with it
+    pub passphrase: Option<Redacted<String>>,
     pub swap_expiry: chrono::TimeDelta,
+    // how long fetched mint keysets are reused before asking the mint again
+    pub keysets_ttl: chrono::TimeDelta,
     pub default_mint_url: MintUrl,
     pub dev_mode: bool,
 }
 
+impl AppStateConfig {
+    pub fn passphrase(&self) -> &str {
+        self.passphrase
+            .as_ref()
+            .map(|p| p.expose().as_str())
+            .unwrap_or_default()
+    }
+
+    pub fn seed(&self) -> Seed {
+        seed_from_mnemonic(self.mnemonic.expose(), self.passphrase())
+    }
+
+    pub fn keypair(&self) -> bitcoin::secp256k1::Keypair {
+        keypair_from_mnemonic(self.mnemonic.expose(), self.passphrase())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NostrConfig {
     pub nprofile: Nip19Profile,
@@ -26,8 +61,12 @@ pub struct NostrConfig {
 }
 
 impl NostrConfig {
-    pub fn new(mnemonic: bip39::Mnemonic, nostr_relays: Vec<RelayUrl>) -> Result<Self> {
-        let keys = Keys::from_mnemonic(mnemonic.to_string(), None)?;
+    pub fn new(
+        mnemonic: bip39::Mnemonic,
+        passphrase: Option<String>,
+        nostr_relays: Vec<RelayUrl>,
+    ) -> Result<Self> {
+        let keys = Keys::from_mnemonic(mnemonic.to_string(), passphrase)?;
 
         Ok(Self {
             nprofile: Nip19Profile::new(keys.public_key, nostr_relays.clone()),
@@ -59,6 +59,12 @@ pub enum Error {
     ReqwestClient(#[from] reqwest::Error),
     #[error("total balance {0} is less than target {1}")]
     InsufficientBalance(cashu::Amount, cashu::Amount),
+    #[error("balance {available} covers the amount, but not the fee {fee}, needed: {needed}")]
+    InsufficientFundsForFee {
+        available: cashu::Amount,
+        needed: cashu::Amount,
+        fee: cashu::Amount,
+    },
     #[error("There already exists a wallet - delete it to create a new one")]
     WalletAlreadyExists,
     #[error("wallet at idx {0} not found")]
@@ -75,6 +81,8 @@ pub enum Error {
     UnknownKeysetId(cashu::Id),
     #[error("inactive keyset {0}")]
     InactiveKeyset(cashu::Id),
+    #[error("duplicate keyset ID {0} with conflicting infos")]
+    DuplicateKeysetId(cashu::Id),
     #[error("invalid currency unit: {0}")]
     InvalidCurrencyUnit(String),
     #[error("no reference to prepare request_id: {0}")]
@@ -85,6 +93,8 @@ pub enum Error {
     NoDebitCurrencyInMint(Vec<cashu::CurrencyUnit>),
     #[error("network mismatch, ours: {0}, theirs: {1}")]
     InvalidNetwork(bitcoin::Network, bitcoin::Network),
+    #[error("network of wallet {0} can't be changed, stored: {1}, configured: {2}")]
+    NetworkImmutable(String, bitcoin::Network, bitcoin::Network),
     #[error("mnemonic mismatch")]
     InvalidMnemonic,
     #[error("mint url mismatch, ours: {0}, theirs: {1}")]
@@ -101,7 +111,7 @@ pub enum Error {
     MissingDleq,
     #[error("intermint payment, but no clowder path")]
     InterMintButNoClowderPath,
-    #[error("spending conditions not supported yet")]
+    #[error("unsupported spending conditions")]
     SpendingConditions,
     #[error("NUT-18 request has no transport")]
     NoTransport,
@@ -127,6 +137,37 @@ pub enum Error {
     External(#[from] crate::external::Error),
     #[error("Dev Mode is disabled")]
     NoDevMode,
+    #[error("invalid proof backup: {0}")]
+    InvalidBackup(String),
+    // earlier batches are committed, resuming repeats only the remaining work
+    #[error("partially completed, {done} committed before: {error}")]
+    PartialCompletion { done: usize, error: Box<Error> },
+    // the incoming transaction which recorded the proofs, if any did
+    #[error("token already received{}", .0.as_ref().map(|id| format!(" in transaction {id}")).unwrap_or_default())]
+    AlreadyReceived(Option<cdk_common::wallet::TransactionId>),
+    #[error("offline send not possible, the prepared send needs a swap")]
+    OfflineSendNotPossible,
+    #[error("send amount mismatch, got {got}, expected {expected}")]
+    SendAmountMismatch {
+        got: cashu::Amount,
+        expected: cashu::Amount,
+    },
+    #[error("invalid proof {0}, its amount has no key in the keyset")]
+    InvalidProof(cashu::PublicKey),
+    #[error(
+        "restore of keyset {kid} interrupted after {restored} proofs, resume at counter {last_good_counter}: {error}"
+    )]
+    RestoreInterrupted {
+        kid: cashu::Id,
+        restored: usize,
+        last_good_counter: u32,
+        error: Box<Error>,
+    },
+    #[error("the mint under-signed the swap, got {got}, expected {expected}")]
+    SwapUnderSigned {
+        expected: cashu::Amount,
+        got: cashu::Amount,
+    },
 }
 
 impl From<bcr_common::core::swap::wallet::Error> for Error {
@@ -18,7 +18,11 @@ use bcr_wallet_core::SendSync;
 use bitcoin::base64::prelude::*;
 use bitcoin::secp256k1;
 use rand::seq::IndexedRandom;
-use std::str::FromStr;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 use tracing::debug;
 
 pub struct SwapCommitmentResult {
@@ -234,10 +238,88 @@ pub trait ClowderMintConnector: SendSync {
         &self,
         req: wire_mint::OnchainMintRequest,
     ) -> Result<wire_mint::MintResponse>;
+    async fn post_mint_quote_bolt11(
+        &self,
+        req: cashu::MintQuoteBolt11Request,
+    ) -> Result<cashu::MintQuoteBolt11Response<String>>;
+    async fn get_mint_quote_bolt11(
+        &self,
+        quote_id: String,
+    ) -> Result<cashu::MintQuoteBolt11Response<String>>;
+    async fn post_mint_bolt11(
+        &self,
+        req: cashu::MintRequest<String>,
+    ) -> Result<cashu::MintResponse>;
     async fn post_protest_mint(
         &self,
         req: wire_mint::MintProtestRequest,
     ) -> Result<wire_mint::MintProtestResponse>;
+    async fn get_mint_info(&self) -> Result<cashu::MintInfo>;
+}
+
+// keysets are content-addressed by their id, so a fetched one only needs checking against
+// its keys once; one past its final expiry is dropped, the mint no longer signs with it
+#[derive(Debug, Clone, Default)]
+struct KeySetCache(Arc<Mutex<HashMap<cashu::Id, cashu::KeySet>>>);
+
+impl KeySetCache {
+    fn get(&self, kid: &cashu::Id, now: u64) -> Option<cashu::KeySet> {
+        let mut cache = self.0.lock().expect("keyset cache lock");
+        let expired = cache
+            .get(kid)?
+            .final_expiry
+            .is_some_and(|expiry| expiry <= now);
+        if expired {
+            cache.remove(kid);
+            return None;
+        }
+        cache.get(kid).cloned()
+    }
+
+    fn insert(&self, keyset: cashu::KeySet) {
+        self.0
+            .lock()
+            .expect("keyset cache lock")
+            .insert(keyset.id, keyset);
+    }
+
+    async fn get_or_fetch(
+        &self,
+        keyset_id: cashu::Id,
+        main: &MintClient,
+    ) -> MintResult<cashu::KeySet> {
+        let now = chrono::Utc::now().timestamp() as u64;
+        self.get_or_fetch_with(keyset_id, now, || main.keys(keyset_id))
+            .await
+    }
+
+    async fn get_or_fetch_with<F, Fut>(
+        &self,
+        keyset_id: cashu::Id,
+        now: u64,
+        fetch: F,
+    ) -> MintResult<cashu::KeySet>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = MintResult<cashu::KeySet>>,
+    {
+        if let Some(keyset) = self.get(&keyset_id, now) {
+            return Ok(keyset);
+        }
+        let keyset = fetch().await?;
+        // a keyset not matching its keys must never be used to unblind
+        if keyset.id != keyset_id {
+            return Err(MintError::Internal(format!(
+                "asked for keyset {keyset_id}, got {}",
+                keyset.id
+            )));
+        }
+        keyset.verify_id().map_err(|e| {
+            MintError::Internal(format!("keyset {keyset_id} doesn't match its keys: {e}"))
+        })?;
+        self.insert(keyset.clone());
+        Ok(keyset)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -245,6 +327,7 @@ pub struct HttpClientExt {
     main: MintClient,
     url: reqwest::Url,
     secondary: reqwest::Client,
+    keysets: KeySetCache,
 }
 
 impl HttpClientExt {
@@ -255,8 +338,17 @@ impl HttpClientExt {
             main: MintClient::new(mint_url.clone()),
             url: mint_url,
             secondary: reqwest::Client::new(),
+            keysets: KeySetCache::default(),
         }
     }
+
+    // fetches the given keysets ahead of the first swap, already cached ones are skipped
+    pub async fn warm_keysets(&self, kids: &[cashu::Id]) -> MintResult<()> {
+        for kid in kids {
+            self.keysets.get_or_fetch(*kid, &self.main).await?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -284,7 +376,7 @@ impl ClowderMintConnector for HttpClientExt {
 
     async fn get_mint_keyset(&self, keyset_id: cashu::Id) -> MintResult<cashu::KeySet> {
         debug!("HTTP call to get_mint_keyset");
-        self.main.keys(keyset_id).await
+        self.keysets.get_or_fetch(keyset_id, &self.main).await
     }
 
     async fn get_mint_keysets(&self) -> MintResult<Vec<cashu::KeySetInfo>> {
@@ -555,6 +647,63 @@ impl ClowderMintConnector for HttpClientExt {
         Ok(response)
     }
 
+    async fn post_mint_quote_bolt11(
+        &self,
+        req: cashu::MintQuoteBolt11Request,
+    ) -> Result<cashu::MintQuoteBolt11Response<String>> {
+        let url = self
+            .url
+            .join("v1/mint/quote/bolt11")
+            .expect("mint_quote_bolt11 url error");
+        debug!("HTTP call to mint_quote_bolt11 on {url}");
+
+        let res = self
+            .secondary
+            .post(url)
+            .json(&req)
+            .send()
+            .await?
+            .error_for_status()?;
+        let response: cashu::MintQuoteBolt11Response<String> = res.json().await?;
+        Ok(response)
+    }
+
+    async fn get_mint_quote_bolt11(
+        &self,
+        quote_id: String,
+    ) -> Result<cashu::MintQuoteBolt11Response<String>> {
+        let url = self
+            .url
+            .join(&format!("v1/mint/quote/bolt11/{quote_id}"))
+            .expect("mint_quote_bolt11 url error");
+        debug!("HTTP call to get mint_quote_bolt11 on {url}");
+
+        let res = self.secondary.get(url).send().await?.error_for_status()?;
+        let response: cashu::MintQuoteBolt11Response<String> = res.json().await?;
+        Ok(response)
+    }
+
+    async fn post_mint_bolt11(
+        &self,
+        req: cashu::MintRequest<String>,
+    ) -> Result<cashu::MintResponse> {
+        let url = self
+            .url
+            .join("v1/mint/bolt11")
+            .expect("mint_bolt11 url error");
+        debug!("HTTP call to mint_bolt11 on {url}");
+
+        let res = self
+            .secondary
+            .post(url)
+            .json(&req)
+            .send()
+            .await?
+            .error_for_status()?;
+        let response: cashu::MintResponse = res.json().await?;
+        Ok(response)
+    }
+
     async fn post_protest_mint(
         &self,
         req: wire_mint::MintProtestRequest,
@@ -575,6 +724,15 @@ impl ClowderMintConnector for HttpClientExt {
         let response: wire_mint::MintProtestResponse = res.json().await?;
         Ok(response)
     }
+
+    async fn get_mint_info(&self) -> Result<cashu::MintInfo> {
+        let url = self.url.join("v1/info").expect("info url error");
+        debug!("HTTP call to get info on {url}");
+
+        let res = self.secondary.get(url).send().await?.error_for_status()?;
+        let response: cashu::MintInfo = res.json().await?;
+        Ok(response)
+    }
 }
 
 /// A client wrapper that forwards wallet events to sentinel nodes.
@@ -586,6 +744,7 @@ pub struct SentinelClient {
     main: MintClient,
     url: reqwest::Url,
     secondary: reqwest::Client,
+    keysets: KeySetCache,
     sentinels: Vec<reqwest::Url>,
 }
 
@@ -603,11 +762,13 @@ impl SentinelClient {
             main,
             url,
             secondary,
+            keysets,
         } = client;
         Self {
             main,
             url,
             secondary,
+            keysets,
             sentinels,
         }
     }
@@ -646,7 +807,7 @@ impl ClowderMintConnector for SentinelClient {
     }
     async fn get_mint_keyset(&self, keyset_id: cashu::Id) -> MintResult<cashu::KeySet> {
         debug!("HTTP call to get_mint_keyset on sentinel");
-        self.main.keys(keyset_id).await
+        self.keysets.get_or_fetch(keyset_id, &self.main).await
     }
     async fn get_mint_keysets(&self) -> MintResult<Vec<cashu::KeySetInfo>> {
         debug!("HTTP call to get_mint_keysets on sentinel");
@@ -924,6 +1085,63 @@ impl ClowderMintConnector for SentinelClient {
         Ok(response)
     }
 
+    async fn post_mint_quote_bolt11(
+        &self,
+        req: cashu::MintQuoteBolt11Request,
+    ) -> Result<cashu::MintQuoteBolt11Response<String>> {
+        let url = self
+            .url
+            .join("v1/mint/quote/bolt11")
+            .expect("mint_quote_bolt11 url error");
+        debug!("HTTP call on sentinel to mint_quote_bolt11 on {url}");
+
+        let res = self
+            .secondary
+            .post(url)
+            .json(&req)
+            .send()
+            .await?
+            .error_for_status()?;
+        let response: cashu::MintQuoteBolt11Response<String> = res.json().await?;
+        Ok(response)
+    }
+
+    async fn get_mint_quote_bolt11(
+        &self,
+        quote_id: String,
+    ) -> Result<cashu::MintQuoteBolt11Response<String>> {
+        let url = self
+            .url
+            .join(&format!("v1/mint/quote/bolt11/{quote_id}"))
+            .expect("mint_quote_bolt11 url error");
+        debug!("HTTP call on sentinel to get mint_quote_bolt11 on {url}");
+
+        let res = self.secondary.get(url).send().await?.error_for_status()?;
+        let response: cashu::MintQuoteBolt11Response<String> = res.json().await?;
+        Ok(response)
+    }
+
+    async fn post_mint_bolt11(
+        &self,
+        req: cashu::MintRequest<String>,
+    ) -> Result<cashu::MintResponse> {
+        let url = self
+            .url
+            .join("v1/mint/bolt11")
+            .expect("mint_bolt11 url error");
+        debug!("HTTP call on sentinel to mint_bolt11 on {url}");
+
+        let res = self
+            .secondary
+            .post(url)
+            .json(&req)
+            .send()
+            .await?
+            .error_for_status()?;
+        let response: cashu::MintResponse = res.json().await?;
+        Ok(response)
+    }
+
     async fn post_protest_mint(
         &self,
         req: wire_mint::MintProtestRequest,
@@ -944,4 +1162,93 @@ impl ClowderMintConnector for SentinelClient {
         let response: wire_mint::MintProtestResponse = res.json().await?;
         Ok(response)
     }
+
+    async fn get_mint_info(&self) -> Result<cashu::MintInfo> {
+        let url = self.url.join("v1/info").expect("info url error");
+        debug!("HTTP call on sentinel to get info on {url}");
+
+        let res = self.secondary.get(url).send().await?.error_for_status()?;
+        let response: cashu::MintInfo = res.json().await?;
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bcr_common::core_tests;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn keyset(final_expiry: Option<u64>) -> cashu::KeySet {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let mut keyset = cashu::KeySet::from(keyset);
+        keyset.final_expiry = final_expiry;
+        keyset.id = cashu::Id::v1_from_keys(&keyset.keys);
+        keyset
+    }
+
+    async fn fetch_counted(
+        cache: &KeySetCache,
+        fetched: &AtomicUsize,
+        keyset: &cashu::KeySet,
+        now: u64,
+    ) -> MintResult<cashu::KeySet> {
+        cache
+            .get_or_fetch_with(keyset.id, now, || async {
+                fetched.fetch_add(1, Ordering::SeqCst);
+                Ok(keyset.clone())
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn keyset_cache_fetches_on_miss_only() {
+        let cache = KeySetCache::default();
+        let fetched = AtomicUsize::new(0);
+        let keyset = keyset(None);
+
+        let first = fetch_counted(&cache, &fetched, &keyset, 100).await.unwrap();
+        assert_eq!(first.id, keyset.id);
+        assert_eq!(fetched.load(Ordering::SeqCst), 1);
+
+        let second = fetch_counted(&cache, &fetched, &keyset, 100).await.unwrap();
+        assert_eq!(second.id, keyset.id);
+        assert_eq!(fetched.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn keyset_cache_drops_expired_keysets() {
+        let cache = KeySetCache::default();
+        let fetched = AtomicUsize::new(0);
+        let keyset = keyset(Some(200));
+
+        fetch_counted(&cache, &fetched, &keyset, 100).await.unwrap();
+        fetch_counted(&cache, &fetched, &keyset, 199).await.unwrap();
+        assert_eq!(fetched.load(Ordering::SeqCst), 1);
+
+        fetch_counted(&cache, &fetched, &keyset, 200).await.unwrap();
+        assert_eq!(fetched.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn keyset_cache_rejects_keys_not_matching_the_id() {
+        let cache = KeySetCache::default();
+        let requested = keyset(None);
+        let mut tampered = keyset(None);
+        tampered.id = requested.id;
+
+        let res = cache
+            .get_or_fetch_with(requested.id, 100, || async { Ok(tampered) })
+            .await;
+        assert!(matches!(res, Err(MintError::Internal(_))));
+        assert!(cache.get(&requested.id, 100).is_none());
+
+        // nor is a keyset other than the requested one cached
+        let other = keyset(None);
+        let res = cache
+            .get_or_fetch_with(requested.id, 100, || async { Ok(other) })
+            .await;
+        assert!(matches!(res, Err(MintError::Internal(_))));
+        assert!(cache.get(&requested.id, 100).is_none());
+    }
 }
@@ -7,6 +7,9 @@ pub mod tests {
         wire::{keys as wire_keys, melt as wire_melt, mint as wire_mint, swap as wire_swap},
     };
     use bitcoin::secp256k1;
+    use std::collections::{HashMap, HashSet};
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
 
     use bcr_common::wire::clowder::{AlphaStateResponse, ConnectedMintResponse};
 
@@ -90,10 +93,195 @@ pub mod tests {
                 &self,
                 req: wire_mint::OnchainMintRequest,
             ) -> Result<wire_mint::MintResponse>;
+            async fn post_mint_quote_bolt11(
+                &self,
+                req: cashu::MintQuoteBolt11Request,
+            ) -> Result<cashu::MintQuoteBolt11Response<String>>;
+            async fn get_mint_quote_bolt11(
+                &self,
+                quote_id: String,
+            ) -> Result<cashu::MintQuoteBolt11Response<String>>;
+            async fn post_mint_bolt11(
+                &self,
+                req: cashu::MintRequest<String>,
+            ) -> Result<cashu::MintResponse>;
             async fn post_protest_mint(
                 &self,
                 req: wire_mint::MintProtestRequest,
             ) -> Result<wire_mint::MintProtestResponse>;
+            async fn get_mint_info(&self) -> Result<cashu::MintInfo>;
+        }
+    }
+
+    ///////////////////////////////////////////// fake mint
+    type Signer = Arc<dyn Fn(&[cashu::Amount]) -> Vec<cashu::BlindSignature> + Send + Sync>;
+    type ProofMaker = Arc<dyn Fn(&[cashu::Amount]) -> Vec<cashu::Proof> + Send + Sync>;
+
+    #[derive(Default)]
+    pub struct FakeMintState {
+        pub spent: HashSet<cashu::PublicKey>,
+        pub swaps: usize,
+        pub signed: HashMap<cashu::PublicKey, cashu::BlindSignature>,
+        // the mint refuses every swap
+        pub fail_swaps: bool,
+    }
+
+    /// a mint with a single active keyset, which signs swap/mint outputs, answers restores
+    /// with what it signed and tracks the spent ys
+    /// clones share the same state, so several wallets can talk to the same mint
+    #[derive(Clone)]
+    pub struct FakeMint {
+        pub info: cashu::KeySetInfo,
+        pub keyset: cashu::KeySet,
+        sign: Signer,
+        mint_proofs: ProofMaker,
+        state: Arc<Mutex<FakeMintState>>,
+    }
+
+    impl FakeMint {
+        pub const URL: &'static str = "https://mint.example";
+
+        pub fn new() -> Self {
+            let (info, keyset) = bcr_common::core_tests::generate_random_ecash_keyset();
+            let mut info = cashu::KeySetInfo::from(info);
+            info.active = true;
+            info.final_expiry = None;
+            info.input_fee_ppk = 0;
+            let signing_keyset = keyset.clone();
+            let proofs_keyset = keyset.clone();
+            Self {
+                info,
+                keyset: cashu::KeySet::from(keyset),
+                sign: Arc::new(move |amounts| {
+                    bcr_common::core_tests::generate_ecash_signatures(&signing_keyset, amounts)
+                }),
+                mint_proofs: Arc::new(move |amounts| {
+                    bcr_common::core_tests::generate_random_ecash_proofs(&proofs_keyset, amounts)
+                }),
+                state: Arc::new(Mutex::new(FakeMintState::default())),
+            }
+        }
+
+        pub fn mint_url(&self) -> cashu::MintUrl {
+            cashu::MintUrl::from_str(Self::URL).expect("valid url")
+        }
+
+        /// fresh proofs signed by the mint keyset, e.g. to fund a wallet
+        pub fn proofs(&self, amounts: &[cashu::Amount]) -> Vec<cashu::Proof> {
+            (self.mint_proofs)(amounts)
+        }
+
+        pub fn token(&self, amounts: &[cashu::Amount]) -> cashu::wallet::Token {
+            cashu::wallet::Token::new_cashu(
+                self.mint_url(),
+                self.proofs(amounts),
+                None,
+                cashu::CurrencyUnit::Sat,
+            )
+        }
+
+        pub fn state(&self) -> std::sync::MutexGuard<'_, FakeMintState> {
+            self.state.lock().unwrap()
+        }
+
+        fn sign_outputs(&self, outputs: &[cashu::BlindedMessage]) -> Vec<cashu::BlindSignature> {
+            let amounts = outputs.iter().map(|b| b.amount).collect::<Vec<_>>();
+            let signatures = (self.sign)(&amounts);
+            let mut state = self.state();
+            for (output, signature) in outputs.iter().zip(signatures.iter()) {
+                state
+                    .signed
+                    .insert(output.blinded_secret, signature.clone());
+            }
+            signatures
+        }
+
+        /// a connector backed by this mint
+        /// further expectations can be added to it for the endpoints the fake doesn't model
+        pub fn connector(&self) -> MockMintConnector {
+            let mut client = MockMintConnector::new();
+            client.expect_fmt().returning(|_| Ok(()));
+            let mint_url = self.mint_url();
+            client.expect_mint_url().returning(move || mint_url.clone());
+            let info = self.info.clone();
+            client
+                .expect_get_mint_keysets()
+                .returning(move || Ok(vec![info.clone()]));
+            let keyset = self.keyset.clone();
+            client
+                .expect_get_mint_keyset()
+                .returning(move |_| Ok(keyset.clone()));
+            client
+                .expect_post_swap_commitment()
+                .returning(|_, _, _, _| {
+                    Ok(crate::pocket::test_utils::tests::mock_commitment_result())
+                });
+            let mint = self.clone();
+            client
+                .expect_post_swap_committed()
+                .returning(move |request| {
+                    let ys = request
+                        .inputs
+                        .iter()
+                        .map(|p| p.y().expect("Hash to curve should not fail"))
+                        .collect::<Vec<_>>();
+                    {
+                        let mut state = mint.state();
+                        if state.fail_swaps {
+                            return Err(crate::error::Error::Swap(String::from("swap refused")));
+                        }
+                        if ys.iter().any(|y| state.spent.contains(y)) {
+                            return Err(crate::error::Error::Swap(String::from(
+                                "proof already spent",
+                            )));
+                        }
+                        state.spent.extend(ys);
+                        state.swaps += 1;
+                    }
+                    let signatures = mint.sign_outputs(&request.outputs);
+                    Ok(wire_swap::SwapResponse { signatures })
+                });
+            let mint = self.clone();
+            client.expect_post_mint_bolt11().returning(move |request| {
+                let signatures = mint.sign_outputs(&request.outputs);
+                Ok(cashu::MintResponse { signatures })
+            });
+            let mint = self.clone();
+            client.expect_post_restore().returning(move |request| {
+                let state = mint.state();
+                Ok(request
+                    .outputs
+                    .into_iter()
+                    .filter_map(|output| {
+                        let signature = state.signed.get(&output.blinded_secret)?.clone();
+                        Some((output, signature))
+                    })
+                    .collect())
+            });
+            let mint = self.clone();
+            client.expect_post_check_state().returning(move |request| {
+                let state = mint.state();
+                Ok(request
+                    .ys
+                    .into_iter()
+                    .map(|y| cashu::nut07::ProofState {
+                        y,
+                        state: if state.spent.contains(&y) {
+                            cashu::nut07::State::Spent
+                        } else {
+                            cashu::nut07::State::Unspent
+                        },
+                        witness: None,
+                    })
+                    .collect())
+            });
+            client
+        }
+    }
+
+    impl Default for FakeMint {
+        fn default() -> Self {
+            Self::new()
         }
     }
 }
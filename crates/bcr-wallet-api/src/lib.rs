@@ -1,18 +1,26 @@
 use crate::config::AppStateConfig;
 use crate::external::mint::{ClowderMintConnector, HttpClientExt};
-use crate::wallet::types::{WalletBalance, WalletDetailedBalanceEntry, WalletProtestResult};
+use crate::wallet::types::{
+    CounterAdjustment, LocalDbCleanup, MintCapabilities, PendingTransaction, ReceiveResult,
+    SendResult, UnitBalance, WalletBalance, WalletDetailedBalanceEntry, WalletEventCallback,
+    WalletProtestResult, WalletStats,
+};
 use crate::{config::NostrConfig, wallet::api::WalletApi};
 use bcr_common::cdk_common::wallet::Transaction;
 use bcr_common::{
     cashu::{self, CurrencyUnit, MintUrl, nut18 as cdk18},
-    cdk_common::wallet::TransactionId,
+    cdk_common::wallet::{TransactionDirection, TransactionId},
     wallet::Token,
 };
 use bcr_wallet_core::types::{
-    self, MintSummary, PaymentResultCallback, PaymentSummary, Seed, WalletConfig,
+    self, MintSummary, PaymentResultCallback, PaymentSummary, SecretScheme, Seed, SendSummary,
+    WalletConfig,
+};
+use bcr_wallet_core::util::{build_wallet_id, keypair_from_seed, teaser};
+use bcr_wallet_persistence::redb::{
+    Database, build_pursedb, build_wallet_dbs, create_db, delete_wallet_dbs,
 };
-use bcr_wallet_core::util::{build_wallet_id, keypair_from_mnemonic, seed_from_mnemonic};
-use bcr_wallet_persistence::redb::{Database, build_pursedb, build_wallet_dbs, create_db};
+use bcr_wallet_persistence::{TxChanges, TxFilter};
 use error::{Error, Result};
 use nostr::nips::nip19::{Nip19Profile, ToBech32};
 use std::{
@@ -52,7 +60,11 @@ impl AppState {
 
         let pursedb = build_pursedb(AppState::DB_VERSION, db.clone()).await?;
 
-        let nostr_cfg = NostrConfig::new(cfg.mnemonic.clone(), cfg.nostr_relays.clone())?;
+        let nostr_cfg = NostrConfig::new(
+            cfg.mnemonic.expose().clone(),
+            cfg.passphrase.as_ref().map(|p| p.expose().clone()),
+            cfg.nostr_relays.clone(),
+        )?;
         let nostr_filter = nostr_sdk::Filter::new()
             .kind(nostr_sdk::Kind::GiftWrap)
             .pubkey(nostr_cfg.nostr_signer.public_key());
@@ -84,25 +96,29 @@ impl AppState {
 
         let purse = self.get_purse();
         let db = self.get_db();
-        let w_ids = purse.list_wallets().await?;
-        for wid in w_ids {
+        let slots = purse.wallet_slots().await?;
+        for (idx, wid) in slots.into_iter().enumerate() {
+            // deleted wallets keep their index empty
+            let Some(wid) = wid else {
+                continue;
+            };
             tracing::debug!("Loading wallet with id: {wid}");
-            let mut w_cfg = purse.load_wallet_config(&wid).await?;
-
-            if w_cfg.network != self.cfg.network {
-                tracing::error!(
-                    "Network mismatch: wallet {wid} with network {:?}, expected {:?}",
-                    w_cfg.network,
-                    self.cfg.network,
-                );
-                return Err(Error::InvalidNetwork(w_cfg.network, self.cfg.network));
-            }
+            let mut w_cfg = match purse
+                .load_wallet_config_for_network(&wid, self.cfg.network)
+                .await
+            {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    tracing::error!("Could not load wallet {wid}: {e}");
+                    return Err(e);
+                }
+            };
 
-            let seed = seed_from_mnemonic(&self.cfg.mnemonic);
-            let keypair = keypair_from_mnemonic(&self.cfg.mnemonic);
+            let seed = self.cfg.seed();
+            let keypair = self.cfg.keypair();
             if w_cfg.pub_key != keypair.public_key() {
                 tracing::error!(
-                    "Key mismatch: wallet {wid} has a different pubkey than the one given via the config mnemonic"
+                    "Key mismatch: wallet {wid} has a different pubkey than the one given via the config mnemonic and passphrase"
                 );
                 return Err(Error::InvalidMnemonic);
             }
@@ -161,11 +177,12 @@ impl AppState {
                 client,
                 Self::DB_VERSION,
                 self.cfg.swap_expiry,
+                self.cfg.keysets_ttl,
                 db.clone(),
                 seed,
             )
             .await?;
-            purse.add_wallet(wallet).await?;
+            purse.load_wallet(idx, wallet).await?;
         }
         Ok(())
     }
@@ -193,10 +210,32 @@ impl AppState {
         Ok(purse.ids().await.iter().map(|id| *id as usize).collect())
     }
 
+    // the balance over all wallets, one entry per unit
+    pub async fn purse_total_balance(&self) -> Result<Vec<UnitBalance>> {
+        tracing::debug!("purse_total_balance");
+        let totals = self.get_purse().total_balance().await?;
+        let mut balances: Vec<UnitBalance> = totals
+            .into_iter()
+            .map(|(unit, (credit, debit))| UnitBalance {
+                unit,
+                credit,
+                debit,
+            })
+            .collect();
+        balances.sort_by_key(|b| b.unit.to_string());
+        Ok(balances)
+    }
+
     pub async fn purse_add_wallet(&self, name: String) -> Result<usize> {
         let mint_url = self.cfg.default_mint_url.clone();
         tracing::debug!("Adding a new wallet for mint {name}, {mint_url}");
         let purse = self.get_purse();
+        // re-adding the same mint with the same mnemonic gives back the wallet we already have
+        let wallet_id = build_wallet_id(&self.cfg.seed());
+        if let Some(idx) = purse.find_wallet(&wallet_id, &mint_url).await? {
+            tracing::debug!("Wallet {wallet_id} already exists at {idx}");
+            return Ok(idx);
+        }
         if !purse.can_add_wallet().await {
             return Err(Error::WalletAlreadyExists);
         }
@@ -205,9 +244,10 @@ impl AppState {
             name,
             self.cfg.network,
             mint_url,
-            self.cfg.mnemonic.clone(),
+            self.cfg.seed(),
             AppState::DB_VERSION,
             self.cfg.swap_expiry,
+            self.cfg.keysets_ttl,
             self.get_db(),
         )
         .await?;
@@ -218,6 +258,15 @@ impl AppState {
     }
 
     pub async fn purse_restore_wallet(&self, name: String) -> Result<usize> {
+        self.purse_restore_wallet_with_progress(name, None).await
+    }
+
+    // progress is reported after every restored batch, one keyset at a time
+    pub async fn purse_restore_wallet_with_progress(
+        &self,
+        name: String,
+        progress: Option<types::RestoreProgressCallback>,
+    ) -> Result<usize> {
         let mint_url = self.cfg.default_mint_url.clone();
         tracing::debug!("Restoring a new wallet for mint {name}, {mint_url}");
         let purse = self.get_purse();
@@ -229,23 +278,27 @@ impl AppState {
             name,
             self.cfg.network,
             mint_url,
-            self.cfg.mnemonic.clone(),
+            self.cfg.seed(),
             AppState::DB_VERSION,
             self.cfg.swap_expiry,
+            self.cfg.keysets_ttl,
             self.get_db(),
         )
         .await?;
-        wallet.restore_local_proofs().await?;
+        wallet.restore_local_proofs(progress).await?;
 
         let idx = purse.add_wallet(wallet).await?;
         tracing::debug!("Wallet restored successfully");
         Ok(idx)
     }
 
+    // the other wallets keep their index, `purse_wallets_ids` no longer lists this one
     pub async fn purse_delete_wallet(&self, idx: usize) -> Result<()> {
         tracing::debug!("delete wallet {idx}");
         let purse = self.get_purse();
-        purse.delete_wallet(idx).await?;
+        let wallet_id = purse.delete_wallet(idx).await?;
+        let tables = delete_wallet_dbs(&wallet_id, self.get_db()).await?;
+        tracing::debug!("deleted {tables} tables of wallet {wallet_id}");
         Ok(())
     }
 
@@ -266,6 +319,28 @@ impl AppState {
         Ok(wallet.read().await.name())
     }
 
+    pub async fn wallet_rename(&self, idx: usize, name: String) -> Result<()> {
+        tracing::debug!("rename wallet {idx} to {name}");
+
+        self.get_purse().rename_wallet(idx, name).await
+    }
+
+    pub async fn wallet_set_coin_selection(
+        &self,
+        idx: usize,
+        strategy: types::CoinSelectionStrategy,
+    ) -> Result<()> {
+        tracing::debug!("wallet_set_coin_selection({idx}, {strategy})");
+
+        self.get_purse().set_coin_selection(idx, strategy).await
+    }
+
+    pub async fn wallet_set_keep_sent_tokens(&self, idx: usize, keep: bool) -> Result<()> {
+        tracing::debug!("wallet_set_keep_sent_tokens({idx}, {keep})");
+
+        self.get_purse().set_keep_sent_tokens(idx, keep).await
+    }
+
     pub async fn wallet_mint_url(&self, idx: usize) -> Result<String> {
         tracing::debug!("mint_url for wallet {idx}");
         let wallet = self.get_wallet(idx).await?;
@@ -287,14 +362,192 @@ impl AppState {
         wallet.read().await.balance().await
     }
 
-    pub async fn wallet_receive_token(&self, idx: usize, token: String) -> Result<TransactionId> {
+    pub async fn wallet_estimate_send(&self, idx: usize, amount: u64) -> Result<SendSummary> {
+        tracing::debug!("wallet_estimate_send({idx}, {amount})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let wallet = wallet.read().await;
+        let unit = wallet.debit_unit();
+        wallet
+            .estimate_send(cashu::Amount::from(amount), unit)
+            .await
+    }
+
+    pub async fn wallet_keyset_fees(&self, idx: usize) -> Result<HashMap<cashu::Id, u64>> {
+        tracing::debug!("wallet_keyset_fees({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.keyset_fees().await
+    }
+
+    pub async fn wallet_restore_keyset(&self, idx: usize, keyset_id: cashu::Id) -> Result<usize> {
+        tracing::debug!("wallet_restore_keyset({idx}, {keyset_id})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.restore_keyset(keyset_id).await
+    }
+
+    pub async fn wallet_verify_counters(&self, idx: usize) -> Result<Vec<CounterAdjustment>> {
+        tracing::debug!("wallet_verify_counters({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.verify_counters().await
+    }
+
+    pub async fn wallet_rebuild_counters(&self, idx: usize) -> Result<HashMap<cashu::Id, u32>> {
+        tracing::debug!("wallet_rebuild_counters({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.rebuild_counters_from_proofs().await
+    }
+
+    /// WARN: the returned blob contains spendable ecash, protected only by the passphrase
+    pub async fn wallet_export_proofs_encrypted(
+        &self,
+        idx: usize,
+        passphrase: String,
+    ) -> Result<Vec<u8>> {
+        tracing::debug!("wallet_export_proofs_encrypted({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet
+            .read()
+            .await
+            .export_proofs_encrypted(&passphrase)
+            .await
+    }
+
+    /// WARN: the returned blob contains spendable ecash, protected only by the passphrase
+    pub async fn wallet_export_backup(&self, idx: usize, passphrase: String) -> Result<Vec<u8>> {
+        tracing::debug!("wallet_export_backup({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.export_backup(&passphrase).await
+    }
+
+    // the wallet is built from the backed up config into fresh stores, so the purse needs room
+    pub async fn purse_import_wallet_backup(
+        &self,
+        blob: Vec<u8>,
+        passphrase: String,
+    ) -> Result<usize> {
+        tracing::debug!("purse_import_wallet_backup");
+        let purse = self.get_purse();
+        if !purse.can_add_wallet().await {
+            return Err(Error::WalletAlreadyExists);
+        }
+
+        let backup = wallet::util::decode_wallet_backup(&blob, &passphrase)?;
+        let w_cfg = backup.config.clone();
+        if w_cfg.network != self.cfg.network {
+            return Err(Error::NetworkImmutable(
+                w_cfg.wallet_id,
+                w_cfg.network,
+                self.cfg.network,
+            ));
+        }
+        if w_cfg.pub_key != self.cfg.keypair().public_key() {
+            tracing::error!(
+                "Key mismatch: the backed up wallet was not created with this mnemonic"
+            );
+            return Err(Error::InvalidMnemonic);
+        }
+
+        let client = HttpClientExt::new(w_cfg.mint.clone());
+        let wallet = build_wallet(
+            w_cfg,
+            client,
+            Self::DB_VERSION,
+            self.cfg.swap_expiry,
+            self.cfg.keysets_ttl,
+            self.get_db(),
+            self.cfg.seed(),
+        )
+        .await?;
+        let imported = wallet.import_backup(backup).await?;
+        tracing::debug!("imported {imported} proofs from the wallet backup");
+
+        let idx = purse.add_wallet(wallet).await?;
+        Ok(idx)
+    }
+
+    pub async fn wallet_import_proofs_encrypted(
+        &self,
+        idx: usize,
+        blob: Vec<u8>,
+        passphrase: String,
+    ) -> Result<usize> {
+        tracing::debug!("wallet_import_proofs_encrypted({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet
+            .read()
+            .await
+            .import_proofs_encrypted(&blob, &passphrase)
+            .await
+    }
+
+    pub async fn wallet_receive_token(&self, idx: usize, token: String) -> Result<ReceiveResult> {
         let tstamp = chrono::Utc::now().timestamp() as u64;
-        tracing::debug!("wallet_receive({idx}, {token}, {tstamp})");
+        tracing::debug!("wallet_receive({idx}, {}, {tstamp})", teaser(&token));
 
-        let token = Token::from_str(&token).map_err(|e| Error::InvalidToken(e.to_string()))?;
+        let token = is_valid_token(&token)?;
         let wallet = self.get_wallet(idx).await?;
-        let tx_id = wallet.read().await.receive_token(token, tstamp).await?;
-        Ok(tx_id)
+        wallet.read().await.receive_token(token, tstamp).await
+    }
+
+    pub async fn wallet_receive_multisig_token(
+        &self,
+        idx: usize,
+        token: String,
+    ) -> Result<(TransactionId, cashu::Amount, Vec<cashu::PublicKey>)> {
+        let tstamp = chrono::Utc::now().timestamp() as u64;
+        tracing::debug!(
+            "wallet_receive_multisig_token({idx}, {}, {tstamp})",
+            teaser(&token)
+        );
+
+        let token = is_valid_token(&token)?;
+        let keypair = self.cfg.keypair();
+        let signing_key = cashu::SecretKey::from(keypair.secret_key());
+        let wallet = self.get_wallet(idx).await?;
+        let result = wallet
+            .read()
+            .await
+            .receive_multisig_token(token, &signing_key, tstamp)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn wallet_partial_sign_proof(&self, idx: usize, y: String) -> Result<cashu::Witness> {
+        tracing::debug!("wallet_partial_sign_proof({idx}, {y})");
+
+        let y = cashu::PublicKey::from_str(&y)?;
+        let wallet = self.get_wallet(idx).await?;
+        let witness = wallet.read().await.partial_sign_proof(y).await?;
+        Ok(witness)
+    }
+
+    // returns whether the locked proof holds enough signatures to be spent now
+    pub async fn wallet_combine_partial_signatures(
+        &self,
+        idx: usize,
+        y: String,
+        witnesses: Vec<cashu::Witness>,
+    ) -> Result<bool> {
+        tracing::debug!(
+            "wallet_combine_partial_signatures({idx}, {y}, {})",
+            witnesses.len()
+        );
+
+        let y = cashu::PublicKey::from_str(&y)?;
+        let wallet = self.get_wallet(idx).await?;
+        let spendable = wallet
+            .read()
+            .await
+            .combine_partial_signatures(y, witnesses)
+            .await?;
+        Ok(spendable)
     }
 
     pub async fn wallet_mint_is_rabid(&self, idx: usize) -> Result<bool> {
@@ -311,6 +564,19 @@ impl AppState {
         Ok(is_offline)
     }
 
+    // what the payment request asks for as JSON, without preparing the payment
+    pub async fn wallet_inspect_payment_request(
+        &self,
+        idx: usize,
+        input: String,
+    ) -> Result<String> {
+        tracing::debug!("wallet_inspect_payment_request({idx}, {})", teaser(&input));
+
+        let wallet = self.get_wallet(idx).await?;
+        let info = wallet.read().await.inspect_payment_request(&input)?;
+        Ok(serde_json::to_string(&info)?)
+    }
+
     pub async fn wallet_prepare_pay_by_token(
         &self,
         idx: usize,
@@ -325,28 +591,121 @@ impl AppState {
         let summary = wallet
             .read()
             .await
-            .prepare_pay_by_token(amount, unit, description)
+            .prepare_pay_by_token(amount, unit, description, None, SecretScheme::Deterministic)
+            .await?;
+
+        Ok(summary)
+    }
+
+    // Prepares a token payment of the whole balance of the wallet
+    pub async fn wallet_prepare_send_max(
+        &self,
+        idx: usize,
+        unit: CurrencyUnit,
+        description: Option<String>,
+    ) -> Result<PaymentSummary> {
+        tracing::debug!("wallet_prepare_send_max({idx}, {unit}, {description:?})");
+        let wallet = self.get_wallet(idx).await?;
+
+        let summary = wallet
+            .read()
+            .await
+            .prepare_send_max(unit, description, SecretScheme::Deterministic)
+            .await?;
+
+        Ok(summary)
+    }
+
+    // Prepares a token payment with random secrets, which are unlinkable to the wallet seed
+    // neither the sent proofs nor the change of the split can be restored from the seed
+    pub async fn wallet_prepare_pay_by_token_random(
+        &self,
+        idx: usize,
+        amount: u64,
+        description: Option<String>,
+    ) -> Result<PaymentSummary> {
+        tracing::debug!("wallet_prepare_pay_by_token_random({idx}, {amount}, {description:?})");
+        let amount = cashu::Amount::from(amount);
+        let wallet = self.get_wallet(idx).await?;
+        let unit = wallet.read().await.debit_unit();
+
+        let summary = wallet
+            .read()
+            .await
+            .prepare_pay_by_token(amount, unit, description, None, SecretScheme::Random)
             .await?;
 
         Ok(summary)
     }
 
-    pub async fn wallet_pay_by_token(&self, idx: usize, rid: String) -> Result<CreatedToken> {
+    // Prepares a token payment, whose proofs are P2PK-locked to the given public key
+    // if a locktime is given, the proofs can be spent with the refund key afterwards
+    pub async fn wallet_prepare_send_locked(
+        &self,
+        idx: usize,
+        amount: u64,
+        unit: CurrencyUnit,
+        pubkey: String,
+        locktime: Option<u64>,
+        refund_key: Option<String>,
+        description: Option<String>,
+    ) -> Result<PaymentSummary> {
+        tracing::debug!(
+            "wallet_prepare_send_locked({idx}, {amount}, {unit}, {pubkey}, {locktime:?}, {refund_key:?}, {description:?})"
+        );
+        let amount = cashu::Amount::from(amount);
+        let pubkey = cashu::PublicKey::from_str(&pubkey)?;
+        let refund_keys = refund_key
+            .map(|k| cashu::PublicKey::from_str(&k))
+            .transpose()?
+            .map(|k| vec![k]);
+        let conditions = cashu::Conditions::new(locktime, None, refund_keys, None, None, None)?;
+        let conditions = cashu::SpendingConditions::new_p2pk(pubkey, Some(conditions));
+
+        let wallet = self.get_wallet(idx).await?;
+
+        let summary = wallet
+            .read()
+            .await
+            .prepare_pay_by_token(
+                amount,
+                unit,
+                description,
+                Some(conditions),
+                SecretScheme::Deterministic,
+            )
+            .await?;
+
+        Ok(summary)
+    }
+
+    pub async fn wallet_pay_by_token(&self, idx: usize, rid: String) -> Result<SendResult> {
         let tstamp = chrono::Utc::now().timestamp() as u64;
         tracing::debug!("wallet_pay_by_token({rid}, {tstamp})");
         let p_id = Uuid::from_str(&rid)?;
 
         let wallet = self.get_wallet(idx).await?;
-        let (tx_id, token) = wallet
+        wallet
             .read()
             .await
             .pay(p_id, &self.nostr_cl, &self.http_cl, tstamp)
-            .await?;
+            .await
+    }
 
-        Ok(CreatedToken {
-            tx_id,
-            token: token.expect("pay by token returns a token"),
-        })
+    // only if the prepared amount is covered exactly by local proofs, the mint isn't contacted
+    pub async fn wallet_send_offline(
+        &self,
+        idx: usize,
+        rid: String,
+        memo: Option<String>,
+    ) -> Result<CreatedToken> {
+        let tstamp = chrono::Utc::now().timestamp() as u64;
+        tracing::debug!("wallet_send_offline({rid}, {tstamp})");
+        let p_id = Uuid::from_str(&rid)?;
+
+        let wallet = self.get_wallet(idx).await?;
+        let (tx_id, token) = wallet.read().await.send_offline(p_id, memo, tstamp).await?;
+        Ok(CreatedToken { tx_id, token })
     }
 
     pub async fn wallet_prepare_melt(
@@ -385,17 +744,17 @@ impl AppState {
         let wallet = self.get_wallet(idx).await?;
         let p_id = Uuid::from_str(&rid)?;
 
-        let (tx_id, _) = wallet
+        let result = wallet
             .read()
             .await
             .pay(p_id, &self.nostr_cl, &self.http_cl, tstamp)
             .await?;
 
-        Ok(tx_id)
+        Ok(result.tx_id)
     }
 
-    pub async fn wallet_mint(&self, idx: usize, amount: u64) -> Result<MintSummary> {
-        tracing::debug!("wallet_mint({idx}, {amount})");
+    pub async fn wallet_mint_onchain(&self, idx: usize, amount: u64) -> Result<MintSummary> {
+        tracing::debug!("wallet_mint_onchain({idx}, {amount})");
 
         if amount < Self::MINT_MELT_THRESHOLD_SAT {
             return Err(Error::InsufficientOnChainMintAmount(amount));
@@ -403,11 +762,33 @@ impl AppState {
 
         let parsed_amount = bitcoin::Amount::from_sat(amount);
         let wallet = self.get_wallet(idx).await?;
-        let summary = wallet.read().await.mint(parsed_amount).await?;
+        let summary = wallet.read().await.mint_onchain(parsed_amount).await?;
 
         Ok(summary)
     }
 
+    pub async fn wallet_prepare_mint(
+        &self,
+        idx: usize,
+        amount: u64,
+    ) -> Result<types::MintQuoteSummary> {
+        tracing::debug!("wallet_prepare_mint({idx}, {amount})");
+
+        let amount = cashu::Amount::from(amount);
+        let wallet = self.get_wallet(idx).await?;
+        let summary = wallet.read().await.prepare_mint(amount).await?;
+        Ok(summary)
+    }
+
+    pub async fn wallet_mint(&self, idx: usize, quote_id: String) -> Result<TransactionId> {
+        let tstamp = chrono::Utc::now().timestamp() as u64;
+        tracing::debug!("wallet_mint({idx}, {quote_id}, {tstamp})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let tx_id = wallet.read().await.mint(quote_id, tstamp).await?;
+        Ok(tx_id)
+    }
+
     pub async fn wallet_check_pending_mints(&self, idx: usize) -> Result<Vec<TransactionId>> {
         tracing::debug!("wallet_check_pending_mints({idx})");
         let wallet = self.get_wallet(idx).await?;
@@ -481,7 +862,8 @@ impl AppState {
         idx: usize,
         input: String,
     ) -> Result<PaymentSummary> {
-        tracing::debug!("wallet_prepare_payment({idx}, {input})");
+        // the input can carry a payment request of the payee, only the wallet is logged
+        tracing::debug!("wallet_prepare_payment({idx})");
 
         let wallet = self.get_wallet(idx).await?;
         let summary = wallet.read().await.prepare_pay(input).await?;
@@ -489,19 +871,26 @@ impl AppState {
         Ok(summary)
     }
 
-    pub async fn wallet_pay(&self, idx: usize, rid: String) -> Result<TransactionId> {
+    pub async fn wallet_pay(&self, idx: usize, rid: String) -> Result<SendResult> {
         let tstamp = chrono::Utc::now().timestamp() as u64;
         tracing::debug!("wallet_pay({rid}, {tstamp})");
 
         let wallet = self.get_wallet(idx).await?;
         let p_id = Uuid::from_str(&rid)?;
 
-        let (tx_id, _) = wallet
+        wallet
             .read()
             .await
             .pay(p_id, &self.nostr_cl, &self.http_cl, tstamp)
-            .await?;
-        Ok(tx_id)
+            .await
+    }
+
+    pub async fn wallet_cancel_payment(&self, idx: usize, rid: String) -> Result<()> {
+        tracing::debug!("wallet_cancel_payment({rid})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let p_id = Uuid::from_str(&rid)?;
+        wallet.read().await.cancel_payment(p_id).await
     }
 
     pub async fn wallet_prepare_payment_request(
@@ -561,6 +950,36 @@ impl AppState {
         Ok(())
     }
 
+    // Forwards wallet events to the callback until the token is cancelled
+    pub async fn wallet_subscribe(
+        &self,
+        idx: usize,
+        cancel_token: CancellationToken,
+        callback: WalletEventCallback,
+    ) -> Result<()> {
+        tracing::debug!("wallet_subscribe({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        // don't keep the wallet locked while waiting for events
+        let mut events = wallet.read().await.subscribe();
+        loop {
+            tokio::select! {
+                _ = cancel_token.cancelled() => {
+                    tracing::info!("wallet_subscribe cancelled: {idx}");
+                    return Ok(());
+                }
+                event = events.recv() => match event {
+                    Ok(event) => callback(event),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                        tracing::warn!("wallet_subscribe({idx}) missed {missed} events");
+                    }
+                    // the wallet was dropped, nothing more will come
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return Ok(()),
+                },
+            }
+        }
+    }
+
     pub async fn wallet_list_tx_ids(&self, idx: usize) -> Result<Vec<TransactionId>> {
         tracing::debug!("wallet_list_tx_ids({idx})");
 
@@ -578,6 +997,53 @@ impl AppState {
         Ok(txs)
     }
 
+    pub async fn wallet_list_transactions(
+        &self,
+        idx: usize,
+        offset: usize,
+        limit: usize,
+        refresh_pending: bool,
+    ) -> Result<Vec<Transaction>> {
+        tracing::debug!("wallet_list_transactions({idx}, {offset}, {limit}, {refresh_pending})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let txs = wallet
+            .read()
+            .await
+            .list_transactions(offset, limit, refresh_pending)
+            .await?;
+        Ok(txs)
+    }
+
+    // ids of the matching transactions, newest first, unset criteria match everything
+    pub async fn wallet_search_tx(
+        &self,
+        idx: usize,
+        direction: Option<TransactionDirection>,
+        unit: Option<CurrencyUnit>,
+        memo_contains: Option<String>,
+    ) -> Result<Vec<TransactionId>> {
+        tracing::debug!("wallet_search_tx({idx}, {direction:?}, {unit:?}, {memo_contains:?})");
+
+        let filter = TxFilter {
+            direction,
+            unit,
+            memo_contains,
+        };
+        let wallet = self.get_wallet(idx).await?;
+        let tx_ids = wallet.read().await.search_txs(filter).await?;
+        Ok(tx_ids)
+    }
+
+    // Returns the transactions written or deleted after `seq` and the marker for the next sync
+    pub async fn wallet_list_tx_since(&self, idx: usize, seq: u64) -> Result<(TxChanges, u64)> {
+        tracing::debug!("wallet_list_tx_since({idx}, {seq})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let res = wallet.read().await.list_txs_since(seq).await?;
+        Ok(res)
+    }
+
     pub async fn wallet_load_tx(&self, idx: usize, tx_id: &str) -> Result<Transaction> {
         tracing::debug!("wallet_load_tx({idx}, {tx_id})");
 
@@ -587,6 +1053,16 @@ impl AppState {
         Ok(tx)
     }
 
+    // the receipt of the transaction as JSON
+    pub async fn wallet_export_receipt(&self, idx: usize, tx_id: &str) -> Result<String> {
+        tracing::debug!("wallet_export_receipt({idx}, {tx_id})");
+
+        let tx_id = TransactionId::from_str(tx_id)?;
+        let wallet = self.get_wallet(idx).await?;
+        let receipt = wallet.read().await.export_receipt(tx_id).await?;
+        Ok(serde_json::to_string(&receipt)?)
+    }
+
     pub async fn wallet_reclaim_tx(&self, idx: usize, tx_id: &str) -> Result<cashu::Amount> {
         tracing::debug!("wallet_reclaim_tx({idx}, {tx_id})");
         let tx_id = TransactionId::from_str(tx_id)?;
@@ -595,6 +1071,12 @@ impl AppState {
         Ok(amount)
     }
 
+    pub async fn wallet_reclaim_funds(&self, idx: usize) -> Result<WalletBalance> {
+        tracing::debug!("wallet_reclaim_funds({idx})");
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.reclaim_funds().await
+    }
+
     // Recover pending stale proofs
     pub async fn wallet_recover_pending_stale_proofs(&self, idx: usize) -> Result<cashu::Amount> {
         tracing::debug!("wallet_recover_pending_stale_proofs({idx})");
@@ -654,6 +1136,129 @@ impl AppState {
         Ok(updated)
     }
 
+    // Waits up to `timeout_secs` for the receiver to redeem a NUT-18 payment
+    pub async fn wallet_confirm_nut18_delivery(
+        &self,
+        idx: usize,
+        tx_id: &str,
+        timeout_secs: u64,
+    ) -> Result<bool> {
+        tracing::debug!("wallet_confirm_nut18_delivery({idx}, {tx_id}, {timeout_secs})");
+
+        let tx_id = TransactionId::from_str(tx_id)?;
+        let wallet = self.get_wallet(idx).await?;
+        let delivered = wallet
+            .read()
+            .await
+            .confirm_nut18_delivery(tx_id, std::time::Duration::from_secs(timeout_secs))
+            .await?;
+        Ok(delivered)
+    }
+
+    /// WARN: the token holds the whole balance, the wallet keeps spending the same proofs
+    pub async fn wallet_export_balance_token(
+        &self,
+        idx: usize,
+        unit: CurrencyUnit,
+    ) -> Result<String> {
+        tracing::debug!("wallet_export_balance_token({idx}, {unit})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let token = wallet.read().await.export_all_as_token(unit).await?;
+        Ok(token.to_string())
+    }
+
+    // the token of a send, if the wallet keeps them and the transaction is still pending
+    pub async fn wallet_get_tx_token(&self, idx: usize, tx_id: &str) -> Result<Option<String>> {
+        tracing::debug!("wallet_get_tx_token({idx}, {tx_id})");
+
+        let tx_id = TransactionId::from_str(tx_id)?;
+        let wallet = self.get_wallet(idx).await?;
+        let token = wallet.read().await.sent_token(tx_id).await?;
+        Ok(token)
+    }
+
+    // the nprofile payers target with NUT-18 payments over nostr
+    // derived from the mnemonic (NIP-06), the purse holds a single wallet at a time
+    pub async fn wallet_get_nprofile(&self, idx: usize) -> Result<String> {
+        tracing::debug!("wallet_get_nprofile({idx})");
+
+        self.get_wallet(idx).await?;
+        Ok(self.myself.to_bech32()?)
+    }
+
+    pub async fn wallet_mint_capabilities(&self, idx: usize) -> Result<MintCapabilities> {
+        tracing::debug!("wallet_mint_capabilities({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let capabilities = wallet.read().await.mint_capabilities().await?;
+        Ok(capabilities)
+    }
+
+    pub async fn wallet_clean_local_db(
+        &self,
+        idx: usize,
+        prune_counters: bool,
+    ) -> Result<LocalDbCleanup> {
+        tracing::debug!("wallet_clean_local_db({idx}, {prune_counters})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let cleanup = wallet.read().await.clean_local_db(prune_counters).await?;
+        Ok(cleanup)
+    }
+
+    // Reconciles the proofs of the given wallet with the mint, the recovery for a drifted state
+    pub async fn wallet_recheck(&self, idx: usize) -> Result<pocket::RecheckSummary> {
+        tracing::debug!("wallet_recheck({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let summary = wallet.read().await.recheck().await?;
+        Ok(summary)
+    }
+
+    pub async fn wallet_stats(&self, idx: usize) -> Result<WalletStats> {
+        tracing::debug!("wallet_stats({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let stats = wallet.read().await.stats().await?;
+        Ok(stats)
+    }
+
+    // Gives back the proofs of sends prepared but abandoned, e.g. by a crash before paying
+    pub async fn wallet_release_reservations(&self, idx: usize) -> Result<usize> {
+        tracing::debug!("wallet_release_reservations({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        let older_than = std::time::Duration::from_secs(config::STALE_RESERVATION_SECONDS);
+        let released = wallet
+            .read()
+            .await
+            .release_stale_reservations(older_than)
+            .await?;
+        Ok(released)
+    }
+
+    // Outgoing transactions of the given wallet that are still in flight
+    pub async fn wallet_list_pending_txs(&self, idx: usize) -> Result<Vec<PendingTransaction>> {
+        tracing::debug!("wallet_list_pending_txs({idx})");
+        let wallet = self.get_wallet(idx).await?;
+        let pending = wallet.read().await.list_pending_transactions().await?;
+        Ok(pending)
+    }
+
+    pub async fn wallet_retry_pending_tx(&self, idx: usize, tx_id: &str) -> Result<bool> {
+        tracing::debug!("wallet_retry_pending_tx({idx}, {tx_id})");
+
+        let tx_id = TransactionId::from_str(tx_id)?;
+        let wallet = self.get_wallet(idx).await?;
+        let retried = wallet
+            .read()
+            .await
+            .retry_pending(tx_id, &self.nostr_cl, &self.http_cl)
+            .await?;
+        Ok(retried)
+    }
+
     //////////////////////////////////////////////////// Wallet Dev Mode Calls
     pub async fn wallet_dev_mode_detailed_balance(
         &self,
@@ -668,6 +1273,16 @@ impl AppState {
         wallet.read().await.dev_mode_detailed_balance().await
     }
 
+    pub async fn wallet_credit_balance_by_keyset(
+        &self,
+        idx: usize,
+    ) -> Result<Vec<WalletDetailedBalanceEntry>> {
+        tracing::debug!("wallet_credit_balance_by_keyset({idx})");
+
+        let wallet = self.get_wallet(idx).await?;
+        wallet.read().await.credit_balance_by_keyset().await
+    }
+
     //////////////////////////////////////////////////// General App-Level calls
     /// Runs the regular jobs for each interval
     /// This should be called in an interval and on app initialization
@@ -762,6 +1377,19 @@ impl AppState {
                     );
                 }
             }
+            match self.wallet_release_reservations(*wallet_id as usize).await {
+                Ok(released) => {
+                    tracing::info!(
+                        "Released stale reservations for wallet {wallet_id}, released: {released}"
+                    );
+                }
+                Err(e) => {
+                    job_failed = true;
+                    tracing::error!(
+                        "Error running wallet_release_reservations job for wallet {wallet_id}: {e}"
+                    );
+                }
+            }
         }
 
         // successful = true
@@ -788,13 +1416,47 @@ pub fn generate_random_mnemonic(mnemonic_len: u32) -> String {
     }
 }
 
+// checked the same way as the config mnemonic, so a wallet can't fail on it afterwards
+pub fn validate_mnemonic(phrase: &str) -> MnemonicCheck {
+    let word_count = phrase.split_whitespace().count() as u32;
+    let error = match bip39::Mnemonic::from_str(phrase) {
+        Ok(_) => None,
+        Err(bip39::Error::BadWordCount(count)) => Some(format!(
+            "{count} words, a mnemonic has 12, 15, 18, 21 or 24 words"
+        )),
+        Err(bip39::Error::UnknownWord(idx)) => {
+            Some(format!("unknown word at position {}", idx + 1))
+        }
+        Err(bip39::Error::InvalidChecksum) => Some(String::from(
+            "invalid checksum, a word is misspelled or out of order",
+        )),
+        Err(e) => Some(e.to_string()),
+    };
+    MnemonicCheck {
+        valid: error.is_none(),
+        word_count,
+        error,
+    }
+}
+
 pub fn is_valid_token(token: &str) -> Result<Token> {
+    // older wallets still emit V3 tokens
+    if let Ok(cashu::Token::TokenV3(v3)) = cashu::Token::from_str(token) {
+        return wallet::util::token_from_v3(v3);
+    }
     let token = Token::from_str(token).map_err(|e| Error::InvalidToken(e.to_string()))?;
     Ok(token)
 }
 
 // FFI types
 
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct MnemonicCheck {
+    pub valid: bool,
+    pub word_count: u32,
+    pub error: Option<String>,
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct PaymentRequest {
     pub request: String,
@@ -816,13 +1478,13 @@ async fn create_new_wallet(
     name: String,
     network: bitcoin::Network,
     mint_url: cashu::MintUrl,
-    mnemonic: bip39::Mnemonic,
+    seed: Seed,
     db_version: u32,
     swap_expiry: chrono::TimeDelta,
+    keysets_ttl: chrono::TimeDelta,
     db: Arc<Database>,
 ) -> Result<wallet::Wallet> {
-    let seed = seed_from_mnemonic(&mnemonic);
-    let keypair = keypair_from_mnemonic(&mnemonic);
+    let keypair = keypair_from_seed(seed);
     let client = HttpClientExt::new(mint_url.clone());
 
     let wallet_id = build_wallet_id(&seed);
@@ -859,18 +1521,32 @@ async fn create_new_wallet(
         debit: debit_unit.to_owned(),
         pub_key: keypair.public_key(),
         betas,
+        coin_selection: types::CoinSelectionStrategy::default(),
+        keep_sent_tokens: false,
     };
-    build_wallet(w_cfg, client, db_version, swap_expiry, db, seed).await
+    build_wallet(
+        w_cfg,
+        client,
+        db_version,
+        swap_expiry,
+        keysets_ttl,
+        db,
+        seed,
+    )
+    .await
 }
 
 async fn build_wallet(
-    w_cfg: WalletConfig,
+    mut w_cfg: WalletConfig,
     client: HttpClientExt,
     db_version: u32,
     swap_expiry: chrono::TimeDelta,
+    keysets_ttl: chrono::TimeDelta,
     db: Arc<Database>,
     seed: Seed,
 ) -> Result<wallet::Wallet> {
+    w_cfg.mint_keyset_infos = wallet::util::dedup_keyset_infos(w_cfg.mint_keyset_infos)?;
+    wallet::util::check_mint_network(&client, w_cfg.debit.clone(), w_cfg.network).await?;
     // building wallet dbs
     let (tx_repo, (debitdb, mintmeltdb)) =
         build_wallet_dbs(db_version, &w_cfg.wallet_id, &w_cfg.debit, db).await?;
@@ -888,6 +1564,18 @@ async fn build_wallet(
         let beta_client = HttpClientExt::new(beta.clone());
         beta_clients.insert(beta, Arc::new(beta_client));
     }
+    let active_kids: Vec<cashu::Id> = w_cfg
+        .mint_keyset_infos
+        .iter()
+        .filter(|info| info.active)
+        .map(|info| info.id)
+        .collect();
+    if let Err(e) = client.warm_keysets(&active_kids).await {
+        tracing::warn!(
+            "Could not pre-fetch the active keysets of {}: {e}",
+            w_cfg.mint
+        );
+    }
     // Wrap the client with SentinelClient to send events to sentinel nodes
     let client = {
         let cl = external::mint::SentinelClient::new(client, w_cfg.betas);
@@ -906,6 +1594,9 @@ async fn build_wallet(
         beta_clients,
         Box::new(|url| Arc::new(external::mint::HttpClientExt::new(url))),
         swap_expiry,
+        keysets_ttl,
+        w_cfg.coin_selection,
+        w_cfg.keep_sent_tokens,
     )
     .await?;
     Ok(new_wallet)
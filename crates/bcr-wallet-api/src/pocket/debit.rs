@@ -2,7 +2,7 @@ use crate::{
     ClowderMintConnector,
     error::{Error, Result},
     pocket::*,
-    wallet::types::SwapConfig,
+    wallet::types::{CounterAdjustment, SwapConfig},
 };
 use async_trait::async_trait;
 use bcr_common::{
@@ -10,10 +10,13 @@ use bcr_common::{
         self, Amount, CurrencyUnit, KeySet, KeySetInfo, Proof, ProofsMethods, amount::SplitTarget,
         nut00 as cdk00, nut01 as cdk01,
     },
-    core::swap::wallet::{PaymentPlan, prepare_payment},
+    core::swap::wallet::{Error as SwapError, PaymentPlan, prepare_payment},
     wire::{common as wire_common, melt as wire_melt, mint as wire_mint, swap as wire_swap},
 };
-use bcr_wallet_core::types::{MeltSummary, MintSummary, Seed, SendSummary};
+use bcr_wallet_core::types::{
+    CoinSelectionStrategy, MeltSummary, MintQuoteSummary, MintSummary, RestoreProgressCallback,
+    SecretScheme, Seed, SendSummary,
+};
 use bcr_wallet_persistence::{MeltCommitmentRecord, MintMeltRepository, PocketRepository};
 use bitcoin::secp256k1;
 use std::{
@@ -55,6 +58,8 @@ pub trait DebitPocketApi: super::PocketApi {
         rid: Uuid,
         client: Arc<dyn ClowderMintConnector>,
     ) -> Result<(wire_melt::MeltTx, HashMap<cashu::PublicKey, cashu::Proof>)>;
+    /// drops a prepared melt and reverts its proofs to unspent
+    async fn cancel_melt(&self, rid: Uuid) -> Result<()>;
     async fn mint_onchain(
         &self,
         amount: bitcoin::Amount,
@@ -70,6 +75,20 @@ pub trait DebitPocketApi: super::PocketApi {
         swap_config: SwapConfig,
         clowder_id: bitcoin::secp256k1::PublicKey,
     ) -> Result<HashMap<Uuid, CheckPendingMintResult>>;
+    /// Request a bolt11 invoice to mint the given amount
+    async fn prepare_mint(
+        &self,
+        amount: Amount,
+        unit: CurrencyUnit,
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<MintQuoteSummary>;
+    /// Mint the proofs of a paid bolt11 quote
+    async fn mint(
+        &self,
+        quote_id: String,
+        keysets_info: &[KeySetInfo],
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<(Amount, Vec<cashu::PublicKey>)>;
     async fn protest_mint(
         &self,
         qid: Uuid,
@@ -95,6 +114,28 @@ pub trait DebitPocketApi: super::PocketApi {
         alpha_id: bitcoin::secp256k1::PublicKey,
     ) -> Result<MeltProtestResult>;
     async fn list_melt_commitments(&self) -> Result<Vec<(Uuid, u64)>>;
+    async fn backup_melt_commitments(&self) -> Result<Vec<MeltCommitmentBackup>>;
+    /// stores the backed up commitments, overwriting those of the same quote
+    async fn import_melt_commitments(&self, backups: Vec<MeltCommitmentBackup>) -> Result<usize>;
+    /// Store proofs locked to a n-of-m multisig including the signing key,
+    /// with our signature as partial witness
+    async fn receive_locked_proofs(
+        &self,
+        client: Arc<dyn ClowderMintConnector>,
+        keysets_info: &[KeySetInfo],
+        inputs: Vec<cdk00::Proof>,
+        signing_key: &cashu::SecretKey,
+    ) -> Result<(Amount, Vec<cashu::PublicKey>)>;
+    /// The partial witness of a locked proof, to be combined with the
+    /// signatures of the other parties before spending
+    async fn partial_sign_proof(&self, y: cashu::PublicKey) -> Result<cdk00::Witness>;
+    /// Adds the signatures of the other parties to a locked proof, once it holds
+    /// enough of them it is spendable like any unspent proof. Returns whether it is
+    async fn combine_partial_signatures(
+        &self,
+        y: cashu::PublicKey,
+        witnesses: Vec<cdk00::Witness>,
+    ) -> Result<bool>;
 }
 
 #[derive(Debug, Clone)]
@@ -109,6 +150,28 @@ pub struct MeltProtestResult {
     pub txid: Option<wire_melt::MeltTx>,
 }
 
+// WARN: holds the ephemeral secret needed to protest the melt
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MeltCommitmentBackup {
+    pub quote_id: Uuid,
+    pub expiry: u64,
+    pub commitment: secp256k1::schnorr::Signature,
+    pub ephemeral_secret: [u8; 32],
+    pub body_content: String,
+}
+
+impl From<MeltCommitmentRecord> for MeltCommitmentBackup {
+    fn from(record: MeltCommitmentRecord) -> Self {
+        Self {
+            quote_id: record.quote_id,
+            expiry: record.expiry,
+            commitment: record.commitment,
+            ephemeral_secret: record.ephemeral_secret.secret_bytes(),
+            body_content: record.body_content,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CheckPendingMintResult {
     pub amount: cashu::Amount,
@@ -119,6 +182,32 @@ pub struct CheckPendingMintResult {
 struct MeltReference {
     rid: Uuid,
     quote_id: Uuid,
+    // proofs moved to pending spent while preparing the melt
+    ys: Vec<cashu::PublicKey>,
+}
+
+// the keys of the n-of-m multisig the proof is locked to, none if it isn't
+fn multisig_keys(proof: &cdk00::Proof) -> Result<Vec<cashu::PublicKey>> {
+    let secret = cashu::nut10::Secret::try_from(&proof.secret)?;
+    let cashu::SpendingConditions::P2PKConditions { data, conditions } =
+        cashu::SpendingConditions::try_from(secret)?
+    else {
+        return Ok(Vec::new());
+    };
+    let Some(conditions) = conditions else {
+        return Ok(Vec::new());
+    };
+    if conditions.num_sigs.unwrap_or(1) < 2 {
+        return Ok(Vec::new());
+    }
+    let mut keys = vec![data];
+    keys.extend(conditions.pubkeys.unwrap_or_default());
+    Ok(keys)
+}
+
+// whether the proof is locked to a n-of-m multisig the given key is part of
+fn is_multisig_signer(proof: &cdk00::Proof, signer: &cashu::PublicKey) -> Result<bool> {
+    Ok(multisig_keys(proof)?.contains(signer))
 }
 
 ///////////////////////////////////////////// debit pocket
@@ -128,7 +217,8 @@ pub struct Pocket {
     pub mdb: Arc<dyn MintMeltRepository>,
     seed: Seed,
 
-    current_send: Mutex<Option<SendReference>>,
+    // prepared sends by request id, each holding the reservation of its inputs
+    prepared_sends: Mutex<HashMap<Uuid, SendReference>>,
     current_melt: Mutex<Option<MeltReference>>,
 }
 
@@ -144,11 +234,33 @@ impl Pocket {
             pdb,
             mdb,
             seed,
-            current_send: Mutex::new(None),
+            prepared_sends: Mutex::new(HashMap::new()),
             current_melt: Mutex::new(None),
         }
     }
 
+    // reserves the inputs of the prepared send, sends prepared earlier keep theirs
+    async fn reserve_send(&self, send_ref: SendReference) -> Result<()> {
+        let ys = send_ref.plan.ys();
+        let mut reserved = Vec::with_capacity(ys.len());
+        for y in ys {
+            match self.pdb.mark_as_reserved(y).await {
+                Ok(_) => reserved.push(y),
+                Err(e) => {
+                    // picked by a concurrent prepare in the meantime
+                    release_reserved(self.pdb.as_ref(), &reserved).await;
+                    return Err(e.into());
+                }
+            }
+        }
+        self.prepared_sends
+            .lock()
+            .unwrap()
+            .insert(send_ref.rid, send_ref);
+        Ok(())
+    }
+
+    // inputs of inactive keysets are fine, they are swapped to the active one
     fn validate_keysets<'inf>(
         &self,
         keysets_info: &'inf [KeySetInfo],
@@ -159,9 +271,6 @@ impl Pocket {
             if info.unit != self.unit {
                 return Err(Error::InvalidCurrencyUnit(info.unit.clone().to_string()));
             }
-            if !info.active {
-                return Err(Error::InactiveKeyset(info.id));
-            }
         }
         Ok(infos)
     }
@@ -195,19 +304,46 @@ impl Pocket {
         // create swap plan
         let swap_plan = prepare_swap(&swap_proofs, &kinfos)?;
         tracing::debug!("Digest proofs - swap plan: {swap_plan:?}");
+        // inactive keysets don't sign anymore, their outputs go to the active one
+        let active_kid = self
+            .find_debit_keysetid(keysets_info)
+            .ok()
+            .map(|info| info.id);
+        let mut output_plan: HashMap<cashu::Id, Amount> = HashMap::new();
+        for (kid, amount) in swap_plan {
+            let output_kid = if kinfos.get(&kid).is_some_and(|info| info.active) {
+                kid
+            } else {
+                active_kid.ok_or(Error::NoActiveKeyset)?
+            };
+            *output_plan.entry(output_kid).or_insert(Amount::ZERO) += amount;
+        }
 
         // collect keysets first as we don't want any failure once the swap request
         // has been made
-        let kids: HashSet<cashu::Id> = swap_proofs.iter().map(|p| p.keyset_id).collect();
+        let kids: HashSet<cashu::Id> = swap_proofs
+            .iter()
+            .map(|p| p.keyset_id)
+            .chain(output_plan.keys().copied())
+            .collect();
         let mut keysets: HashMap<cashu::Id, KeySet> = HashMap::new();
         for kid in kids.iter() {
             let keyset = client.get_mint_keyset(*kid).await?;
             keysets.insert(*kid, keyset);
         }
+        // garbage from a sender is rejected before the counters move or the swap fails
+        for (y, proof) in ys.iter().zip(swap_proofs.iter()) {
+            let known_amount = keysets
+                .get(&proof.keyset_id)
+                .is_some_and(|keyset| keyset.keys.amount_key(proof.amount).is_some());
+            if !known_amount {
+                return Err(Error::InvalidProof(*y));
+            }
+        }
 
         // prepare the premints
         let mut premints: HashMap<cashu::Id, cdk00::PreMintSecrets> = HashMap::new();
-        for (kid, amount) in swap_plan {
+        for (kid, amount) in output_plan {
             let counter = self.pdb.counter(kid).await?;
             let premint = cdk00::PreMintSecrets::from_seed(
                 kid,
@@ -239,6 +375,7 @@ impl Pocket {
         &self,
         target_amount: Amount,
         keysets_info: &[KeySetInfo],
+        strategy: CoinSelectionStrategy,
     ) -> Result<(SendSummary, SendReference)> {
         let unspent_proofs = self.pdb.list_unspent().await?;
         let mut proofs: Vec<Proof> = unspent_proofs.values().cloned().collect();
@@ -249,25 +386,26 @@ impl Pocket {
         let kinfos: HashMap<cashu::Id, KeySetInfo> =
             infos.iter().map(|(k, v)| (*k, (*v).clone())).collect();
 
-        let payment_plan = prepare_payment(&proofs, target_amount, &kinfos)?;
-        let (pocket_summary, send_ref) = match payment_plan {
-            PaymentPlan::Ready { inputs, .. } => {
-                let mut pocket_summary = SendSummary::new();
-                pocket_summary.amount = target_amount;
-                pocket_summary.unit = self.unit.clone();
-
-                let send_ref = SendReference {
-                    rid: pocket_summary.request_id,
-                    target_amount,
-                    plan: SendPlan::Ready {
-                        proofs: inputs
-                            .iter()
-                            .map(|proof| proof.y())
-                            .collect::<std::result::Result<Vec<cashu::PublicKey>, _>>()?,
-                    },
-                };
-                (pocket_summary, send_ref)
+        // exact change first, a swap only if there is none
+        if let Some(inputs) = select_exact_inputs(&proofs, target_amount, &kinfos, strategy) {
+            return self.ready_send(target_amount, &inputs);
+        }
+        let payment_plan = match prepare_payment(&proofs, target_amount, &kinfos) {
+            Ok(plan) => plan,
+            // the amount itself is covered, but not the fees on top
+            Err(SwapError::InsufficientBalance(available, needed))
+                if available >= target_amount =>
+            {
+                return Err(Error::InsufficientFundsForFee {
+                    available,
+                    needed,
+                    fee: needed.checked_sub(target_amount).unwrap_or(Amount::ZERO),
+                });
             }
+            Err(e) => return Err(e.into()),
+        };
+        let (pocket_summary, send_ref) = match payment_plan {
+            PaymentPlan::Ready { inputs, .. } => self.ready_send(target_amount, &inputs)?,
             PaymentPlan::NeedSplit {
                 proof,
                 target,
@@ -277,6 +415,7 @@ impl Pocket {
                 pocket_summary.amount = target_amount;
                 pocket_summary.unit = self.unit.clone();
                 pocket_summary.swap_fees = estimated_fee;
+                pocket_summary.needs_swap = true;
                 let SplitTarget::Value(split_amount) = target else {
                     return Err(Error::InvalidSplitTarget);
                 };
@@ -288,6 +427,7 @@ impl Pocket {
                         split_amount,
                         estimated_fee,
                     },
+                    scheme: SecretScheme::default(),
                 };
                 (pocket_summary, send_ref)
             }
@@ -296,6 +436,29 @@ impl Pocket {
         Ok((pocket_summary, send_ref))
     }
 
+    fn ready_send(
+        &self,
+        target_amount: Amount,
+        inputs: &[Proof],
+    ) -> Result<(SendSummary, SendReference)> {
+        let mut pocket_summary = SendSummary::new();
+        pocket_summary.amount = target_amount;
+        pocket_summary.unit = self.unit.clone();
+
+        let send_ref = SendReference {
+            rid: pocket_summary.request_id,
+            target_amount,
+            plan: SendPlan::Ready {
+                proofs: inputs
+                    .iter()
+                    .map(|proof| proof.y())
+                    .collect::<std::result::Result<Vec<cashu::PublicKey>, _>>()?,
+            },
+            scheme: SecretScheme::default(),
+        };
+        Ok((pocket_summary, send_ref))
+    }
+
     /// Construct proofs from blind signatures, swap them into the wallet, and return the result.
     async fn finalize_mint_proofs(
         &self,
@@ -383,39 +546,52 @@ impl super::PocketApi for Pocket {
     }
 
     async fn balance(&self, keysets_info: &[KeySetInfo]) -> Result<PocketBalance> {
-        let proofs: Vec<Proof> = self.pdb.list_unspent().await?.into_values().collect();
-        let mut debit = Amount::ZERO;
-        let mut credit = Amount::ZERO;
-
-        let infos = collect_keyset_infos_from_proofs(proofs.iter(), keysets_info)?;
-        let start_of_today = chrono::Utc::now()
-            .date_naive()
-            .and_hms_opt(0, 0, 0)
-            .expect("valid date")
-            .and_utc()
-            .timestamp() as u64;
-
-        for proof in proofs {
-            let info = infos
-                .get(&proof.keyset_id)
-                .ok_or(Error::UnknownKeysetId(proof.keyset_id))?;
-
-            // no final expiry -> debit
-            // final expiry before today -> debit
-            // final expiry today, or after -> credit
-            let is_credit = match info.final_expiry {
-                Some(expiry) => expiry >= start_of_today,
-                None => false,
-            };
+        // queried concurrently, the errors are still checked in order
+        // the unspent proofs are only summed up, they are the bulk of the pocket
+        let (unspent, pending, reserved) = futures::join!(
+            self.pdb.sum_unspent(),
+            self.pdb.list_pending(),
+            self.pdb.list_reserved()
+        );
+        let unspent = unspent?;
+        let pending: Vec<Proof> = pending?.into_values().collect();
+        let reserved: Vec<Proof> = reserved?.into_values().collect();
+
+        let kids = unspent.keys().copied().chain(
+            pending
+                .iter()
+                .chain(reserved.iter())
+                .map(|proof| proof.keyset_id),
+        );
+        let infos = collect_keyset_infos(kids, keysets_info)?;
+        let is_credit = |kid: &cashu::Id| -> Result<bool> {
+            let info = infos.get(kid).ok_or(Error::UnknownKeysetId(*kid))?;
+            Ok(is_credit_keyset(info.final_expiry))
+        };
 
-            if is_credit {
-                credit += proof.amount;
+        let mut balance = PocketBalance::default();
+        for (kid, amount) in unspent {
+            if is_credit(&kid)? {
+                balance.credit += amount;
             } else {
-                debit += proof.amount;
+                balance.debit += amount;
             }
         }
-
-        Ok(PocketBalance { debit, credit })
+        for proof in pending {
+            if is_credit(&proof.keyset_id)? {
+                balance.credit_pending += proof.amount;
+            } else {
+                balance.debit_pending += proof.amount;
+            }
+        }
+        for proof in reserved {
+            if is_credit(&proof.keyset_id)? {
+                balance.credit_reserved += proof.amount;
+            } else {
+                balance.debit_reserved += proof.amount;
+            }
+        }
+        Ok(balance)
     }
 
     async fn receive_proofs(
@@ -441,9 +617,56 @@ impl super::PocketApi for Pocket {
         &self,
         target: Amount,
         keysets_info: &[KeySetInfo],
+        scheme: SecretScheme,
+        strategy: CoinSelectionStrategy,
+    ) -> Result<SendSummary> {
+        let (mut summary, mut send_ref) = self
+            .compute_send_costs(target, keysets_info, strategy)
+            .await?;
+        summary.scheme = scheme;
+        send_ref.scheme = scheme;
+        self.reserve_send(send_ref).await?;
+        Ok(summary)
+    }
+
+    async fn estimate_send(
+        &self,
+        target: Amount,
+        keysets_info: &[KeySetInfo],
+        strategy: CoinSelectionStrategy,
+    ) -> Result<SendSummary> {
+        let (summary, _) = self
+            .compute_send_costs(target, keysets_info, strategy)
+            .await?;
+        Ok(summary)
+    }
+
+    async fn prepare_send_max(
+        &self,
+        keysets_info: &[KeySetInfo],
+        scheme: SecretScheme,
+        strategy: CoinSelectionStrategy,
     ) -> Result<SendSummary> {
-        let (summary, send_ref) = self.compute_send_costs(target, keysets_info).await?;
-        *self.current_send.lock().unwrap() = Some(send_ref);
+        let proofs: Vec<Proof> = self.pdb.list_unspent().await?.into_values().collect();
+        collect_keyset_infos_from_proofs(proofs.iter(), keysets_info)?;
+        let total = proofs.total_amount()?;
+        if total == Amount::ZERO {
+            return Err(Error::InsufficientBalance(total, Amount::ONE));
+        }
+        // the balance less the input fees of spending every proof
+        let fee = crate::wallet::util::input_fee(&proofs, keysets_info)?;
+        let Some(max) = total.checked_sub(fee).filter(|max| *max > Amount::ZERO) else {
+            return Err(Error::InsufficientFundsForFee {
+                available: total,
+                needed: fee + Amount::ONE,
+                fee,
+            });
+        };
+        let (mut summary, mut send_ref) =
+            self.compute_send_costs(max, keysets_info, strategy).await?;
+        summary.scheme = scheme;
+        send_ref.scheme = scheme;
+        self.reserve_send(send_ref).await?;
         Ok(summary)
     }
 
@@ -454,16 +677,12 @@ impl super::PocketApi for Pocket {
         client: Arc<dyn ClowderMintConnector>,
         swap_config: SwapConfig,
     ) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
-        let send_ref = {
-            let mut locked = self.current_send.lock().unwrap();
-            if locked.is_none() {
-                return Err(Error::NoPrepareRef(rid));
-            }
-            if locked.as_ref().unwrap().rid != rid {
-                return Err(Error::NoPrepareRef(rid));
-            }
-            locked.take().unwrap()
-        };
+        let send_ref = self
+            .prepared_sends
+            .lock()
+            .unwrap()
+            .remove(&rid)
+            .ok_or(Error::NoPrepareRef(rid))?;
         let sending_proofs = send_proofs(
             send_ref.plan,
             keysets_info,
@@ -472,12 +691,103 @@ impl super::PocketApi for Pocket {
             self.pdb.as_ref(),
             &client,
             swap_config,
+            send_ref.scheme,
         )
         .await?;
 
         Ok(sending_proofs)
     }
 
+    async fn cancel_send(&self, rid: Uuid) -> Result<()> {
+        let send_ref = self
+            .prepared_sends
+            .lock()
+            .unwrap()
+            .remove(&rid)
+            .ok_or(Error::NoPrepareRef(rid))?;
+        release_reserved(self.pdb.as_ref(), &send_ref.plan.ys()).await;
+        Ok(())
+    }
+
+    async fn replace_sent_proofs(
+        &self,
+        sent: &[cdk01::PublicKey],
+        locked: Vec<cdk00::Proof>,
+        change: Vec<cdk00::Proof>,
+    ) -> Result<()> {
+        for proof in locked {
+            self.pdb.store_pendingspent(proof).await?;
+        }
+        if !change.is_empty() {
+            self.pdb.store_new_batch(change).await?;
+        }
+        for y in sent {
+            self.pdb.mark_pending_as_spent(*y).await?;
+        }
+        Ok(())
+    }
+
+    async fn change_premint(
+        &self,
+        amount: Amount,
+        keysets_info: &[KeySetInfo],
+    ) -> Result<cdk00::PreMintSecrets> {
+        let kid = self.find_debit_keysetid(keysets_info)?.id;
+        let counter = self.pdb.counter(kid).await?;
+        let premint =
+            cdk00::PreMintSecrets::from_seed(kid, counter, &self.seed, amount, &SplitTarget::None)?;
+        self.pdb
+            .increment_counter(kid, counter, premint.len() as u32)
+            .await?;
+        Ok(premint)
+    }
+
+    async fn revert_sent_proofs(&self, sent: &[cdk01::PublicKey]) -> Result<()> {
+        revert_pendingspent(self.pdb.as_ref(), sent).await;
+        Ok(())
+    }
+
+    async fn release_stale_reservations(&self, tstamp: u64) -> Result<usize> {
+        let mut stale = self.pdb.list_reserved_before(tstamp).await?;
+        // a prepared send holding stale proofs was abandoned, it goes as a whole
+        let abandoned: Vec<SendReference> = {
+            let mut sends = self.prepared_sends.lock().unwrap();
+            let rids: Vec<Uuid> = sends
+                .values()
+                .filter(|send_ref| send_ref.plan.ys().iter().any(|y| stale.contains(y)))
+                .map(|send_ref| send_ref.rid)
+                .collect();
+            rids.iter().filter_map(|rid| sends.remove(rid)).collect()
+        };
+        for y in abandoned.iter().flat_map(|send_ref| send_ref.plan.ys()) {
+            if !stale.contains(&y) {
+                stale.push(y);
+            }
+        }
+        release_reserved(self.pdb.as_ref(), &stale).await;
+        Ok(stale.len())
+    }
+
+    async fn send_proofs_offline(
+        &self,
+        rid: Uuid,
+    ) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
+        let send_ref = {
+            let mut sends = self.prepared_sends.lock().unwrap();
+            match sends.get(&rid) {
+                Some(send_ref) if matches!(send_ref.plan, SendPlan::NeedSplit { .. }) => {
+                    return Err(Error::OfflineSendNotPossible);
+                }
+                Some(_) => {}
+                None => return Err(Error::NoPrepareRef(rid)),
+            }
+            sends.remove(&rid).expect("checked above")
+        };
+        let (_, sending_proofs) =
+            return_proofs_to_send_for_offline_payment(send_ref.plan, self.pdb.as_ref()).await?;
+        Ok(sending_proofs)
+    }
+
     async fn cleanup_local_proofs(
         &self,
         client: Arc<dyn ClowderMintConnector>,
@@ -486,26 +796,161 @@ impl super::PocketApi for Pocket {
         Ok(cleaned_ys)
     }
 
+    async fn prune_counters(&self, keysets_info: &[KeySetInfo]) -> Result<usize> {
+        prune_counters(self.pdb.as_ref(), keysets_info).await
+    }
+
     async fn restore_local_proofs(
         &self,
         keysets_info: &[KeySetInfo],
         client: Arc<dyn ClowderMintConnector>,
+        progress: Option<RestoreProgressCallback>,
+    ) -> Result<usize> {
+        let kids: Vec<cashu::Id> = keysets_info.iter().map(|info| info.id).collect();
+        self.restore_keysets(&kids, keysets_info, client, progress)
+            .await
+    }
+
+    async fn restore_keysets(
+        &self,
+        kids: &[cashu::Id],
+        keysets_info: &[KeySetInfo],
+        client: Arc<dyn ClowderMintConnector>,
+        progress: Option<RestoreProgressCallback>,
     ) -> Result<usize> {
         let kids = keysets_info.iter().filter_map(|info| {
-            if info.unit == self.unit {
+            if info.unit == self.unit && kids.contains(&info.id) {
                 Some(info.id)
             } else {
                 None
             }
         });
+        // keysets are restored concurrently, each one only moves its own counter
+        // progress is reported per keyset, so reports of different keysets interleave
+        let restores = kids.map(|kid| {
+            restore::restore_keysetid(
+                &self.seed,
+                kid,
+                &client,
+                self.pdb.as_ref(),
+                progress.as_ref(),
+            )
+        });
         let mut total_recovered = 0;
-        for kid in kids.into_iter() {
-            total_recovered +=
-                restore::restore_keysetid(&self.seed, kid, &client, self.pdb.as_ref()).await?;
+        for restored in futures::future::join_all(restores).await {
+            total_recovered += restored?;
         }
         Ok(total_recovered)
     }
 
+    async fn rebuild_counters(
+        &self,
+        keysets_info: &[KeySetInfo],
+    ) -> Result<HashMap<cashu::Id, u32>> {
+        let ys = self.pdb.list_all().await?;
+        let proofs = self.pdb.load_proofs(&ys).await?;
+        let mut secrets = HashMap::<cashu::Id, HashSet<cashu::secret::Secret>>::new();
+        for proof in proofs.into_values() {
+            secrets
+                .entry(proof.keyset_id)
+                .or_default()
+                .insert(proof.secret);
+        }
+
+        let mut counters = HashMap::new();
+        for info in keysets_info.iter().filter(|info| info.unit == self.unit) {
+            let Some(kid_secrets) = secrets.get(&info.id) else {
+                continue;
+            };
+            let counter = restore::rebuild_keysetid_counter(
+                &self.seed,
+                info.id,
+                kid_secrets,
+                self.pdb.as_ref(),
+                restore::COUNTER_REBUILD_BOUND,
+            )
+            .await?;
+            counters.insert(info.id, counter);
+        }
+        Ok(counters)
+    }
+
+    async fn verify_counters(
+        &self,
+        keysets_info: &[KeySetInfo],
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<Vec<CounterAdjustment>> {
+        let mut adjusted = Vec::new();
+        for info in keysets_info.iter().filter(|info| info.unit == self.unit) {
+            let res =
+                restore::verify_keysetid_counter(&self.seed, info.id, &client, self.pdb.as_ref())
+                    .await?;
+            adjusted.extend(res);
+        }
+        Ok(adjusted)
+    }
+
+    async fn backup_proofs(&self) -> Result<PocketBackup> {
+        let unspent = self.pdb.list_unspent().await?;
+        let pending = self.pdb.list_pending().await?;
+        let locked = self.pdb.list_locked().await?;
+        Ok(PocketBackup {
+            unspent: unspent.into_values().collect(),
+            pending: pending.into_values().collect(),
+            locked: locked.into_values().collect(),
+        })
+    }
+
+    async fn import_backup(&self, backup: PocketBackup) -> Result<usize> {
+        let known: HashSet<cashu::PublicKey> = self.pdb.list_all().await?.into_iter().collect();
+        let known_locked = self.pdb.list_locked().await?;
+        let is_new = |proof: &cdk00::Proof| -> Result<bool> {
+            let y = proof.y()?;
+            Ok(!known.contains(&y) && !known_locked.contains_key(&y))
+        };
+
+        let mut imported = 0;
+        for proof in backup.unspent {
+            if is_new(&proof)? {
+                self.pdb.store_new(proof).await?;
+                imported += 1;
+            }
+        }
+        for proof in backup.pending {
+            if is_new(&proof)? {
+                self.pdb.store_pendingspent(proof).await?;
+                imported += 1;
+            }
+        }
+        for proof in backup.locked {
+            if is_new(&proof)? {
+                self.pdb.store_locked(proof).await?;
+                imported += 1;
+            }
+        }
+        Ok(imported)
+    }
+
+    async fn contains(&self, ys: &[cdk01::PublicKey]) -> Result<Vec<cdk01::PublicKey>> {
+        let (known, pending) = futures::join!(self.pdb.load_proofs(ys), self.pdb.list_pending());
+        let known = known?;
+        // pending ones may be our own token coming back, receiving them reclaims it
+        let pending = pending?;
+        Ok(known
+            .into_keys()
+            .filter(|y| !pending.contains_key(y))
+            .collect())
+    }
+
+    async fn pending(&self, ys: &[cdk01::PublicKey]) -> Result<Vec<cdk01::PublicKey>> {
+        let pending = self.pdb.list_pending().await?;
+        Ok(ys
+            .iter()
+            .filter(|y| pending.contains_key(y))
+            .copied()
+            .collect())
+    }
+
     async fn delete_proofs(&self) -> Result<HashMap<cashu::Id, Vec<cdk00::Proof>>> {
         let proofs = self.pdb.list_all().await?;
 
@@ -527,16 +972,12 @@ impl super::PocketApi for Pocket {
         &self,
         rid: Uuid,
     ) -> Result<(Amount, HashMap<cdk01::PublicKey, cdk00::Proof>)> {
-        let send_ref = {
-            let mut locked = self.current_send.lock().unwrap();
-            if locked.is_none() {
-                return Err(Error::NoPrepareRef(rid));
-            }
-            if locked.as_ref().unwrap().rid != rid {
-                return Err(Error::NoPrepareRef(rid));
-            }
-            locked.take().unwrap()
-        };
+        let send_ref = self
+            .prepared_sends
+            .lock()
+            .unwrap()
+            .remove(&rid)
+            .ok_or(Error::NoPrepareRef(rid))?;
         let proofs_to_send =
             return_proofs_to_send_for_offline_payment(send_ref.plan, self.pdb.as_ref()).await?;
         Ok(proofs_to_send)
@@ -648,6 +1089,44 @@ impl super::PocketApi for Pocket {
 
         Ok(balances)
     }
+
+    async fn recheck(
+        &self,
+        keep: &[cashu::PublicKey],
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<RecheckSummary> {
+        // the prepared melt already moved its proofs to pending spent
+        let mut keep = keep.to_vec();
+        if let Some(melt_ref) = self.current_melt.lock().unwrap().as_ref() {
+            keep.extend(melt_ref.ys.iter().copied());
+        }
+        recheck_local_proofs(self.pdb.as_ref(), client, &keep).await
+    }
+
+    async fn stats(&self) -> Result<PocketStats> {
+        let (unspent, pending, reserved, all, random) = futures::join!(
+            self.pdb.list_unspent(),
+            self.pdb.list_pending(),
+            self.pdb.list_reserved(),
+            self.pdb.list_all(),
+            self.pdb.list_random()
+        );
+        let (unspent, pending, reserved) = (unspent?, pending?, reserved?);
+        let keysets = unspent
+            .values()
+            .chain(pending.values())
+            .chain(reserved.values())
+            .map(|proof| proof.keyset_id)
+            .collect::<HashSet<_>>();
+        Ok(PocketStats {
+            unspent: unspent.len(),
+            pending: pending.len(),
+            reserved: reserved.len(),
+            total: all?.len(),
+            keysets: keysets.len(),
+            random: random?.len(),
+        })
+    }
 }
 
 #[async_trait]
@@ -682,10 +1161,8 @@ impl DebitPocketApi for Pocket {
         let remove_set: HashSet<&cashu::PublicKey> = pending_txs_ys.iter().collect();
         pendings.retain(|k, _| !remove_set.contains(k));
 
-        let req = cdk07::CheckStateRequest {
-            ys: pendings.keys().cloned().collect(),
-        };
-        let states = client.post_check_state(req).await?;
+        let pending_ys: Vec<cashu::PublicKey> = pendings.keys().cloned().collect();
+        let states = check_states(client.as_ref(), &pending_ys).await?;
         let mut to_digest = HashMap::new();
         for state in states.iter() {
             match state.state {
@@ -758,7 +1235,12 @@ impl DebitPocketApi for Pocket {
         let btc_amount = bitcoin::Amount::from_sat(amount);
 
         let (_, send_ref) = self
-            .compute_send_costs(Amount::from(amount), keysets_info)
+            .compute_send_costs(
+                Amount::from(amount),
+                keysets_info,
+                // melts avoid a swap before the quote where they can
+                CoinSelectionStrategy::MinimizeSwaps,
+            )
             .await?;
 
         let sending_proofs = send_proofs(
@@ -769,6 +1251,7 @@ impl DebitPocketApi for Pocket {
             self.pdb.as_ref(),
             &client,
             swap_config.clone(),
+            send_ref.scheme,
         )
         .await?;
         let sent_ys: Vec<cdk01::PublicKey> = sending_proofs.keys().cloned().collect();
@@ -795,13 +1278,7 @@ impl DebitPocketApi for Pocket {
         let (quote_id, expiry) = match quote_and_record {
             Ok(r) => r,
             Err(e) => {
-                for y in &sent_ys {
-                    if let Err(revert_err) = self.pdb.revert_pendingspent_to_unspent(*y).await {
-                        tracing::error!(
-                            "failed to revert proof {y} to unspent after melt prepare failure: {revert_err}"
-                        );
-                    }
-                }
+                revert_pendingspent(self.pdb.as_ref(), &sent_ys).await;
                 return Err(e);
             }
         };
@@ -812,6 +1289,7 @@ impl DebitPocketApi for Pocket {
         let melt_ref = MeltReference {
             rid: summary.request_id,
             quote_id,
+            ys: sent_ys,
         };
         self.current_melt.lock().unwrap().replace(melt_ref);
         Ok(summary)
@@ -828,22 +1306,52 @@ impl DebitPocketApi for Pocket {
             return Err(Error::NoPrepareRef(rid));
         }
 
-        let record = self.mdb.load_melt_commitment(melt_ref.quote_id).await?;
-        let body: wire_melt::MeltQuoteOnchainResponseBody =
-            bcr_common::core::signature::deserialize_borsh_msg(&record.body_content)?;
-        let input_ys: Vec<cashu::PublicKey> = body.inputs.iter().map(|fp| fp.y).collect();
-        let sending_proofs = self.pdb.load_proofs(&input_ys).await?;
+        let melt = async {
+            let record = self.mdb.load_melt_commitment(melt_ref.quote_id).await?;
+            let body: wire_melt::MeltQuoteOnchainResponseBody =
+                bcr_common::core::signature::deserialize_borsh_msg(&record.body_content)?;
+            let input_ys: Vec<cashu::PublicKey> = body.inputs.iter().map(|fp| fp.y).collect();
+            let sending_proofs = self.pdb.load_proofs(&input_ys).await?;
 
-        let request = wire_melt::MeltOnchainRequest {
-            quote: melt_ref.quote_id,
-            inputs: sending_proofs.values().cloned().collect(),
+            let request = wire_melt::MeltOnchainRequest {
+                quote: melt_ref.quote_id,
+                inputs: sending_proofs.values().cloned().collect(),
+            };
+            let response = client.post_melt_onchain(request).await?;
+            Ok::<_, Error>((response, sending_proofs))
+        }
+        .await;
+        let (response, sending_proofs) = match melt {
+            Ok(r) => r,
+            Err(e) => {
+                // the commitment stays, in case the mint melted the proofs anyway
+                revert_pendingspent(self.pdb.as_ref(), &melt_ref.ys).await;
+                return Err(e);
+            }
         };
-        let response = client.post_melt_onchain(request).await?;
 
         self.mdb.delete_melt_commitment(melt_ref.quote_id).await?;
         Ok((response.txid, sending_proofs))
     }
 
+    async fn cancel_melt(&self, rid: Uuid) -> Result<()> {
+        let melt_ref = {
+            let mut locked = self.current_melt.lock().unwrap();
+            match locked.as_ref() {
+                Some(melt_ref) if melt_ref.rid == rid => locked.take(),
+                _ => None,
+            }
+        };
+        let melt_ref = melt_ref.ok_or(Error::NoPrepareRef(rid))?;
+
+        for y in melt_ref.ys.iter() {
+            self.pdb.revert_pendingspent_to_unspent(*y).await?;
+        }
+        // the commitment stays: it is the only proof of what the mint quoted for the inputs,
+        // in case the mint melts them anyway the pending melt check can still protest
+        Ok(())
+    }
+
     async fn mint_onchain(
         &self,
         amount: bitcoin::Amount,
@@ -959,26 +1467,95 @@ impl DebitPocketApi for Pocket {
         Ok(res)
     }
 
-    async fn check_pending_commitments(&self, tstamp: u64) -> Result<()> {
-        let commitments = self.pdb.list_commitments().await?;
-        tracing::debug!(
-            "check pending commitments for {} entries",
-            commitments.len()
-        );
-        for record in commitments {
-            if record.expiry < tstamp {
-                tracing::warn!(
-                    "Swap commitment {} expired at {} (now: {tstamp}) - deleting record.",
-                    record.commitment,
-                    record.expiry,
-                );
-                self.pdb.delete_commitment(record.commitment).await?;
-            }
-        }
-        Ok(())
-    }
-
-    async fn protest_mint(
+    async fn prepare_mint(
+        &self,
+        amount: Amount,
+        unit: CurrencyUnit,
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<MintQuoteSummary> {
+        if unit != self.unit {
+            return Err(Error::InvalidCurrencyUnit(unit.to_string()));
+        }
+        let request = cashu::MintQuoteBolt11Request {
+            amount,
+            unit: unit.clone(),
+            description: None,
+            pubkey: None,
+        };
+        let response = client.post_mint_quote_bolt11(request).await?;
+        Ok(MintQuoteSummary {
+            quote_id: response.quote,
+            request: response.request,
+            amount,
+            unit,
+            expiry: response.expiry,
+        })
+    }
+
+    async fn mint(
+        &self,
+        quote_id: String,
+        keysets_info: &[KeySetInfo],
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<(Amount, Vec<cashu::PublicKey>)> {
+        let quote = client.get_mint_quote_bolt11(quote_id.clone()).await?;
+        if quote.state != cashu::MintQuoteState::Paid {
+            tracing::info!("Mint {quote_id} - quote is {}", quote.state);
+            return Err(Error::MintingError(quote_id));
+        }
+        let Some(amount) = quote.amount else {
+            tracing::error!("Mint {quote_id} - quote without amount");
+            return Err(Error::MintingError(quote_id));
+        };
+
+        let active_info = self.find_debit_keysetid(keysets_info)?;
+        let kid = active_info.id;
+        // fetch the keyset first as we don't want any failure once the mint request
+        // has been made
+        let keyset = client.get_mint_keyset(kid).await?;
+        let counter = self.pdb.counter(kid).await?;
+        let premint =
+            cdk00::PreMintSecrets::from_seed(kid, counter, &self.seed, amount, &SplitTarget::None)?;
+        self.pdb
+            .increment_counter(kid, counter, premint.len() as u32)
+            .await?;
+
+        let request = cashu::MintRequest {
+            quote: quote_id.clone(),
+            outputs: premint.blinded_messages(),
+            signature: None,
+        };
+        let response = client.post_mint_bolt11(request).await?;
+        let proofs = unblind_proofs(&keyset, response.signatures, premint);
+
+        let minted = proofs
+            .iter()
+            .fold(Amount::ZERO, |acc, proof| acc + proof.amount);
+        let ys = self.pdb.store_new_batch(proofs).await?;
+        tracing::info!("Minted {quote_id} successfully for {minted}");
+        Ok((minted, ys))
+    }
+
+    async fn check_pending_commitments(&self, tstamp: u64) -> Result<()> {
+        let commitments = self.pdb.list_commitments().await?;
+        tracing::debug!(
+            "check pending commitments for {} entries",
+            commitments.len()
+        );
+        for record in commitments {
+            if record.expiry < tstamp {
+                tracing::warn!(
+                    "Swap commitment {} expired at {} (now: {tstamp}) - deleting record.",
+                    record.commitment,
+                    record.expiry,
+                );
+                self.pdb.delete_commitment(record.commitment).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn protest_mint(
         &self,
         qid: Uuid,
         keysets_info: &[KeySetInfo],
@@ -1177,6 +1754,118 @@ impl DebitPocketApi for Pocket {
             .map(|r| (r.quote_id, r.expiry))
             .collect())
     }
+
+    async fn backup_melt_commitments(&self) -> Result<Vec<MeltCommitmentBackup>> {
+        let records = self.mdb.list_melt_commitments().await?;
+        Ok(records
+            .into_iter()
+            .map(MeltCommitmentBackup::from)
+            .collect())
+    }
+
+    async fn import_melt_commitments(&self, backups: Vec<MeltCommitmentBackup>) -> Result<usize> {
+        // all of them are checked before any is stored
+        let records = backups
+            .into_iter()
+            .map(|backup| {
+                let ephemeral_secret = secp256k1::SecretKey::from_slice(&backup.ephemeral_secret)
+                    .map_err(|e| Error::InvalidBackup(e.to_string()))?;
+                Ok(MeltCommitmentRecord {
+                    quote_id: backup.quote_id,
+                    expiry: backup.expiry,
+                    commitment: backup.commitment,
+                    ephemeral_secret,
+                    body_content: backup.body_content,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let imported = records.len();
+        for record in records {
+            self.mdb.store_melt_commitment(record).await?;
+        }
+        Ok(imported)
+    }
+
+    async fn receive_locked_proofs(
+        &self,
+        client: Arc<dyn ClowderMintConnector>,
+        keysets_info: &[KeySetInfo],
+        inputs: Vec<cdk00::Proof>,
+        signing_key: &cashu::SecretKey,
+    ) -> Result<(Amount, Vec<cashu::PublicKey>)> {
+        self.validate_keysets(keysets_info, &inputs)?;
+        let signer = signing_key.public_key();
+        let mut ys = Vec::with_capacity(inputs.len());
+        for input in inputs.iter() {
+            if !is_multisig_signer(input, &signer)? {
+                return Err(Error::SpendingConditions);
+            }
+            ys.push(input.y()?);
+        }
+        // the proofs are not swapped, so we have to check they are still valid
+        let states = check_states(client.as_ref(), &ys).await?;
+        if let Some(state) = states
+            .iter()
+            .find(|state| state.state != cdk07::State::Unspent)
+        {
+            return Err(Error::InvalidToken(format!(
+                "locked proof {} is {}",
+                state.y, state.state
+            )));
+        }
+
+        let mut received = Amount::ZERO;
+        for mut proof in inputs.into_iter() {
+            proof.sign_p2pk(signing_key.clone())?;
+            received += proof.amount;
+            self.pdb.store_locked(proof).await?;
+        }
+        Ok((received, ys))
+    }
+
+    async fn partial_sign_proof(&self, y: cashu::PublicKey) -> Result<cdk00::Witness> {
+        let proof = self.pdb.load_locked(y).await?;
+        proof.witness.ok_or(Error::InvalidToken(format!(
+            "locked proof {y} has no witness"
+        )))
+    }
+
+    async fn combine_partial_signatures(
+        &self,
+        y: cashu::PublicKey,
+        witnesses: Vec<cdk00::Witness>,
+    ) -> Result<bool> {
+        let mut proof = self.pdb.load_locked(y).await?;
+        let keys = multisig_keys(&proof)?;
+        let msg = proof.secret.to_bytes();
+        let mut signatures = proof
+            .witness
+            .as_ref()
+            .and_then(|witness| witness.signatures())
+            .unwrap_or_default();
+        for signature in witnesses.iter().filter_map(|w| w.signatures()).flatten() {
+            if signatures.contains(&signature) {
+                continue;
+            }
+            // a bad signature would keep the proof unspendable for good
+            let parsed = signature
+                .parse::<secp256k1::schnorr::Signature>()
+                .map_err(|_| Error::SpendingConditions)?;
+            if !keys.iter().any(|key| key.verify(&msg, &parsed).is_ok()) {
+                return Err(Error::SpendingConditions);
+            }
+            signatures.push(signature);
+        }
+        proof.witness = Some(cdk00::Witness::P2PKWitness(cashu::nut11::P2PKWitness {
+            signatures,
+        }));
+        if proof.verify_p2pk().is_ok() {
+            self.pdb.release_locked(proof).await?;
+            return Ok(true);
+        }
+        self.pdb.store_locked(proof).await?;
+        Ok(false)
+    }
 }
 
 #[cfg(test)]
@@ -1191,7 +1880,7 @@ mod tests {
     use bcr_common::{core_tests, wire::mint::MintResponse};
     use bcr_wallet_persistence::{
         MockMintMeltRepository, MockPocketRepository,
-        test_utils::tests::valid_payment_address_testnet,
+        test_utils::tests::{in_memory_wallet_dbs, valid_payment_address_testnet},
     };
     use mockall::predicate::*;
 
@@ -1213,13 +1902,16 @@ mod tests {
         let mut pdb = MockPocketRepository::new();
         let mdb = MockMintMeltRepository::new();
 
-        let proofs_clone = proofs.clone();
-        pdb.expect_list_unspent().times(1).returning(move || {
-            let mut map = HashMap::new();
-            map.insert(proofs_clone[0].y().unwrap(), proofs_clone[0].clone());
-            map.insert(proofs_clone[1].y().unwrap(), proofs_clone[1].clone());
-            Ok(map)
-        });
+        let kid = proofs[0].keyset_id;
+        pdb.expect_sum_unspent()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(kid, Amount::from(24u64))])));
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
+        pdb.expect_list_reserved()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let balance = pocket.balance(&k_infos).await.expect("balance works");
@@ -1227,6 +1919,51 @@ mod tests {
         assert_eq!(balance.debit, Amount::from(24u64))
     }
 
+    #[tokio::test]
+    async fn contains_skips_pending_proofs() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<cdk01::PublicKey> = proofs.iter().map(|p| p.y().unwrap()).collect();
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+
+        let proofs_clone = proofs.clone();
+        pdb.expect_load_proofs().times(1).returning(move |_| {
+            Ok(proofs_clone
+                .iter()
+                .map(|p| (p.y().unwrap(), p.clone()))
+                .collect())
+        });
+        let pending = proofs[1].clone();
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(pending.y().unwrap(), pending.clone())])));
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let known = pocket.contains(&ys).await.unwrap();
+        assert_eq!(known, vec![ys[0]]);
+    }
+
+    #[tokio::test]
+    async fn pending_keeps_only_pending_proofs() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<cdk01::PublicKey> = proofs.iter().map(|p| p.y().unwrap()).collect();
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+
+        let pending = proofs[1].clone();
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(pending.y().unwrap(), pending.clone())])));
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let pending = pocket.pending(&ys).await.unwrap();
+        assert_eq!(pending, vec![ys[1]]);
+    }
+
     #[tokio::test]
     async fn credit_balance_keyset_expiring_in_future() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -1240,13 +1977,16 @@ mod tests {
         let mut pdb = MockPocketRepository::new();
         let mdb = MockMintMeltRepository::new();
 
-        let proofs_clone = proofs.clone();
-        pdb.expect_list_unspent().times(1).returning(move || {
-            let mut map = HashMap::new();
-            map.insert(proofs_clone[0].y().unwrap(), proofs_clone[0].clone());
-            map.insert(proofs_clone[1].y().unwrap(), proofs_clone[1].clone());
-            Ok(map)
-        });
+        let kid = proofs[0].keyset_id;
+        pdb.expect_sum_unspent()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(kid, Amount::from(24u64))])));
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
+        pdb.expect_list_reserved()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let balance = pocket.balance(&k_infos).await.expect("balance works");
@@ -1255,6 +1995,153 @@ mod tests {
         assert_eq!(balance.credit, Amount::from(24u64));
     }
 
+    #[tokio::test]
+    async fn rebuild_counters_from_stored_proofs() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_info = KeySetInfo::from(info);
+        let kid = k_info.id;
+        let mnemonic = bip39::Mnemonic::generate(12).unwrap();
+        let seed = mnemonic.to_seed("");
+
+        // proofs derived at index 3 and 141, the counter store was lost
+        let premints = cdk00::PreMintSecrets::restore_batch(kid, &seed, 0, 199).unwrap();
+        let derived: Vec<_> = premints.iter().map(|p| p.secret.clone()).collect();
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let mut proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        proofs[0].secret = derived[3].clone();
+        proofs[1].secret = derived[141].clone();
+        let stored: HashMap<cashu::PublicKey, cdk00::Proof> =
+            proofs.into_iter().map(|p| (p.y().unwrap(), p)).collect();
+        let ys: Vec<cashu::PublicKey> = stored.keys().cloned().collect();
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_all()
+            .times(1)
+            .returning(move || Ok(ys.clone()));
+        pdb.expect_load_proofs()
+            .times(1)
+            .returning(move |_| Ok(stored.clone()));
+        pdb.expect_counter()
+            .with(eq(kid))
+            .times(1)
+            .returning(|_| Ok(0));
+        pdb.expect_increment_counter()
+            .with(eq(kid), eq(0), eq(142))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let pocket = super::Pocket::new(CurrencyUnit::Sat, Arc::new(pdb), Arc::new(mdb), seed);
+        let counters = pocket
+            .rebuild_counters(&[k_info])
+            .await
+            .expect("rebuild works");
+        assert_eq!(counters.get(&kid), Some(&142));
+    }
+
+    #[tokio::test]
+    async fn restore_keysets_only_requested_ids() {
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let (other_info, _) = core_tests::generate_random_ecash_keyset();
+        let k_info = KeySetInfo::from(info);
+        let kid = k_info.id;
+        let k_infos = vec![k_info, KeySetInfo::from(other_info)];
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        let mut client = MockMintConnector::new();
+
+        pdb.expect_counter()
+            .with(eq(kid))
+            .times(1)
+            .returning(|_| Ok(0));
+        client
+            .expect_post_restore()
+            .times(3)
+            .returning(|_| Ok(vec![]));
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let restored = pocket
+            .restore_keysets(&[kid], &k_infos, Arc::new(client), None)
+            .await
+            .expect("restore works");
+        assert_eq!(restored, 0);
+    }
+
+    #[tokio::test]
+    async fn restore_keysets_concurrently() {
+        let (info, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let (other_info, other_mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info), KeySetInfo::from(other_info)];
+        let kids: Vec<cashu::Id> = k_infos.iter().map(|info| info.id).collect();
+        let mintkeysets = HashMap::from([
+            (mintkeyset.id, mintkeyset.clone()),
+            (other_mintkeyset.id, other_mintkeyset.clone()),
+        ]);
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        let mut client = MockMintConnector::new();
+
+        let keysets = mintkeysets.clone();
+        client
+            .expect_get_mint_keyset()
+            .times(2)
+            .returning(move |kid| Ok(KeySet::from(keysets.get(&kid).unwrap().clone())));
+        // the first batch of each keyset was signed
+        let signed = Arc::new(Mutex::new(HashSet::new()));
+        client.expect_post_restore().returning(move |request| {
+            let Some(kid) = request.outputs.first().map(|blind| blind.keyset_id) else {
+                return Ok(vec![]);
+            };
+            if !signed.lock().unwrap().insert(kid) {
+                return Ok(vec![]);
+            }
+            let mintkeyset = mintkeysets.get(&kid).unwrap();
+            Ok(request
+                .outputs
+                .into_iter()
+                .map(|blind| {
+                    let mut bblind = blind.clone();
+                    bblind.amount = Amount::from(1u64);
+                    let signature = bcr_common::core::signature::sign_ecash(mintkeyset, &bblind)
+                        .expect("signatures should be generated");
+                    (blind, signature)
+                })
+                .collect())
+        });
+        client
+            .expect_post_check_state()
+            .times(2)
+            .returning(|request| {
+                Ok(request
+                    .ys
+                    .into_iter()
+                    .map(|y| cdk07::ProofState {
+                        y,
+                        state: cdk07::State::Unspent,
+                        witness: None,
+                    })
+                    .collect())
+            });
+        pdb.expect_counter().times(2).returning(|_| Ok(0));
+        pdb.expect_store_new_batch()
+            .withf(|proofs| proofs.len() == restore::BATCH_SIZE as usize)
+            .times(2)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
+        for kid in kids.iter() {
+            pdb.expect_increment_counter()
+                .with(eq(*kid), eq(0), eq(restore::BATCH_SIZE))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+        }
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let restored = pocket
+            .restore_keysets(&kids, &k_infos, Arc::new(client), None)
+            .await
+            .expect("restore works");
+        assert_eq!(restored, 2 * restore::BATCH_SIZE as usize);
+    }
+
     #[tokio::test]
     async fn credit_balance_keyset_expiring_earlier_today() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -1275,13 +2162,16 @@ mod tests {
         let mut pdb = MockPocketRepository::new();
         let mdb = MockMintMeltRepository::new();
 
-        let proofs_clone = proofs.clone();
-        pdb.expect_list_unspent().times(1).returning(move || {
-            let mut map = HashMap::new();
-            map.insert(proofs_clone[0].y().unwrap(), proofs_clone[0].clone());
-            map.insert(proofs_clone[1].y().unwrap(), proofs_clone[1].clone());
-            Ok(map)
-        });
+        let kid = proofs[0].keyset_id;
+        pdb.expect_sum_unspent()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(kid, Amount::from(24u64))])));
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
+        pdb.expect_list_reserved()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let balance = pocket.balance(&k_infos).await.expect("balance works");
@@ -1317,15 +2207,19 @@ mod tests {
         let mut pdb = MockPocketRepository::new();
         let mdb = MockMintMeltRepository::new();
 
-        let p_debit = proofs_debit[0].clone();
-        let p_credit = proofs_credit[0].clone();
-
-        pdb.expect_list_unspent().times(1).returning(move || {
-            let mut map = HashMap::new();
-            map.insert(p_debit.y().unwrap(), p_debit.clone());
-            map.insert(p_credit.y().unwrap(), p_credit.clone());
-            Ok(map)
-        });
+        let sums = HashMap::from([
+            (proofs_debit[0].keyset_id, debit_amount),
+            (proofs_credit[0].keyset_id, credit_amount),
+        ]);
+        pdb.expect_sum_unspent()
+            .times(1)
+            .returning(move || Ok(sums.clone()));
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
+        pdb.expect_list_reserved()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let balance = pocket.balance(&k_infos).await.expect("balance works");
@@ -1334,6 +2228,93 @@ mod tests {
         assert_eq!(balance.credit, credit_amount);
     }
 
+    #[tokio::test]
+    async fn stats_counts_proofs_and_keysets() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let (_, other_keyset) = core_tests::generate_random_ecash_keyset();
+        let unspent = core_tests::generate_random_ecash_proofs(
+            &keyset,
+            &[Amount::from(8u64), Amount::from(16u64)],
+        );
+        let pending =
+            core_tests::generate_random_ecash_proofs(&other_keyset, &[Amount::from(4u64)]);
+        let all: Vec<_> = unspent
+            .iter()
+            .chain(pending.iter())
+            .map(|p| p.y().unwrap())
+            .chain(std::iter::once(cashu::SecretKey::generate().public_key()))
+            .collect();
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_unspent().times(1).returning(move || {
+            Ok(unspent
+                .iter()
+                .map(|p| (p.y().unwrap(), p.clone()))
+                .collect())
+        });
+        pdb.expect_list_pending().times(1).returning(move || {
+            Ok(pending
+                .iter()
+                .map(|p| (p.y().unwrap(), p.clone()))
+                .collect())
+        });
+        pdb.expect_list_reserved()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
+        pdb.expect_list_all()
+            .times(1)
+            .returning(move || Ok(all.clone()));
+        pdb.expect_list_random()
+            .times(1)
+            .returning(|| Ok(Vec::new()));
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        let stats = pocket.stats().await.unwrap();
+        assert_eq!(
+            stats,
+            PocketStats {
+                unspent: 2,
+                pending: 1,
+                reserved: 0,
+                total: 4,
+                keysets: 2,
+                random: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn balance_with_pending_and_reserved() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amounts = [Amount::from(8u64), Amount::from(16u64), Amount::from(32u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+
+        let kid = proofs[0].keyset_id;
+        pdb.expect_sum_unspent()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(kid, Amount::from(8u64))])));
+        let pending = proofs[1].clone();
+        pdb.expect_list_pending()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(pending.y().unwrap(), pending.clone())])));
+        let reserved = proofs[2].clone();
+        pdb.expect_list_reserved()
+            .times(1)
+            .returning(move || Ok(HashMap::from([(reserved.y().unwrap(), reserved.clone())])));
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let balance = pocket.balance(&k_infos).await.expect("balance works");
+        assert_eq!(balance.debit, Amount::from(8u64));
+        assert_eq!(balance.debit_pending, Amount::from(16u64));
+        assert_eq!(balance.debit_reserved, Amount::from(32u64));
+        assert_eq!(balance.credit, Amount::ZERO);
+        assert_eq!(balance.credit_pending, Amount::ZERO);
+        assert_eq!(balance.credit_reserved, Amount::ZERO);
+    }
+
     #[tokio::test]
     async fn debit_receive_proofs() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -1368,10 +2349,68 @@ mod tests {
                 let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
-        pdb.expect_store_new().times(2).returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        pdb.expect_store_new_batch()
+            .withf(|proofs| proofs.len() == 2)
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let (cashed, _) = pocket
+            .receive_proofs(Arc::new(connector), &k_infos, proofs, test_swap_config())
+            .await
+            .unwrap();
+        assert_eq!(cashed, Amount::from(24u64));
+    }
+
+    #[tokio::test]
+    async fn debit_receive_proofs_of_inactive_keyset() {
+        let (inactive_info, inactive_keyset) = core_tests::generate_random_ecash_keyset();
+        let (active_info, active_keyset) = core_tests::generate_random_ecash_keyset();
+        let inactive_kid = inactive_info.id;
+        let active_kid = active_info.id;
+        let mut inactive_info = KeySetInfo::from(inactive_info);
+        inactive_info.active = false;
+        let mut active_info = KeySetInfo::from(active_info);
+        active_info.active = true;
+        let k_infos = vec![inactive_info, active_info];
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&inactive_keyset, &amounts);
+
+        let mdb = MockMintMeltRepository::new();
+        let mut pdb = MockPocketRepository::new();
+        let mut connector = MockMintConnector::new();
+        connector
+            .expect_get_mint_keyset()
+            .times(1)
+            .with(eq(inactive_kid))
+            .returning(move |_| Ok(KeySet::from(inactive_keyset.clone())));
+        let cloned_keyset = active_keyset.clone();
+        connector
+            .expect_get_mint_keyset()
+            .times(1)
+            .with(eq(active_kid))
+            .returning(move |_| Ok(KeySet::from(cloned_keyset.clone())));
+        // the outputs are derived for the active keyset only
+        pdb.expect_counter()
+            .times(1)
+            .with(eq(active_kid))
+            .returning(|_| Ok(0));
+        pdb.expect_increment_counter()
+            .times(1)
+            .with(eq(active_kid), eq(0), eq(2))
+            .returning(|_, _, _| Ok(()));
+        setup_commitment_mocks(&mut connector, &mut pdb);
+        connector
+            .expect_post_swap_committed()
+            .times(1)
+            .returning(move |request| {
+                let amounts = request.outputs.iter().map(|b| b.amount).collect::<Vec<_>>();
+                let signatures = core_tests::generate_ecash_signatures(&active_keyset, &amounts);
+                Ok(bcr_common::wire::swap::SwapResponse { signatures })
+            });
+        pdb.expect_store_new_batch()
+            .withf(move |proofs| proofs.iter().all(|p| p.keyset_id == active_kid))
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let (cashed, _) = pocket
             .receive_proofs(Arc::new(connector), &k_infos, proofs, test_swap_config())
@@ -1380,6 +2419,163 @@ mod tests {
         assert_eq!(cashed, Amount::from(24u64));
     }
 
+    #[tokio::test]
+    async fn debit_receive_proofs_rejects_amount_not_in_keyset() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let kid = info.id;
+        let k_infos = vec![KeySetInfo::from(info)];
+        let mut proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        proofs[0].amount = Amount::from(3u64);
+        let y = proofs[0].y().unwrap();
+
+        let mdb = MockMintMeltRepository::new();
+        let pdb = MockPocketRepository::new();
+        let mut connector = MockMintConnector::new();
+        connector
+            .expect_get_mint_keyset()
+            .times(1)
+            .with(eq(kid))
+            .returning(move |_| Ok(KeySet::from(keyset.clone())));
+        // no counter, commitment or swap expectations, none of it may happen
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let err = pocket
+            .receive_proofs(Arc::new(connector), &k_infos, proofs, test_swap_config())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidProof(bad) if bad == y));
+    }
+
+    #[tokio::test]
+    async fn debit_receive_locked_proofs_2_of_2() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let ours = cashu::SecretKey::generate();
+        let theirs = cashu::SecretKey::generate();
+        let conditions = cashu::SpendingConditions::new_p2pk(
+            ours.public_key(),
+            Some(
+                cashu::Conditions::new(
+                    None,
+                    Some(vec![theirs.public_key()]),
+                    None,
+                    Some(2),
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ),
+        );
+        let secret: cashu::secret::Secret =
+            cashu::nut10::Secret::from(conditions).try_into().unwrap();
+        let mut proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        proofs[0].secret = secret;
+        let y = proofs[0].y().unwrap();
+
+        let mdb = MockMintMeltRepository::new();
+        let mut pdb = MockPocketRepository::new();
+        let mut connector = MockMintConnector::new();
+        connector
+            .expect_post_check_state()
+            .times(1)
+            .returning(|req| {
+                Ok(req
+                    .ys
+                    .into_iter()
+                    .map(|y| cdk07::ProofState {
+                        y,
+                        state: cdk07::State::Unspent,
+                        witness: None,
+                    })
+                    .collect())
+            });
+        let stored = Arc::new(Mutex::new(None));
+        let stored_clone = stored.clone();
+        pdb.expect_store_locked().times(1).returning(move |p| {
+            let y = p.y().expect("Hash to curve should not fail");
+            *stored_clone.lock().unwrap() = Some(p);
+            Ok(y)
+        });
+        let loaded = stored.clone();
+        pdb.expect_load_locked()
+            .times(1)
+            .with(eq(y))
+            .returning(move |_| Ok(loaded.lock().unwrap().clone().unwrap()));
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let (received, ys) = pocket
+            .receive_locked_proofs(Arc::new(connector), &k_infos, proofs, &ours)
+            .await
+            .unwrap();
+        assert_eq!(received, Amount::from(8u64));
+        assert_eq!(ys, vec![y]);
+
+        // the wallet contributes one of the two signatures
+        let witness = pocket.partial_sign_proof(y).await.unwrap();
+        match witness {
+            cdk00::Witness::P2PKWitness(w) => assert_eq!(w.signatures.len(), 1),
+            other => panic!("expected P2PK witness, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn debit_combine_partial_signatures_2_of_2() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let ours = cashu::SecretKey::generate();
+        let theirs = cashu::SecretKey::generate();
+        let conditions = cashu::SpendingConditions::new_p2pk(
+            ours.public_key(),
+            Some(
+                cashu::Conditions::new(
+                    None,
+                    Some(vec![theirs.public_key()]),
+                    None,
+                    Some(2),
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ),
+        );
+        let secret: cashu::secret::Secret =
+            cashu::nut10::Secret::from(conditions).try_into().unwrap();
+        let mut proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        proofs[0].secret = secret;
+        let y = proofs[0].y().unwrap();
+        let mut stored = proofs[0].clone();
+        stored.sign_p2pk(ours).unwrap();
+        let mut other = proofs[0].clone();
+        other.sign_p2pk(theirs).unwrap();
+        let their_witness = other.witness.unwrap();
+
+        let mdb = MockMintMeltRepository::new();
+        let mut pdb = MockPocketRepository::new();
+        pdb.expect_load_locked()
+            .times(2)
+            .with(eq(y))
+            .returning(move |_| Ok(stored.clone()));
+        pdb.expect_store_locked().never();
+        pdb.expect_release_locked().times(1).returning(|p| {
+            assert_eq!(p.witness.as_ref().unwrap().signatures().unwrap().len(), 2);
+            Ok(p.y().expect("Hash to curve should not fail"))
+        });
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        // a signature of someone outside the multisig is rejected
+        let mut outsider = proofs[0].clone();
+        outsider.sign_p2pk(cashu::SecretKey::generate()).unwrap();
+        let err = pocket
+            .combine_partial_signatures(y, vec![outsider.witness.unwrap()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::SpendingConditions));
+
+        let spendable = pocket
+            .combine_partial_signatures(y, vec![their_witness])
+            .await
+            .unwrap();
+        assert!(spendable);
+    }
+
     #[tokio::test]
     async fn debit_reclaim_proofs() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -1428,10 +2624,10 @@ mod tests {
                 let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
-        pdb.expect_store_new().times(2).returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        pdb.expect_store_new_batch()
+            .withf(|proofs| proofs.len() == 2)
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let reclaimed = pocket
@@ -1505,10 +2701,10 @@ mod tests {
                 let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
-        pdb.expect_store_new().times(1).returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        pdb.expect_store_new_batch()
+            .withf(|proofs| proofs.len() == 1)
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let recovered = pocket
@@ -1586,14 +2782,131 @@ mod tests {
             .returning(|_| Ok(()));
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
-        let melt_ref = MeltReference { rid, quote_id };
+        let melt_ref = MeltReference {
+            rid,
+            quote_id,
+            ys: vec![],
+        };
+        pocket.current_melt.lock().unwrap().replace(melt_ref);
+
+        let res = pocket
+            .pay_onchain_melt(rid, Arc::new(connector))
+            .await
+            .expect("pay melt works");
+        assert_eq!(res.0.alpha_txid, Some(tx_id));
+    }
+
+    #[tokio::test]
+    async fn pay_onchain_melt_failure_reverts_proofs() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        let y = proofs[0].y().unwrap();
+        let quote_id = Uuid::new_v4();
+        let rid = Uuid::new_v4();
+
+        let mut mdb = MockMintMeltRepository::new();
+        let mut pdb = MockPocketRepository::new();
+        let mut connector = MockMintConnector::new();
+
+        let ephemeral = secp256k1::Keypair::new_global(&mut secp256k1::rand::thread_rng());
+        let commitment_sig = cashu::SecretKey::generate().sign(&[0u8; 32]).unwrap();
+        let wallet_key = cashu::PublicKey::from(secp256k1::PublicKey::from_keypair(&ephemeral));
+        let body = wire_melt::MeltQuoteOnchainResponseBody {
+            quote: quote_id,
+            inputs: vec![],
+            address: bitcoin::Address::from_str("tb1qteyk7pfvvql2r2zrsu4h4xpvju0nz7ykvguyk0")
+                .expect("valid address"),
+            amount: bitcoin::Amount::from_sat(100),
+            total: cashu::Amount::from(100u64),
+            expiry: 999999,
+            wallet_key,
+        };
+        use bitcoin::base64::{Engine, engine::general_purpose::STANDARD};
+        let body_content = STANDARD.encode(borsh::to_vec(&body).unwrap());
+        mdb.expect_load_melt_commitment()
+            .times(1)
+            .returning(move |_| {
+                Ok(bcr_wallet_persistence::MeltCommitmentRecord {
+                    quote_id,
+                    expiry: 999999,
+                    commitment: commitment_sig,
+                    ephemeral_secret: secp256k1::SecretKey::from_keypair(&ephemeral),
+                    body_content: body_content.clone(),
+                })
+            });
+        pdb.expect_load_proofs()
+            .times(1)
+            .returning(|_| Ok(HashMap::new()));
+        connector
+            .expect_post_melt_onchain()
+            .times(1)
+            .returning(|_| Err(Error::MintingError("melt failed".to_string())));
+        let proof = proofs[0].clone();
+        pdb.expect_revert_pendingspent_to_unspent()
+            .times(1)
+            .with(eq(y))
+            .returning(move |_| Ok(proof.clone()));
+        // the commitment is kept for a protest
+        mdb.expect_delete_melt_commitment().never();
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let melt_ref = MeltReference {
+            rid,
+            quote_id,
+            ys: vec![y],
+        };
+        pocket.current_melt.lock().unwrap().replace(melt_ref);
+
+        let res = pocket.pay_onchain_melt(rid, Arc::new(connector)).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn cancel_melt_reverts_proofs() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<cashu::PublicKey> = proofs.iter().map(|p| p.y().unwrap()).collect();
+        let rid = Uuid::new_v4();
+        let quote_id = Uuid::new_v4();
+
+        let mut pdb = MockPocketRepository::new();
+        let mut mdb = MockMintMeltRepository::new();
+        for proof in proofs.into_iter() {
+            let y = proof.y().unwrap();
+            pdb.expect_revert_pendingspent_to_unspent()
+                .with(eq(y))
+                .times(1)
+                .returning(move |_| Ok(proof.clone()));
+        }
+        mdb.expect_delete_melt_commitment().never();
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let melt_ref = MeltReference { rid, quote_id, ys };
+        pocket.current_melt.lock().unwrap().replace(melt_ref);
+
+        pocket.cancel_melt(rid).await.expect("cancel melt works");
+        assert!(pocket.current_melt.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn cancel_melt_wrong_rid_keeps_reference() {
+        let rid = Uuid::new_v4();
+        let quote_id = Uuid::new_v4();
+        let pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let melt_ref = MeltReference {
+            rid,
+            quote_id,
+            ys: vec![],
+        };
         pocket.current_melt.lock().unwrap().replace(melt_ref);
 
-        let res = pocket
-            .pay_onchain_melt(rid, Arc::new(connector))
-            .await
-            .expect("pay melt works");
-        assert_eq!(res.0.alpha_txid, Some(tx_id));
+        let res = pocket.cancel_melt(Uuid::new_v4()).await;
+        assert!(matches!(res, Err(Error::NoPrepareRef(_))));
+        assert!(pocket.current_melt.lock().unwrap().is_some());
     }
 
     fn mock_melt_commitment_body(quote_id: Uuid, amount: u64) -> String {
@@ -1791,6 +3104,99 @@ mod tests {
         assert_eq!(summary.amount, amount);
     }
 
+    #[tokio::test]
+    async fn mint() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let kid = info.id;
+        let mut info = KeySetInfo::from(info);
+        info.active = true;
+        info.final_expiry = None;
+        let k_infos = vec![info];
+
+        let mdb = MockMintMeltRepository::new();
+        let mut pdb = MockPocketRepository::new();
+        let mut connector = MockMintConnector::new();
+        connector
+            .expect_get_mint_quote_bolt11()
+            .times(1)
+            .with(eq(String::from("q-1")))
+            .returning(|quote| {
+                Ok(cashu::MintQuoteBolt11Response {
+                    quote,
+                    request: String::from("lnbc..."),
+                    amount: Some(Amount::from(24u64)),
+                    unit: Some(CurrencyUnit::Sat),
+                    state: cashu::MintQuoteState::Paid,
+                    expiry: None,
+                    pubkey: None,
+                })
+            });
+        let cloned_keyset = keyset.clone();
+        connector
+            .expect_get_mint_keyset()
+            .times(1)
+            .with(eq(kid))
+            .returning(move |_| Ok(KeySet::from(cloned_keyset.clone())));
+        // counters are handled like in `digest_proofs`, so restore keeps working
+        pdb.expect_counter()
+            .times(1)
+            .with(eq(kid))
+            .returning(|_| Ok(5));
+        pdb.expect_increment_counter()
+            .times(1)
+            .with(eq(kid), eq(5), eq(2))
+            .returning(|_, _, _| Ok(()));
+        connector
+            .expect_post_mint_bolt11()
+            .times(1)
+            .returning(move |request| {
+                assert_eq!(request.quote, "q-1");
+                let amounts = request.outputs.iter().map(|b| b.amount).collect::<Vec<_>>();
+                let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
+                Ok(cashu::MintResponse { signatures })
+            });
+        pdb.expect_store_new_batch()
+            .withf(|proofs| proofs.len() == 2)
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let (minted, ys) = pocket
+            .mint(String::from("q-1"), &k_infos, Arc::new(connector))
+            .await
+            .unwrap();
+        assert_eq!(minted, Amount::from(24u64));
+        assert_eq!(ys.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn mint_unpaid_quote() {
+        let mdb = MockMintMeltRepository::new();
+        let pdb = MockPocketRepository::new();
+        let mut connector = MockMintConnector::new();
+        connector
+            .expect_get_mint_quote_bolt11()
+            .times(1)
+            .returning(|quote| {
+                Ok(cashu::MintQuoteBolt11Response {
+                    quote,
+                    request: String::from("lnbc..."),
+                    amount: Some(Amount::from(24u64)),
+                    unit: Some(CurrencyUnit::Sat),
+                    state: cashu::MintQuoteState::Unpaid,
+                    expiry: None,
+                    pubkey: None,
+                })
+            });
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let err = pocket
+            .mint(String::from("q-1"), &[], Arc::new(connector))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::MintingError(_)));
+    }
+
     #[tokio::test]
     async fn check_pending_mints() {
         let uuid = Uuid::new_v4();
@@ -1952,10 +3358,9 @@ mod tests {
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
 
-        pdb.expect_store_new().returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        pdb.expect_store_new_batch()
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         mdb.expect_delete_mint().times(1).returning(move |_| Ok(()));
 
@@ -2159,10 +3564,9 @@ mod tests {
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
 
-        pdb.expect_store_new().returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        pdb.expect_store_new_batch()
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         pdb.expect_delete_commitment()
             .times(1)
@@ -2277,6 +3681,314 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn send_proofs_offline_only_sends_exact_proofs() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        let y = proofs[0].y().unwrap();
+        let rid = Uuid::new_v4();
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        let proof = proofs[0].clone();
+        pdb.expect_mark_as_pendingspent()
+            .with(eq(y))
+            .times(1)
+            .returning(move |_| Ok(proof.clone()));
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        pocket.prepared_sends.lock().unwrap().insert(
+            rid,
+            SendReference {
+                rid,
+                target_amount: Amount::from(4u64),
+                plan: SendPlan::NeedSplit {
+                    proof: y,
+                    split_amount: Amount::from(4u64),
+                    estimated_fee: Amount::ZERO,
+                },
+                scheme: SecretScheme::default(),
+            },
+        );
+        let err = pocket.send_proofs_offline(rid).await.unwrap_err();
+        assert!(matches!(err, Error::OfflineSendNotPossible));
+        // still there for the online send
+        assert!(pocket.prepared_sends.lock().unwrap().contains_key(&rid));
+
+        pocket.prepared_sends.lock().unwrap().insert(
+            rid,
+            SendReference {
+                rid,
+                target_amount: Amount::from(8u64),
+                plan: SendPlan::Ready { proofs: vec![y] },
+                scheme: SecretScheme::default(),
+            },
+        );
+        let sent = pocket.send_proofs_offline(rid).await.unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent.contains_key(&y));
+        assert!(pocket.prepared_sends.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn estimate_send_keeps_no_reference() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(16u64)]);
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_unspent()
+            .times(1)
+            .returning(move || Ok(proofs.iter().map(|p| (p.y().unwrap(), p.clone())).collect()));
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        let summary = pocket
+            .estimate_send(
+                Amount::from(8u64),
+                &k_infos,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .expect("estimate send works");
+        assert_eq!(summary.amount, Amount::from(8u64));
+        assert!(summary.needs_swap);
+        assert!(pocket.prepared_sends.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn prepare_send_max_sweeps_all_proofs() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amounts = [Amount::from(1u64), Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<_> = proofs.iter().map(|p| p.y().unwrap()).collect();
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        let proofs_clone = proofs.clone();
+        pdb.expect_mark_as_reserved().times(3).returning(move |y| {
+            Ok(proofs_clone
+                .iter()
+                .find(|p| p.y().unwrap() == y)
+                .unwrap()
+                .clone())
+        });
+        pdb.expect_list_unspent()
+            .times(2)
+            .returning(move || Ok(proofs.iter().map(|p| (p.y().unwrap(), p.clone())).collect()));
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        let summary = pocket
+            .prepare_send_max(
+                &k_infos,
+                SecretScheme::Random,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .expect("prepare send max works");
+        assert_eq!(summary.amount, Amount::from(25u64));
+        assert_eq!(summary.swap_fees, Amount::ZERO);
+        assert!(!summary.needs_swap);
+        assert_eq!(summary.scheme, SecretScheme::Random);
+
+        let send_ref = pocket
+            .prepared_sends
+            .lock()
+            .unwrap()
+            .remove(&summary.request_id)
+            .unwrap();
+        assert_eq!(send_ref.rid, summary.request_id);
+        match send_ref.plan {
+            SendPlan::Ready { proofs: selected } => {
+                assert_eq!(selected.len(), ys.len());
+                assert!(ys.iter().all(|y| selected.contains(y)));
+            }
+            SendPlan::NeedSplit { .. } => panic!("expected ready send plan"),
+        }
+    }
+
+    #[tokio::test]
+    async fn prepare_send_max_deducts_the_input_fees() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let mut info = KeySetInfo::from(info);
+        info.input_fee_ppk = 1000;
+        let k_infos = vec![info];
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        let proofs_clone = proofs.clone();
+        pdb.expect_mark_as_reserved().times(2).returning(move |y| {
+            Ok(proofs_clone
+                .iter()
+                .find(|p| p.y().unwrap() == y)
+                .unwrap()
+                .clone())
+        });
+        pdb.expect_list_unspent()
+            .times(2)
+            .returning(move || Ok(proofs.iter().map(|p| (p.y().unwrap(), p.clone())).collect()));
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        // 24 less 1 sat for each input
+        let summary = pocket
+            .prepare_send_max(
+                &k_infos,
+                SecretScheme::Deterministic,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .expect("prepare send max works");
+        assert_eq!(summary.amount, Amount::from(22u64));
+        assert!(!summary.needs_swap);
+    }
+
+    #[tokio::test]
+    async fn prepare_send_max_fails_when_the_fees_eat_the_balance() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let mut info = KeySetInfo::from(info);
+        info.input_fee_ppk = 1000;
+        let k_infos = vec![info];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(1u64)]);
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_unspent()
+            .times(1)
+            .returning(move || Ok(proofs.iter().map(|p| (p.y().unwrap(), p.clone())).collect()));
+        pdb.expect_mark_as_reserved().never();
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        let err = pocket
+            .prepare_send_max(
+                &k_infos,
+                SecretScheme::Deterministic,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InsufficientFundsForFee { .. }));
+        assert!(pocket.prepared_sends.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn prepare_send_max_empty_pocket() {
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_unspent()
+            .times(1)
+            .returning(|| Ok(HashMap::new()));
+        pdb.expect_mark_as_reserved().never();
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+
+        let err = pocket
+            .prepare_send_max(
+                &k_infos,
+                SecretScheme::Deterministic,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InsufficientBalance(..)));
+        assert!(pocket.prepared_sends.lock().unwrap().is_empty());
+    }
+
+    async fn db_pocket(id: &str) -> super::Pocket {
+        let (_, (pdb, mdb)) = in_memory_wallet_dbs(id).await;
+        pocket(Arc::new(pdb), Arc::new(mdb))
+    }
+
+    #[tokio::test]
+    async fn release_stale_reservations_drops_abandoned_sends() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let pocket = db_pocket("stale").await;
+        let ys = pocket.pdb.store_new_batch(proofs).await.unwrap();
+
+        let summary = pocket
+            .prepare_send(
+                Amount::from(8u64),
+                &k_infos,
+                SecretScheme::default(),
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .unwrap();
+        // left behind by a previous run
+        pocket.pdb.mark_as_reserved(ys[1]).await.unwrap();
+        let later = chrono::Utc::now().timestamp() as u64 + 60;
+
+        assert_eq!(
+            pocket
+                .release_stale_reservations(later - 120)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(pocket.pdb.list_reserved().await.unwrap().len(), 2);
+        let released = pocket.release_stale_reservations(later).await.unwrap();
+        assert_eq!(released, 2);
+        assert!(pocket.pdb.list_reserved().await.unwrap().is_empty());
+        assert_eq!(pocket.pdb.list_unspent().await.unwrap().len(), 2);
+        let err = pocket.cancel_send(summary.request_id).await.unwrap_err();
+        assert!(matches!(err, Error::NoPrepareRef(_)));
+    }
+
+    #[tokio::test]
+    async fn overlapping_prepares_select_distinct_proofs() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let pocket = db_pocket("overlap").await;
+        let ys = pocket.pdb.store_new_batch(proofs).await.unwrap();
+
+        let first = pocket
+            .prepare_send(
+                Amount::from(8u64),
+                &k_infos,
+                SecretScheme::default(),
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .unwrap();
+        assert!(!first.needs_swap);
+        let reserved = pocket.pdb.list_reserved().await.unwrap();
+        assert!(reserved.contains_key(&ys[0]));
+        let balance = pocket.balance(&k_infos).await.unwrap();
+        assert_eq!(balance.debit, Amount::from(16u64));
+
+        // the reserved 8 can't be picked again, so the 16 has to be split
+        let second = pocket
+            .prepare_send(
+                Amount::from(8u64),
+                &k_infos,
+                SecretScheme::default(),
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .unwrap();
+        assert!(second.needs_swap);
+        // the first send keeps its proof
+        let reserved = pocket.pdb.list_reserved().await.unwrap();
+        assert_eq!(reserved.len(), 2);
+        assert!(pocket.pdb.list_unspent().await.unwrap().is_empty());
+
+        pocket.cancel_send(first.request_id).await.unwrap();
+        let reserved = pocket.pdb.list_reserved().await.unwrap();
+        assert_eq!(reserved.len(), 1);
+        assert!(reserved.contains_key(&ys[1]));
+        pocket.cancel_send(second.request_id).await.unwrap();
+        assert!(pocket.pdb.list_reserved().await.unwrap().is_empty());
+        assert_eq!(pocket.pdb.list_unspent().await.unwrap().len(), 2);
+    }
+
     #[tokio::test]
     async fn compute_send_costs_ready() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -2299,7 +4011,7 @@ mod tests {
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let (summary, send_ref) = pocket
-            .compute_send_costs(target, &k_infos)
+            .compute_send_costs(target, &k_infos, CoinSelectionStrategy::default())
             .await
             .expect("compute send costs works");
 
@@ -2320,6 +4032,51 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn compute_send_costs_prefers_exact_subset() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amounts = [
+            Amount::from(8u64),
+            Amount::from(4u64),
+            Amount::from(2u64),
+            Amount::from(1u64),
+        ];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let expected: Vec<_> = proofs[1..3].iter().map(|p| p.y().unwrap()).collect();
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_unspent().times(1).returning(move || {
+            Ok(proofs
+                .iter()
+                .map(|proof| (proof.y().unwrap(), proof.clone()))
+                .collect())
+        });
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let (summary, send_ref) = pocket
+            .compute_send_costs(
+                Amount::from(6u64),
+                &k_infos,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .expect("compute send costs works");
+
+        assert!(!summary.needs_swap);
+        assert_eq!(summary.swap_fees, Amount::ZERO);
+        match send_ref.plan {
+            SendPlan::Ready { proofs: selected } => {
+                assert_eq!(selected.len(), 2);
+                for y in expected {
+                    assert!(selected.contains(&y));
+                }
+            }
+            SendPlan::NeedSplit { .. } => panic!("expected ready send plan"),
+        }
+    }
+
     #[tokio::test]
     async fn compute_send_costs_need_split_after_collecting_input() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -2349,7 +4106,7 @@ mod tests {
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let (summary, send_ref) = pocket
-            .compute_send_costs(target, &k_infos)
+            .compute_send_costs(target, &k_infos, CoinSelectionStrategy::default())
             .await
             .expect("compute send costs works");
 
@@ -2367,6 +4124,7 @@ mod tests {
                 assert_eq!(proof, split_proof_y);
                 assert_eq!(split_amount, Amount::from(1u64));
                 assert_eq!(summary.swap_fees, estimated_fee);
+                assert!(summary.needs_swap);
             }
             SendPlan::Ready { .. } => panic!("expected split send plan"),
         }
@@ -2399,7 +4157,7 @@ mod tests {
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let (summary, send_ref) = pocket
-            .compute_send_costs(target, &k_infos)
+            .compute_send_costs(target, &k_infos, CoinSelectionStrategy::default())
             .await
             .expect("compute send costs works");
 
@@ -2436,12 +4194,60 @@ mod tests {
 
         let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
         let result = pocket
-            .compute_send_costs(Amount::from(1u64), &k_infos)
+            .compute_send_costs(
+                Amount::from(1u64),
+                &k_infos,
+                CoinSelectionStrategy::default(),
+            )
             .await;
 
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn compute_send_costs_errors_when_fee_exceeds_balance() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let mut info = KeySetInfo::from(info);
+        info.input_fee_ppk = 1000;
+        let k_infos = vec![info];
+
+        // the exact balance is requested, the fee makes it unaffordable
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+
+        let mut pdb = MockPocketRepository::new();
+        let mdb = MockMintMeltRepository::new();
+        pdb.expect_list_unspent().times(1).returning(move || {
+            Ok(proofs
+                .iter()
+                .map(|proof| (proof.y().unwrap(), proof.clone()))
+                .collect())
+        });
+
+        let pocket = pocket(Arc::new(pdb), Arc::new(mdb));
+        let err = pocket
+            .compute_send_costs(
+                Amount::from(24u64),
+                &k_infos,
+                CoinSelectionStrategy::default(),
+            )
+            .await
+            .unwrap_err();
+
+        match err {
+            Error::InsufficientFundsForFee {
+                available,
+                needed,
+                fee,
+            } => {
+                assert_eq!(available, Amount::from(24u64));
+                assert!(fee > Amount::ZERO);
+                assert_eq!(needed, Amount::from(24u64) + fee);
+            }
+            other => panic!("expected InsufficientFundsForFee, got: {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn check_pending_mint_success() {
         let qid = Uuid::new_v4();
@@ -2521,10 +4327,9 @@ mod tests {
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
 
-        pdb.expect_store_new().returning(|p| {
-            let y = p.y().unwrap();
-            Ok(y)
-        });
+        pdb.expect_store_new_batch()
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         mdb.expect_delete_mint().times(1).returning(|_| Ok(()));
 
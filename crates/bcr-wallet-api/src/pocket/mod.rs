@@ -1,5 +1,6 @@
 use crate::{
     ClowderMintConnector,
+    config::CHECK_STATE_BATCH_SIZE,
     error::{Error, Result},
     wallet::types::SwapConfig,
 };
@@ -13,7 +14,8 @@ use bcr_common::{
 };
 use bcr_wallet_core::{
     SendSync,
-    types::{Seed, SendSummary},
+    types::{CoinSelectionStrategy, RestoreProgressCallback, SecretScheme, Seed, SendSummary},
+    util::keypair_from_seed,
 };
 use bcr_wallet_persistence::PocketRepository;
 use std::collections::{HashMap, HashSet};
@@ -39,7 +41,27 @@ pub trait PocketApi: SendSync {
         proofs: Vec<cashu::Proof>,
         swap_config: SwapConfig,
     ) -> Result<(Amount, Vec<cashu::PublicKey>)>;
-    async fn prepare_send(&self, amount: Amount, infos: &[KeySetInfo]) -> Result<SendSummary>;
+    async fn prepare_send(
+        &self,
+        amount: Amount,
+        infos: &[KeySetInfo],
+        scheme: SecretScheme,
+        strategy: CoinSelectionStrategy,
+    ) -> Result<SendSummary>;
+    // the costs `prepare_send` would report, without keeping a reference to send
+    async fn estimate_send(
+        &self,
+        amount: Amount,
+        infos: &[KeySetInfo],
+        strategy: CoinSelectionStrategy,
+    ) -> Result<SendSummary>;
+    // sweeps the whole pocket, the summary amount is what can be sent after the input fees
+    async fn prepare_send_max(
+        &self,
+        infos: &[KeySetInfo],
+        scheme: SecretScheme,
+        strategy: CoinSelectionStrategy,
+    ) -> Result<SendSummary>;
     async fn send_proofs(
         &self,
         rid: Uuid,
@@ -47,15 +69,71 @@ pub trait PocketApi: SendSync {
         client: Arc<dyn ClowderMintConnector>,
         swap_config: SwapConfig,
     ) -> Result<HashMap<cashu::PublicKey, cashu::Proof>>;
+    /// drops the prepared send, its proofs are unspent again
+    async fn cancel_send(&self, rid: Uuid) -> Result<()>;
+    /// the sent proofs got swapped into locked ones, which are kept pending in their place
+    /// the change of the swap is unspent
+    async fn replace_sent_proofs(
+        &self,
+        sent: &[cashu::PublicKey],
+        locked: Vec<cashu::Proof>,
+        change: Vec<cashu::Proof>,
+    ) -> Result<()>;
+    /// deterministic outputs of the active keyset for `amount`, e.g. the change of a lock swap
+    async fn change_premint(
+        &self,
+        amount: Amount,
+        keysets_info: &[KeySetInfo],
+    ) -> Result<cdk00::PreMintSecrets>;
+    /// the sent proofs did not leave the wallet, they are unspent again
+    async fn revert_sent_proofs(&self, sent: &[cashu::PublicKey]) -> Result<()>;
+    /// proofs reserved before `tstamp` and not part of the prepared send are unspent again
+    /// returns the number of proofs released
+    async fn release_stale_reservations(&self, tstamp: u64) -> Result<usize>;
+    /// sends the prepared proofs as they are, fails if the send needs a swap
+    /// the prepared send is kept on failure
+    async fn send_proofs_offline(
+        &self,
+        rid: Uuid,
+    ) -> Result<HashMap<cashu::PublicKey, cashu::Proof>>;
     async fn cleanup_local_proofs(
         &self,
         client: Arc<dyn ClowderMintConnector>,
     ) -> Result<Vec<cashu::PublicKey>>;
+    /// removes the counters of keysets not in keysets_info that have no stored proofs left
+    async fn prune_counters(&self, keysets_info: &[KeySetInfo]) -> Result<usize>;
     async fn restore_local_proofs(
         &self,
         keysets_info: &[KeySetInfo],
         client: Arc<dyn ClowderMintConnector>,
+        progress: Option<RestoreProgressCallback>,
     ) -> Result<usize>;
+    /// restores only the given keysets, ignoring ids not in keysets_info or of another unit
+    async fn restore_keysets(
+        &self,
+        kids: &[cashu::Id],
+        keysets_info: &[KeySetInfo],
+        client: Arc<dyn ClowderMintConnector>,
+        progress: Option<RestoreProgressCallback>,
+    ) -> Result<usize>;
+    /// moves each keyset counter past the derivation indices used by the stored proofs
+    async fn rebuild_counters(
+        &self,
+        keysets_info: &[KeySetInfo],
+    ) -> Result<HashMap<cashu::Id, u32>>;
+    async fn backup_proofs(&self) -> Result<PocketBackup>;
+    /// stores the proofs of a backup not known yet, in the state they were backed up in
+    async fn import_backup(&self, backup: PocketBackup) -> Result<usize>;
+    /// advances the counters the mint has already signed outputs past
+    async fn verify_counters(
+        &self,
+        keysets_info: &[KeySetInfo],
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<Vec<crate::wallet::types::CounterAdjustment>>;
+    // the given ys of the proofs held by the pocket, pending ones excluded
+    async fn contains(&self, ys: &[cashu::PublicKey]) -> Result<Vec<cashu::PublicKey>>;
+    // the given ys of the proofs held by the pocket as pending-spent
+    async fn pending(&self, ys: &[cashu::PublicKey]) -> Result<Vec<cashu::PublicKey>>;
     async fn delete_proofs(&self) -> Result<HashMap<cashu::Id, Vec<cashu::Proof>>>;
     async fn return_proofs_to_send_for_offline_payment(
         &self,
@@ -74,12 +152,75 @@ pub trait PocketApi: SendSync {
         &self,
         keysets_info: &[KeySetInfo],
     ) -> Result<HashMap<cashu::Id, (Option<u64>, Amount)>>;
+    async fn stats(&self) -> Result<PocketStats>;
+    /// reconciles the local proof states with the mint, the `keep` proofs stay pending spent
+    async fn recheck(
+        &self,
+        keep: &[cashu::PublicKey],
+        client: Arc<dyn ClowderMintConnector>,
+    ) -> Result<RecheckSummary>;
 }
 
 #[derive(Default, Debug, Clone)]
 pub struct PocketBalance {
+    // unspent
     pub debit: Amount,
     pub credit: Amount,
+    // pending or pending-spent, e.g. during a send
+    pub debit_pending: Amount,
+    pub credit_pending: Amount,
+    pub debit_reserved: Amount,
+    pub credit_reserved: Amount,
+}
+
+// proof counts, e.g. to see why a wallet got slow
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PocketStats {
+    pub unspent: usize,
+    pub pending: usize,
+    pub reserved: usize,
+    // every stored proof, spent ones included
+    pub total: usize,
+    // distinct keysets among the unspent, pending and reserved proofs
+    pub keysets: usize,
+    // not spent proofs with random secrets, restoring from the seed can't find them
+    pub random: usize,
+}
+
+// what a recheck against the mint changed
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecheckSummary {
+    // spent on the mint, removed locally
+    pub deleted: usize,
+    // pending spent locally but unspent on the mint, spendable again
+    pub restored: usize,
+    // pending on the mint, left as they are
+    pub pending: usize,
+}
+
+// WARN: holds spendable secrets
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PocketBackup {
+    pub unspent: Vec<cdk00::Proof>,
+    pub pending: Vec<cdk00::Proof>,
+    pub locked: Vec<cdk00::Proof>,
+}
+
+///////////////////////////////////////////// is_credit_keyset
+// no final expiry -> debit
+// final expiry before today -> debit
+// final expiry today, or after -> credit
+pub(crate) fn is_credit_keyset(final_expiry: Option<u64>) -> bool {
+    let start_of_today = chrono::Utc::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .expect("valid date")
+        .and_utc()
+        .timestamp() as u64;
+    match final_expiry {
+        Some(expiry) => expiry >= start_of_today,
+        None => false,
+    }
 }
 
 ///////////////////////////////////////////// SendReference
@@ -88,6 +229,7 @@ struct SendReference {
     rid: Uuid,
     target_amount: Amount,
     plan: SendPlan,
+    scheme: SecretScheme,
 }
 
 #[derive(Debug, Clone)]
@@ -102,25 +244,126 @@ enum SendPlan {
     },
 }
 
+impl SendPlan {
+    // the proofs the plan spends, reserved until sent or canceled
+    fn ys(&self) -> Vec<cdk01::PublicKey> {
+        match self {
+            SendPlan::Ready { proofs } => proofs.clone(),
+            SendPlan::NeedSplit { proof, .. } => vec![*proof],
+        }
+    }
+}
+
+///////////////////////////////////////////// check_states
+// the states of all ys, checked in chunks and merged
+// callers match the states back to their proofs by y, not by position
+async fn check_states(
+    client: &dyn ClowderMintConnector,
+    ys: &[cdk01::PublicKey],
+) -> Result<Vec<cdk07::ProofState>> {
+    check_states_in_batches(client, ys, CHECK_STATE_BATCH_SIZE).await
+}
+
+async fn check_states_in_batches(
+    client: &dyn ClowderMintConnector,
+    ys: &[cdk01::PublicKey],
+    batch_size: usize,
+) -> Result<Vec<cdk07::ProofState>> {
+    let mut states = Vec::with_capacity(ys.len());
+    for batch in ys.chunks(batch_size) {
+        let request = cdk07::CheckStateRequest { ys: batch.to_vec() };
+        states.extend(client.post_check_state(request).await?);
+    }
+    Ok(states)
+}
+
 ///////////////////////////////////////////// cleanup_local_proofs
 // Removes Spent proofs from local DB
 async fn cleanup_local_proofs(
     db: &dyn PocketRepository,
     client: Arc<dyn ClowderMintConnector>,
+) -> Result<Vec<cdk01::PublicKey>> {
+    cleanup_local_proofs_in_batches(db, client, CHECK_STATE_BATCH_SIZE).await
+}
+
+// each batch is committed before the next one is checked, a failing batch
+// returns PartialCompletion with the number of proofs cleaned so far
+async fn cleanup_local_proofs_in_batches(
+    db: &dyn PocketRepository,
+    client: Arc<dyn ClowderMintConnector>,
+    batch_size: usize,
 ) -> Result<Vec<cdk01::PublicKey>> {
     let ys = db.list_all().await?;
-    let request = cdk07::CheckStateRequest { ys };
-    let response = client.post_check_state(request).await?;
-    let mut cleaned_ys: Vec<cdk01::PublicKey> = Vec::with_capacity(response.len());
-    for proofstate in response {
-        if proofstate.state == cdk07::State::Spent {
-            db.delete_proof(proofstate.y).await?;
-            cleaned_ys.push(proofstate.y);
+    let mut cleaned_ys: Vec<cdk01::PublicKey> = Vec::new();
+    for (batch_idx, batch) in ys.chunks(batch_size).enumerate() {
+        let request = cdk07::CheckStateRequest { ys: batch.to_vec() };
+        let response = match client.post_check_state(request).await {
+            Ok(response) => response,
+            Err(e) if batch_idx > 0 => {
+                return Err(Error::PartialCompletion {
+                    done: cleaned_ys.len(),
+                    error: Box::new(Error::from(e)),
+                });
+            }
+            Err(e) => return Err(e.into()),
+        };
+        for proofstate in response {
+            if proofstate.state == cdk07::State::Spent {
+                db.delete_proof(proofstate.y).await?;
+                cleaned_ys.push(proofstate.y);
+            }
         }
     }
     Ok(cleaned_ys)
 }
 
+///////////////////////////////////////////// recheck_local_proofs
+// spent proofs are deleted, pending spent ones unspent on the mint are unspent again
+// unless listed in `keep`, e.g. because an open transaction still holds them
+async fn recheck_local_proofs(
+    db: &dyn PocketRepository,
+    client: Arc<dyn ClowderMintConnector>,
+    keep: &[cdk01::PublicKey],
+) -> Result<RecheckSummary> {
+    let keep: HashSet<&cdk01::PublicKey> = keep.iter().collect();
+    let ys = db.list_all().await?;
+    let mut summary = RecheckSummary::default();
+    for proofstate in check_states(client.as_ref(), &ys).await? {
+        match proofstate.state {
+            cdk07::State::Spent => {
+                db.delete_proof(proofstate.y).await?;
+                summary.deleted += 1;
+            }
+            cdk07::State::Pending => summary.pending += 1,
+            cdk07::State::Unspent if !keep.contains(&proofstate.y) => {
+                let (_, state) = db.load_proof(proofstate.y).await?;
+                if state == cdk07::State::PendingSpent {
+                    db.revert_pendingspent_to_unspent(proofstate.y).await?;
+                    summary.restored += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(summary)
+}
+
+///////////////////////////////////////////// prune_counters
+// a counter of a keyset with stored proofs is kept, restoring them relies on it
+async fn prune_counters(db: &dyn PocketRepository, keysets_info: &[KeySetInfo]) -> Result<usize> {
+    let listed: HashSet<cashu::Id> = keysets_info.iter().map(|info| info.id).collect();
+    let mut pruned = 0;
+    for kid in db.list_counters().await? {
+        if listed.contains(&kid) {
+            continue;
+        }
+        if db.delete_unused_counter(kid).await? {
+            pruned += 1;
+        }
+    }
+    Ok(pruned)
+}
+
 ///////////////////////////////////////////// unblind_proofs
 pub(crate) fn unblind_proofs(
     keyset: &KeySet,
@@ -128,9 +371,9 @@ pub(crate) fn unblind_proofs(
     premint: cdk00::PreMintSecrets,
 ) -> Vec<cdk00::Proof> {
     let mut proofs: Vec<cdk00::Proof> = Vec::new();
-    if signatures.len() > premint.len() {
+    if signatures.len() != premint.len() {
         tracing::error!(
-            "signatures and premint len mismatch: {} > {}",
+            "signatures and premint len mismatch: {} != {}",
             signatures.len(),
             premint.len()
         )
@@ -207,6 +450,22 @@ pub(crate) async fn committed_swap(
     Ok(response.signatures)
 }
 
+///////////////////////////////////////////// check_signed_outputs
+/// a mint returning fewer signatures than outputs would silently keep part of the inputs
+fn check_signed_outputs(
+    blinds: &[cdk00::BlindedMessage],
+    signatures: &[cdk00::BlindSignature],
+) -> Result<()> {
+    let expected = blinds.iter().fold(Amount::ZERO, |acc, b| acc + b.amount);
+    let got = signatures
+        .iter()
+        .fold(Amount::ZERO, |acc, sig| acc + sig.amount);
+    if signatures.len() < blinds.len() || got < expected {
+        return Err(Error::SwapUnderSigned { expected, got });
+    }
+    Ok(())
+}
+
 ///////////////////////////////////////////// swap
 async fn swap(
     output_unit: CurrencyUnit,
@@ -228,11 +487,12 @@ async fn swap(
         client.as_ref(),
         Some(db),
         inputs,
-        blinds,
+        blinds.clone(),
         &swap_config,
         premints.iter().map(|(k, v)| (*k, v.clone())).collect(),
     )
     .await?;
+    check_signed_outputs(&blinds, &signatures)?;
 
     let output_len = signatures.len();
     let total_output = signatures
@@ -253,16 +513,16 @@ async fn swap(
         let premint = premints.remove(&kid).expect("premint should be here");
         let keyset = keysets.get(&kid).expect("keyset should be here");
         let proofs = unblind_proofs(keyset, sigs, premint);
+        let amount = proofs
+            .iter()
+            .fold(Amount::ZERO, |acc, proof| acc + proof.amount);
 
-        for proof in proofs {
-            let amount = proof.amount;
-            let response = db.store_new(proof).await;
-            if let Err(e) = response {
-                tracing::error!("failed at storing new proof: {kid}, {amount}, {e}");
-                continue;
-            }
-            total_cashed_in += amount;
+        // one transaction per keyset instead of one per proof
+        if let Err(e) = db.store_new_batch(proofs).await {
+            tracing::error!("failed at storing new proofs: {kid}, {amount}, {e}");
+            return Err(e.into());
         }
+        total_cashed_in += amount;
     }
     Ok(total_cashed_in)
 }
@@ -277,6 +537,7 @@ async fn swap_proof_to_target(
     db: &dyn PocketRepository,
     client: &Arc<dyn ClowderMintConnector>,
     swap_config: SwapConfig,
+    scheme: SecretScheme,
 ) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
     let kinfos: HashMap<cashu::Id, KeySetInfo> =
         keysets_info.iter().cloned().map(|k| (k.id, k)).collect();
@@ -288,46 +549,203 @@ async fn swap_proof_to_target(
             "Swap Plan didn't contain proof keyset to swap to".to_string(),
         ));
     };
-    let target = SplitTarget::Value(target_amount);
+    // with random secrets only the outputs sent away are random, the change is derived
+    // from the seed as always so restoring still finds it
+    let (derived_amount, target, random) = match scheme {
+        SecretScheme::Deterministic => (*swap_amount, SplitTarget::Value(target_amount), None),
+        SecretScheme::Random => {
+            let change = swap_amount
+                .checked_sub(target_amount)
+                .ok_or(Error::InsufficientBalance(*swap_amount, target_amount))?;
+            let random =
+                cdk00::PreMintSecrets::random(target_keyset.id, target_amount, &SplitTarget::None)?;
+            (change, SplitTarget::None, Some(random))
+        }
+    };
     let counter = db.counter(target_keyset.id).await?;
     let premint =
-        cdk00::PreMintSecrets::from_seed(target_keyset.id, counter, seed, *swap_amount, &target)?;
-    let blinds = premint.blinded_messages();
+        cdk00::PreMintSecrets::from_seed(target_keyset.id, counter, seed, derived_amount, &target)?;
     db.increment_counter(target_keyset.id, counter, premint.len() as u32)
         .await?;
+    let outputs = match &random {
+        Some(random) => {
+            let mut outputs = random.clone();
+            outputs.combine(premint.clone());
+            outputs
+        }
+        None => premint.clone(),
+    };
+    let blinds = outputs.blinded_messages();
 
-    let signatures = committed_swap(
+    let mut signatures = committed_swap(
         client.as_ref(),
         Some(db),
         vec![proof],
-        blinds,
+        blinds.clone(),
         &swap_config,
-        HashMap::from([(target_keyset.id, premint.clone())]),
+        HashMap::from([(target_keyset.id, outputs)]),
     )
     .await?;
+    check_signed_outputs(&blinds, &signatures)?;
+    if let Some(random) = random {
+        let change_signatures = signatures.split_off(random.len());
+        let change = unblind_proofs(target_keyset, change_signatures, premint);
+        db.store_new_batch(change).await?;
+        // marked, so the wallet knows only a backup brings them back
+        let sent = unblind_proofs(target_keyset, signatures, random);
+        let ys = db.store_random_batch(sent.clone()).await?;
+        return Ok(ys.into_iter().zip(sent).collect());
+    }
     let mut on_target: HashMap<cdk01::PublicKey, cdk00::Proof> = HashMap::new();
     let mut proofs = unblind_proofs(target_keyset, signatures, premint);
     proofs.sort_by_key(|proof| std::cmp::Reverse(proof.amount));
+    let ys = db.store_new_batch(proofs.clone()).await?;
     let mut current_amount = Amount::ZERO;
-    for proof in proofs.into_iter() {
-        let result = db.store_new(proof.clone()).await;
-        match result {
-            Ok(y) => {
-                if current_amount + proof.amount <= target_amount {
-                    current_amount += proof.amount;
-                    on_target.insert(y, proof);
+    for (y, proof) in ys.into_iter().zip(proofs) {
+        if current_amount + proof.amount <= target_amount {
+            current_amount += proof.amount;
+            on_target.insert(y, proof);
+        }
+    }
+    Ok(on_target)
+}
+
+///////////////////////////////////////////// select_exact_inputs
+// search nodes visited before giving up on exact change and falling back to a swap
+const EXACT_SELECTION_BOUND: usize = 10_000;
+
+// Picks proofs summing up exactly to the target plus their own input fees, so no swap is needed
+fn select_exact_inputs(
+    proofs: &[cdk00::Proof],
+    target: Amount,
+    kinfos: &HashMap<cashu::Id, KeySetInfo>,
+    strategy: CoinSelectionStrategy,
+) -> Option<Vec<cdk00::Proof>> {
+    // (index in proofs, amount, input fee ppk)
+    let mut candidates: Vec<(usize, u64, u64)> = proofs
+        .iter()
+        .enumerate()
+        .map(|(idx, proof)| {
+            let ppk = kinfos
+                .get(&proof.keyset_id)
+                .map(|info| info.input_fee_ppk)
+                .unwrap_or_default();
+            (idx, u64::from(proof.amount), ppk)
+        })
+        .collect();
+    if strategy == CoinSelectionStrategy::SmallestFirst {
+        candidates.sort_by_key(|(_, amount, _)| *amount);
+    } else {
+        // largest first to find small selections early
+        candidates.sort_by_key(|(_, amount, _)| std::cmp::Reverse(*amount));
+    }
+    let target = u64::from(target);
+    let chosen = match strategy {
+        CoinSelectionStrategy::LargestFirst | CoinSelectionStrategy::SmallestFirst => {
+            select_greedy(&candidates, target)
+        }
+        CoinSelectionStrategy::MinimizeSwaps => ExactSearch::new(candidates, target, false).find(),
+        CoinSelectionStrategy::MinimizeProofCount => {
+            ExactSearch::new(candidates, target, true).find()
+        }
+    }?;
+    Some(chosen.into_iter().map(|idx| proofs[idx].clone()).collect())
+}
+
+// takes every candidate in order that still fits below the target, exact only by chance
+fn select_greedy(candidates: &[(usize, u64, u64)], target: u64) -> Option<Vec<usize>> {
+    let (mut chosen, mut sum, mut ppk) = (Vec::new(), 0, 0);
+    for (idx, amount, input_ppk) in candidates {
+        if sum + amount > target + (ppk + input_ppk).div_ceil(1000) {
+            continue;
+        }
+        chosen.push(*idx);
+        sum += amount;
+        ppk += input_ppk;
+        if sum == target + ppk.div_ceil(1000) {
+            return Some(chosen);
+        }
+    }
+    None
+}
+
+struct ExactSearch {
+    candidates: Vec<(usize, u64, u64)>,
+    // amount and ppk still available from each position on
+    remaining: Vec<(u64, u64)>,
+    target: u64,
+    budget: usize,
+    chosen: Vec<usize>,
+    // set to keep searching for the selection with the fewest proofs
+    fewest: Option<Vec<usize>>,
+}
+
+impl ExactSearch {
+    fn new(candidates: Vec<(usize, u64, u64)>, target: u64, fewest: bool) -> Self {
+        let mut remaining = vec![(0, 0); candidates.len() + 1];
+        for i in (0..candidates.len()).rev() {
+            let (_, amount, ppk) = candidates[i];
+            let (rest_amount, rest_ppk) = remaining[i + 1];
+            remaining[i] = (rest_amount + amount, rest_ppk + ppk);
+        }
+        Self {
+            candidates,
+            remaining,
+            target,
+            budget: EXACT_SELECTION_BOUND,
+            chosen: Vec::new(),
+            fewest: fewest.then(Vec::new),
+        }
+    }
+
+    // indices of the selected candidates
+    fn find(mut self) -> Option<Vec<usize>> {
+        let found = self.run(0, 0, 0);
+        match self.fewest {
+            Some(fewest) => (!fewest.is_empty()).then_some(fewest),
+            None => found.then_some(self.chosen),
+        }
+    }
+
+    fn run(&mut self, start: usize, sum: u64, ppk: u64) -> bool {
+        if self.budget == 0 {
+            return false;
+        }
+        self.budget -= 1;
+        if !self.chosen.is_empty() && sum == self.target + ppk.div_ceil(1000) {
+            match &mut self.fewest {
+                Some(fewest) => {
+                    *fewest = self.chosen.clone();
+                    return false;
                 }
+                None => return true,
             }
-            Err(e) => {
-                tracing::error!(
-                    "error in storing proof {}, {}: {e}",
-                    target_keyset.id,
-                    proof.amount
-                );
+        }
+        if let Some(fewest) = &self.fewest
+            && !fewest.is_empty()
+            && self.chosen.len() + 1 >= fewest.len()
+        {
+            return false;
+        }
+        let (rest_amount, rest_ppk) = self.remaining[start];
+        if sum + rest_amount < self.target + ppk.div_ceil(1000) {
+            return false;
+        }
+        for i in start..self.candidates.len() {
+            let (idx, amount, input_ppk) = self.candidates[i];
+            let (sum, ppk) = (sum + amount, ppk + input_ppk);
+            // more inputs can't bring the sum back down below the target
+            if sum > self.target + (ppk + rest_ppk).div_ceil(1000) {
+                continue;
+            }
+            self.chosen.push(idx);
+            if self.run(i + 1, sum, ppk) {
+                return true;
             }
+            self.chosen.pop();
         }
+        false
     }
-    Ok(on_target)
 }
 
 ///////////////////////////////////////////// collect_keyset_infos_from_proofs
@@ -335,7 +753,14 @@ fn collect_keyset_infos_from_proofs<'it, 'inf>(
     proofs: impl Iterator<Item = &'it cdk00::Proof>,
     keysets_info: &'inf [KeySetInfo],
 ) -> Result<HashMap<cashu::Id, &'inf KeySetInfo>> {
-    let kids = proofs.map(|p| p.keyset_id).collect::<HashSet<_>>();
+    collect_keyset_infos(proofs.map(|p| p.keyset_id), keysets_info)
+}
+
+fn collect_keyset_infos<'inf>(
+    kids: impl Iterator<Item = cashu::Id>,
+    keysets_info: &'inf [KeySetInfo],
+) -> Result<HashMap<cashu::Id, &'inf KeySetInfo>> {
+    let kids = kids.collect::<HashSet<_>>();
     let mut infos: HashMap<cashu::Id, &'inf KeySetInfo> = HashMap::new();
     for kid in kids {
         let info = keysets_info.iter().find(|info| info.id == kid);
@@ -365,7 +790,57 @@ fn sign_content_b64(
     Ok(bitcoin::secp256k1::SECP256K1.sign_schnorr(&msg, keypair))
 }
 
+///////////////////////////////////////////// revert_pendingspent
+// best effort, a failed revert is logged and leaves the proof to `reclaim`
+async fn revert_pendingspent(db: &dyn PocketRepository, ys: &[cdk01::PublicKey]) {
+    for y in ys {
+        if let Err(e) = db.revert_pendingspent_to_unspent(*y).await {
+            tracing::error!("failed to revert proof {y} to unspent: {e}");
+        }
+    }
+}
+
+///////////////////////////////////////////// release_reserved
+// best effort, a failed release is logged and leaves the proof reserved until the next run
+async fn release_reserved(db: &dyn PocketRepository, ys: &[cdk01::PublicKey]) {
+    for y in ys {
+        if let Err(e) = db.revert_reserved_to_unspent(*y).await {
+            tracing::error!("failed to release reserved proof {y}: {e}");
+        }
+    }
+}
+
+///////////////////////////////////////////// sign_locked_input
+// Signs an input locked to our key, plain proofs are left as they are
+// locks we can't open alone with a single signature on the proof are unspendable
+fn sign_locked_input(proof: &mut cdk00::Proof, signing_key: &cashu::SecretKey) -> Result<()> {
+    let Ok(secret) = cashu::nut10::Secret::try_from(&proof.secret) else {
+        return Ok(());
+    };
+    let cashu::SpendingConditions::P2PKConditions { data, conditions } =
+        cashu::SpendingConditions::try_from(secret)?
+    else {
+        return Err(Error::SpendingConditions);
+    };
+    let ours = signing_key.public_key();
+    let mut signers = vec![data];
+    if let Some(conditions) = &conditions {
+        if conditions.num_sigs.unwrap_or(1) > 1
+            || matches!(conditions.sig_flag, cashu::nut11::SigFlag::SigAll)
+        {
+            return Err(Error::SpendingConditions);
+        }
+        signers.extend(conditions.pubkeys.iter().flatten().copied());
+    }
+    if !signers.contains(&ours) {
+        return Err(Error::SpendingConditions);
+    }
+    proof.sign_p2pk(signing_key.clone())?;
+    Ok(())
+}
+
 ///////////////////////////////////////////// send_proofs
+// on error, the proofs marked pending-spent and not swapped yet are unspent again
 async fn send_proofs(
     plan: SendPlan,
     keysets_info: &[KeySetInfo],
@@ -374,14 +849,70 @@ async fn send_proofs(
     db: &dyn PocketRepository,
     client: &Arc<dyn ClowderMintConnector>,
     swap_config: SwapConfig,
+    scheme: SecretScheme,
+) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
+    let mut reserved = plan.ys();
+    let mut marked: Vec<cdk01::PublicKey> = Vec::new();
+    let result = send_marked_proofs(
+        plan,
+        keysets_info,
+        target_amount,
+        seed,
+        db,
+        client,
+        swap_config,
+        scheme,
+        &mut reserved,
+        &mut marked,
+    )
+    .await;
+    if result.is_err() {
+        revert_pendingspent(db, &marked).await;
+        // the send reference is gone, nothing else releases the inputs not marked yet
+        release_reserved(db, &reserved).await;
+    }
+    result
+}
+
+// moves an input to pending spent, keeping track of what a failed send has to give back
+async fn mark_input(
+    db: &dyn PocketRepository,
+    y: cdk01::PublicKey,
+    reserved: &mut Vec<cdk01::PublicKey>,
+    marked: &mut Vec<cdk01::PublicKey>,
+) -> Result<cdk00::Proof> {
+    let proof = db.mark_as_pendingspent(y).await?;
+    reserved.retain(|r| *r != y);
+    marked.push(y);
+    Ok(proof)
+}
+
+async fn send_marked_proofs(
+    plan: SendPlan,
+    keysets_info: &[KeySetInfo],
+    target_amount: Amount,
+    seed: &Seed,
+    db: &dyn PocketRepository,
+    client: &Arc<dyn ClowderMintConnector>,
+    swap_config: SwapConfig,
+    scheme: SecretScheme,
+    reserved: &mut Vec<cdk01::PublicKey>,
+    marked: &mut Vec<cdk01::PublicKey>,
 ) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
     let mut current_amount = Amount::ZERO;
     let mut sending_proofs: HashMap<cdk01::PublicKey, cdk00::Proof> = HashMap::new();
 
+    // locked inputs are signed with the wallet key, any we can't spend fails before marking
+    let signing_key = cashu::SecretKey::from(keypair_from_seed(*seed).secret_key());
+    for mut input in db.load_proofs(&plan.ys()).await?.into_values() {
+        sign_locked_input(&mut input, &signing_key)?;
+    }
+
     match plan {
         SendPlan::Ready { proofs } => {
             for y in proofs {
-                let proof = db.mark_as_pendingspent(y).await?;
+                let mut proof = mark_input(db, y, reserved, marked).await?;
+                sign_locked_input(&mut proof, &signing_key)?;
                 current_amount += proof.amount;
                 sending_proofs.insert(y, proof);
             }
@@ -394,7 +925,8 @@ async fn send_proofs(
             tracing::debug!(
                 "Send Proof for {target_amount} - splitting with split {split_amount} and {estimated_fee} fee"
             );
-            let swap_proof = db.mark_as_pendingspent(proof).await?;
+            let mut swap_proof = mark_input(db, proof, reserved, marked).await?;
+            sign_locked_input(&mut swap_proof, &signing_key)?;
             let target_kid = swap_proof.keyset_id;
             let swap_proof_keyset = client.get_mint_keyset(target_kid).await?;
 
@@ -407,8 +939,11 @@ async fn send_proofs(
                 db,
                 client,
                 swap_config,
+                scheme,
             )
             .await?;
+            // spent in the swap, a later failure must not revert it
+            marked.retain(|y| *y != proof);
 
             // after swap, do prepare_payment again, expecting Ready and send proofs
             let unspent_proofs = db.list_unspent().await?;
@@ -429,7 +964,8 @@ async fn send_proofs(
                         .map(|proof| proof.y())
                         .collect::<std::result::Result<Vec<cashu::PublicKey>, _>>()?;
                     for y in proofs_to_send {
-                        let proof = db.mark_as_pendingspent(y).await?;
+                        let mut proof = mark_input(db, y, reserved, marked).await?;
+                        sign_locked_input(&mut proof, &signing_key)?;
                         current_amount += proof.amount;
                         sending_proofs.insert(y, proof);
                     }
@@ -442,7 +978,11 @@ async fn send_proofs(
     };
 
     if current_amount < target_amount {
-        tracing::warn!("Send Proofs: Target was {target_amount}, sending only {current_amount}");
+        tracing::error!("Send Proofs: Target was {target_amount}, got only {current_amount}");
+        return Err(Error::SendAmountMismatch {
+            got: current_amount,
+            expected: target_amount,
+        });
     }
 
     Ok(sending_proofs)
@@ -489,6 +1029,274 @@ mod tests {
     use cashu::nut02 as cdk02;
     use mockall::predicate::*;
 
+    #[test]
+    fn select_exact_inputs_covers_input_fees() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let mut info = KeySetInfo::from(info);
+        info.input_fee_ppk = 1000;
+        let kinfos = HashMap::from([(info.id, info)]);
+        let amounts = [Amount::from(8u64), Amount::from(4u64), Amount::from(1u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let strategy = CoinSelectionStrategy::default();
+
+        // 8 + 4 - 2 sat input fees
+        let selected =
+            select_exact_inputs(&proofs, Amount::from(10u64), &kinfos, strategy).unwrap();
+        assert_eq!(selected.total_amount().unwrap(), Amount::from(12u64));
+        // 8 - 1 sat input fee
+        let selected = select_exact_inputs(&proofs, Amount::from(7u64), &kinfos, strategy).unwrap();
+        assert_eq!(selected.total_amount().unwrap(), Amount::from(8u64));
+        // exact without fees, not with them
+        assert!(select_exact_inputs(&proofs, Amount::from(12u64), &kinfos, strategy).is_none());
+    }
+
+    #[test]
+    fn select_exact_inputs_greedy_strategies() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let info = KeySetInfo::from(info);
+        let kinfos = HashMap::from([(info.id, info)]);
+        let amounts = [
+            Amount::from(1u64),
+            Amount::from(1u64),
+            Amount::from(2u64),
+            Amount::from(4u64),
+        ];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+
+        let largest = select_exact_inputs(
+            &proofs,
+            Amount::from(4u64),
+            &kinfos,
+            CoinSelectionStrategy::LargestFirst,
+        )
+        .unwrap();
+        assert_eq!(largest.len(), 1);
+        let smallest = select_exact_inputs(
+            &proofs,
+            Amount::from(4u64),
+            &kinfos,
+            CoinSelectionStrategy::SmallestFirst,
+        )
+        .unwrap();
+        assert_eq!(smallest.len(), 3);
+        // 1 + 1 + 2 fit, then 4 overshoots, the search still finds 4 + 1
+        assert!(
+            select_exact_inputs(
+                &proofs,
+                Amount::from(5u64),
+                &kinfos,
+                CoinSelectionStrategy::SmallestFirst
+            )
+            .is_none()
+        );
+        assert!(
+            select_exact_inputs(
+                &proofs,
+                Amount::from(5u64),
+                &kinfos,
+                CoinSelectionStrategy::MinimizeSwaps
+            )
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn exact_search_fewest_keeps_searching() {
+        let candidates = vec![(0, 6, 0), (1, 4, 0), (2, 4, 0), (3, 1, 0), (4, 1, 0)];
+        // 6 + 1 + 1 is found first, 4 + 4 is shorter
+        let first = ExactSearch::new(candidates.clone(), 8, false).find();
+        assert_eq!(first, Some(vec![0, 3, 4]));
+        let fewest = ExactSearch::new(candidates, 8, true).find();
+        assert_eq!(fewest, Some(vec![1, 2]));
+    }
+
+    #[tokio::test]
+    async fn prune_counters_skips_listed_keysets() {
+        let (listed, _) = core_tests::generate_random_ecash_keyset();
+        let (_, gone) = core_tests::generate_random_ecash_keyset();
+        let (_, in_use) = core_tests::generate_random_ecash_keyset();
+        let listed = KeySetInfo::from(listed);
+        let (listed_id, gone_id, in_use_id) = (listed.id, gone.id, in_use.id);
+
+        let mut mockdb = MockPocketRepository::new();
+        mockdb
+            .expect_list_counters()
+            .times(1)
+            .returning(move || Ok(vec![listed_id, gone_id, in_use_id]));
+        mockdb
+            .expect_delete_unused_counter()
+            .with(eq(gone_id))
+            .times(1)
+            .returning(|_| Ok(true));
+        mockdb
+            .expect_delete_unused_counter()
+            .with(eq(in_use_id))
+            .times(1)
+            .returning(|_| Ok(false));
+
+        let pruned = super::prune_counters(&mockdb, &[listed]).await.unwrap();
+        assert_eq!(pruned, 1);
+    }
+
+    #[tokio::test]
+    async fn cleanup_local_proofs_keeps_batches_before_error() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(1u64); 5];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<cdk01::PublicKey> = proofs.iter().map(|p| p.y().unwrap()).collect();
+
+        let mut mockdb = MockPocketRepository::new();
+        let cloned_ys = ys.clone();
+        mockdb
+            .expect_list_all()
+            .times(1)
+            .returning(move || Ok(cloned_ys.clone()));
+        let mut mockclient = MockMintConnector::new();
+        let mut calls = 0;
+        mockclient
+            .expect_post_check_state()
+            .times(3)
+            .returning(move |request| {
+                calls += 1;
+                if calls == 3 {
+                    return Err(bcr_common::client::mint::Error::Internal(String::from(
+                        "mint down",
+                    )));
+                }
+                Ok(request
+                    .ys
+                    .iter()
+                    .map(|y| cdk07::ProofState {
+                        y: *y,
+                        state: cdk07::State::Spent,
+                        witness: None,
+                    })
+                    .collect())
+            });
+        for y in ys.iter().take(2) {
+            mockdb
+                .expect_delete_proof()
+                .with(eq(*y))
+                .times(1)
+                .returning(|_| Ok(None));
+        }
+
+        let res = super::cleanup_local_proofs_in_batches(&mockdb, Arc::new(mockclient), 1).await;
+        match res {
+            Err(Error::PartialCompletion { done, .. }) => assert_eq!(done, 2),
+            _ => panic!("expected PartialCompletion"),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_states_merges_batches() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [1u64, 2, 4, 8, 16].map(Amount::from);
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<_> = proofs.iter().map(|p| p.y().unwrap()).collect();
+        let spent = ys[3];
+
+        let mut mockclient = MockMintConnector::new();
+        mockclient
+            .expect_post_check_state()
+            .times(3)
+            .returning(move |request| {
+                assert!(request.ys.len() <= 2);
+                // answered out of order, the states are matched by y
+                Ok(request
+                    .ys
+                    .iter()
+                    .rev()
+                    .map(|y| cdk07::ProofState {
+                        y: *y,
+                        state: if *y == spent {
+                            cdk07::State::Spent
+                        } else {
+                            cdk07::State::Unspent
+                        },
+                        witness: None,
+                    })
+                    .collect())
+            });
+
+        let states = super::check_states_in_batches(&mockclient, &ys, 2)
+            .await
+            .unwrap();
+        assert_eq!(states.len(), ys.len());
+        assert!(ys.iter().all(|y| states.iter().any(|s| s.y == *y)));
+        let spent_state = states.iter().find(|s| s.y == spent).unwrap();
+        assert_eq!(spent_state.state, cdk07::State::Spent);
+    }
+
+    #[tokio::test]
+    async fn recheck_local_proofs_reconciles_with_mint() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [1u64, 2, 4, 8, 16].map(Amount::from);
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys: Vec<_> = proofs.iter().map(|p| p.y().unwrap()).collect();
+        // spent, pending on the mint, stuck pending spent, held by an open tx, plain unspent
+        let mint_states = HashMap::from([
+            (ys[0], cdk07::State::Spent),
+            (ys[1], cdk07::State::Pending),
+            (ys[2], cdk07::State::Unspent),
+            (ys[3], cdk07::State::Unspent),
+            (ys[4], cdk07::State::Unspent),
+        ]);
+
+        let mut mockdb = MockPocketRepository::new();
+        let cloned_ys = ys.clone();
+        mockdb
+            .expect_list_all()
+            .times(1)
+            .returning(move || Ok(cloned_ys.clone()));
+        mockdb
+            .expect_delete_proof()
+            .with(eq(ys[0]))
+            .times(1)
+            .returning(|_| Ok(None));
+        let local = HashMap::from([
+            (ys[2], (proofs[2].clone(), cdk07::State::PendingSpent)),
+            (ys[4], (proofs[4].clone(), cdk07::State::Unspent)),
+        ]);
+        mockdb
+            .expect_load_proof()
+            .times(2)
+            .returning(move |y| Ok(local.get(&y).unwrap().clone()));
+        let restored = proofs[2].clone();
+        mockdb
+            .expect_revert_pendingspent_to_unspent()
+            .with(eq(ys[2]))
+            .times(1)
+            .returning(move |_| Ok(restored.clone()));
+        let mut mockclient = MockMintConnector::new();
+        mockclient
+            .expect_post_check_state()
+            .times(1)
+            .returning(move |request| {
+                Ok(request
+                    .ys
+                    .iter()
+                    .map(|y| cdk07::ProofState {
+                        y: *y,
+                        state: mint_states[y],
+                        witness: None,
+                    })
+                    .collect())
+            });
+
+        let summary = super::recheck_local_proofs(&mockdb, Arc::new(mockclient), &[ys[3]])
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            RecheckSummary {
+                deleted: 1,
+                restored: 1,
+                pending: 1,
+            }
+        );
+    }
+
     #[test]
     fn unblind_proofs() {
         let amounts = [Amount::from(8u64)];
@@ -584,10 +1392,11 @@ mod tests {
                     signatures: mock_signatures,
                 })
             });
-        mockdb.expect_store_new().times(5).returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        mockdb
+            .expect_store_new_batch()
+            .withf(|proofs| proofs.len() == 5)
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(mockclient);
         let proofs = super::swap_proof_to_target(
@@ -599,6 +1408,7 @@ mod tests {
             &mockdb,
             &arc_client,
             test_swap_config(),
+            SecretScheme::Deterministic,
         )
         .await
         .unwrap();
@@ -608,6 +1418,89 @@ mod tests {
         assert_eq!(total, target);
     }
 
+    #[tokio::test]
+    async fn swap_proof_to_target_random_secrets() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amount = Amount::from(16u64);
+        let target = Amount::from(13u64);
+        let proof = core_tests::generate_random_ecash_proofs(&keyset, &[amount])[0].clone();
+        let seed = zero_seed();
+        let mut mockdb = MockPocketRepository::new();
+        let mut mockclient = MockMintConnector::new();
+        // only the change 3 = 2 + 1 is derived
+        mockdb
+            .expect_counter()
+            .times(1)
+            .with(eq(keyset.id))
+            .returning(|_| Ok(0));
+        mockdb
+            .expect_increment_counter()
+            .times(1)
+            .with(eq(keyset.id), eq(0), eq(2))
+            .returning(|_, _, _| Ok(()));
+        let cloned_keyset = keyset.clone();
+        setup_commitment_mocks(&mut mockclient, &mut mockdb);
+        mockclient
+            .expect_post_swap_committed()
+            .times(1)
+            .returning(move |request| {
+                let amounts = request.outputs.iter().map(|b| b.amount).collect::<Vec<_>>();
+                let mock_signatures =
+                    core_tests::generate_ecash_signatures(&cloned_keyset, &amounts);
+                Ok(bcr_common::wire::swap::SwapResponse {
+                    signatures: mock_signatures,
+                })
+            });
+        let restorable = cdk00::PreMintSecrets::from_seed(
+            keyset.id,
+            0,
+            &seed,
+            amount - target,
+            &SplitTarget::None,
+        )
+        .unwrap();
+        let restorable_secrets = restorable
+            .iter()
+            .map(|pre| pre.secret.clone())
+            .collect::<Vec<_>>();
+        let change_secrets = restorable_secrets.clone();
+        mockdb
+            .expect_store_new_batch()
+            .times(1)
+            .returning(move |proofs| {
+                assert_eq!(proofs.total_amount().unwrap(), Amount::from(3u64));
+                assert!(proofs.iter().all(|p| change_secrets.contains(&p.secret)));
+                Ok(proofs.iter().map(|p| p.y().unwrap()).collect())
+            });
+        mockdb
+            .expect_store_random_batch()
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
+        mockdb.expect_store_new().never();
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(mockclient);
+        let proofs = super::swap_proof_to_target(
+            proof,
+            &k_infos,
+            &KeySet::from(keyset.clone()),
+            target,
+            &seed,
+            &mockdb,
+            &arc_client,
+            test_swap_config(),
+            SecretScheme::Random,
+        )
+        .await
+        .unwrap();
+        assert_eq!(proofs.len(), 3);
+        let p: Vec<Proof> = proofs.values().cloned().collect();
+        assert_eq!(p.total_amount().unwrap(), target);
+
+        // restoring from the seed doesn't yield the random secrets
+        assert!(p.iter().all(|p| !restorable_secrets.contains(&p.secret)));
+    }
+
     #[tokio::test]
     async fn swap() {
         let (info, keyset) = core_tests::generate_random_ecash_keyset();
@@ -631,10 +1524,11 @@ mod tests {
                 let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
-        mockdb.expect_store_new().times(2).returning(|p| {
-            let y = p.y().expect("Hash to curve should not fail");
-            Ok(y)
-        });
+        mockdb
+            .expect_store_new_batch()
+            .withf(|proofs| proofs.len() == 2)
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(mockclient);
         let amount = super::swap(
@@ -651,6 +1545,104 @@ mod tests {
         assert_eq!(amount, Amount::from(24u64));
     }
 
+    #[tokio::test]
+    async fn swap_fails_if_storing_fails() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let inputs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let premints = HashMap::from_iter([(
+            info.id,
+            cdk00::PreMintSecrets::random(info.id, Amount::from(24u64), &SplitTarget::None)
+                .unwrap(),
+        )]);
+        let keysets = HashMap::from([(info.id, KeySet::from(keyset.clone()))]);
+        let mut mockclient = MockMintConnector::new();
+        let mut mockdb = MockPocketRepository::new();
+        setup_commitment_mocks(&mut mockclient, &mut mockdb);
+        mockclient
+            .expect_post_swap_committed()
+            .times(1)
+            .returning(move |request| {
+                let amounts = request.outputs.iter().map(|b| b.amount).collect::<Vec<_>>();
+                let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
+                Ok(bcr_common::wire::swap::SwapResponse { signatures })
+            });
+        mockdb.expect_store_new_batch().times(1).returning(|_| {
+            Err(bcr_wallet_persistence::error::Error::Custom(String::from(
+                "full",
+            )))
+        });
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(mockclient);
+        let err = super::swap(
+            CurrencyUnit::Sat,
+            inputs,
+            premints,
+            keysets,
+            arc_client,
+            &mockdb,
+            test_swap_config(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Database(_)));
+    }
+
+    #[tokio::test]
+    async fn swap_under_signed() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let inputs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let premints = HashMap::from_iter([(
+            info.id,
+            cdk00::PreMintSecrets::random(info.id, Amount::from(24u64), &SplitTarget::None)
+                .unwrap(),
+        )]);
+        let keysets = HashMap::from([(info.id, KeySet::from(keyset.clone()))]);
+        let mut mockclient = MockMintConnector::new();
+        let mut mockdb = MockPocketRepository::new();
+        setup_commitment_mocks(&mut mockclient, &mut mockdb);
+        // only the first output gets signed
+        mockclient
+            .expect_post_swap_committed()
+            .times(1)
+            .returning(move |request| {
+                let amounts = [request.outputs[0].amount];
+                let signatures = core_tests::generate_ecash_signatures(&keyset, &amounts);
+                Ok(bcr_common::wire::swap::SwapResponse { signatures })
+            });
+        mockdb.expect_store_new_batch().never();
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(mockclient);
+        let err = super::swap(
+            CurrencyUnit::Sat,
+            inputs,
+            premints,
+            keysets,
+            arc_client,
+            &mockdb,
+            test_swap_config(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::SwapUnderSigned { expected, got }
+                if expected == Amount::from(24u64) && got < expected
+        ));
+    }
+
+    // the inputs `send_proofs` checks for locks before marking them
+    fn expect_load_inputs(db: &mut MockPocketRepository, inputs: Vec<cdk00::Proof>) {
+        db.expect_load_proofs().times(1).returning(move |ys| {
+            Ok(inputs
+                .iter()
+                .map(|p| (p.y().unwrap(), p.clone()))
+                .filter(|(y, _)| ys.contains(y))
+                .collect())
+        });
+    }
+
     #[tokio::test]
     async fn send_proofs_ready() {
         let (_, keyset) = core_tests::generate_random_ecash_keyset();
@@ -665,6 +1657,7 @@ mod tests {
             .collect::<HashMap<_, _>>();
 
         let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, proofs.clone());
         mockdb
             .expect_mark_as_pendingspent()
             .times(2)
@@ -681,6 +1674,7 @@ mod tests {
             &mockdb,
             &arc_client,
             test_swap_config(),
+            SecretScheme::Deterministic,
         )
         .await
         .unwrap();
@@ -719,6 +1713,7 @@ mod tests {
         let unspent = ready_by_y.clone();
 
         let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, vec![swap_proof.clone()]);
         let mut mockclient = MockMintConnector::new();
 
         mockdb.expect_counter().times(1).returning(|_| Ok(0));
@@ -747,7 +1742,10 @@ mod tests {
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
 
-        mockdb.expect_store_new().returning(|p| Ok(p.y().unwrap()));
+        mockdb
+            .expect_store_new_batch()
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         mockdb
             .expect_list_unspent()
@@ -779,6 +1777,7 @@ mod tests {
             &mockdb,
             &arc_client,
             test_swap_config(),
+            SecretScheme::Deterministic,
         )
         .await
         .unwrap();
@@ -808,6 +1807,7 @@ mod tests {
         let unspent = HashMap::from([(unsplittable.y().unwrap(), unsplittable)]);
 
         let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, vec![swap_proof.clone()]);
         let mut mockclient = MockMintConnector::new();
 
         mockdb.expect_counter().times(1).returning(|_| Ok(0));
@@ -836,7 +1836,10 @@ mod tests {
                 Ok(bcr_common::wire::swap::SwapResponse { signatures })
             });
 
-        mockdb.expect_store_new().returning(|p| Ok(p.y().unwrap()));
+        mockdb
+            .expect_store_new_batch()
+            .times(1)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         mockdb
             .expect_list_unspent()
@@ -863,10 +1866,204 @@ mod tests {
             &mockdb,
             &arc_client,
             test_swap_config(),
+            SecretScheme::Deterministic,
         )
         .await
         .unwrap_err();
 
         assert!(matches!(err, Error::ExcessiveSplitting(a) if a == Amount::from(13u64)));
     }
+
+    #[tokio::test]
+    async fn send_proofs_short_of_target_reverts_pending() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        let y = proofs[0].y().unwrap();
+
+        let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, proofs.clone());
+        let proof = proofs[0].clone();
+        mockdb
+            .expect_mark_as_pendingspent()
+            .times(1)
+            .with(eq(y))
+            .returning(move |_| Ok(proof.clone()));
+        let proof = proofs[0].clone();
+        mockdb
+            .expect_revert_pendingspent_to_unspent()
+            .times(1)
+            .with(eq(y))
+            .returning(move |_| Ok(proof.clone()));
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(MockMintConnector::new());
+
+        let err = super::send_proofs(
+            SendPlan::Ready { proofs: vec![y] },
+            &k_infos,
+            Amount::from(16u64),
+            &zero_seed(),
+            &mockdb,
+            &arc_client,
+            test_swap_config(),
+            SecretScheme::Deterministic,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::SendAmountMismatch { got, expected }
+                if got == Amount::from(8u64) && expected == Amount::from(16u64)
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_proofs_failed_marking_reverts_marked() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let amounts = [Amount::from(8u64), Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let y0 = proofs[0].y().unwrap();
+        let y1 = proofs[1].y().unwrap();
+
+        let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, proofs.clone());
+        let proof = proofs[0].clone();
+        mockdb
+            .expect_mark_as_pendingspent()
+            .times(1)
+            .with(eq(y0))
+            .returning(move |_| Ok(proof.clone()));
+        mockdb
+            .expect_mark_as_pendingspent()
+            .times(1)
+            .with(eq(y1))
+            .returning(|y| Err(bcr_wallet_persistence::error::Error::InvalidProofState(y)));
+        let proof = proofs[0].clone();
+        mockdb
+            .expect_revert_pendingspent_to_unspent()
+            .times(1)
+            .with(eq(y0))
+            .returning(move |_| Ok(proof.clone()));
+        // not marked, so still reserved
+        let proof = proofs[1].clone();
+        mockdb
+            .expect_revert_reserved_to_unspent()
+            .times(1)
+            .with(eq(y1))
+            .returning(move |_| Ok(proof.clone()));
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(MockMintConnector::new());
+
+        let res = super::send_proofs(
+            SendPlan::Ready {
+                proofs: vec![y0, y1],
+            },
+            &k_infos,
+            Amount::from(24u64),
+            &zero_seed(),
+            &mockdb,
+            &arc_client,
+            test_swap_config(),
+            SecretScheme::Deterministic,
+        )
+        .await;
+        assert!(res.is_err());
+    }
+
+    fn locked_proof(keyset: &cashu::KeySet, locked_to: cashu::PublicKey) -> cdk00::Proof {
+        let conditions = cashu::SpendingConditions::new_p2pk(locked_to, None);
+        let mut proof =
+            core_tests::generate_random_ecash_proofs(keyset, &[Amount::from(8u64)])[0].clone();
+        proof.secret = cashu::nut10::Secret::from(conditions).try_into().unwrap();
+        proof
+    }
+
+    #[test]
+    fn sign_locked_input_signs_only_our_locks() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let ours = cashu::SecretKey::generate();
+
+        let mut plain =
+            core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)])[0].clone();
+        super::sign_locked_input(&mut plain, &ours).unwrap();
+        assert!(plain.witness.is_none());
+
+        let mut locked = locked_proof(&keyset, ours.public_key());
+        super::sign_locked_input(&mut locked, &ours).unwrap();
+        assert!(matches!(
+            locked.witness,
+            Some(cdk00::Witness::P2PKWitness(_))
+        ));
+
+        let mut foreign = locked_proof(&keyset, cashu::SecretKey::generate().public_key());
+        let err = super::sign_locked_input(&mut foreign, &ours).unwrap_err();
+        assert!(matches!(err, Error::SpendingConditions));
+    }
+
+    #[tokio::test]
+    async fn send_proofs_foreign_lock_fails_before_marking() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let foreign = locked_proof(&keyset, cashu::SecretKey::generate().public_key());
+        let y = foreign.y().unwrap();
+
+        let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, vec![foreign.clone()]);
+        mockdb.expect_mark_as_pendingspent().never();
+        mockdb
+            .expect_revert_reserved_to_unspent()
+            .times(1)
+            .with(eq(y))
+            .returning(move |_| Ok(foreign.clone()));
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(MockMintConnector::new());
+
+        let err = super::send_proofs(
+            SendPlan::Ready { proofs: vec![y] },
+            &k_infos,
+            Amount::from(8u64),
+            &zero_seed(),
+            &mockdb,
+            &arc_client,
+            test_swap_config(),
+            SecretScheme::Deterministic,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::SpendingConditions));
+    }
+
+    #[tokio::test]
+    async fn send_proofs_signs_inputs_locked_to_us() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let k_infos = vec![KeySetInfo::from(info)];
+        let ours = keypair_from_seed(zero_seed()).public_key();
+        let locked = locked_proof(&keyset, cashu::PublicKey::from(ours));
+        let y = locked.y().unwrap();
+
+        let mut mockdb = MockPocketRepository::new();
+        expect_load_inputs(&mut mockdb, vec![locked.clone()]);
+        mockdb
+            .expect_mark_as_pendingspent()
+            .times(1)
+            .with(eq(y))
+            .returning(move |_| Ok(locked.clone()));
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(MockMintConnector::new());
+
+        let sent = super::send_proofs(
+            SendPlan::Ready { proofs: vec![y] },
+            &k_infos,
+            Amount::from(8u64),
+            &zero_seed(),
+            &mockdb,
+            &arc_client,
+            test_swap_config(),
+            SecretScheme::Deterministic,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            sent.get(&y).unwrap().witness,
+            Some(cdk00::Witness::P2PKWitness(_))
+        ));
+    }
 }
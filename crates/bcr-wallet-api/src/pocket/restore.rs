@@ -1,25 +1,55 @@
-use crate::{ClowderMintConnector, error::Result};
+use crate::{
+    ClowderMintConnector,
+    config::{RESTORE_RETRY_ATTEMPTS, RESTORE_RETRY_BACKOFF_MILLIS},
+    error::{Error, Result},
+    wallet::types::CounterAdjustment,
+};
 use bcr_common::cashu::{self, nut00 as cdk00, nut01 as cdk01, nut07 as cdk07, nut09 as cdk09};
-use bcr_wallet_core::types::Seed;
+use bcr_wallet_core::types::{RestoreProgress, RestoreProgressCallback, Seed};
 use bcr_wallet_persistence::PocketRepository;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 // as recommended by NUT13
 const EMPTY_RESPONSES_BEFORE_ABORT: usize = 3;
-const BATCH_SIZE: u32 = 100;
+pub(super) const BATCH_SIZE: u32 = 100;
+// how far to derive secrets when rebuilding a lost counter
+pub const COUNTER_REBUILD_BOUND: u32 = 10_000;
 
 pub async fn restore_keysetid(
     seed: &Seed,
     kid: cashu::Id,
     client: &Arc<dyn ClowderMintConnector>,
     db: &dyn PocketRepository,
+    progress: Option<&RestoreProgressCallback>,
 ) -> Result<usize> {
     let mut zero_response_counter = 0;
     let mut total_proofs_restored = 0;
     let mut dbcursor = db.counter(kid).await?;
     let mut cursor = dbcursor;
     while zero_response_counter < EMPTY_RESPONSES_BEFORE_ABORT {
-        let restored_proofs = restore_batch(seed, kid, client, db, cursor, BATCH_SIZE).await?;
+        // earlier batches are stored and counted, the counter makes resuming skip them
+        let restored_proofs = match restore_batch(seed, kid, client, db, cursor, BATCH_SIZE).await {
+            Ok(restored_proofs) => restored_proofs,
+            Err(e) => {
+                return Err(Error::RestoreInterrupted {
+                    kid,
+                    restored: total_proofs_restored,
+                    last_good_counter: dbcursor,
+                    error: Box::new(e),
+                });
+            }
+        };
+        if let Some(progress) = progress {
+            progress(RestoreProgress {
+                keyset_id: kid,
+                batch_start: cursor,
+                restored_in_batch: restored_proofs,
+                cumulative: total_proofs_restored + restored_proofs,
+            });
+        }
         cursor += BATCH_SIZE;
         if restored_proofs == 0 {
             zero_response_counter += 1;
@@ -34,6 +64,138 @@ pub async fn restore_keysetid(
     Ok(total_proofs_restored)
 }
 
+// moves the counter past the highest derivation index found among the secrets,
+// returns the resulting counter
+pub async fn rebuild_keysetid_counter(
+    seed: &Seed,
+    kid: cashu::Id,
+    secrets: &HashSet<cashu::secret::Secret>,
+    db: &dyn PocketRepository,
+    bound: u32,
+) -> Result<u32> {
+    let mut next_unused = 0;
+    let mut found = 0;
+    let mut start = 0;
+    while start < bound && found < secrets.len() {
+        let end = std::cmp::min(start + BATCH_SIZE, bound) - 1;
+        let premints = cdk00::PreMintSecrets::restore_batch(kid, seed, start, end)?;
+        for (idx, premint) in (start..).zip(premints.iter()) {
+            if secrets.contains(&premint.secret) {
+                found += 1;
+                next_unused = idx + 1;
+            }
+        }
+        start = end + 1;
+    }
+    let counter = db.counter(kid).await?;
+    if next_unused > counter {
+        db.increment_counter(kid, counter, next_unused - counter)
+            .await?;
+        return Ok(next_unused);
+    }
+    Ok(counter)
+}
+
+// moves the counter past the highest index the mint already signed, e.g. after a crash
+// between storing the proofs and incrementing the counter. A counter ahead of the last
+// signed index only leaves a gap, it is reported but kept, counters never move back.
+// Scans from the first index, past the counter it stops as restore does
+pub async fn verify_keysetid_counter(
+    seed: &Seed,
+    kid: cashu::Id,
+    client: &Arc<dyn ClowderMintConnector>,
+    db: &dyn PocketRepository,
+) -> Result<Option<CounterAdjustment>> {
+    let counter = db.counter(kid).await?;
+    let mut cursor = 0;
+    let mut next_unused = 0;
+    let mut zero_response_counter = 0;
+    while zero_response_counter < EMPTY_RESPONSES_BEFORE_ABORT {
+        let premints =
+            cdk00::PreMintSecrets::restore_batch(kid, seed, cursor, cursor + BATCH_SIZE - 1)?;
+        let request = cdk09::RestoreRequest {
+            outputs: premints.blinded_messages(),
+        };
+        let resp = client.post_restore(request).await?;
+        for (idx, premint) in (cursor..).zip(premints.iter()) {
+            if resp
+                .iter()
+                .any(|(output, _)| *output == premint.blinded_message)
+            {
+                next_unused = idx + 1;
+            }
+        }
+        cursor += BATCH_SIZE;
+        // gaps below the counter are expected, e.g. from failed swaps
+        if cursor <= counter {
+            continue;
+        }
+        if resp.is_empty() {
+            zero_response_counter += 1;
+        } else {
+            zero_response_counter = 0;
+        }
+    }
+    if next_unused == counter {
+        return Ok(None);
+    }
+    if next_unused < counter {
+        tracing::warn!("counter of keyset {kid} is ahead of the mint: {counter} > {next_unused}");
+        return Ok(Some(CounterAdjustment {
+            kid,
+            old: counter,
+            new: counter,
+            next_unused,
+        }));
+    }
+    tracing::warn!("counter of keyset {kid} was behind the mint: {counter} -> {next_unused}");
+    db.increment_counter(kid, counter, next_unused - counter)
+        .await?;
+    Ok(Some(CounterAdjustment {
+        kid,
+        old: counter,
+        new: next_unused,
+        next_unused,
+    }))
+}
+
+// connection failures, timeouts and server errors are worth retrying,
+// anything else the mint answered won't change on a retry
+fn is_transient(e: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(e);
+    while let Some(err) = source {
+        if let Some(e) = err.downcast_ref::<reqwest::Error>() {
+            return e.is_timeout()
+                || e.is_connect()
+                || e.status().is_some_and(|status| status.is_server_error());
+        }
+        source = err.source();
+    }
+    false
+}
+
+async fn post_restore_with_retry(
+    client: &Arc<dyn ClowderMintConnector>,
+    request: cdk09::RestoreRequest,
+) -> Result<Vec<(cdk00::BlindedMessage, cdk00::BlindSignature)>> {
+    let mut attempt = 1;
+    loop {
+        match client.post_restore(request.clone()).await {
+            Ok(resp) => return Ok(resp),
+            Err(e) if attempt < RESTORE_RETRY_ATTEMPTS && is_transient(&e) => {
+                let backoff =
+                    std::time::Duration::from_millis(RESTORE_RETRY_BACKOFF_MILLIS << (attempt - 1));
+                tracing::warn!(
+                    "restore request failed, attempt {attempt}, retry in {backoff:?}: {e}"
+                );
+                tokio::time::sleep(backoff).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
 async fn restore_batch(
     seed: &Seed,
     kid: cashu::Id,
@@ -47,7 +209,7 @@ async fn restore_batch(
     let request = cdk09::RestoreRequest {
         outputs: premints.blinded_messages(),
     };
-    let resp = client.post_restore(request).await?;
+    let resp = post_restore_with_retry(client, request).await?;
     if resp.is_empty() {
         return Ok(0);
     }
@@ -96,17 +258,16 @@ async fn restore_batch(
         return Ok(0);
     }
     let proofs_len = proofs.len();
-    let request = cdk07::CheckStateRequest {
-        ys: proofs.keys().cloned().collect(),
-    };
-    let states = client.post_check_state(request).await?;
+    let ys: Vec<cdk01::PublicKey> = proofs.keys().cloned().collect();
+    let states = super::check_states(client.as_ref(), &ys).await?;
+    let mut unspent = Vec::new();
     for state in states.into_iter() {
         match state.state {
             cdk07::State::Unspent => {
                 let proof = proofs
                     .remove(&state.y)
                     .expect("y in response comes from proofs");
-                db.store_new(proof).await?;
+                unspent.push(proof);
             }
             cdk07::State::Pending | cdk07::State::PendingSpent => {
                 let proof = proofs
@@ -117,6 +278,9 @@ async fn restore_batch(
             _ => {}
         }
     }
+    if !unspent.is_empty() {
+        db.store_new_batch(unspent).await?;
+    }
     Ok(proofs_len)
 }
 
@@ -253,9 +417,10 @@ mod tests {
                     .collect();
                 Ok(states)
             });
-        db.expect_store_new()
-            .times(BATCH_SIZE as usize)
-            .returning(|p| Ok(p.y().expect("proof should have y")));
+        db.expect_store_new_batch()
+            .times(1)
+            .withf(|proofs| proofs.len() == BATCH_SIZE as usize)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
 
         let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
         let restored_proofs =
@@ -326,6 +491,167 @@ mod tests {
         assert_eq!(restored_proofs, BATCH_SIZE as usize);
     }
 
+    // the mint answers a restore request signing the outputs at the given indices
+    fn expect_restore_signing(
+        client: &mut MockMintConnector,
+        sign: impl Fn(&cdk00::BlindedMessage) -> cdk00::BlindSignature + Send + 'static,
+        batch_start: u32,
+        signed: std::ops::Range<u32>,
+    ) {
+        client
+            .expect_post_restore()
+            .times(1)
+            .returning(move |request| {
+                let cdk09::RestoreRequest { outputs } = request;
+                Ok((batch_start..)
+                    .zip(outputs)
+                    .filter(|(idx, _)| signed.contains(idx))
+                    .map(|(_, blind)| {
+                        let mut bblind = blind.clone();
+                        bblind.amount = Amount::from(1u64);
+                        let signature = sign(&bblind);
+                        (blind, signature)
+                    })
+                    .collect::<Vec<_>>())
+            });
+    }
+
+    fn expect_empty_restores(client: &mut MockMintConnector) {
+        client
+            .expect_post_restore()
+            .times(EMPTY_RESPONSES_BEFORE_ABORT)
+            .returning(|_| Ok(vec![]));
+    }
+
+    #[tokio::test]
+    async fn verify_keysetid_counter_behind_mint() {
+        let seed = zero_seed();
+        let (_, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let sign = {
+            let mintkeyset = mintkeyset.clone();
+            move |blind: &cdk00::BlindedMessage| {
+                signature::sign_ecash(&mintkeyset, blind).expect("signatures should be generated")
+            }
+        };
+        let mut client = MockMintConnector::new();
+        let mut db = MockPocketRepository::new();
+        db.expect_counter()
+            .times(1)
+            .with(eq(mintkeyset.id))
+            .returning(|_| Ok(10));
+        // the mint signed 5 outputs past the stored counter
+        expect_restore_signing(&mut client, sign.clone(), 0, 0..15);
+        expect_empty_restores(&mut client);
+        db.expect_increment_counter()
+            .times(1)
+            .with(eq(mintkeyset.id), eq(10), eq(5))
+            .returning(|_, _, _| Ok(()));
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
+        let adjusted = verify_keysetid_counter(&seed, mintkeyset.id, &arc_client, &db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((adjusted.old, adjusted.new), (10, 15));
+        assert_eq!(adjusted.next_unused, 15);
+    }
+
+    #[tokio::test]
+    async fn verify_keysetid_counter_behind_mint_past_a_gap() {
+        let seed = zero_seed();
+        let (_, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let sign = {
+            let mintkeyset = mintkeyset.clone();
+            move |blind: &cdk00::BlindedMessage| {
+                signature::sign_ecash(&mintkeyset, blind).expect("signatures should be generated")
+            }
+        };
+        let mut client = MockMintConnector::new();
+        let mut db = MockPocketRepository::new();
+        db.expect_counter()
+            .times(1)
+            .with(eq(mintkeyset.id))
+            .returning(|_| Ok(10));
+        // a batch without any signed output doesn't end the scan yet
+        expect_restore_signing(&mut client, sign.clone(), 0, 0..10);
+        expect_restore_signing(&mut client, sign.clone(), BATCH_SIZE, 0..0);
+        expect_restore_signing(
+            &mut client,
+            sign.clone(),
+            2 * BATCH_SIZE,
+            2 * BATCH_SIZE..2 * BATCH_SIZE + 5,
+        );
+        expect_empty_restores(&mut client);
+        let next_unused = 2 * BATCH_SIZE + 5;
+        db.expect_increment_counter()
+            .times(1)
+            .with(eq(mintkeyset.id), eq(10), eq(next_unused - 10))
+            .returning(|_, _, _| Ok(()));
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
+        let adjusted = verify_keysetid_counter(&seed, mintkeyset.id, &arc_client, &db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((adjusted.old, adjusted.new), (10, next_unused));
+    }
+
+    #[tokio::test]
+    async fn verify_keysetid_counter_ahead_of_mint() {
+        let seed = zero_seed();
+        let (_, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let sign = {
+            let mintkeyset = mintkeyset.clone();
+            move |blind: &cdk00::BlindedMessage| {
+                signature::sign_ecash(&mintkeyset, blind).expect("signatures should be generated")
+            }
+        };
+        let mut client = MockMintConnector::new();
+        let mut db = MockPocketRepository::new();
+        db.expect_counter()
+            .times(1)
+            .with(eq(mintkeyset.id))
+            .returning(|_| Ok(50));
+        expect_restore_signing(&mut client, sign.clone(), 0, 0..20);
+        expect_empty_restores(&mut client);
+        db.expect_increment_counter().never();
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
+        let adjusted = verify_keysetid_counter(&seed, mintkeyset.id, &arc_client, &db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((adjusted.old, adjusted.new), (50, 50));
+        assert_eq!(adjusted.next_unused, 20);
+    }
+
+    #[tokio::test]
+    async fn verify_keysetid_counter_in_sync() {
+        let seed = zero_seed();
+        let (_, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let sign = {
+            let mintkeyset = mintkeyset.clone();
+            move |blind: &cdk00::BlindedMessage| {
+                signature::sign_ecash(&mintkeyset, blind).expect("signatures should be generated")
+            }
+        };
+        let mut client = MockMintConnector::new();
+        let mut db = MockPocketRepository::new();
+        db.expect_counter()
+            .times(1)
+            .with(eq(mintkeyset.id))
+            .returning(|_| Ok(10));
+        expect_restore_signing(&mut client, sign.clone(), 0, 0..10);
+        expect_empty_restores(&mut client);
+        db.expect_increment_counter().never();
+
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
+        let adjusted = verify_keysetid_counter(&seed, mintkeyset.id, &arc_client, &db)
+            .await
+            .unwrap();
+        assert_eq!(adjusted, None);
+    }
+
     #[tokio::test]
     async fn restore_keysetid_1stbatch() {
         let seed = zero_seed();
@@ -373,9 +699,10 @@ mod tests {
                     .collect();
                 Ok(states)
             });
-        db.expect_store_new()
-            .times(BATCH_SIZE as usize)
-            .returning(|p| Ok(p.y().unwrap()));
+        db.expect_store_new_batch()
+            .times(1)
+            .withf(|proofs| proofs.len() == BATCH_SIZE as usize)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
         db.expect_increment_counter()
             .times(1)
             .with(eq(mintkeyset.id), eq(0), eq(BATCH_SIZE))
@@ -385,12 +712,191 @@ mod tests {
             .times(EMPTY_RESPONSES_BEFORE_ABORT)
             .returning(move |_| Ok(vec![]));
         let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
-        let total_restored = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db)
+        let total_restored = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db, None)
             .await
             .unwrap();
         assert_eq!(total_restored, BATCH_SIZE as usize);
     }
 
+    #[tokio::test]
+    async fn restore_keysetid_interrupted_after_1stbatch() {
+        let seed = zero_seed();
+        let (_, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let keyset = KeySet::from(mintkeyset.clone());
+        let mut client = MockMintConnector::new();
+        client
+            .expect_get_mint_keyset()
+            .times(1)
+            .returning(move |_| Ok(keyset.clone()));
+        let mut db = MockPocketRepository::new();
+        db.expect_counter()
+            .times(1)
+            .with(eq(mintkeyset.id))
+            .returning(move |_| Ok(0));
+        let cloned_mintkeyset = mintkeyset.clone();
+        client
+            .expect_post_restore()
+            .times(1)
+            .returning(move |request| {
+                let cdk09::RestoreRequest { outputs } = request;
+                let signatures = outputs
+                    .iter()
+                    .map(|blind| {
+                        let mut bblind = blind.clone();
+                        bblind.amount = Amount::from(1u64);
+                        signature::sign_ecash(&cloned_mintkeyset, &bblind)
+                            .expect("signatures should be generated")
+                    })
+                    .collect::<Vec<_>>();
+                Ok(outputs.into_iter().zip(signatures).collect::<Vec<_>>())
+            });
+        client
+            .expect_post_check_state()
+            .times(1)
+            .returning(move |request| {
+                Ok(request
+                    .ys
+                    .iter()
+                    .map(|y| cdk07::ProofState {
+                        state: cdk07::State::Unspent,
+                        y: *y,
+                        witness: None,
+                    })
+                    .collect())
+            });
+        db.expect_store_new_batch()
+            .times(1)
+            .withf(|proofs| proofs.len() == BATCH_SIZE as usize)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
+        db.expect_increment_counter()
+            .times(1)
+            .with(eq(mintkeyset.id), eq(0), eq(BATCH_SIZE))
+            .returning(|_, _, _| Ok(()));
+        // not a network error, so it isn't retried
+        client.expect_post_restore().times(1).returning(|_| {
+            Err(bcr_common::client::mint::Error::Internal(String::from(
+                "bad request",
+            )))
+        });
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
+        let err = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db, None)
+            .await
+            .unwrap_err();
+        match err {
+            Error::RestoreInterrupted {
+                kid,
+                restored,
+                last_good_counter,
+                ..
+            } => {
+                assert_eq!(kid, mintkeyset.id);
+                assert_eq!(restored, BATCH_SIZE as usize);
+                assert_eq!(last_good_counter, BATCH_SIZE);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn is_transient_only_for_network_errors() {
+        let connect = reqwest::Client::new()
+            .get("http://127.0.0.1:9")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(is_transient(&connect));
+        let internal = bcr_common::client::mint::Error::Internal(String::from("bad request"));
+        assert!(!is_transient(&internal));
+    }
+
+    #[tokio::test]
+    async fn restore_keysetid_reports_progress() {
+        let seed = zero_seed();
+        let (_, mintkeyset) = core_tests::generate_random_ecash_keyset();
+        let keyset = KeySet::from(mintkeyset.clone());
+        let mut client = MockMintConnector::new();
+        client
+            .expect_get_mint_keyset()
+            .times(1)
+            .returning(move |_| Ok(keyset.clone()));
+        let mut db = MockPocketRepository::new();
+        db.expect_counter()
+            .times(1)
+            .with(eq(mintkeyset.id))
+            .returning(move |_| Ok(0));
+        let cloned_mintkeyset = mintkeyset.clone();
+        client
+            .expect_post_restore()
+            .times(1)
+            .returning(move |request| {
+                let cdk09::RestoreRequest { outputs } = request;
+                let signatures = outputs
+                    .iter()
+                    .map(|blind| {
+                        let mut bblind = blind.clone();
+                        bblind.amount = Amount::from(1u64);
+                        signature::sign_ecash(&cloned_mintkeyset, &bblind)
+                            .expect("signatures should be generated")
+                    })
+                    .collect::<Vec<_>>();
+                Ok(outputs.into_iter().zip(signatures).collect::<Vec<_>>())
+            });
+        client
+            .expect_post_check_state()
+            .times(1)
+            .returning(move |request| {
+                let states: Vec<cdk07::ProofState> = request
+                    .ys
+                    .iter()
+                    .map(|y| cdk07::ProofState {
+                        state: cdk07::State::Unspent,
+                        y: *y,
+                        witness: None,
+                    })
+                    .collect();
+                Ok(states)
+            });
+        db.expect_store_new_batch()
+            .times(1)
+            .withf(|proofs| proofs.len() == BATCH_SIZE as usize)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
+        db.expect_increment_counter()
+            .times(1)
+            .with(eq(mintkeyset.id), eq(0), eq(BATCH_SIZE))
+            .returning(|_, _, _| Ok(()));
+        client
+            .expect_post_restore()
+            .times(EMPTY_RESPONSES_BEFORE_ABORT)
+            .returning(move |_| Ok(vec![]));
+        let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
+        let reported = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let cloned_reported = reported.clone();
+        let progress: RestoreProgressCallback =
+            Arc::new(move |p| cloned_reported.lock().unwrap().push(p));
+        restore_keysetid(&seed, mintkeyset.id, &arc_client, &db, Some(&progress))
+            .await
+            .unwrap();
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 1 + EMPTY_RESPONSES_BEFORE_ABORT);
+        assert_eq!(
+            reported[0],
+            RestoreProgress {
+                keyset_id: mintkeyset.id,
+                batch_start: 0,
+                restored_in_batch: BATCH_SIZE as usize,
+                cumulative: BATCH_SIZE as usize,
+            }
+        );
+        let last = reported.last().unwrap();
+        assert_eq!(
+            last.batch_start,
+            BATCH_SIZE * EMPTY_RESPONSES_BEFORE_ABORT as u32
+        );
+        assert_eq!(last.restored_in_batch, 0);
+        assert_eq!(last.cumulative, BATCH_SIZE as usize);
+    }
+
     #[tokio::test]
     async fn restore_keysetid_2ndbatch() {
         let seed = zero_seed();
@@ -442,9 +948,10 @@ mod tests {
                     .collect();
                 Ok(states)
             });
-        db.expect_store_new()
-            .times(BATCH_SIZE as usize)
-            .returning(|p| Ok(p.y().unwrap()));
+        db.expect_store_new_batch()
+            .times(1)
+            .withf(|proofs| proofs.len() == BATCH_SIZE as usize)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
         db.expect_increment_counter()
             .times(1)
             .with(eq(mintkeyset.id), eq(0), eq(2 * BATCH_SIZE))
@@ -454,7 +961,7 @@ mod tests {
             .times(EMPTY_RESPONSES_BEFORE_ABORT)
             .returning(move |_| Ok(vec![]));
         let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
-        let total_restored = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db)
+        let total_restored = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db, None)
             .await
             .unwrap();
         assert_eq!(total_restored, BATCH_SIZE as usize);
@@ -512,9 +1019,10 @@ mod tests {
                     .collect();
                 Ok(states)
             });
-        db.expect_store_new()
-            .times((BATCH_SIZE / 3) as usize)
-            .returning(|p| Ok(p.y().unwrap()));
+        db.expect_store_new_batch()
+            .times(1)
+            .withf(|proofs| proofs.len() == (BATCH_SIZE / 3) as usize)
+            .returning(|proofs| Ok(proofs.iter().map(|p| p.y().unwrap()).collect()));
         db.expect_increment_counter()
             .times(1)
             .with(eq(mintkeyset.id), eq(0), eq(2 * BATCH_SIZE))
@@ -525,7 +1033,7 @@ mod tests {
             .returning(move |_| Ok(vec![]));
         //
         let arc_client: Arc<dyn ClowderMintConnector> = Arc::new(client);
-        let total_restored = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db)
+        let total_restored = restore_keysetid(&seed, mintkeyset.id, &arc_client, &db, None)
             .await
             .unwrap();
         assert_eq!(total_restored, (BATCH_SIZE / 3) as usize);
@@ -3,12 +3,13 @@ pub mod tests {
     use crate::external::mint::ClowderMintConnector;
     use crate::pocket::{
         PocketApi,
-        debit::{DebitPocketApi, MeltProtestResult, ProtestResult},
+        debit::{DebitPocketApi, MeltCommitmentBackup, MeltProtestResult, ProtestResult},
     };
-    use crate::types::{MeltSummary, MintSummary, SendSummary};
+    use crate::types::{MeltSummary, MintQuoteSummary, MintSummary, SendSummary};
     use crate::wallet::types::SwapConfig;
     use async_trait::async_trait;
     use bcr_common::wire::melt as wire_melt;
+    use bcr_wallet_core::types::{CoinSelectionStrategy, RestoreProgressCallback, SecretScheme};
     use std::collections::HashMap;
     use std::sync::Arc;
     use uuid::Uuid;
@@ -64,7 +65,25 @@ pub mod tests {
                 proofs: Vec<cashu::Proof>,
                 swap_config: SwapConfig,
             ) -> Result<(Amount, Vec<cashu::PublicKey>)>;
-            async fn prepare_send(&self, amount: Amount, infos: &[KeySetInfo]) -> Result<SendSummary>;
+            async fn prepare_send(
+                &self,
+                amount: Amount,
+                infos: &[KeySetInfo],
+                scheme: SecretScheme,
+                strategy: CoinSelectionStrategy,
+            ) -> Result<SendSummary>;
+            async fn estimate_send(
+                &self,
+                amount: Amount,
+                infos: &[KeySetInfo],
+                strategy: CoinSelectionStrategy,
+            ) -> Result<SendSummary>;
+            async fn prepare_send_max(
+                &self,
+                infos: &[KeySetInfo],
+                scheme: SecretScheme,
+                strategy: CoinSelectionStrategy,
+            ) -> Result<SendSummary>;
             async fn send_proofs(
                 &self,
                 rid: Uuid,
@@ -72,15 +91,55 @@ pub mod tests {
                 client: Arc<dyn ClowderMintConnector>,
                 swap_config: SwapConfig,
             ) -> Result<HashMap<cashu::PublicKey, cashu::Proof>>;
+            async fn cancel_send(&self, rid: Uuid) -> Result<()>;
+            async fn replace_sent_proofs(
+                &self,
+                sent: &[cashu::PublicKey],
+                locked: Vec<cashu::Proof>,
+                change: Vec<cashu::Proof>,
+            ) -> Result<()>;
+            async fn change_premint(
+                &self,
+                amount: Amount,
+                keysets_info: &[KeySetInfo],
+            ) -> Result<cashu::PreMintSecrets>;
+            async fn revert_sent_proofs(&self, sent: &[cashu::PublicKey]) -> Result<()>;
+            async fn release_stale_reservations(&self, tstamp: u64) -> Result<usize>;
+            async fn send_proofs_offline(
+                &self,
+                rid: Uuid,
+            ) -> Result<HashMap<cashu::PublicKey, cashu::Proof>>;
             async fn cleanup_local_proofs(
                 &self,
                 client: Arc<dyn ClowderMintConnector>,
             ) -> Result<Vec<cashu::PublicKey>>;
+            async fn prune_counters(&self, keysets_info: &[KeySetInfo]) -> Result<usize>;
             async fn restore_local_proofs(
                 &self,
                 keysets_info: &[KeySetInfo],
                 client: Arc<dyn ClowderMintConnector>,
+                progress: Option<RestoreProgressCallback>,
             ) -> Result<usize>;
+            async fn restore_keysets(
+                &self,
+                kids: &[cashu::Id],
+                keysets_info: &[KeySetInfo],
+                client: Arc<dyn ClowderMintConnector>,
+                progress: Option<RestoreProgressCallback>,
+            ) -> Result<usize>;
+            async fn rebuild_counters(
+                &self,
+                keysets_info: &[KeySetInfo],
+            ) -> Result<HashMap<cashu::Id, u32>>;
+            async fn backup_proofs(&self) -> Result<crate::pocket::PocketBackup>;
+            async fn import_backup(&self, backup: crate::pocket::PocketBackup) -> Result<usize>;
+            async fn verify_counters(
+                &self,
+                keysets_info: &[KeySetInfo],
+                client: Arc<dyn ClowderMintConnector>,
+            ) -> Result<Vec<crate::wallet::types::CounterAdjustment>>;
+            async fn contains(&self, ys: &[cashu::PublicKey]) -> Result<Vec<cashu::PublicKey>>;
+            async fn pending(&self, ys: &[cashu::PublicKey]) -> Result<Vec<cashu::PublicKey>>;
             async fn delete_proofs(&self) -> Result<HashMap<cashu::Id, Vec<cashu::Proof>>>;
             async fn return_proofs_to_send_for_offline_payment(
                 &self,
@@ -98,6 +157,12 @@ pub mod tests {
                 &self,
                 keysets_info: &[KeySetInfo],
             ) -> Result<HashMap<cashu::Id, (Option<u64>, Amount)>>;
+            async fn stats(&self) -> Result<crate::pocket::PocketStats>;
+            async fn recheck(
+                &self,
+                keep: &[cashu::PublicKey],
+                client: Arc<dyn ClowderMintConnector>,
+            ) -> Result<crate::pocket::RecheckSummary>;
         }
 
         #[async_trait]
@@ -129,6 +194,7 @@ pub mod tests {
                 rid: Uuid,
                 client: Arc<dyn ClowderMintConnector>,
             ) -> Result<(wire_melt::MeltTx, HashMap<cashu::PublicKey, cashu::Proof>)>;
+            async fn cancel_melt(&self, rid: Uuid) -> Result<()>;
             async fn mint_onchain(
                 &self,
                 amount: bitcoin::Amount,
@@ -144,6 +210,18 @@ pub mod tests {
                 swap_config: SwapConfig,
                 clowder_id: bitcoin::secp256k1::PublicKey,
             ) -> Result<HashMap<Uuid, crate::pocket::debit::CheckPendingMintResult>>;
+            async fn prepare_mint(
+                &self,
+                amount: Amount,
+                unit: CurrencyUnit,
+                client: Arc<dyn ClowderMintConnector>,
+            ) -> Result<MintQuoteSummary>;
+            async fn mint(
+                &self,
+                quote_id: String,
+                keysets_info: &[KeySetInfo],
+                client: Arc<dyn ClowderMintConnector>,
+            ) -> Result<(Amount, Vec<cashu::PublicKey>)>;
             async fn check_pending_commitments(&self, tstamp: u64) -> Result<()>;
             async fn protest_mint(
                 &self,
@@ -169,6 +247,24 @@ pub mod tests {
                 alpha_id: bitcoin::secp256k1::PublicKey,
             ) -> Result<MeltProtestResult>;
             async fn list_melt_commitments(&self) -> Result<Vec<(Uuid, u64)>>;
+            async fn backup_melt_commitments(&self) -> Result<Vec<MeltCommitmentBackup>>;
+            async fn import_melt_commitments(
+                &self,
+                backups: Vec<MeltCommitmentBackup>,
+            ) -> Result<usize>;
+            async fn receive_locked_proofs(
+                &self,
+                client: Arc<dyn ClowderMintConnector>,
+                keysets_info: &[KeySetInfo],
+                inputs: Vec<cashu::Proof>,
+                signing_key: &cashu::SecretKey,
+            ) -> Result<(Amount, Vec<cashu::PublicKey>)>;
+            async fn partial_sign_proof(&self, y: cashu::PublicKey) -> Result<cashu::Witness>;
+            async fn combine_partial_signatures(
+                &self,
+                y: cashu::PublicKey,
+                witnesses: Vec<cashu::Witness>,
+            ) -> Result<bool>;
         }
     }
 }
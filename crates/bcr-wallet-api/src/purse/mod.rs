@@ -2,15 +2,17 @@ use crate::{
     error::{Error, Result},
     wallet::api::WalletApi,
 };
-use bcr_common::cashu::MintUrl;
-use bcr_wallet_core::types::WalletConfig;
+use bcr_common::cashu::{Amount, CurrencyUnit, MintUrl};
+use bcr_wallet_core::types::{CoinSelectionStrategy, WalletConfig};
 use bcr_wallet_persistence::{PurseRepository, redb::purse::PurseDB};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::RwLock;
 
 pub struct Purse<Wlt> {
     pub repo: Box<dyn PurseRepository>,
-    pub wallets: Arc<RwLock<Vec<Arc<RwLock<Wlt>>>>>,
+    // deleted wallets leave an empty slot, so the indices of the others stay stable
+    // the indices are persisted with `PurseRepository::add_slot`
+    pub wallets: Arc<RwLock<Vec<Option<Arc<RwLock<Wlt>>>>>>,
 }
 
 impl<Wlt> Purse<Wlt> {
@@ -26,22 +28,75 @@ impl<Wlt> Purse<Wlt> {
         Ok(res)
     }
 
+    // A wallet's network is immutable after creation, since the derived secrets and
+    // counters depend on it
+    pub async fn load_wallet_config_for_network(
+        &self,
+        wallet_id: &str,
+        network: bitcoin::Network,
+    ) -> Result<WalletConfig> {
+        let cfg = self.load_wallet_config(wallet_id).await?;
+        if cfg.network != network {
+            return Err(Error::NetworkImmutable(
+                wallet_id.to_owned(),
+                cfg.network,
+                network,
+            ));
+        }
+        Ok(cfg)
+    }
+
     pub async fn list_wallets(&self) -> Result<Vec<String>> {
         let res = self.repo.list_ids().await?;
         Ok(res)
     }
 
+    // the stored wallet of each index, None for deleted ones
+    // purses stored before the indices were kept get them in the order of `list_wallets`
+    pub async fn wallet_slots(&self) -> Result<Vec<Option<String>>> {
+        let ids = self.repo.list_ids().await?;
+        let mut slots = self.repo.list_slots().await?;
+        if slots.is_empty() {
+            for id in ids.iter() {
+                self.repo.add_slot(id).await?;
+            }
+            return Ok(ids.into_iter().map(Some).collect());
+        }
+        // e.g. the wallet got deleted, but clearing its slot failed
+        for slot in slots.iter_mut() {
+            if slot.as_ref().is_some_and(|id| !ids.contains(id)) {
+                *slot = None;
+            }
+        }
+        Ok(slots)
+    }
+
+    async fn place_wallet(&self, idx: usize, wallet: Wlt) {
+        let mut wallets = self.wallets.write().await;
+        if wallets.len() <= idx {
+            wallets.resize_with(idx + 1, || None);
+        }
+        wallets[idx] = Some(Arc::new(RwLock::new(wallet)));
+    }
+
     pub async fn get_wallet(&self, idx: usize) -> Option<Arc<RwLock<Wlt>>> {
-        self.wallets.read().await.get(idx).cloned()
+        self.wallets.read().await.get(idx).cloned().flatten()
     }
 
     pub async fn ids(&self) -> Vec<u32> {
-        (0..self.wallets.read().await.len() as u32).collect()
+        self.wallets
+            .read()
+            .await
+            .iter()
+            .enumerate()
+            .filter(|(_, wlt)| wlt.is_some())
+            .map(|(idx, _)| idx as u32)
+            .collect()
     }
 
     // Current limitation to 1 wallet
     pub async fn can_add_wallet(&self) -> bool {
-        self.wallets.read().await.is_empty()
+        self.wallets.read().await.iter().all(Option::is_none)
     }
 }
 
@@ -50,27 +105,121 @@ where
     Wlt: WalletApi,
 {
     pub async fn add_wallet(&self, wallet: Wlt) -> Result<usize> {
+        let cfg = wallet.config()?;
+        let wallet_id = cfg.wallet_id.clone();
+        self.repo.store(cfg).await?;
+        let idx = self.repo.add_slot(&wallet_id).await?;
+        self.place_wallet(idx, wallet).await;
+        Ok(idx)
+    }
+
+    // puts a loaded wallet back at its stored index, storing its refreshed config
+    pub async fn load_wallet(&self, idx: usize, wallet: Wlt) -> Result<()> {
         self.repo.store(wallet.config()?).await?;
-        let mut wallets = self.wallets.write().await;
-        wallets.push(Arc::new(RwLock::new(wallet)));
-        Ok(wallets.len() - 1)
+        self.place_wallet(idx, wallet).await;
+        Ok(())
+    }
+
+    // the index of the loaded wallet with this id on this mint
+    pub async fn find_wallet(&self, wallet_id: &str, mint: &MintUrl) -> Result<Option<usize>> {
+        let wallets = self.wallets.read().await;
+        for (idx, wlt) in wallets.iter().enumerate() {
+            let Some(wlt) = wlt else {
+                continue;
+            };
+            let wlt = wlt.read().await;
+            if wlt.id() == wallet_id && wlt.mint_url()? == *mint {
+                return Ok(Some(idx));
+            }
+        }
+        Ok(None)
     }
 
-    pub async fn delete_wallet(&self, idx: usize) -> Result<()> {
+    // Returns the id of the deleted wallet, the index is never reused
+    pub async fn delete_wallet(&self, idx: usize) -> Result<String> {
         let Some(wlt) = self.get_wallet(idx).await else {
             return Err(Error::WalletNotFound(idx));
         };
         let id = wlt.read().await.id();
         wlt.read().await.cleanup_local_proofs().await?;
         self.repo.delete(&id).await?;
-        self.wallets.write().await.remove(idx);
+        self.repo.clear_slot(idx).await?;
+        self.wallets.write().await[idx] = None;
+        Ok(id)
+    }
+
+    // the new name is persisted before the wallet in memory is renamed
+    pub async fn rename_wallet(&self, idx: usize, name: String) -> Result<()> {
+        let Some(wlt) = self.get_wallet(idx).await else {
+            return Err(Error::WalletNotFound(idx));
+        };
+        let mut wlt = wlt.write().await;
+        let mut cfg = wlt.config()?;
+        cfg.name = name.clone();
+        self.repo.store(cfg).await?;
+        wlt.set_name(name);
+        Ok(())
+    }
+
+    // persisted first, like the name in `rename_wallet`
+    pub async fn set_coin_selection(
+        &self,
+        idx: usize,
+        strategy: CoinSelectionStrategy,
+    ) -> Result<()> {
+        let Some(wlt) = self.get_wallet(idx).await else {
+            return Err(Error::WalletNotFound(idx));
+        };
+        let mut wlt = wlt.write().await;
+        let mut cfg = wlt.config()?;
+        cfg.coin_selection = strategy;
+        self.repo.store(cfg).await?;
+        wlt.set_coin_selection(strategy);
+        Ok(())
+    }
+
+    pub async fn set_keep_sent_tokens(&self, idx: usize, keep: bool) -> Result<()> {
+        let Some(wlt) = self.get_wallet(idx).await else {
+            return Err(Error::WalletNotFound(idx));
+        };
+        let mut wlt = wlt.write().await;
+        let mut cfg = wlt.config()?;
+        cfg.keep_sent_tokens = keep;
+        self.repo.store(cfg).await?;
+        wlt.set_keep_sent_tokens(keep);
         Ok(())
     }
 
+    // (credit, debit) of all the wallets, wallets of different units are kept apart
+    pub async fn total_balance(&self) -> Result<HashMap<CurrencyUnit, (Amount, Amount)>> {
+        let wallets: Vec<Arc<RwLock<Wlt>>> = self
+            .wallets
+            .read()
+            .await
+            .iter()
+            .flatten()
+            .cloned()
+            .collect();
+        let balances = futures::future::join_all(wallets.iter().map(|wlt| async move {
+            let wlt = wlt.read().await;
+            let balance = wlt.balance().await?;
+            Ok::<_, Error>((wlt.debit_unit(), balance))
+        }))
+        .await;
+        let mut totals: HashMap<CurrencyUnit, (Amount, Amount)> = HashMap::new();
+        for res in balances {
+            let (unit, balance) = res?;
+            let (credit, debit) = totals.entry(unit).or_default();
+            *credit += balance.credit_available;
+            *debit += balance.debit_available;
+        }
+        Ok(totals)
+    }
+
     pub async fn migrate_rabid_wallets(&self) -> Result<HashMap<String, MintUrl>> {
         let mut res = HashMap::new();
         let wlts = self.wallets.read().await;
-        for wlt in wlts.iter() {
+        for wlt in wlts.iter().flatten() {
             let wallet_id = wlt.read().await.id();
             tracing::info!("Checking if alpha is rabid..");
             let is_rabid = wlt.read().await.is_wallet_mint_rabid().await?;
@@ -121,6 +270,15 @@ mod tests {
         }
     }
 
+    // each added wallet gets the next index
+    fn expect_slots(db: &mut MockPurseRepository, added: usize, cleared: usize) {
+        let next = std::sync::atomic::AtomicUsize::new(0);
+        db.expect_add_slot()
+            .times(added)
+            .returning(move |_| Ok(next.fetch_add(1, std::sync::atomic::Ordering::SeqCst)));
+        db.expect_clear_slot().times(cleared).returning(|_| Ok(()));
+    }
+
     fn wlt_cfg() -> WalletConfig {
         WalletConfig {
             wallet_id: "wlt-1".to_owned(),
@@ -132,12 +290,15 @@ mod tests {
             debit: CurrencyUnit::Sat,
             pub_key: test_pub_key(),
             betas: vec![],
+            coin_selection: CoinSelectionStrategy::default(),
+            keep_sent_tokens: false,
         }
     }
 
     #[tokio::test]
     async fn test_wallet_lifecycle() {
         let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 1, 1);
         db.expect_load().times(1).returning(|_| Ok(wlt_cfg()));
         db.expect_store().times(1).returning(|_| Ok(()));
         db.expect_delete().times(1).returning(|_| Ok(()));
@@ -168,13 +329,284 @@ mod tests {
         let gotten = purse.get_wallet(wlt_id).await.expect("get wallet works");
         assert_eq!(gotten.read().await.id(), new_wlt_id);
 
-        purse.delete_wallet(wlt_id).await.expect("delete works");
+        let deleted = purse.delete_wallet(wlt_id).await.expect("delete works");
+        assert_eq!(deleted, new_wlt_id);
         assert!(purse.can_add_wallet().await);
+        assert!(purse.ids().await.is_empty());
+        assert!(purse.get_wallet(wlt_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_indices_stay_stable_after_delete() {
+        let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 2, 1);
+        db.expect_store().times(2).returning(|_| Ok(()));
+        db.expect_delete().times(1).returning(|_| Ok(()));
+        let purse = purse(Box::new(db));
+
+        let mut first = MockWalletApi::new();
+        first.expect_id().returning(|| "wlt-1".to_owned());
+        first.expect_config().times(1).returning(|| Ok(wlt_cfg()));
+        first.expect_cleanup_local_proofs().returning(|| Ok(()));
+        let first_idx = purse.add_wallet(first).await.expect("can create wallet");
+        purse.delete_wallet(first_idx).await.expect("delete works");
+
+        let mut second = MockWalletApi::new();
+        second.expect_config().times(1).returning(|| Ok(wlt_cfg()));
+        let second_idx = purse.add_wallet(second).await.expect("can create wallet");
+        assert_ne!(first_idx, second_idx);
+        assert_eq!(purse.ids().await, vec![second_idx as u32]);
+
+        let err = purse.delete_wallet(first_idx).await.unwrap_err();
+        assert!(matches!(err, Error::WalletNotFound(idx) if idx == first_idx));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_slots() {
+        let mut db = MockPurseRepository::new();
+        db.expect_list_ids()
+            .times(1)
+            .returning(|| Ok(vec!["wlt-1".to_owned(), "wlt-3".to_owned()]));
+        // wlt-2 got deleted without its slot being cleared
+        db.expect_list_slots().times(1).returning(|| {
+            Ok(vec![
+                Some("wlt-1".to_owned()),
+                None,
+                Some("wlt-2".to_owned()),
+                Some("wlt-3".to_owned()),
+            ])
+        });
+        db.expect_add_slot().never();
+        let purse = purse(Box::new(db));
+
+        let slots = purse.wallet_slots().await.expect("wallet slots works");
+        assert_eq!(
+            slots,
+            vec![
+                Some("wlt-1".to_owned()),
+                None,
+                None,
+                Some("wlt-3".to_owned())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wallet_slots_of_a_purse_without_them() {
+        let mut db = MockPurseRepository::new();
+        db.expect_list_ids()
+            .times(1)
+            .returning(|| Ok(vec!["wlt-1".to_owned(), "wlt-2".to_owned()]));
+        db.expect_list_slots().times(1).returning(|| Ok(vec![]));
+        expect_slots(&mut db, 2, 0);
+        let purse = purse(Box::new(db));
+
+        let slots = purse.wallet_slots().await.expect("wallet slots works");
+        assert_eq!(
+            slots,
+            vec![Some("wlt-1".to_owned()), Some("wlt-2".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_wallet_keeps_its_index() {
+        let mut db = MockPurseRepository::new();
+        db.expect_store().times(1).returning(|_| Ok(()));
+        db.expect_add_slot().never();
+        let purse = purse(Box::new(db));
+
+        let mut wlt = MockWalletApi::new();
+        wlt.expect_id().returning(|| "wlt-1".to_owned());
+        wlt.expect_config().times(1).returning(|| Ok(wlt_cfg()));
+        purse.load_wallet(2, wlt).await.expect("load wallet works");
+
+        assert_eq!(purse.ids().await, vec![2]);
+        assert!(purse.get_wallet(0).await.is_none());
+        let loaded = purse.get_wallet(2).await.expect("wallet is at its index");
+        assert_eq!(loaded.read().await.id(), "wlt-1");
+    }
+
+    #[tokio::test]
+    async fn test_find_wallet_matches_id_and_mint() {
+        let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 1, 0);
+        db.expect_store().times(1).returning(|_| Ok(()));
+        let purse = purse(Box::new(db));
+
+        let mut wlt = MockWalletApi::new();
+        wlt.expect_id().returning(|| "wlt-1".to_owned());
+        wlt.expect_mint_url()
+            .returning(|| Ok(MintUrl::from_str("https://example.com").unwrap()));
+        wlt.expect_config().times(1).returning(|| Ok(wlt_cfg()));
+        let idx = purse.add_wallet(wlt).await.expect("can create wallet");
+
+        let mint = MintUrl::from_str("https://example.com").unwrap();
+        let other_mint = MintUrl::from_str("https://other.example.com").unwrap();
+        assert_eq!(purse.find_wallet("wlt-1", &mint).await.unwrap(), Some(idx));
+        assert_eq!(purse.find_wallet("wlt-2", &mint).await.unwrap(), None);
+        assert_eq!(purse.find_wallet("wlt-1", &other_mint).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_total_balance_keeps_units_apart() {
+        let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 3, 0);
+        db.expect_store().times(3).returning(|_| Ok(()));
+        let purse = purse(Box::new(db));
+
+        for (unit, debit, credit) in [
+            (CurrencyUnit::Sat, 10u64, 1u64),
+            (CurrencyUnit::Usd, 5, 0),
+            (CurrencyUnit::Sat, 20, 2),
+        ] {
+            let mut wlt = MockWalletApi::new();
+            wlt.expect_config().times(1).returning(|| Ok(wlt_cfg()));
+            wlt.expect_debit_unit().returning(move || unit.clone());
+            wlt.expect_balance().times(1).returning(move || {
+                Ok(crate::wallet::types::WalletBalance {
+                    debit_available: Amount::from(debit),
+                    credit_available: Amount::from(credit),
+                    total: Amount::from(debit + credit),
+                    ..Default::default()
+                })
+            });
+            purse.add_wallet(wlt).await.expect("can create wallet");
+        }
+
+        let totals = purse.total_balance().await.expect("total balance works");
+        assert_eq!(totals.len(), 2);
+        assert_eq!(
+            totals[&CurrencyUnit::Sat],
+            (Amount::from(3u64), Amount::from(30u64))
+        );
+        assert_eq!(
+            totals[&CurrencyUnit::Usd],
+            (Amount::ZERO, Amount::from(5u64))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rename_wallet() {
+        let stored = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let stored_clone = stored.clone();
+        let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 1, 0);
+        db.expect_store().times(2).returning(move |cfg| {
+            stored_clone.lock().unwrap().push(cfg.name);
+            Ok(())
+        });
+        let purse = purse(Box::new(db));
+
+        let mut wlt = MockWalletApi::new();
+        wlt.expect_config().times(2).returning(|| Ok(wlt_cfg()));
+        wlt.expect_set_name()
+            .times(1)
+            .withf(|name| name == "renamed")
+            .returning(|_| ());
+        let idx = purse.add_wallet(wlt).await.expect("can create wallet");
+
+        purse
+            .rename_wallet(idx, String::from("renamed"))
+            .await
+            .expect("rename works");
+        assert_eq!(stored.lock().unwrap().last().unwrap(), "renamed");
+        let err = purse
+            .rename_wallet(idx + 1, String::from("renamed"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::WalletNotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_set_coin_selection() {
+        let stored = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let stored_clone = stored.clone();
+        let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 1, 0);
+        db.expect_store().times(2).returning(move |cfg| {
+            stored_clone.lock().unwrap().push(cfg.coin_selection);
+            Ok(())
+        });
+        let purse = purse(Box::new(db));
+
+        let mut wlt = MockWalletApi::new();
+        wlt.expect_config().times(2).returning(|| Ok(wlt_cfg()));
+        wlt.expect_set_coin_selection()
+            .times(1)
+            .withf(|strategy| *strategy == CoinSelectionStrategy::LargestFirst)
+            .returning(|_| ());
+        let idx = purse.add_wallet(wlt).await.expect("can create wallet");
+
+        purse
+            .set_coin_selection(idx, CoinSelectionStrategy::LargestFirst)
+            .await
+            .expect("set coin selection works");
+        assert_eq!(
+            *stored.lock().unwrap(),
+            vec![
+                CoinSelectionStrategy::MinimizeSwaps,
+                CoinSelectionStrategy::LargestFirst
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_keep_sent_tokens() {
+        let stored = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let stored_clone = stored.clone();
+        let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 1, 0);
+        db.expect_store().times(2).returning(move |cfg| {
+            stored_clone.lock().unwrap().push(cfg.keep_sent_tokens);
+            Ok(())
+        });
+        let purse = purse(Box::new(db));
+
+        let mut wlt = MockWalletApi::new();
+        wlt.expect_config().times(2).returning(|| Ok(wlt_cfg()));
+        wlt.expect_set_keep_sent_tokens()
+            .times(1)
+            .withf(|keep| *keep)
+            .returning(|_| ());
+        let idx = purse.add_wallet(wlt).await.expect("can create wallet");
+
+        purse
+            .set_keep_sent_tokens(idx, true)
+            .await
+            .expect("set keep sent tokens works");
+        assert_eq!(*stored.lock().unwrap(), vec![false, true]);
+    }
+
+    #[tokio::test]
+    async fn test_load_wallet_config_for_network() {
+        let mut db = MockPurseRepository::new();
+        db.expect_load().times(2).returning(|_| Ok(wlt_cfg()));
+        let purse = purse(Box::new(db));
+
+        let cfg = purse
+            .load_wallet_config_for_network("wlt-1", bitcoin::Network::Testnet)
+            .await
+            .expect("same network works");
+        assert_eq!(cfg.network, bitcoin::Network::Testnet);
+
+        let err = purse
+            .load_wallet_config_for_network("wlt-1", bitcoin::Network::Bitcoin)
+            .await
+            .unwrap_err();
+        match err {
+            Error::NetworkImmutable(id, stored, configured) => {
+                assert_eq!(id, "wlt-1");
+                assert_eq!(stored, bitcoin::Network::Testnet);
+                assert_eq!(configured, bitcoin::Network::Bitcoin);
+            }
+            other => panic!("expected NetworkImmutable, got: {other:?}"),
+        }
     }
 
     #[tokio::test]
     async fn test_migrate_rabid_baseline() {
         let mut db = MockPurseRepository::new();
+        expect_slots(&mut db, 1, 0);
         db.expect_store().times(2).returning(|_| Ok(()));
         let purse = purse(Box::new(db));
         let mut wlt = MockWalletApi::new();
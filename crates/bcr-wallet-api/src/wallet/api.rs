@@ -3,10 +3,12 @@ use crate::{
     error::{Error, Result},
     pocket::debit::{MeltProtestResult, ProtestResult},
     types::{
-        MintSummary, PAYMENT_TYPE_METADATA_KEY, PaymentSummary, TRANSACTION_STATUS_METADATA_KEY,
-        WalletConfig,
+        MintQuoteSummary, MintSummary, PAYMENT_TYPE_METADATA_KEY, PaymentSummary,
+        TRANSACTION_STATUS_METADATA_KEY, WalletConfig,
+    },
+    wallet::types::{
+        PayReference, SendResult, WalletBalance, WalletPaymentType, WalletProtestResult,
     },
-    wallet::types::{PayReference, WalletPaymentType, WalletProtestResult},
 };
 use async_trait::async_trait;
 use bcr_common::{
@@ -18,8 +20,9 @@ use bcr_common::{
 use bcr_wallet_core::{
     SendSync,
     types::{
-        BTC_ALPHA_TX_ID_TYPE_METADATA_KEY, BTC_BETA_TX_ID_TYPE_METADATA_KEY, PaymentResultCallback,
-        PaymentType, TransactionStatus,
+        BTC_ALPHA_TX_ID_TYPE_METADATA_KEY, BTC_BETA_TX_ID_TYPE_METADATA_KEY, CoinSelectionStrategy,
+        PaymentResultCallback, PaymentType, SECRET_SCHEME_METADATA_KEY, SecretScheme,
+        TransactionStatus,
     },
 };
 use bitcoin::secp256k1;
@@ -35,12 +38,17 @@ use uuid::Uuid;
 pub trait WalletApi: SendSync {
     fn config(&self) -> Result<WalletConfig>;
     fn name(&self) -> String;
+    fn set_name(&mut self, name: String);
+    fn set_coin_selection(&mut self, strategy: CoinSelectionStrategy);
+    fn set_keep_sent_tokens(&mut self, keep: bool);
     fn id(&self) -> String;
     fn mint_url(&self) -> Result<MintUrl>;
     fn betas(&self) -> Vec<MintUrl>;
     #[allow(dead_code)]
     fn clowder_id(&self) -> secp256k1::PublicKey;
     fn mint_urls(&self) -> Result<Vec<MintUrl>>;
+    fn debit_unit(&self) -> CurrencyUnit;
+    async fn balance(&self) -> Result<WalletBalance>;
     async fn prepare_melt(
         &self,
         amount: bitcoin::Amount,
@@ -72,9 +80,12 @@ pub trait WalletApi: SendSync {
         nostr_cl: &nostr_sdk::Client,
         http_cl: &reqwest::Client,
         tstamp: u64,
-    ) -> Result<(TransactionId, Option<Token>)>;
-    async fn mint(&self, amount: bitcoin::Amount) -> Result<MintSummary>;
+    ) -> Result<SendResult>;
+    async fn cancel_payment(&self, p_id: Uuid) -> Result<()>;
+    async fn mint_onchain(&self, amount: bitcoin::Amount) -> Result<MintSummary>;
     async fn check_pending_mints(&self) -> Result<Vec<TransactionId>>;
+    async fn prepare_mint(&self, amount: Amount) -> Result<MintQuoteSummary>;
+    async fn mint(&self, quote_id: String, tstamp: u64) -> Result<TransactionId>;
     async fn check_pending_commitments(&self) -> Result<()>;
     async fn protest_mint(&self, quote_id: Uuid) -> Result<WalletProtestResult>;
     async fn protest_swap(
@@ -101,6 +112,14 @@ pub trait WalletApi: SendSync {
         amount: Amount,
         unit: CurrencyUnit,
         description: Option<String>,
+        conditions: Option<cashu::SpendingConditions>,
+        scheme: SecretScheme,
+    ) -> Result<PaymentSummary>;
+    async fn prepare_send_max(
+        &self,
+        unit: CurrencyUnit,
+        description: Option<String>,
+        scheme: SecretScheme,
     ) -> Result<PaymentSummary>;
     async fn offline_pay_by_token(
         &self,
@@ -109,7 +128,7 @@ pub trait WalletApi: SendSync {
         fees: Amount,
         memo: Option<String>,
         now: u64,
-    ) -> Result<(TransactionId, Option<Token>)>;
+    ) -> Result<SendResult>;
     async fn cleanup_local_proofs(&self) -> Result<()>;
 }
 
@@ -126,6 +145,8 @@ impl WalletApi for super::Wallet {
             clowder_id: self.clowder_id,
             pub_key: self.pub_key,
             betas: self.betas(),
+            coin_selection: self.coin_selection,
+            keep_sent_tokens: self.keep_sent_tokens,
         })
     }
 
@@ -133,6 +154,18 @@ impl WalletApi for super::Wallet {
         self.name.clone()
     }
 
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    fn set_coin_selection(&mut self, strategy: CoinSelectionStrategy) {
+        self.coin_selection = strategy;
+    }
+
+    fn set_keep_sent_tokens(&mut self, keep: bool) {
+        self.keep_sent_tokens = keep;
+    }
+
     fn id(&self) -> String {
         self.id.clone()
     }
@@ -163,11 +196,17 @@ impl WalletApi for super::Wallet {
         let pref = PayReference {
             request_id: summary.request_id,
             unit: summary.unit.clone(),
+            amount: summary.amount,
             fees: summary.fees,
             ptype: WalletPaymentType::OnChain,
             memo: description,
+            conditions: None,
+            scheme: SecretScheme::default(),
         };
-        *self.current_payment.lock().await = Some(pref);
+        self.current_payment
+            .lock()
+            .await
+            .insert(pref.request_id, pref);
         Ok(summary)
     }
 
@@ -175,24 +214,32 @@ impl WalletApi for super::Wallet {
         let infos = self.get_wallet_mint_keyset_infos().await?;
 
         if let Ok(request) = cashu::PaymentRequest::from_str(&input) {
-            let (amount, unit, transport) = self.check_nut18_request(&request).await?;
+            let (amount, unit, transports, conditions) = self.check_nut18_request(&request).await?;
             if unit != self.debit.unit() {
                 return Err(Error::InvalidCurrencyUnit(unit.to_string()));
             }
-            let s_summary = self.debit.prepare_send(amount, &infos).await?;
+            let s_summary = self
+                .prepare_send_with_conditions(amount, conditions, SecretScheme::default(), &infos)
+                .await?;
             let mut summary = PaymentSummary::from(s_summary);
             summary.ptype = PaymentType::Cdk18;
             let pref = PayReference {
                 request_id: summary.request_id,
                 unit: summary.unit.clone(),
+                amount: summary.amount,
                 fees: summary.fees,
                 ptype: WalletPaymentType::Cdk18 {
-                    transport,
+                    transports,
                     id: request.payment_id,
                 },
                 memo: request.description,
+                conditions: summary.conditions.clone(),
+                scheme: SecretScheme::default(),
             };
-            *self.current_payment.lock().await = Some(pref);
+            self.current_payment
+                .lock()
+                .await
+                .insert(pref.request_id, pref);
             Ok(summary)
         } else {
             Err(Error::UnknownPaymentRequest(input))
@@ -206,6 +253,10 @@ impl WalletApi for super::Wallet {
         description: Option<String>,
         nostr_transport: cdk18::Transport,
     ) -> Result<cdk18::PaymentRequest> {
+        // the request must pass our own check_nut18_request
+        if unit != self.debit.unit() {
+            return Err(Error::InvalidCurrencyUnit(unit.to_string()));
+        }
         let mints = self.mint_urls()?;
         let request = cdk18::PaymentRequest {
             payment_id: Some(Uuid::new_v4().to_string()),
@@ -240,6 +291,10 @@ impl WalletApi for super::Wallet {
 
         let start = tokio::time::Instant::now();
         let signer = nostr_cl.signer().await?;
+        let mints = req
+            .mints
+            .clone()
+            .unwrap_or_else(|| vec![self.client.mint_url()]);
 
         tracing::debug!("Subscribing to events from Nostr...");
         let mut events = nostr_cl.notifications();
@@ -265,7 +320,13 @@ impl WalletApi for super::Wallet {
                     };
                     if let RelayPoolNotification::Event { event, .. } = received_evt {
                     match self
-                        .handle_event(*event, signer.clone(), p_id, req.amount.unwrap_or_default())
+                        .handle_event(
+                            *event,
+                            signer.clone(),
+                            p_id,
+                            req.amount.unwrap_or_default(),
+                            &mints,
+                        )
                         .await
                         {
                             Ok(None) => {
@@ -293,37 +354,40 @@ impl WalletApi for super::Wallet {
         nostr_cl: &nostr_sdk::Client,
         http_cl: &reqwest::Client,
         now: u64,
-    ) -> Result<(TransactionId, Option<Token>)> {
-        let p_ref = self.current_payment.lock().await.take();
+    ) -> Result<SendResult> {
+        let p_ref = self.current_payment.lock().await.remove(&p_id);
         let Some(p_ref) = p_ref else {
-            tracing::error!("wallet: No current payment reference found");
+            tracing::error!("wallet: No payment reference found for {p_id}");
             return Err(Error::NoPrepareRef(p_id));
         };
-        if p_ref.request_id != p_id {
-            tracing::error!(
-                "wallet: Payment reference ID mismatch: expected {}, got {}",
-                p_ref.request_id,
-                p_id
-            );
-            return Err(Error::NoPrepareRef(p_id));
-        }
         let infos = self.get_wallet_mint_keyset_infos().await?;
         let PayReference {
             request_id,
             unit,
+            amount: lock_amount,
             fees,
             ptype,
             memo,
+            conditions,
+            scheme,
         } = p_ref;
         if unit != self.debit.unit() {
             return Err(Error::InvalidCurrencyUnit(unit.to_string()));
         }
         match ptype {
-            WalletPaymentType::Cdk18 { transport, id } => {
-                let proofs = self
+            WalletPaymentType::Cdk18 { transports, id } => {
+                let sent = self
                     .debit
                     .send_proofs(request_id, &infos, self.client.clone(), self.swap_config())
-                    .await?;
+                    .await;
+                let mut proofs = self.invalidate_keysets_on_rotation(sent).await?;
+                // unless the payee gets exactly the request amount,
+                // locking fails before any transport is used
+                if let Some(conditions) = &conditions {
+                    proofs = self
+                        .lock_sent_proofs(proofs, conditions, lock_amount, &infos)
+                        .await?;
+                }
                 let (ys, proofs): (Vec<cashu::PublicKey>, Vec<cashu::Proof>) =
                     proofs.into_iter().unzip();
                 let amount = proofs.total_amount()?;
@@ -351,15 +415,26 @@ impl WalletApi for super::Wallet {
                     quote_id: None,
                 };
                 let tx_id = self
-                    .pay_nut18(proofs, nostr_cl, http_cl, transport, id, partial_tx)
+                    .pay_nut18(proofs, nostr_cl, http_cl, transports, id, partial_tx)
                     .await?;
-                Ok((tx_id, None))
+                Ok(SendResult {
+                    tx_id,
+                    token: None,
+                    amount,
+                    fees,
+                    unit,
+                })
             }
             WalletPaymentType::Token => {
                 // Handle Wallet Mint Offline Case
                 match self.is_wallet_mint_offline().await {
                     Ok(is_offline) => {
                         if is_offline {
+                            if conditions.is_some() {
+                                return Err(Error::Unsupported(
+                                    "locked token while wallet mint is offline".to_string(),
+                                ));
+                            }
                             return self
                                 .offline_pay_by_token(request_id, unit, fees, memo, now)
                                 .await;
@@ -373,10 +448,16 @@ impl WalletApi for super::Wallet {
                 };
 
                 let (proofs, token) = {
-                    let p = self
+                    let sent = self
                         .debit
                         .send_proofs(request_id, &infos, self.client.clone(), self.swap_config())
-                        .await?;
+                        .await;
+                    let mut p = self.invalidate_keysets_on_rotation(sent).await?;
+                    if let Some(conditions) = &conditions {
+                        p = self
+                            .lock_sent_proofs(p, conditions, lock_amount, &infos)
+                            .await?;
+                    }
                     (
                         p.clone(),
                         Token::new_cashu(
@@ -399,6 +480,14 @@ impl WalletApi for super::Wallet {
                     TRANSACTION_STATUS_METADATA_KEY.to_owned(),
                     TransactionStatus::Pending.to_string(),
                 );
+                // the sent proofs can't be restored from the seed
+                if scheme == SecretScheme::Random {
+                    metadata.insert(
+                        SECRET_SCHEME_METADATA_KEY.to_owned(),
+                        SecretScheme::Random.to_string(),
+                    );
+                }
+                self.keep_sent_token(&mut metadata, &token);
 
                 let partial_tx = Transaction {
                     mint_url: self.client.mint_url(),
@@ -412,8 +501,14 @@ impl WalletApi for super::Wallet {
                     metadata,
                     quote_id: None,
                 };
-                let tx_id = self.tx_repo.store_tx(partial_tx).await?;
-                Ok((tx_id, Some(token)))
+                let tx_id = self.store_tx(partial_tx).await?;
+                Ok(SendResult {
+                    tx_id,
+                    token: Some(token),
+                    amount,
+                    fees,
+                    unit,
+                })
             }
             WalletPaymentType::OnChain => {
                 let (btc_tx_id, proofs) = self
@@ -457,13 +552,31 @@ impl WalletApi for super::Wallet {
                     metadata,
                     quote_id: None,
                 };
-                let tx_id = self.tx_repo.store_tx(partial_tx).await?;
-                Ok((tx_id, None))
+                let tx_id = self.store_tx(partial_tx).await?;
+                Ok(SendResult {
+                    tx_id,
+                    token: None,
+                    amount,
+                    fees,
+                    unit,
+                })
             }
         }
     }
 
-    async fn mint(&self, amount: bitcoin::Amount) -> Result<MintSummary> {
+    async fn cancel_payment(&self, p_id: Uuid) -> Result<()> {
+        let p_ref = self.current_payment.lock().await.remove(&p_id);
+        let p_ref = p_ref.ok_or(Error::NoPrepareRef(p_id))?;
+        // sends reserved their proofs, melts already moved them to pending spent
+        if matches!(p_ref.ptype, WalletPaymentType::OnChain) {
+            self.debit.cancel_melt(p_id).await?;
+        } else {
+            self.debit.cancel_send(p_id).await?;
+        }
+        Ok(())
+    }
+
+    async fn mint_onchain(&self, amount: bitcoin::Amount) -> Result<MintSummary> {
         let keysets_info = self.get_wallet_mint_keyset_infos().await?;
         let summary = self
             .debit
@@ -510,12 +623,52 @@ impl WalletApi for super::Wallet {
                 metadata,
                 quote_id: Some(qid.to_string()),
             };
-            let tx_id = self.tx_repo.store_tx(tx).await?;
+            let tx_id = self.store_tx(tx).await?;
             res.push(tx_id);
         }
         Ok(res)
     }
 
+    async fn prepare_mint(&self, amount: Amount) -> Result<MintQuoteSummary> {
+        self.debit
+            .prepare_mint(amount, self.debit.unit(), self.client.clone())
+            .await
+    }
+
+    async fn mint(&self, quote_id: String, tstamp: u64) -> Result<TransactionId> {
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        let (amount, ys) = self
+            .retry_on_rotation(&keysets_info, |infos| {
+                let quote_id = quote_id.clone();
+                async move { self.debit.mint(quote_id, &infos, self.client.clone()).await }
+            })
+            .await?;
+
+        let mut metadata = HashMap::default();
+        metadata.insert(
+            PAYMENT_TYPE_METADATA_KEY.to_owned(),
+            PaymentType::Bolt11.to_string(),
+        );
+        metadata.insert(
+            TRANSACTION_STATUS_METADATA_KEY.to_owned(),
+            TransactionStatus::Settled.to_string(),
+        );
+        let tx = Transaction {
+            mint_url: self.client.mint_url(),
+            fee: Amount::ZERO,
+            direction: TransactionDirection::Incoming,
+            memo: None,
+            timestamp: tstamp,
+            unit: self.debit_unit(),
+            ys,
+            amount,
+            metadata,
+            quote_id: Some(quote_id),
+        };
+        let tx_id = self.store_tx(tx).await?;
+        Ok(tx_id)
+    }
+
     async fn check_pending_commitments(&self) -> Result<()> {
         let now = chrono::Utc::now().timestamp() as u64;
         self.debit.check_pending_commitments(now).await
@@ -558,7 +711,7 @@ impl WalletApi for super::Wallet {
                 metadata,
                 quote_id: Some(quote_id.to_string()),
             };
-            self.tx_repo.store_tx(tx).await?;
+            self.store_tx(tx).await?;
         }
 
         Ok(WalletProtestResult { status, result })
@@ -615,7 +768,7 @@ impl WalletApi for super::Wallet {
                 metadata,
                 quote_id: None,
             };
-            self.tx_repo.store_tx(tx).await?;
+            self.store_tx(tx).await?;
         }
 
         Ok(WalletProtestResult { status, result })
@@ -675,7 +828,7 @@ impl WalletApi for super::Wallet {
                 metadata,
                 quote_id: Some(quote_id.to_string()),
             };
-            self.tx_repo.store_tx(tx).await?;
+            self.store_tx(tx).await?;
         }
 
         Ok(WalletProtestResult { status, result })
@@ -723,6 +876,7 @@ impl WalletApi for super::Wallet {
             metadata,
         )
         .await
+        .map(|result| result.tx_id)
     }
 
     async fn is_wallet_mint_rabid(&self) -> Result<bool> {
@@ -829,6 +983,24 @@ impl WalletApi for super::Wallet {
         Ok(urls)
     }
 
+    fn debit_unit(&self) -> CurrencyUnit {
+        self.debit.unit()
+    }
+
+    async fn balance(&self) -> Result<WalletBalance> {
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        let balance = self.debit.balance(&keysets_info).await?;
+        Ok(WalletBalance {
+            debit_available: balance.debit,
+            debit_pending: balance.debit_pending,
+            debit_reserved: balance.debit_reserved,
+            credit_available: balance.credit,
+            credit_pending: balance.credit_pending,
+            credit_reserved: balance.credit_reserved,
+            total: balance.debit + balance.credit,
+        })
+    }
+
     fn betas(&self) -> Vec<cashu::MintUrl> {
         self.beta_clients.keys().cloned().collect()
     }
@@ -865,6 +1037,7 @@ impl WalletApi for super::Wallet {
         }
 
         self.client = substitute;
+        self.invalidate_keysets().await;
         self.clowder_id = self.client.get_clowder_id().await?;
         let mut beta_clients = HashMap::<cashu::MintUrl, Arc<dyn ClowderMintConnector>>::new();
 
@@ -897,22 +1070,66 @@ impl WalletApi for super::Wallet {
         amount: Amount,
         unit: CurrencyUnit,
         description: Option<String>,
+        conditions: Option<cashu::SpendingConditions>,
+        scheme: SecretScheme,
     ) -> Result<PaymentSummary> {
         let infos = self.get_wallet_mint_keyset_infos().await?;
         if unit != self.debit.unit() {
             return Err(Error::InvalidCurrencyUnit(unit.to_string()));
         }
 
-        let s_summary = self.debit.prepare_send(amount, &infos).await?;
+        let s_summary = self
+            .prepare_send_with_conditions(amount, conditions, scheme, &infos)
+            .await?;
         let summary = PaymentSummary::from(s_summary);
         let pref = PayReference {
             request_id: summary.request_id,
             unit: summary.unit.clone(),
+            amount: summary.amount,
             fees: summary.fees,
             ptype: WalletPaymentType::Token,
             memo: description,
+            conditions: summary.conditions.clone(),
+            scheme,
         };
-        *self.current_payment.lock().await = Some(pref);
+        self.current_payment
+            .lock()
+            .await
+            .insert(pref.request_id, pref);
+        Ok(summary)
+    }
+
+    // a token payment of the whole balance, paid with `pay` like any other token payment
+    async fn prepare_send_max(
+        &self,
+        unit: CurrencyUnit,
+        description: Option<String>,
+        scheme: SecretScheme,
+    ) -> Result<PaymentSummary> {
+        let infos = self.get_wallet_mint_keyset_infos().await?;
+        if unit != self.debit.unit() {
+            return Err(Error::InvalidCurrencyUnit(unit.to_string()));
+        }
+
+        let s_summary = self
+            .debit
+            .prepare_send_max(&infos, scheme, self.coin_selection)
+            .await?;
+        let summary = PaymentSummary::from(s_summary);
+        let pref = PayReference {
+            request_id: summary.request_id,
+            unit: summary.unit.clone(),
+            amount: summary.amount,
+            fees: summary.fees,
+            ptype: WalletPaymentType::Token,
+            memo: description,
+            conditions: None,
+            scheme,
+        };
+        self.current_payment
+            .lock()
+            .await
+            .insert(pref.request_id, pref);
         Ok(summary)
     }
 
@@ -933,7 +1150,7 @@ impl WalletApi for super::Wallet {
         fees: Amount,
         memo: Option<String>,
         now: u64,
-    ) -> Result<(TransactionId, Option<Token>)> {
+    ) -> Result<SendResult> {
         tracing::warn!(
             "Pay by Token: Wallet mint is offline - find substitute and attempt offline exchange for tokens"
         );
@@ -1001,6 +1218,7 @@ impl WalletApi for super::Wallet {
                 TRANSACTION_STATUS_METADATA_KEY.to_owned(),
                 TransactionStatus::Pending.to_string(),
             );
+            self.keep_sent_token(&mut metadata, &token);
 
             // Create Transaction
             let partial_tx = Transaction {
@@ -1015,8 +1233,14 @@ impl WalletApi for super::Wallet {
                 metadata,
                 quote_id: None,
             };
-            let tx_id = self.tx_repo.store_tx(partial_tx).await?;
-            Ok((tx_id, Some(token)))
+            let tx_id = self.store_tx(partial_tx).await?;
+            Ok(SendResult {
+                tx_id,
+                token: Some(token),
+                amount,
+                fees,
+                unit,
+            })
         } else {
             Err(Error::NoSubstitute)
         }
@@ -5,29 +5,45 @@ pub mod util;
 use crate::{
     ClowderMintConnector,
     error::{Error, Result},
-    pocket::debit::DebitPocketApi,
-    types::{PAYMENT_TYPE_METADATA_KEY, TRANSACTION_STATUS_METADATA_KEY},
-    wallet::types::{PayReference, SwapConfig, WalletBalance, WalletDetailedBalanceEntry},
+    pocket::{RecheckSummary, debit::DebitPocketApi},
+    types::{PAYMENT_TYPE_METADATA_KEY, SendSummary, TRANSACTION_STATUS_METADATA_KEY},
+    wallet::types::{
+        CounterAdjustment, LocalDbCleanup, MintCapabilities, NUT18_RESEND_METADATA_KEY,
+        Nut18Resend, PayReference, PaymentRequestInfo, PendingTransaction, ProofBackup, Receipt,
+        ReceiveResult, SwapConfig, WALLET_BACKUP_VERSION, WalletBackup, WalletBalance,
+        WalletDetailedBalanceEntry, WalletEvent, WalletPaymentType, WalletStats, get_nut18_resend,
+    },
 };
 use bcr_common::{
     cashu::{
         self, Amount, CurrencyUnit, KeySetInfo, MintUrl, PaymentRequest, Proof, ProofsMethods,
         nut18 as cdk18,
     },
-    cdk_common::wallet::{Transaction, TransactionDirection, TransactionId},
+    cdk_common::{
+        self,
+        wallet::{Transaction, TransactionDirection, TransactionId},
+    },
     wallet::Token,
     wire::clowder::{ConnectedMintResponse, ConnectedMintsResponse},
 };
-use bcr_wallet_core::types::{PaymentType, TransactionStatus};
-use bcr_wallet_persistence::TransactionRepository;
+use bcr_wallet_core::types::{
+    CoinSelectionStrategy, PaymentType, RestoreProgressCallback, SECRET_SCHEME_METADATA_KEY,
+    SecretScheme, TOKEN_METADATA_KEY, TransactionStatus, get_payment_type, get_token,
+    get_transaction_status,
+};
+use bcr_wallet_core::util::teaser;
+use bcr_wallet_persistence::{TransactionRepository, TxChanges, TxFilter};
 use bitcoin::{
     hashes::{Hash, sha256::Hash as Sha256},
     secp256k1,
 };
 use nostr::{nips::nip59::UnwrappedGift, signer::NostrSigner};
-use nostr_sdk::nips::nip19::{FromBech32, Nip19Profile};
-use std::{collections::HashMap, str::FromStr, sync::Arc};
-use tokio::sync::Mutex;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+};
+use tokio::sync::{Mutex, broadcast};
 use uuid::Uuid;
 
 pub struct Wallet {
@@ -40,11 +56,65 @@ pub struct Wallet {
     name: String,
     id: String,
     pub_key: secp256k1::PublicKey,
-    current_payment: Mutex<Option<PayReference>>,
+    // prepared payments by request id
+    current_payment: Mutex<HashMap<Uuid, PayReference>>,
     current_payment_request: Mutex<Option<PaymentRequest>>,
     clowder_id: secp256k1::PublicKey,
     client_factory: Box<dyn Fn(cashu::MintUrl) -> Arc<dyn ClowderMintConnector> + Send + Sync>,
     swap_expiry: chrono::TimeDelta,
+    events: broadcast::Sender<WalletEvent>,
+    keysets_ttl: chrono::TimeDelta,
+    keysets_cache: Mutex<Option<(tokio::time::Instant, Vec<KeySetInfo>)>>,
+    mint_info: Mutex<Option<cashu::MintInfo>>,
+    coin_selection: CoinSelectionStrategy,
+    keep_sent_tokens: bool,
+}
+
+// slow subscribers past this many pending events start missing the oldest ones
+const EVENTS_CAPACITY: usize = 64;
+
+// zero-amount proofs are worthless, storing or swapping them only bloats the db
+fn drop_dust_proofs(proofs: Vec<cashu::Proof>) -> Vec<cashu::Proof> {
+    let (dust, proofs): (Vec<_>, Vec<_>) = proofs
+        .into_iter()
+        .partition(|proof| proof.amount == Amount::ZERO);
+    if !dust.is_empty() {
+        tracing::warn!("dropping {} zero-amount proofs", dust.len());
+    }
+    proofs
+}
+
+fn is_rotation_error(e: &Error) -> bool {
+    matches!(
+        e,
+        Error::UnknownKeysetId(_) | Error::InactiveKeyset(_) | Error::NoActiveKeyset
+    ) || is_mint_keyset_error(e)
+}
+
+// the mint rejects a rotated keyset with the NUT error codes 12001 (unknown) and 12002 (inactive),
+// the client hands them over either as a cdk error or as the raw error response
+fn is_mint_keyset_error(e: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(e);
+    while let Some(err) = source {
+        if let Some(e) = err.downcast_ref::<cdk_common::Error>() {
+            return matches!(
+                e,
+                cdk_common::Error::UnknownKeySet | cdk_common::Error::InactiveKeyset
+            );
+        }
+        if let Some(bcr_common::client::mint::Error::Internal(msg)) =
+            err.downcast_ref::<bcr_common::client::mint::Error>()
+            && let Ok(response) = cdk_common::error::ErrorResponse::from_json(msg)
+        {
+            return matches!(
+                response.code,
+                cdk_common::error::ErrorCode::KeysetNotFound
+                    | cdk_common::error::ErrorCode::KeysetInactive
+            );
+        }
+        source = err.source();
+    }
+    false
 }
 
 impl Wallet {
@@ -61,6 +131,9 @@ impl Wallet {
         beta_clients: HashMap<cashu::MintUrl, Arc<dyn ClowderMintConnector>>,
         client_factory: Box<dyn Fn(cashu::MintUrl) -> Arc<dyn ClowderMintConnector> + Send + Sync>,
         swap_expiry: chrono::TimeDelta,
+        keysets_ttl: chrono::TimeDelta,
+        coin_selection: CoinSelectionStrategy,
+        keep_sent_tokens: bool,
     ) -> Result<Self> {
         Ok(Self {
             network,
@@ -71,12 +144,18 @@ impl Wallet {
             name,
             id,
             pub_key,
-            current_payment: Mutex::new(None),
+            current_payment: Mutex::new(HashMap::new()),
             current_payment_request: Mutex::new(None),
             beta_clients,
             clowder_id,
             client_factory,
             swap_expiry,
+            events: broadcast::channel(EVENTS_CAPACITY).0,
+            keysets_ttl,
+            keysets_cache: Mutex::new(None),
+            mint_info: Mutex::new(None),
+            coin_selection,
+            keep_sent_tokens,
         })
     }
 
@@ -84,6 +163,22 @@ impl Wallet {
         self.name.clone()
     }
 
+    pub fn subscribe(&self) -> broadcast::Receiver<WalletEvent> {
+        self.events.subscribe()
+    }
+
+    fn emit(&self, event: WalletEvent) {
+        // no subscribers is not an error
+        let _ = self.events.send(event);
+    }
+
+    async fn store_tx(&self, tx: Transaction) -> Result<TransactionId> {
+        let txid = self.tx_repo.store_tx(tx).await?;
+        self.emit(WalletEvent::TransactionStored(txid));
+        self.emit(WalletEvent::BalanceChanged);
+        Ok(txid)
+    }
+
     fn swap_config(&self) -> SwapConfig {
         SwapConfig {
             expiry: self.swap_expiry,
@@ -101,6 +196,43 @@ impl Wallet {
         Ok(res)
     }
 
+    pub async fn search_txs(&self, filter: TxFilter) -> Result<Vec<TransactionId>> {
+        let res = self.tx_repo.query(filter).await?;
+        Ok(res)
+    }
+
+    // Returns a page of transactions, newest first
+    // pending ones are checked against the mint only if `refresh_pending` is set
+    pub async fn list_transactions(
+        &self,
+        offset: usize,
+        limit: usize,
+        refresh_pending: bool,
+    ) -> Result<Vec<Transaction>> {
+        let mut txs = self.tx_repo.list_txs().await?;
+        txs.sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+        let mut page: Vec<Transaction> = txs.into_iter().skip(offset).take(limit).collect();
+        if refresh_pending {
+            for tx in page.iter_mut().filter(|tx| util::tx_can_be_refreshed(tx)) {
+                if self.refresh_tx(tx.id()).await? {
+                    tx.metadata.insert(
+                        String::from(TRANSACTION_STATUS_METADATA_KEY),
+                        TransactionStatus::Settled.to_string(),
+                    );
+                }
+            }
+        }
+        Ok(page)
+    }
+
+    // Returns the transactions written or deleted after `seq` and the next marker to use
+    // the marker is read first, so a concurrent write may be returned twice, but never missed
+    pub async fn list_txs_since(&self, seq: u64) -> Result<(TxChanges, u64)> {
+        let marker = self.tx_repo.current_seq().await?;
+        let changes = self.tx_repo.list_since(seq).await?;
+        Ok((changes, marker))
+    }
+
     // Returns (Option<(clowder_path, intermint_alpha_keyset)>, local_alpha_keyset)
     async fn get_clowder_path_and_keysets_info(
         &self,
@@ -110,7 +242,7 @@ impl Wallet {
         Vec<KeySetInfo>,
     )> {
         let local_keysets_info = self.get_wallet_mint_keyset_infos().await?;
-        if mint_url == self.client.mint_url() {
+        if util::same_mint(&mint_url, &self.client.mint_url()) {
             Ok((None, local_keysets_info))
         } else {
             // Intermint Exchange
@@ -162,9 +294,21 @@ impl Wallet {
         }
     }
 
+    // served from the cache while younger than `keysets_ttl`, the config fallback is never cached
     async fn get_wallet_mint_keyset_infos(&self) -> Result<Vec<KeySetInfo>> {
+        let mut cache = self.keysets_cache.lock().await;
+        if let Some((fetched_at, infos)) = cache.as_ref()
+            && chrono::TimeDelta::from_std(fetched_at.elapsed())
+                .is_ok_and(|age| age < self.keysets_ttl)
+        {
+            return Ok(infos.clone());
+        }
         Ok(match self.client.get_mint_keysets().await {
-            Ok(infos) => infos,
+            Ok(infos) => {
+                let infos = util::dedup_keyset_infos(infos)?;
+                *cache = Some((tokio::time::Instant::now(), infos.clone()));
+                infos
+            }
             Err(e) => {
                 tracing::warn!(
                     "Couldn't fetch mint keysets for wallet mint - falling back to config: {:?}, {e}",
@@ -175,32 +319,126 @@ impl Wallet {
         })
     }
 
-    pub fn debit_unit(&self) -> CurrencyUnit {
-        self.debit.unit()
+    // fetched once, the mint info doesn't change over the wallet's lifetime
+    pub async fn mint_info(&self) -> Result<cashu::MintInfo> {
+        let mut cache = self.mint_info.lock().await;
+        if let Some(info) = cache.as_ref() {
+            return Ok(info.clone());
+        }
+        let info = self.client.get_mint_info().await?;
+        *cache = Some(info.clone());
+        Ok(info)
+    }
+
+    pub async fn mint_capabilities(&self) -> Result<MintCapabilities> {
+        let info = self.mint_info().await?;
+        Ok(MintCapabilities::from(&info))
+    }
+
+    async fn invalidate_keysets(&self) {
+        *self.keysets_cache.lock().await = None;
+    }
+
+    // the cached infos, refetched first if any of the given keysets is missing from them
+    async fn keyset_infos_covering(&self, kids: &[cashu::Id]) -> Result<Vec<KeySetInfo>> {
+        let infos = self.get_wallet_mint_keyset_infos().await?;
+        if kids
+            .iter()
+            .all(|kid| infos.iter().any(|info| info.id == *kid))
+        {
+            return Ok(infos);
+        }
+        tracing::debug!("unknown keyset in {kids:?}, refreshing mint keysets");
+        self.invalidate_keysets().await;
+        self.get_wallet_mint_keyset_infos().await
+    }
+
+    // keyset errors usually mean the mint rotated its keysets after we cached them
+    async fn invalidate_keysets_on_rotation<T>(&self, res: Result<T>) -> Result<T> {
+        if matches!(&res, Err(e) if is_rotation_error(e)) {
+            self.invalidate_keysets().await;
+        }
+        res
+    }
+
+    // only for operations that can run again after failing, i.e. not consuming a prepared send
+    async fn retry_on_rotation<T, F, Fut>(&self, infos: &[KeySetInfo], op: F) -> Result<T>
+    where
+        F: Fn(Vec<KeySetInfo>) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let res = op(infos.to_vec()).await;
+        if !matches!(&res, Err(e) if is_rotation_error(e)) {
+            return res;
+        }
+        self.invalidate_keysets().await;
+        let refreshed = self.get_wallet_mint_keyset_infos().await?;
+        if refreshed == infos {
+            return res;
+        }
+        tracing::debug!("mint keysets rotated, retrying with the refreshed ones");
+        self.invalidate_keysets_on_rotation(op(refreshed).await)
+            .await
+    }
+
+    // read-only, a following `prepare_pay_by_token` may still select other proofs
+    pub async fn estimate_send(&self, amount: Amount, unit: CurrencyUnit) -> Result<SendSummary> {
+        if unit != self.debit.unit() {
+            return Err(Error::InvalidCurrencyUnit(unit.to_string()));
+        }
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        self.debit
+            .estimate_send(amount, &keysets_info, self.coin_selection)
+            .await
     }
 
-    pub async fn balance(&self) -> Result<WalletBalance> {
+    // the input fee of each keyset of the wallet mint, in parts per thousand
+    pub async fn keyset_fees(&self) -> Result<HashMap<cashu::Id, u64>> {
         let keysets_info = self.get_wallet_mint_keyset_infos().await?;
-        let balance = self.debit.balance(&keysets_info).await?;
-        Ok(WalletBalance {
-            debit: balance.debit,
-            credit: balance.credit,
-            total: balance.debit + balance.credit,
+        Ok(keysets_info
+            .into_iter()
+            .map(|info| (info.id, info.input_fee_ppk))
+            .collect())
+    }
+
+    pub fn inspect_payment_request(&self, input: &str) -> Result<PaymentRequestInfo> {
+        let Ok(req) = cashu::PaymentRequest::from_str(input) else {
+            return Err(Error::UnknownPaymentRequest(input.to_owned()));
+        };
+        let transports = req
+            .transports
+            .iter()
+            .map(|t| match t._type {
+                cashu::TransportType::Nostr => String::from("nostr"),
+                cashu::TransportType::HttpPost => String::from("post"),
+            })
+            .collect();
+        Ok(PaymentRequestInfo {
+            amount: req.amount,
+            unit: req.unit,
+            mints: req.mints,
+            transports,
+            has_spending_conditions: req.nut10.is_some(),
         })
     }
 
     async fn check_nut18_request(
         &self,
         req: &cashu::PaymentRequest,
-    ) -> Result<(Amount, CurrencyUnit, cashu::Transport)> {
+    ) -> Result<(
+        Amount,
+        CurrencyUnit,
+        Vec<cashu::Transport>,
+        Option<cashu::SpendingConditions>,
+    )> {
         if let Some(mints) = &req.mints
-            && !mints.contains(&self.client.mint_url())
+            && !mints
+                .iter()
+                .any(|mint| util::same_mint(mint, &self.client.mint_url()))
         {
             return Err(Error::InterMint);
         }
-        if req.nut10.is_some() {
-            return Err(Error::SpendingConditions);
-        }
+        let conditions = util::nut18_conditions(req.nut10.as_ref())?;
         let Some(amount) = req.amount else {
             return Err(Error::MissingAmount);
         };
@@ -212,32 +450,344 @@ impl Wallet {
         } else {
             self.debit.unit()
         };
-        let (nostr_transports, http_transports): (Vec<_>, Vec<_>) = req
+        // http first, nostr as the fallback
+        let (nostr_transports, mut transports): (Vec<_>, Vec<_>) = req
             .transports
             .iter()
+            .cloned()
             .partition(|t| matches!(t._type, cashu::TransportType::Nostr));
-        if !http_transports.is_empty() {
-            Ok((amount, unit, http_transports[0].clone()))
-        } else if !nostr_transports.is_empty() {
-            Ok((amount, unit, nostr_transports[0].clone()))
-        } else {
-            Err(Error::NoTransport)
+        transports.extend(nostr_transports);
+        if transports.is_empty() {
+            return Err(Error::NoTransport);
         }
+        Ok((amount, unit, transports, conditions))
+    }
+
+    async fn prepare_send_with_conditions(
+        &self,
+        amount: Amount,
+        conditions: Option<cashu::SpendingConditions>,
+        scheme: SecretScheme,
+        infos: &[KeySetInfo],
+    ) -> Result<SendSummary> {
+        self.retry_on_rotation(infos, |infos| {
+            let conditions = conditions.clone();
+            async move {
+                self.try_prepare_send_with_conditions(amount, conditions, scheme, &infos)
+                    .await
+            }
+        })
+        .await
+    }
+
+    async fn try_prepare_send_with_conditions(
+        &self,
+        amount: Amount,
+        conditions: Option<cashu::SpendingConditions>,
+        scheme: SecretScheme,
+        infos: &[KeySetInfo],
+    ) -> Result<SendSummary> {
+        let Some(conditions) = conditions else {
+            return self
+                .debit
+                .prepare_send(amount, infos, scheme, self.coin_selection)
+                .await;
+        };
+        // locking needs an extra swap, so we send enough to cover its input fee
+        let lock_fee = util::estimate_lock_fee(amount, &self.debit.unit(), infos)?;
+        let mut s_summary = match self
+            .debit
+            .prepare_send(amount + lock_fee, infos, scheme, self.coin_selection)
+            .await
+        {
+            Ok(s_summary) => s_summary,
+            Err(Error::InsufficientBalance(available, _)) if available >= amount => {
+                return Err(Error::InsufficientFundsForFee {
+                    available,
+                    needed: amount + lock_fee,
+                    fee: lock_fee,
+                });
+            }
+            Err(Error::InsufficientFundsForFee {
+                available, needed, ..
+            }) => {
+                return Err(Error::InsufficientFundsForFee {
+                    available,
+                    needed,
+                    fee: needed.checked_sub(amount).unwrap_or(Amount::ZERO),
+                });
+            }
+            Err(e) => return Err(e),
+        };
+        s_summary.amount = amount;
+        s_summary.send_fees = lock_fee;
+        s_summary.conditions = Some(conditions);
+        Ok(s_summary)
+    }
+
+    // the original proofs get spent by the lock swap, the locked ones take their place in the pocket
+    // what the actual inputs leave over the amount and their fee comes back as change
+    // if locking fails, the original proofs are unspent again
+    async fn lock_sent_proofs(
+        &self,
+        proofs: HashMap<cashu::PublicKey, Proof>,
+        conditions: &cashu::SpendingConditions,
+        amount: Amount,
+        infos: &[KeySetInfo],
+    ) -> Result<HashMap<cashu::PublicKey, Proof>> {
+        let sent: Vec<cashu::PublicKey> = proofs.keys().copied().collect();
+        let (locked, change) = match self
+            .try_lock_sent_proofs(proofs.into_values().collect(), conditions, amount, infos)
+            .await
+        {
+            Ok(locked) => locked,
+            Err(e) => {
+                // should the mint have swapped them anyway, the next cleanup drops them
+                if let Err(revert) = self.debit.revert_sent_proofs(&sent).await {
+                    tracing::error!("failed to revert the proofs of a failed lock: {revert}");
+                }
+                return Err(e);
+            }
+        };
+        self.debit
+            .replace_sent_proofs(&sent, locked.clone(), change)
+            .await?;
+        locked
+            .into_iter()
+            .map(|proof| Ok((proof.y()?, proof)))
+            .collect()
+    }
+
+    async fn try_lock_sent_proofs(
+        &self,
+        proofs: Vec<Proof>,
+        conditions: &cashu::SpendingConditions,
+        amount: Amount,
+        infos: &[KeySetInfo],
+    ) -> Result<(Vec<Proof>, Vec<Proof>)> {
+        let total = proofs.total_amount()?;
+        let fee = util::input_fee(&proofs, infos)?;
+        let needed = amount + fee;
+        let surplus = total
+            .checked_sub(needed)
+            .ok_or(Error::InsufficientFundsForFee {
+                available: total,
+                needed,
+                fee,
+            })?;
+        let change = if surplus > Amount::ZERO {
+            Some(self.debit.change_premint(surplus, infos).await?)
+        } else {
+            None
+        };
+        util::lock_proofs(
+            self.client.as_ref(),
+            proofs,
+            conditions,
+            amount,
+            change,
+            infos,
+            self.debit.unit(),
+            self.swap_config(),
+        )
+        .await
+    }
+
+    // removes the spent proofs, and optionally the counters of keysets the mint dropped
+    pub async fn clean_local_db(&self, prune_counters: bool) -> Result<LocalDbCleanup> {
+        let cleaned = self.debit.cleanup_local_proofs(self.client.clone()).await?;
+        let counters_removed = if prune_counters {
+            // never the config fallback, a keyset missing from it may still be listed by the mint
+            let keysets_info = self.client.get_mint_keysets().await?;
+            self.debit.prune_counters(&keysets_info).await?
+        } else {
+            0
+        };
+        Ok(LocalDbCleanup {
+            proofs_removed: cleaned.len(),
+            counters_removed,
+        })
+    }
+
+    // local only, nothing is asked from the mint
+    pub async fn stats(&self) -> Result<WalletStats> {
+        let debit = self.debit.stats().await?;
+        let txs = self.tx_repo.list_txs().await?;
+        Ok(WalletStats {
+            debit,
+            transactions: txs.len(),
+            oldest_tx: txs.iter().map(|tx| tx.timestamp).min(),
+        })
+    }
+
+    // Reconciles the local proof states with the mint, e.g. after failed operations
+    // the proofs of pending transactions are left to `reclaim`
+    pub async fn recheck(&self) -> Result<RecheckSummary> {
+        let pending_txs_ys: Vec<cashu::PublicKey> = self
+            .tx_repo
+            .list_txs()
+            .await?
+            .into_iter()
+            .filter(util::tx_can_be_refreshed)
+            .flat_map(|tx| tx.ys)
+            .collect();
+        self.debit
+            .recheck(&pending_txs_ys, self.client.clone())
+            .await
     }
 
-    pub async fn restore_local_proofs(&self) -> Result<()> {
+    pub async fn restore_local_proofs(
+        &self,
+        progress: Option<RestoreProgressCallback>,
+    ) -> Result<()> {
         let keysets_info = self.get_wallet_mint_keyset_infos().await?;
         self.debit
-            .restore_local_proofs(&keysets_info, self.client.clone())
+            .restore_local_proofs(&keysets_info, self.client.clone(), progress)
             .await?;
         Ok(())
     }
 
+    pub async fn restore_keyset(&self, kid: cashu::Id) -> Result<usize> {
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        if !keysets_info.iter().any(|info| info.id == kid) {
+            return Err(Error::UnknownKeysetId(kid));
+        }
+        self.debit
+            .restore_keysets(&[kid], &keysets_info, self.client.clone(), None)
+            .await
+    }
+
+    // recovery for a lost counter store, distinct from restoring proofs from the mint
+    pub async fn rebuild_counters_from_proofs(&self) -> Result<HashMap<cashu::Id, u32>> {
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        self.debit.rebuild_counters(&keysets_info).await
+    }
+
+    // repairs counters left behind the mint by a crash, see `restore_keyset` for the proofs
+    pub async fn verify_counters(&self) -> Result<Vec<CounterAdjustment>> {
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        self.debit
+            .verify_counters(&keysets_info, self.client.clone())
+            .await
+    }
+
+    /// WARN: the blob holds the spendable secrets of every proof, its safety is the passphrase
+    pub async fn export_proofs_encrypted(&self, passphrase: &str) -> Result<Vec<u8>> {
+        let backup = ProofBackup {
+            mint_url: self.client.mint_url(),
+            unit: self.debit.unit(),
+            debit: self.debit.backup_proofs().await?,
+        };
+        let plaintext = serde_json::to_vec(&backup)?;
+        util::encrypt_backup(&plaintext, passphrase)
+    }
+
+    // restores the proofs straight into the pocket, without asking the mint
+    pub async fn import_proofs_encrypted(&self, blob: &[u8], passphrase: &str) -> Result<usize> {
+        let plaintext = util::decrypt_backup(blob, passphrase)?;
+        let backup: ProofBackup = serde_json::from_slice(&plaintext)?;
+        let mint_url = self.client.mint_url();
+        if !util::same_mint(&backup.mint_url, &mint_url) {
+            return Err(Error::InvalidMintUrl(mint_url, backup.mint_url));
+        }
+        if backup.unit != self.debit.unit() {
+            return Err(Error::InvalidCurrencyUnit(backup.unit.to_string()));
+        }
+        self.debit.import_backup(backup.debit).await
+    }
+
+    /// WARN: the blob holds the spendable secrets of every proof, its safety is the passphrase
+    pub async fn export_backup(&self, passphrase: &str) -> Result<Vec<u8>> {
+        let backup = WalletBackup {
+            version: WALLET_BACKUP_VERSION,
+            config: <Self as api::WalletApi>::config(self)?,
+            debit: self.debit.backup_proofs().await?,
+            transactions: self.tx_repo.list_txs().await?,
+            melt_commitments: self.debit.backup_melt_commitments().await?,
+        };
+        let plaintext = serde_json::to_vec(&backup)?;
+        util::encrypt_backup(&plaintext, passphrase)
+    }
+
+    // meant for a wallet freshly built from the backed up config, returns the imported proofs
+    pub async fn import_backup(&self, backup: WalletBackup) -> Result<usize> {
+        if backup.config.wallet_id != self.id {
+            return Err(Error::InvalidBackup(format!(
+                "backup of wallet {}",
+                backup.config.wallet_id
+            )));
+        }
+        let imported = self.debit.import_backup(backup.debit).await?;
+        self.debit
+            .import_melt_commitments(backup.melt_commitments)
+            .await?;
+        for tx in backup.transactions {
+            self.tx_repo.store_tx(tx).await?;
+        }
+        self.emit(WalletEvent::BalanceChanged);
+        Ok(imported)
+    }
+
+    /// WARN: a snapshot, nothing is swapped or marked pending, so spending the token elsewhere
+    /// while still using this wallet double-spends
+    pub async fn export_all_as_token(&self, unit: CurrencyUnit) -> Result<Token> {
+        if unit != self.debit.unit() {
+            return Err(Error::InvalidCurrencyUnit(unit.to_string()));
+        }
+        let proofs = self.debit.backup_proofs().await?.unspent;
+        if proofs.is_empty() {
+            return Err(Error::EmptyToken(String::from("no unspent proofs")));
+        }
+        tracing::warn!(
+            "exporting {} unspent proofs as a token, they stay spendable by this wallet",
+            proofs.len()
+        );
+        Ok(Token::new_cashu(self.client.mint_url(), proofs, None, unit))
+    }
+
     pub async fn load_tx(&self, tx_id: TransactionId) -> Result<Transaction> {
         let tx = self.tx_repo.load_tx(tx_id).await?;
         Ok(tx)
     }
 
+    // only if the wallet opted in to keep the tokens it sends
+    fn keep_sent_token(&self, metadata: &mut HashMap<String, String>, token: &Token) {
+        if self.keep_sent_tokens {
+            metadata.insert(TOKEN_METADATA_KEY.to_owned(), token.to_string());
+        }
+    }
+
+    // Returns the token of a send, kept while the transaction is pending
+    pub async fn sent_token(&self, tx_id: TransactionId) -> Result<Option<String>> {
+        let tx = self.tx_repo.load_tx(tx_id).await?;
+        Ok(get_token(&tx.metadata))
+    }
+
+    // a transaction no longer pending has no use for the spendable token
+    async fn forget_sent_token(&self, tx: &Transaction) -> Result<()> {
+        if get_token(&tx.metadata).is_some() {
+            self.tx_repo
+                .update_metadata(tx.id(), TOKEN_METADATA_KEY.to_owned(), String::new())
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn export_receipt(&self, tx_id: TransactionId) -> Result<Receipt> {
+        let tx = self.tx_repo.load_tx(tx_id).await?;
+        Ok(Receipt {
+            tx_id: tx_id.to_string(),
+            amount: tx.amount,
+            unit: tx.unit,
+            fee: tx.fee,
+            timestamp: tx.timestamp,
+            direction: tx.direction,
+            mint_url: tx.mint_url,
+            memo: tx.memo,
+            status: get_transaction_status(&tx.metadata),
+        })
+    }
+
     // Fetches the transaction with the given ID from the database and, if it's in a pending state
     // it attempts to get the current state from the mint and, if it's spent, changes it to spent
     // Returns whether the transaction has been updated
@@ -260,11 +810,113 @@ impl Wallet {
                     TransactionStatus::Settled.to_string(),
                 )
                 .await?;
+            self.forget_sent_token(&tx).await?;
             updated = true;
         }
         Ok(updated)
     }
 
+    // Waits for the receiver of a NUT-18 payment to redeem the sent proofs, over nostr or http
+    // the spent proofs settle the transaction, returns whether that happened before the timeout
+    pub async fn confirm_nut18_delivery(
+        &self,
+        tx_id: TransactionId,
+        timeout: std::time::Duration,
+    ) -> Result<bool> {
+        let tx = self.tx_repo.load_tx(tx_id).await?;
+        if !matches!(get_payment_type(&tx.metadata), PaymentType::Cdk18) {
+            return Err(Error::Unsupported(format!(
+                "{tx_id} is not a NUT-18 payment"
+            )));
+        }
+        if !util::tx_can_be_refreshed(&tx) {
+            let status = get_transaction_status(&tx.metadata);
+            return Ok(matches!(status, TransactionStatus::Settled));
+        }
+        // nothing to wait for until the payment is sent again
+        if get_nut18_resend(&tx.metadata).is_some() {
+            return Ok(false);
+        }
+        let poll = std::time::Duration::from_millis(crate::config::NUT18_DELIVERY_POLL_MILLIS);
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            if self.refresh_tx(tx_id).await? {
+                return Ok(true);
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Ok(false);
+            }
+            tokio::time::sleep(poll.min(deadline - now)).await;
+        }
+    }
+
+    // Gives back the proofs of prepared sends abandoned for longer than `older_than`
+    // returns the number of proofs released
+    pub async fn release_stale_reservations(
+        &self,
+        older_than: std::time::Duration,
+    ) -> Result<usize> {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let tstamp = now.saturating_sub(older_than.as_secs());
+        self.debit.release_stale_reservations(tstamp).await
+    }
+
+    // Outgoing transactions still pending, newest first
+    pub async fn list_pending_transactions(&self) -> Result<Vec<PendingTransaction>> {
+        let mut txs = self.tx_repo.list_txs().await?;
+        txs.retain(util::tx_can_be_refreshed);
+        txs.sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+        let mut pending = Vec::with_capacity(txs.len());
+        for tx in txs {
+            let pending_ys = self.debit.pending(&tx.ys).await?;
+            let unsent = get_nut18_resend(&tx.metadata).is_some();
+            pending.push(PendingTransaction {
+                tx,
+                pending_ys,
+                unsent,
+            });
+        }
+        Ok(pending)
+    }
+
+    // Sends a NUT-18 payment whose transport failed again, otherwise refreshes the transaction
+    // Returns whether the payment got through or the transaction has been updated
+    pub async fn retry_pending(
+        &self,
+        tx_id: TransactionId,
+        nostr_cl: &nostr_sdk::Client,
+        http_cl: &reqwest::Client,
+    ) -> Result<bool> {
+        let tx = self.tx_repo.load_tx(tx_id).await?;
+        if !util::tx_can_be_refreshed(&tx) {
+            return Ok(false);
+        }
+        let Some(resend) = get_nut18_resend(&tx.metadata) else {
+            return self.refresh_tx(tx_id).await;
+        };
+        tracing::debug!("Resend NUT-18 payment {tx_id}");
+        let transports: Vec<cashu::Transport> = std::iter::once(resend.transport)
+            .chain(resend.fallbacks)
+            .collect();
+        let event_id =
+            util::send_nut18_payload_with_fallback(nostr_cl, http_cl, &transports, &resend.payload)
+                .await?;
+        if let Some(event_id) = event_id {
+            self.tx_repo
+                .update_metadata(tx_id, String::from("nostr::event_id"), event_id)
+                .await?;
+        }
+        self.tx_repo
+            .update_metadata(
+                tx_id,
+                String::from(NUT18_RESEND_METADATA_KEY),
+                String::new(),
+            )
+            .await?;
+        Ok(true)
+    }
+
     pub async fn recover_pending_stale_proofs(
         &self,
         pending_txs_ys: &[cashu::PublicKey],
@@ -331,10 +983,56 @@ impl Wallet {
                 self.tx_repo.update_fee(tx_id, fee).await?;
             }
         }
+        self.forget_sent_token(&tx).await?;
 
         Ok(amount)
     }
 
+    // Reclaims every pending outgoing transaction of the wallet unit
+    // a transaction failing to reclaim is logged and left pending
+    pub async fn reclaim_funds(&self) -> Result<WalletBalance> {
+        let mut txs = self.tx_repo.list_txs().await?;
+        txs.retain(|tx| util::tx_can_be_refreshed(tx) && tx.unit == self.debit.unit());
+        for tx in txs {
+            let tx_id = tx.id();
+            match self.reclaim_tx(tx_id).await {
+                Ok(amount) => tracing::debug!("reclaimed {amount} from {tx_id}"),
+                // settled when refreshed, nothing left to reclaim
+                Err(Error::TransactionCantBeReclaimed(_)) => {}
+                Err(e) => tracing::warn!("reclaim of {tx_id} failed: {e}"),
+            }
+        }
+        <Self as api::WalletApi>::balance(self).await
+    }
+
+    // Drops the proofs held by the pocket or already recorded in an incoming transaction
+    // fails with AlreadyReceived if none is left
+    async fn drop_known_proofs(&self, proofs: Vec<cashu::Proof>) -> Result<Vec<cashu::Proof>> {
+        let ys = proofs.ys()?;
+        let (known, received) = futures::join!(
+            self.debit.contains(&ys),
+            self.tx_repo.find_incoming(ys.clone())
+        );
+        let received = received?;
+        let mut known: HashSet<cashu::PublicKey> = known?.into_iter().collect();
+        known.extend(received.keys().copied());
+        if known.is_empty() {
+            return Ok(proofs);
+        }
+        if ys.iter().all(|y| known.contains(y)) {
+            // proofs only held by the pocket, e.g. a token sent to itself, have no incoming one
+            let prior_tx = ys.iter().find_map(|y| received.get(y).cloned());
+            return Err(Error::AlreadyReceived(prior_tx));
+        }
+        tracing::warn!("dropping {} already received proofs", known.len());
+        Ok(proofs
+            .into_iter()
+            .zip(ys)
+            .filter(|(_, y)| !known.contains(y))
+            .map(|(proof, _)| proof)
+            .collect())
+    }
+
     async fn _receive_proofs(
         &self,
         local_alpha_keysets_info: &[KeySetInfo],
@@ -345,12 +1043,16 @@ impl Wallet {
         tstamp: u64,
         memo: Option<String>,
         metadata: HashMap<String, String>,
-    ) -> Result<TransactionId> {
+    ) -> Result<ReceiveResult> {
         if unit != self.debit.unit() {
             return Err(Error::InvalidCurrencyUnit(unit.to_string()));
         }
-        let mut proofs = proofs;
-        if mint != self.client.mint_url() {
+        let proofs = drop_dust_proofs(proofs);
+        if proofs.is_empty() {
+            return Err(Error::EmptyToken(String::from("only zero-amount proofs")));
+        }
+        let mut proofs = self.drop_known_proofs(proofs).await?;
+        if !util::same_mint(&mint, &self.client.mint_url()) {
             if let Some((clowder_path, _)) = intermint_infos {
                 let alpha_id = clowder_path.mints[0].node_id;
                 let alpha_client = (self.client_factory)(mint.clone());
@@ -418,32 +1120,55 @@ impl Wallet {
             };
         }
 
+        let kids: Vec<cashu::Id> = proofs.iter().map(|p| p.keyset_id).collect();
+        let refreshed_infos;
+        let keysets_info = if kids
+            .iter()
+            .all(|kid| local_alpha_keysets_info.iter().any(|info| info.id == *kid))
+        {
+            local_alpha_keysets_info
+        } else {
+            refreshed_infos = self.keyset_infos_covering(&kids).await?;
+            &refreshed_infos
+        };
         let received_amount = proofs.total_amount()?;
         let (stored_amount, ys) = self
-            .debit
-            .receive_proofs(
-                self.client.clone(),
-                local_alpha_keysets_info,
-                proofs,
-                self.swap_config(),
-            )
+            .retry_on_rotation(keysets_info, |infos| {
+                let proofs = proofs.clone();
+                async move {
+                    self.debit
+                        .receive_proofs(self.client.clone(), &infos, proofs, self.swap_config())
+                        .await
+                }
+            })
             .await?;
+        self.emit(WalletEvent::ProofsReceived {
+            amount: stored_amount,
+            ys: ys.clone(),
+        });
+        let fee = received_amount
+            .checked_sub(stored_amount)
+            .expect("fee cannot be negative");
         let tx = Transaction {
             mint_url: self.client.mint_url(),
             direction: TransactionDirection::Incoming,
-            fee: received_amount
-                .checked_sub(stored_amount)
-                .expect("fee cannot be negative"),
+            fee,
             amount: received_amount,
             memo,
             metadata,
             timestamp: tstamp,
-            unit,
+            unit: unit.clone(),
             ys,
             quote_id: None,
         };
-        let txid = self.tx_repo.store_tx(tx).await?;
-        Ok(txid)
+        let tx_id = self.store_tx(tx).await?;
+        Ok(ReceiveResult {
+            tx_id,
+            received: received_amount,
+            stored: stored_amount,
+            fee,
+            unit,
+        })
     }
 
     async fn offline_exchange(
@@ -584,17 +1309,84 @@ impl Wallet {
         Ok(beta_proofs)
     }
 
-    pub async fn receive_token(&self, token: Token, tstamp: u64) -> Result<TransactionId> {
-        let token_teaser = token.to_string().chars().take(20).collect::<String>();
-        let (intermint_infos, keysets_info) = self
-            .get_clowder_path_and_keysets_info(token.mint_url())
-            .await?;
-
-        let proofs = if token.mint_url() == self.client.mint_url() {
-            token.proofs(&keysets_info)?
-        } else if let Some((_, ref intermint_alpha_infos)) = intermint_infos {
-            token.proofs(intermint_alpha_infos)?
-        } else {
+    // Pays a prepared token payment from proofs matching the amount exactly, without the mint
+    // the preparation is kept if a swap or a lock would be needed, so it can still be paid online
+    pub async fn send_offline(
+        &self,
+        p_id: Uuid,
+        memo: Option<String>,
+        now: u64,
+    ) -> Result<(TransactionId, Token)> {
+        let mut current = self.current_payment.lock().await;
+        let p_ref = current.get(&p_id).ok_or(Error::NoPrepareRef(p_id))?;
+        if !matches!(p_ref.ptype, WalletPaymentType::Token) || p_ref.conditions.is_some() {
+            return Err(Error::OfflineSendNotPossible);
+        }
+        let proofs = self.debit.send_proofs_offline(p_id).await?;
+        let p_ref = current.remove(&p_id).expect("checked above");
+        drop(current);
+
+        let memo = memo.or(p_ref.memo);
+        let (ys, proofs): (Vec<cashu::PublicKey>, Vec<cashu::Proof>) = proofs.into_iter().unzip();
+        let amount = proofs.total_amount()?;
+        let token = Token::new_cashu(
+            self.client.mint_url(),
+            proofs,
+            memo.clone(),
+            self.debit.unit(),
+        );
+        let mut metadata = HashMap::default();
+        metadata.insert(
+            PAYMENT_TYPE_METADATA_KEY.to_owned(),
+            PaymentType::Token.to_string(),
+        );
+        metadata.insert(
+            TRANSACTION_STATUS_METADATA_KEY.to_owned(),
+            TransactionStatus::Pending.to_string(),
+        );
+        if p_ref.scheme == SecretScheme::Random {
+            metadata.insert(
+                SECRET_SCHEME_METADATA_KEY.to_owned(),
+                SecretScheme::Random.to_string(),
+            );
+        }
+        self.keep_sent_token(&mut metadata, &token);
+        let tx = Transaction {
+            mint_url: self.client.mint_url(),
+            fee: p_ref.fees,
+            direction: TransactionDirection::Outgoing,
+            memo,
+            timestamp: now,
+            unit: p_ref.unit,
+            ys,
+            amount,
+            metadata,
+            quote_id: None,
+        };
+        let tx_id = self.store_tx(tx).await?;
+        Ok((tx_id, token))
+    }
+
+    pub async fn receive_token(&self, token: Token, tstamp: u64) -> Result<ReceiveResult> {
+        let token_teaser = teaser(&token.to_string());
+        let (intermint_infos, mut keysets_info) = self
+            .get_clowder_path_and_keysets_info(token.mint_url())
+            .await?;
+
+        let proofs = if util::same_mint(&token.mint_url(), &self.client.mint_url()) {
+            match token.proofs(&keysets_info) {
+                Ok(proofs) => proofs,
+                Err(e) => {
+                    // most likely a keyset the mint added after we cached ours
+                    tracing::debug!("token proofs failed with cached keysets, refreshing: {e}");
+                    self.invalidate_keysets().await;
+                    keysets_info = self.get_wallet_mint_keyset_infos().await?;
+                    token.proofs(&keysets_info)?
+                }
+            }
+        } else if let Some((_, ref intermint_alpha_infos)) = intermint_infos {
+            token.proofs(intermint_alpha_infos)?
+        } else {
             // different mint, but no clowder-path set
             return Err(Error::InterMintButNoClowderPath);
         };
@@ -613,7 +1405,7 @@ impl Wallet {
             TransactionStatus::Settled.to_string(),
         );
 
-        let tx_id = if token.unit().is_some() && token.unit() == Some(self.debit.unit()) {
+        let result = if token.unit().is_some() && token.unit() == Some(self.debit.unit()) {
             tracing::debug!("import debit token");
 
             self._receive_proofs(
@@ -630,7 +1422,70 @@ impl Wallet {
         } else {
             return Err(Error::InvalidToken(token_teaser));
         };
-        Ok(tx_id)
+        Ok(result)
+    }
+
+    // multisig locked proofs can't be swapped by us alone, so they are kept as they are
+    // with our signature, until the other parties signed them off-band
+    pub async fn receive_multisig_token(
+        &self,
+        token: Token,
+        signing_key: &cashu::SecretKey,
+        tstamp: u64,
+    ) -> Result<(TransactionId, Amount, Vec<cashu::PublicKey>)> {
+        let token_teaser = teaser(&token.to_string());
+        if !util::same_mint(&token.mint_url(), &self.client.mint_url()) {
+            return Err(Error::InterMint);
+        }
+        if token.unit() != Some(self.debit.unit()) {
+            return Err(Error::InvalidToken(token_teaser));
+        }
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+        let proofs = token.proofs(&keysets_info)?;
+        if proofs.is_empty() {
+            return Err(Error::EmptyToken(token_teaser));
+        }
+        let (amount, ys) = self
+            .debit
+            .receive_locked_proofs(self.client.clone(), &keysets_info, proofs, signing_key)
+            .await?;
+
+        let mut metadata = HashMap::default();
+        metadata.insert(
+            PAYMENT_TYPE_METADATA_KEY.to_owned(),
+            PaymentType::Token.to_string(),
+        );
+        metadata.insert(
+            TRANSACTION_STATUS_METADATA_KEY.to_owned(),
+            TransactionStatus::Settled.to_string(),
+        );
+        let tx = Transaction {
+            mint_url: self.client.mint_url(),
+            direction: TransactionDirection::Incoming,
+            fee: Amount::ZERO,
+            amount,
+            memo: token.memo().clone(),
+            metadata,
+            timestamp: tstamp,
+            unit: self.debit.unit(),
+            ys: ys.clone(),
+            quote_id: None,
+        };
+        let tx_id = self.store_tx(tx).await?;
+        Ok((tx_id, amount, ys))
+    }
+
+    pub async fn partial_sign_proof(&self, y: cashu::PublicKey) -> Result<cashu::Witness> {
+        self.debit.partial_sign_proof(y).await
+    }
+
+    // once signed by enough parties, the locked proof is selected for sends like any other
+    pub async fn combine_partial_signatures(
+        &self,
+        y: cashu::PublicKey,
+        witnesses: Vec<cashu::Witness>,
+    ) -> Result<bool> {
+        self.debit.combine_partial_signatures(y, witnesses).await
     }
 
     async fn pay_nut18(
@@ -638,10 +1493,13 @@ impl Wallet {
         proofs: Vec<cashu::Proof>,
         nostr_cl: &nostr_sdk::Client,
         http_cl: &reqwest::Client,
-        transport: cashu::Transport,
+        transports: Vec<cashu::Transport>,
         p_id: Option<String>,
         mut partial_tx: Transaction,
     ) -> Result<TransactionId> {
+        let Some((transport, fallbacks)) = transports.split_first() else {
+            return Err(Error::NoTransport);
+        };
         let payload = cashu::PaymentRequestPayload {
             id: p_id,
             memo: partial_tx.memo.clone(),
@@ -649,29 +1507,32 @@ impl Wallet {
             mint: self.client.mint_url(),
             proofs,
         };
-        match transport._type {
-            cashu::TransportType::HttpPost => {
-                let url = reqwest::Url::from_str(&transport.target)?;
-                let response = http_cl.post(url).json(&payload).send().await?;
-                response.error_for_status()?;
+        match util::send_nut18_payload_with_fallback(nostr_cl, http_cl, &transports, &payload).await
+        {
+            Ok(event_id) => {
+                if let Some(event_id) = event_id {
+                    partial_tx
+                        .metadata
+                        .insert(String::from("nostr::event_id"), event_id);
+                }
             }
-            cashu::TransportType::Nostr => {
-                let payload = serde_json::to_string(&payload)?;
-                let receiver = Nip19Profile::from_bech32(&transport.target)?;
-                let output = nostr_cl
-                    .send_private_msg_to(
-                        receiver.relays,
-                        receiver.public_key,
-                        payload,
-                        std::iter::empty(),
-                    )
-                    .await?;
-                partial_tx
-                    .metadata
-                    .insert(String::from("nostr::event_id"), output.id().to_string());
+            Err(e) => {
+                // the proofs are already swapped, keep the payment to send it again
+                let resend = Nut18Resend {
+                    transport: transport.clone(),
+                    fallbacks: fallbacks.to_vec(),
+                    payload,
+                };
+                partial_tx.metadata.insert(
+                    String::from(NUT18_RESEND_METADATA_KEY),
+                    serde_json::to_string(&resend)?,
+                );
+                let txid = self.store_tx(partial_tx).await?;
+                tracing::error!("NUT-18 transport failed, {txid} kept to retry: {e}");
+                return Err(e);
             }
         }
-        let txid = self.tx_repo.store_tx(partial_tx).await?;
+        let txid = self.store_tx(partial_tx).await?;
         Ok(txid)
     }
 
@@ -681,6 +1542,8 @@ impl Wallet {
         signer: Arc<dyn NostrSigner>,
         payment_id: Uuid,
         expected: Amount,
+        // the mints the payment request listed
+        mints: &[MintUrl],
     ) -> Result<Option<TransactionId>> {
         if event.kind != nostr_sdk::Kind::GiftWrap {
             tracing::debug!("handle event, but no GiftWrap - {}", event.kind);
@@ -715,6 +1578,21 @@ impl Wallet {
             tracing::debug!("handle event, payment id doesn't match");
             return Ok(None);
         }
+        if !mints
+            .iter()
+            .any(|mint| util::same_mint(mint, &payload.mint))
+        {
+            tracing::warn!("Received proofs from {}, not asked for", payload.mint);
+            return Ok(None);
+        }
+        if payload.unit != self.debit.unit() {
+            tracing::warn!(
+                "Received proofs in {}, expected {}",
+                payload.unit,
+                self.debit.unit()
+            );
+            return Ok(None);
+        }
 
         let amount = payload.proofs.total_amount()?;
         if amount < expected {
@@ -777,24 +1655,45 @@ impl Wallet {
 
         Ok(res)
     }
+
+    // the unspent amount under each credit keyset, the one expiring first at the front
+    pub async fn credit_balance_by_keyset(&self) -> Result<Vec<WalletDetailedBalanceEntry>> {
+        let keysets_info = self.get_wallet_mint_keyset_infos().await?;
+
+        let detailed_balance = self.debit.dev_mode_detailed_balance(&keysets_info).await?;
+        let mut res: Vec<WalletDetailedBalanceEntry> = detailed_balance
+            .into_iter()
+            .filter(|(_, (final_expiry, _))| crate::pocket::is_credit_keyset(*final_expiry))
+            .map(|(kid, (final_expiry, amount))| WalletDetailedBalanceEntry {
+                kid,
+                final_expiry,
+                amount,
+            })
+            .collect();
+        res.sort_by_key(|e| e.final_expiry);
+        Ok(res)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use bcr_common::wire::clowder as wire_clowder;
+    use bcr_common::{core_tests, wire::clowder as wire_clowder};
     use bcr_wallet_core::types::{MintSummary, PaymentResultCallback};
     use bcr_wallet_persistence::{
         MockTransactionRepository,
-        test_utils::tests::{test_pub_key, valid_payment_address_testnet},
+        test_utils::tests::{in_memory_wallet_dbs, test_pub_key, valid_payment_address_testnet},
     };
     use nostr::nips::nip19::ToBech32;
     use tokio_util::sync::CancellationToken;
 
     use super::*;
     use crate::{
-        external::{mint::HttpClientExt, test_utils::tests::MockMintConnector},
+        external::{
+            mint::HttpClientExt,
+            test_utils::tests::{FakeMint, MockMintConnector},
+        },
         pocket::{PocketBalance, test_utils::tests::MockDebitPocket},
-        wallet::{api::WalletApi, types::WalletPaymentType},
+        wallet::api::WalletApi,
     };
 
     struct MockWalletCtx {
@@ -825,11 +1724,17 @@ mod tests {
             name: "wallet-1".to_owned(),
             id: "w-1".to_owned(),
             pub_key: test_pub_key(),
-            current_payment: Mutex::new(None),
+            current_payment: Mutex::new(HashMap::new()),
             current_payment_request: Mutex::new(None),
             clowder_id: test_pub_key(),
             client_factory: Box::new(|url| Arc::new(HttpClientExt::new(url))),
             swap_expiry: chrono::TimeDelta::seconds(60),
+            events: broadcast::channel(EVENTS_CAPACITY).0,
+            keysets_ttl: chrono::TimeDelta::seconds(60),
+            keysets_cache: Mutex::new(None),
+            mint_info: Mutex::new(None),
+            coin_selection: CoinSelectionStrategy::default(),
+            keep_sent_tokens: false,
         }
     }
 
@@ -975,9 +1880,164 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_check_nut18_request_prefers_http_with_nostr_fallback() {
+        let mut ctx = wallet_ctx();
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        let wlt = wallet(ctx);
+
+        let req = cdk18::PaymentRequest {
+            payment_id: None,
+            amount: Some(cashu::Amount::from(21)),
+            mints: None,
+            unit: Some(CurrencyUnit::Sat),
+            single_use: None,
+            description: None,
+            nut10: None,
+            transports: vec![
+                cdk18::Transport {
+                    _type: cdk18::TransportType::Nostr,
+                    target: String::from("nprofile1-receiver"),
+                    tags: None,
+                },
+                cdk18::Transport {
+                    _type: cdk18::TransportType::HttpPost,
+                    target: String::from("https://merchant.example/pay"),
+                    tags: None,
+                },
+            ],
+        };
+
+        let (_, _, transports, _) = wlt.check_nut18_request(&req).await.unwrap();
+        assert_eq!(transports.len(), 2);
+        assert!(matches!(
+            transports[0]._type,
+            cdk18::TransportType::HttpPost
+        ));
+        assert!(matches!(transports[1]._type, cdk18::TransportType::Nostr));
+    }
+
+    #[tokio::test]
+    async fn test_check_nut18_request_matches_listed_mint_loosely() {
+        let mut ctx = wallet_ctx();
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.client
+            .expect_mint_url()
+            .returning(|| cashu::MintUrl::from_str("https://mint.example").unwrap());
+        let wlt = wallet(ctx);
+
+        let mut req = cdk18::PaymentRequest {
+            payment_id: None,
+            amount: Some(cashu::Amount::from(21)),
+            mints: Some(vec![
+                cashu::MintUrl::from_str("https://Mint.Example/").unwrap(),
+            ]),
+            unit: Some(CurrencyUnit::Sat),
+            single_use: None,
+            description: None,
+            nut10: None,
+            transports: vec![cdk18::Transport {
+                _type: cdk18::TransportType::HttpPost,
+                target: String::from("https://merchant.example/pay"),
+                tags: None,
+            }],
+        };
+        let (amount, ..) = wlt.check_nut18_request(&req).await.unwrap();
+        assert_eq!(amount, cashu::Amount::from(21));
+
+        req.mints = Some(vec![
+            cashu::MintUrl::from_str("https://other.example").unwrap(),
+        ]);
+        let err = wlt.check_nut18_request(&req).await.unwrap_err();
+        assert!(matches!(err, Error::InterMint));
+    }
+
+    #[tokio::test]
+    async fn test_check_nut18_request_with_p2pk_conditions() {
+        let mut ctx = wallet_ctx();
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        let wlt = wallet(ctx);
+
+        let pk = cashu::SecretKey::generate().public_key();
+        let req = cdk18::PaymentRequest {
+            payment_id: Some(String::from("p-1")),
+            amount: Some(cashu::Amount::from(21)),
+            mints: None,
+            unit: Some(CurrencyUnit::Sat),
+            single_use: Some(true),
+            description: None,
+            nut10: Some(cdk18::Nut10SecretRequest::new(
+                cashu::nut10::Kind::P2PK,
+                pk.to_string(),
+                None::<Vec<Vec<String>>>,
+            )),
+            transports: vec![cdk18::Transport {
+                _type: cdk18::TransportType::HttpPost,
+                target: String::from("https://merchant.example/pay"),
+                tags: None,
+            }],
+        };
+
+        let (amount, unit, transports, conditions) = wlt.check_nut18_request(&req).await.unwrap();
+        assert_eq!(amount, cashu::Amount::from(21));
+        assert_eq!(unit, CurrencyUnit::Sat);
+        assert_eq!(transports.len(), 1);
+        assert!(matches!(
+            transports[0]._type,
+            cdk18::TransportType::HttpPost
+        ));
+        match conditions {
+            Some(cashu::SpendingConditions::P2PKConditions { data, .. }) => assert_eq!(data, pk),
+            other => panic!("expected P2PK conditions, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_inspect_payment_request_lists_all_transports() {
+        let wlt = wallet(wallet_ctx());
+        let req = cdk18::PaymentRequest {
+            payment_id: Some(String::from("p-1")),
+            amount: Some(cashu::Amount::from(21)),
+            mints: Some(vec![
+                cashu::MintUrl::from_str("https://mint.example").unwrap(),
+            ]),
+            unit: Some(CurrencyUnit::Sat),
+            single_use: Some(true),
+            description: None,
+            nut10: None,
+            transports: vec![
+                cdk18::Transport {
+                    _type: cdk18::TransportType::HttpPost,
+                    target: String::from("https://merchant.example/pay"),
+                    tags: None,
+                },
+                cdk18::Transport {
+                    _type: cdk18::TransportType::Nostr,
+                    target: nostr::PublicKey::from(test_pub_key().x_only_public_key().0)
+                        .to_bech32()
+                        .unwrap(),
+                    tags: None,
+                },
+            ],
+        };
+
+        let info = wlt.inspect_payment_request(&req.to_string()).unwrap();
+        assert_eq!(info.amount, Some(cashu::Amount::from(21)));
+        assert_eq!(info.unit, Some(CurrencyUnit::Sat));
+        assert_eq!(
+            info.mints,
+            Some(vec![
+                cashu::MintUrl::from_str("https://mint.example").unwrap()
+            ])
+        );
+        assert_eq!(info.transports, vec!["post", "nostr"]);
+        assert!(!info.has_spending_conditions);
+    }
+
     #[tokio::test]
     async fn test_prepare_payment_request_sets_current_request() {
         let mut ctx = wallet_ctx();
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
 
         ctx.client
             .expect_mint_url()
@@ -1012,6 +2072,106 @@ mod tests {
         assert_eq!(req.single_use, Some(true));
     }
 
+    #[tokio::test]
+    async fn test_prepared_payment_request_is_accepted_by_check_nut18_request() {
+        let mut ctx = wallet_ctx();
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.client
+            .expect_mint_url()
+            .returning(|| cashu::MintUrl::from_str("https://mint.example").unwrap());
+        let wlt = wallet(ctx);
+        let nostr_transport = cdk18::Transport {
+            _type: cdk18::TransportType::Nostr,
+            target: nostr::PublicKey::from(test_pub_key().x_only_public_key().0)
+                .to_bech32()
+                .unwrap(),
+            tags: Some(vec![vec![String::from("n"), String::from("17")]]),
+        };
+
+        let req = wlt
+            .prepare_payment_request(
+                cashu::Amount::from(123),
+                CurrencyUnit::Sat,
+                None,
+                nostr_transport,
+            )
+            .await
+            .unwrap();
+        let parsed = cdk18::PaymentRequest::from_str(&req.to_string()).unwrap();
+        assert_eq!(parsed.payment_id, req.payment_id);
+
+        let (amount, unit, transports, conditions) =
+            wlt.check_nut18_request(&parsed).await.unwrap();
+        assert_eq!(amount, cashu::Amount::from(123));
+        assert_eq!(unit, CurrencyUnit::Sat);
+        assert_eq!(transports.len(), 1);
+        assert!(matches!(transports[0]._type, cdk18::TransportType::Nostr));
+        assert!(conditions.is_none());
+
+        let err = wlt
+            .prepare_payment_request(
+                cashu::Amount::from(123),
+                CurrencyUnit::Usd,
+                None,
+                parsed.transports[0].clone(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidCurrencyUnit(_)));
+    }
+
+    #[tokio::test]
+    async fn test_handle_event_skips_payload_of_other_mint_or_unit() {
+        let mut ctx = wallet_ctx();
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        let wlt = wallet(ctx);
+
+        let receiver = nostr_sdk::Keys::generate();
+        let sender = nostr_sdk::Keys::generate();
+        let signer: Arc<dyn NostrSigner> = Arc::new(receiver.clone());
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        let mint = cashu::MintUrl::from_str("https://mint.example").unwrap();
+        let p_id = Uuid::new_v4();
+
+        let payloads = [
+            (
+                cashu::MintUrl::from_str("https://other.example").unwrap(),
+                CurrencyUnit::Sat,
+            ),
+            (mint.clone(), CurrencyUnit::Usd),
+        ];
+        for (payload_mint, unit) in payloads {
+            let payload = cashu::PaymentRequestPayload {
+                id: Some(p_id.to_string()),
+                memo: None,
+                mint: payload_mint,
+                unit,
+                proofs: proofs.clone(),
+            };
+            let event = nostr::EventBuilder::private_msg(
+                &sender,
+                receiver.public_key(),
+                serde_json::to_string(&payload).unwrap(),
+                [],
+            )
+            .await
+            .unwrap();
+            // the pocket mock panics if the proofs get received
+            let received = wlt
+                .handle_event(
+                    event,
+                    signer.clone(),
+                    p_id,
+                    Amount::from(8u64),
+                    std::slice::from_ref(&mint),
+                )
+                .await
+                .unwrap();
+            assert!(received.is_none());
+        }
+    }
+
     #[tokio::test]
     async fn test_check_received_payment_errors_if_no_current_request() {
         let ctx = wallet_ctx();
@@ -1067,97 +2227,451 @@ mod tests {
         let wlt = wallet(ctx);
 
         let res = wlt.balance().await.expect("balance works");
-        assert_eq!(res.debit, Amount::ZERO);
-        assert_eq!(res.credit, Amount::ZERO);
+        assert_eq!(res.debit_available, Amount::ZERO);
+        assert_eq!(res.credit_available, Amount::ZERO);
         assert_eq!(res.total, Amount::ZERO);
     }
 
     #[tokio::test]
-    async fn test_list_tx_ids() {
+    async fn test_credit_balance_by_keyset() {
         let mut ctx = wallet_ctx();
-        ctx.tx_repo
-            .expect_list_tx_ids()
+        let debit_kid = core_tests::generate_random_ecash_keyset().0.id;
+        let expired_kid = core_tests::generate_random_ecash_keyset().0.id;
+        let soon_kid = core_tests::generate_random_ecash_keyset().0.id;
+        let later_kid = core_tests::generate_random_ecash_keyset().0.id;
+        let now = chrono::Utc::now();
+        let yesterday = (now - chrono::TimeDelta::days(1)).timestamp() as u64;
+        let tomorrow = (now + chrono::TimeDelta::days(1)).timestamp() as u64;
+        let next_week = (now + chrono::TimeDelta::days(7)).timestamp() as u64;
+        ctx.client
+            .expect_get_mint_keysets()
             .times(1)
             .returning(|| Ok(vec![]));
+        ctx.debit
+            .expect_dev_mode_detailed_balance()
+            .times(1)
+            .returning(move |_| {
+                Ok(HashMap::from([
+                    (debit_kid, (None, Amount::from(1u64))),
+                    (expired_kid, (Some(yesterday), Amount::from(2u64))),
+                    (later_kid, (Some(next_week), Amount::from(4u64))),
+                    (soon_kid, (Some(tomorrow), Amount::from(8u64))),
+                ]))
+            });
         let wlt = wallet(ctx);
 
-        let res = wlt.list_tx_ids().await.unwrap();
-        assert!(res.is_empty());
+        let res = wlt.credit_balance_by_keyset().await.unwrap();
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].kid, soon_kid);
+        assert_eq!(res[0].final_expiry, Some(tomorrow));
+        assert_eq!(res[0].amount, Amount::from(8u64));
+        assert_eq!(res[1].kid, later_kid);
+        assert_eq!(res[1].amount, Amount::from(4u64));
     }
 
     #[tokio::test]
-    async fn test_list_txs() {
+    async fn test_wallet_mint_keyset_infos_dedups_duplicate_ids() {
         let mut ctx = wallet_ctx();
-        ctx.tx_repo
-            .expect_list_txs()
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let mut active = KeySetInfo::from(info);
+        active.active = true;
+        let mut inactive = active.clone();
+        inactive.active = false;
+        let infos = vec![inactive, active.clone()];
+        ctx.client
+            .expect_get_mint_keysets()
             .times(1)
-            .returning(|| Ok(vec![]));
+            .returning(move || Ok(infos.clone()));
         let wlt = wallet(ctx);
 
-        let res = wlt.list_txs().await.unwrap();
-        assert!(res.is_empty());
+        let res = wlt.get_wallet_mint_keyset_infos().await.unwrap();
+        assert_eq!(res, vec![active]);
     }
 
     #[tokio::test]
-    async fn test_cleanup_local_proofs_calls_both_pockets() {
+    async fn test_wallet_mint_keyset_infos_are_cached() {
         let mut ctx = wallet_ctx();
-
-        ctx.debit
-            .expect_cleanup_local_proofs()
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let infos = vec![KeySetInfo::from(info)];
+        let returned = infos.clone();
+        ctx.client
+            .expect_get_mint_keysets()
             .times(1)
-            .returning(|_client| Ok(vec![]));
-
+            .returning(move || Ok(returned.clone()));
         let wlt = wallet(ctx);
-        wlt.cleanup_local_proofs().await.unwrap();
+
+        assert_eq!(wlt.get_wallet_mint_keyset_infos().await.unwrap(), infos);
+        assert_eq!(wlt.get_wallet_mint_keyset_infos().await.unwrap(), infos);
     }
 
     #[tokio::test]
-    async fn test_is_wallet_mint_offline_majority_true() {
-        let ctx = wallet_ctx();
-        let mut wlt = wallet(ctx);
-
-        let b1 = cashu::MintUrl::from_str("https://b1.example").unwrap();
-        let b2 = cashu::MintUrl::from_str("https://b2.example").unwrap();
-        let b3 = cashu::MintUrl::from_str("https://b3.example").unwrap();
-
-        let mut m1 = MockMintConnector::new();
-        let mut m2 = MockMintConnector::new();
-        let mut m3 = MockMintConnector::new();
-
-        m1.expect_get_alpha_status().returning(|_pk| {
-            Ok(wire_clowder::AlphaStateResponse {
-                state: wire_clowder::SimpleAlphaState::Offline(0),
-            })
-        });
-        m2.expect_get_alpha_status().returning(|_pk| {
-            Ok(wire_clowder::AlphaStateResponse {
-                state: wire_clowder::SimpleAlphaState::Offline(0),
-            })
-        });
-        m3.expect_get_alpha_status().returning(|_pk| {
-            Ok(wire_clowder::AlphaStateResponse {
-                state: wire_clowder::SimpleAlphaState::Online(0),
-            })
-        });
-
-        wlt = wallet_with_betas(
-            wlt,
-            vec![(b1, Arc::new(m1)), (b2, Arc::new(m2)), (b3, Arc::new(m3))],
-        );
+    async fn test_mint_capabilities_fetch_the_info_once() {
+        let mut ctx = wallet_ctx();
+        let mut info = cashu::MintInfo::default();
+        info.nuts.nut05.disabled = true;
+        info.nuts.nut07.supported = true;
+        info.nuts.nut12.supported = true;
+        ctx.client
+            .expect_get_mint_info()
+            .times(1)
+            .returning(move || Ok(info.clone()));
+        let wlt = wallet(ctx);
 
-        let res = wlt.is_wallet_mint_offline().await.unwrap();
-        assert!(res);
+        let caps = wlt.mint_capabilities().await.unwrap();
+        assert!(caps.mint_enabled);
+        assert!(!caps.melt_enabled);
+        assert!(caps.token_state_check);
+        assert!(caps.dleq);
+        assert!(!caps.htlc);
+        assert_eq!(wlt.mint_capabilities().await.unwrap(), caps);
     }
 
     #[tokio::test]
-    async fn test_is_wallet_mint_rabid_majority_false() {
-        let ctx = wallet_ctx();
+    async fn test_wallet_mint_keyset_infos_expire() {
+        let mut ctx = wallet_ctx();
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let infos = vec![KeySetInfo::from(info)];
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(2)
+            .returning(move || Ok(infos.clone()));
         let mut wlt = wallet(ctx);
+        wlt.keysets_ttl = chrono::TimeDelta::zero();
 
-        let b1 = cashu::MintUrl::from_str("https://b1.example").unwrap();
-        let b2 = cashu::MintUrl::from_str("https://b2.example").unwrap();
+        wlt.get_wallet_mint_keyset_infos().await.unwrap();
+        wlt.get_wallet_mint_keyset_infos().await.unwrap();
+    }
 
-        let mut m1 = MockMintConnector::new();
+    #[tokio::test]
+    async fn test_keyset_infos_covering_refreshes_on_new_keyset() {
+        let mut ctx = wallet_ctx();
+        let (old, _) = core_tests::generate_random_ecash_keyset();
+        let (new, _) = core_tests::generate_random_ecash_keyset();
+        let old = KeySetInfo::from(old);
+        let new = KeySetInfo::from(new);
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let (first, second) = (vec![old.clone()], vec![old.clone(), new.clone()]);
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(2)
+            .returning(
+                move || match calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                    0 => Ok(first.clone()),
+                    _ => Ok(second.clone()),
+                },
+            );
+        let wlt = wallet(ctx);
+
+        let cached = wlt.keyset_infos_covering(&[old.id]).await.unwrap();
+        assert_eq!(cached, vec![old.clone()]);
+        let refreshed = wlt.keyset_infos_covering(&[new.id]).await.unwrap();
+        assert_eq!(refreshed, vec![old, new]);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_send_retries_once_after_keyset_rotation() {
+        let mut ctx = wallet_ctx();
+        let (old, _) = core_tests::generate_random_ecash_keyset();
+        let (new, _) = core_tests::generate_random_ecash_keyset();
+        let old = KeySetInfo::from(old);
+        let new = KeySetInfo::from(new);
+        let rotated = vec![new.clone()];
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(move || Ok(rotated.clone()));
+        let new_id = new.id;
+        ctx.debit
+            .expect_prepare_send()
+            .times(2)
+            .returning(move |amount, infos, _, _| {
+                if infos.iter().any(|info| info.id == new_id) {
+                    let mut summary = SendSummary::new();
+                    summary.amount = amount;
+                    Ok(summary)
+                } else {
+                    Err(Error::NoActiveKeyset)
+                }
+            });
+        let wlt = wallet(ctx);
+
+        let summary = wlt
+            .prepare_send_with_conditions(Amount::from(8u64), None, SecretScheme::default(), &[old])
+            .await
+            .expect("retried with the rotated keysets");
+        assert_eq!(summary.amount, Amount::from(8u64));
+    }
+
+    #[test]
+    fn test_is_rotation_error_maps_mint_keyset_codes() {
+        let mint_error = |code: u16| {
+            Error::Mint(bcr_common::client::mint::Error::Internal(format!(
+                r#"{{"code":{code},"detail":"keyset"}}"#
+            )))
+        };
+        assert!(is_rotation_error(&mint_error(12001)));
+        assert!(is_rotation_error(&mint_error(12002)));
+        assert!(!is_rotation_error(&mint_error(11001)));
+        assert!(is_rotation_error(&Error::Cdk(
+            cdk_common::Error::InactiveKeyset
+        )));
+        assert!(!is_rotation_error(&Error::Mint(
+            bcr_common::client::mint::Error::Internal(String::from("connection refused"))
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_send_retries_after_the_mint_rejects_an_inactive_keyset() {
+        let mut ctx = wallet_ctx();
+        let (old, _) = core_tests::generate_random_ecash_keyset();
+        let (new, _) = core_tests::generate_random_ecash_keyset();
+        let old = KeySetInfo::from(old);
+        let new = KeySetInfo::from(new);
+        let rotated = vec![new.clone()];
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(move || Ok(rotated.clone()));
+        let new_id = new.id;
+        ctx.debit
+            .expect_prepare_send()
+            .times(2)
+            .returning(move |amount, infos, _, _| {
+                if infos.iter().any(|info| info.id == new_id) {
+                    let mut summary = SendSummary::new();
+                    summary.amount = amount;
+                    Ok(summary)
+                } else {
+                    Err(Error::Mint(bcr_common::client::mint::Error::Internal(
+                        String::from(r#"{"code":12002,"detail":"Keyset is inactive"}"#),
+                    )))
+                }
+            });
+        let wlt = wallet(ctx);
+
+        let summary = wlt
+            .prepare_send_with_conditions(Amount::from(8u64), None, SecretScheme::default(), &[old])
+            .await
+            .expect("retried with the rotated keysets");
+        assert_eq!(summary.amount, Amount::from(8u64));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_send_gives_up_when_keysets_did_not_rotate() {
+        let mut ctx = wallet_ctx();
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let infos = vec![KeySetInfo::from(info)];
+        let returned = infos.clone();
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(move || Ok(returned.clone()));
+        ctx.debit
+            .expect_prepare_send()
+            .times(1)
+            .returning(|_, _, _, _| Err(Error::NoActiveKeyset));
+        let wlt = wallet(ctx);
+
+        let err = wlt
+            .prepare_send_with_conditions(Amount::from(8u64), None, SecretScheme::default(), &infos)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NoActiveKeyset));
+    }
+
+    #[tokio::test]
+    async fn test_estimate_send() {
+        let mut ctx = wallet_ctx();
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(|| Ok(vec![]));
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit
+            .expect_estimate_send()
+            .times(1)
+            .returning(|amount, _, _| {
+                let mut summary = SendSummary::new();
+                summary.amount = amount;
+                summary.swap_fees = Amount::from(1u64);
+                summary.needs_swap = true;
+                Ok(summary)
+            });
+        ctx.debit.expect_prepare_send().never();
+        let wlt = wallet(ctx);
+
+        let summary = wlt
+            .estimate_send(Amount::from(8u64), CurrencyUnit::Sat)
+            .await
+            .expect("estimate send works");
+        assert_eq!(summary.amount, Amount::from(8u64));
+        assert!(summary.needs_swap);
+
+        let err = wlt
+            .estimate_send(Amount::from(8u64), CurrencyUnit::Usd)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidCurrencyUnit(_)));
+    }
+
+    #[tokio::test]
+    async fn test_keyset_fees() {
+        let mut ctx = wallet_ctx();
+        let (cheap, _) = core_tests::generate_random_ecash_keyset();
+        let (pricey, _) = core_tests::generate_random_ecash_keyset();
+        let mut cheap = KeySetInfo::from(cheap);
+        cheap.input_fee_ppk = 0;
+        let mut pricey = KeySetInfo::from(pricey);
+        pricey.input_fee_ppk = 250;
+        let infos = vec![cheap.clone(), pricey.clone()];
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(move || Ok(infos.clone()));
+        let wlt = wallet(ctx);
+
+        let fees = wlt.keyset_fees().await.expect("keyset fees works");
+        assert_eq!(fees.len(), 2);
+        assert_eq!(fees.get(&cheap.id), Some(&0));
+        assert_eq!(fees.get(&pricey.id), Some(&250));
+    }
+
+    #[tokio::test]
+    async fn test_list_tx_ids() {
+        let mut ctx = wallet_ctx();
+        ctx.tx_repo
+            .expect_list_tx_ids()
+            .times(1)
+            .returning(|| Ok(vec![]));
+        let wlt = wallet(ctx);
+
+        let res = wlt.list_tx_ids().await.unwrap();
+        assert!(res.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_txs() {
+        let mut ctx = wallet_ctx();
+        ctx.tx_repo
+            .expect_list_txs()
+            .times(1)
+            .returning(|| Ok(vec![]));
+        let wlt = wallet(ctx);
+
+        let res = wlt.list_txs().await.unwrap();
+        assert!(res.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_txs_since() {
+        let mut ctx = wallet_ctx();
+        ctx.tx_repo
+            .expect_current_seq()
+            .times(1)
+            .returning(|| Ok(7));
+        ctx.tx_repo
+            .expect_list_since()
+            .times(1)
+            .withf(|seq| *seq == 5)
+            .returning(|_| Ok(TxChanges::default()));
+        let wlt = wallet(ctx);
+
+        let (changes, marker) = wlt.list_txs_since(5).await.unwrap();
+        assert!(changes.updated.is_empty());
+        assert!(changes.deleted.is_empty());
+        assert_eq!(marker, 7);
+    }
+
+    #[tokio::test]
+    async fn test_clean_local_db_prunes_counters_only_on_request() {
+        let mut ctx = wallet_ctx();
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let infos = vec![KeySetInfo::from(info)];
+        let expected = infos.clone();
+        ctx.debit
+            .expect_cleanup_local_proofs()
+            .times(2)
+            .returning(|_client| Ok(vec![cashu::SecretKey::generate().public_key()]));
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(move || Ok(infos.clone()));
+        ctx.debit
+            .expect_prune_counters()
+            .times(1)
+            .returning(move |infos| {
+                assert_eq!(infos, expected.as_slice());
+                Ok(2)
+            });
+        let wlt = wallet(ctx);
+
+        let cleanup = wlt.clean_local_db(false).await.unwrap();
+        assert_eq!(cleanup.proofs_removed, 1);
+        assert_eq!(cleanup.counters_removed, 0);
+        let cleanup = wlt.clean_local_db(true).await.unwrap();
+        assert_eq!(cleanup.counters_removed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_local_proofs_calls_both_pockets() {
+        let mut ctx = wallet_ctx();
+
+        ctx.debit
+            .expect_cleanup_local_proofs()
+            .times(1)
+            .returning(|_client| Ok(vec![]));
+
+        let wlt = wallet(ctx);
+        wlt.cleanup_local_proofs().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_is_wallet_mint_offline_majority_true() {
+        let ctx = wallet_ctx();
+        let mut wlt = wallet(ctx);
+
+        let b1 = cashu::MintUrl::from_str("https://b1.example").unwrap();
+        let b2 = cashu::MintUrl::from_str("https://b2.example").unwrap();
+        let b3 = cashu::MintUrl::from_str("https://b3.example").unwrap();
+
+        let mut m1 = MockMintConnector::new();
+        let mut m2 = MockMintConnector::new();
+        let mut m3 = MockMintConnector::new();
+
+        m1.expect_get_alpha_status().returning(|_pk| {
+            Ok(wire_clowder::AlphaStateResponse {
+                state: wire_clowder::SimpleAlphaState::Offline(0),
+            })
+        });
+        m2.expect_get_alpha_status().returning(|_pk| {
+            Ok(wire_clowder::AlphaStateResponse {
+                state: wire_clowder::SimpleAlphaState::Offline(0),
+            })
+        });
+        m3.expect_get_alpha_status().returning(|_pk| {
+            Ok(wire_clowder::AlphaStateResponse {
+                state: wire_clowder::SimpleAlphaState::Online(0),
+            })
+        });
+
+        wlt = wallet_with_betas(
+            wlt,
+            vec![(b1, Arc::new(m1)), (b2, Arc::new(m2)), (b3, Arc::new(m3))],
+        );
+
+        let res = wlt.is_wallet_mint_offline().await.unwrap();
+        assert!(res);
+    }
+
+    #[tokio::test]
+    async fn test_is_wallet_mint_rabid_majority_false() {
+        let ctx = wallet_ctx();
+        let mut wlt = wallet(ctx);
+
+        let b1 = cashu::MintUrl::from_str("https://b1.example").unwrap();
+        let b2 = cashu::MintUrl::from_str("https://b2.example").unwrap();
+
+        let mut m1 = MockMintConnector::new();
         let mut m2 = MockMintConnector::new();
 
         m1.expect_get_alpha_status().returning(|_pk| {
@@ -1291,44 +2805,732 @@ mod tests {
             .returning(|_tx| Ok(TransactionId::new(vec![])));
 
         let wlt = wallet(ctx);
-        *wlt.current_payment.lock().await = Some(PayReference {
-            request_id: pid,
-            unit: CurrencyUnit::Sat,
-            fees: cashu::Amount::ZERO,
-            ptype: WalletPaymentType::Token,
-            memo: Some("memo".to_string()),
-        });
+        wlt.current_payment.lock().await.insert(
+            pid,
+            PayReference {
+                request_id: pid,
+                unit: CurrencyUnit::Sat,
+                amount: cashu::Amount::ZERO,
+                fees: cashu::Amount::ZERO,
+                ptype: WalletPaymentType::Token,
+                memo: Some("memo".to_string()),
+                conditions: None,
+                scheme: SecretScheme::Deterministic,
+            },
+        );
 
         let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
         let http_cl = reqwest::Client::new();
 
-        let (_txid, token) = wlt.pay(pid, &nostr_cl, &http_cl, 123).await.unwrap();
+        let result = wlt.pay(pid, &nostr_cl, &http_cl, 123).await.unwrap();
 
-        assert!(token.is_some());
+        assert!(result.token.is_some());
     }
 
     #[tokio::test]
-    async fn test_mint_uses_debit() {
+    async fn test_receive_multisig_token_stores_incoming_tx() {
         let mut ctx = wallet_ctx();
 
+        let (info, keyset) = bcr_common::core_tests::generate_random_ecash_keyset();
+        let k_info = KeySetInfo::from(info);
+        let proofs =
+            bcr_common::core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+        let y = proofs[0].y().unwrap();
+        let mint_url = cashu::MintUrl::from_str("https://mint.example").unwrap();
+        let token = Token::new_cashu(
+            mint_url.clone(),
+            proofs,
+            Some(String::from("shared")),
+            CurrencyUnit::Sat,
+        );
+
         ctx.client
             .expect_get_mint_keysets()
             .times(1)
-            .returning(|| Ok(vec![]));
+            .returning(move || Ok(vec![k_info.clone()]));
+        ctx.client
+            .expect_mint_url()
+            .returning(move || mint_url.clone());
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit
+            .expect_receive_locked_proofs()
+            .times(1)
+            .returning(move |_, _, _, _| Ok((Amount::from(8u64), vec![y])));
+        ctx.tx_repo
+            .expect_store_tx()
+            .times(1)
+            .withf(move |tx| {
+                tx.direction == TransactionDirection::Incoming
+                    && tx.amount == Amount::from(8u64)
+                    && tx.ys == vec![y]
+                    && tx.memo == Some(String::from("shared"))
+                    && tx.timestamp == 100
+            })
+            .returning(|_tx| Ok(TransactionId::new(vec![])));
+
+        let wlt = wallet(ctx);
+        let signing_key = cashu::SecretKey::generate();
+        let (_tx_id, amount, ys) = wlt
+            .receive_multisig_token(token, &signing_key, 100)
+            .await
+            .unwrap();
+        assert_eq!(amount, Amount::from(8u64));
+        assert_eq!(ys, vec![y]);
+    }
+
+    #[tokio::test]
+    async fn test_send_offline_creates_token_without_mint() {
+        let mut ctx = wallet_ctx();
+        let pid = Uuid::new_v4();
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+
+        ctx.client
+            .expect_mint_url()
+            .returning(|| cashu::MintUrl::from_str("https://mint.example").unwrap());
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit
+            .expect_send_proofs_offline()
+            .withf(move |rid| *rid == pid)
+            .times(1)
+            .returning(move |_| Ok(HashMap::from([(proofs[0].y().unwrap(), proofs[0].clone())])));
+        ctx.tx_repo.expect_store_tx().times(1).returning(|tx| {
+            assert_eq!(tx.amount, Amount::from(8u64));
+            assert_eq!(tx.memo, Some(String::from("override")));
+            assert!(matches!(tx.direction, TransactionDirection::Outgoing));
+            // not kept unless the wallet opted in
+            assert!(get_token(&tx.metadata).is_none());
+            Ok(TransactionId::new(vec![]))
+        });
+
+        let wlt = wallet(ctx);
+        wlt.current_payment.lock().await.insert(
+            pid,
+            PayReference {
+                request_id: pid,
+                unit: CurrencyUnit::Sat,
+                amount: cashu::Amount::ZERO,
+                fees: cashu::Amount::ZERO,
+                ptype: WalletPaymentType::Token,
+                memo: Some("memo".to_string()),
+                conditions: None,
+                scheme: SecretScheme::Deterministic,
+            },
+        );
+
+        let (_, token) = wlt
+            .send_offline(pid, Some(String::from("override")), 123)
+            .await
+            .unwrap();
+        assert_eq!(token.memo().clone(), Some(String::from("override")));
+        assert!(wlt.current_payment.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_offline_keeps_token_if_opted_in() {
+        let mut ctx = wallet_ctx();
+        let pid = Uuid::new_v4();
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+
+        ctx.client
+            .expect_mint_url()
+            .returning(|| cashu::MintUrl::from_str("https://mint.example").unwrap());
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit
+            .expect_send_proofs_offline()
+            .times(1)
+            .returning(move |_| Ok(HashMap::from([(proofs[0].y().unwrap(), proofs[0].clone())])));
+        let stored = Arc::new(std::sync::Mutex::new(None));
+        let stored_clone = stored.clone();
+        ctx.tx_repo.expect_store_tx().times(1).returning(move |tx| {
+            *stored_clone.lock().unwrap() = get_token(&tx.metadata);
+            Ok(TransactionId::new(vec![]))
+        });
+
+        let mut wlt = wallet(ctx);
+        wlt.keep_sent_tokens = true;
+        wlt.current_payment.lock().await.insert(
+            pid,
+            PayReference {
+                request_id: pid,
+                unit: CurrencyUnit::Sat,
+                amount: cashu::Amount::ZERO,
+                fees: cashu::Amount::ZERO,
+                ptype: WalletPaymentType::Token,
+                memo: None,
+                conditions: None,
+                scheme: SecretScheme::Deterministic,
+            },
+        );
+
+        let (_, token) = wlt.send_offline(pid, None, 123).await.unwrap();
+        assert_eq!(*stored.lock().unwrap(), Some(token.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_tx_forgets_sent_token() {
+        let mut ctx = wallet_ctx();
+        let mut tx = reclaimable_tx(Amount::from(10u64));
+        tx.metadata
+            .insert(String::from(TOKEN_METADATA_KEY), String::from("cashuB..."));
+
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(1)
+            .returning(move |_| Ok(tx.clone()));
+        ctx.client
+            .expect_post_check_state()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![cashu::ProofState {
+                    y: cashu::SecretKey::generate().public_key(),
+                    state: cashu::State::Spent,
+                    witness: None,
+                }])
+            });
+        let updates = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let updates_clone = updates.clone();
+        ctx.tx_repo
+            .expect_update_metadata()
+            .times(2)
+            .returning(move |_, key, value| {
+                updates_clone.lock().unwrap().push((key, value));
+                Ok(None)
+            });
+
+        let wlt = wallet(ctx);
+        assert!(wlt.refresh_tx(TransactionId::new(vec![])).await.unwrap());
+        assert_eq!(
+            *updates.lock().unwrap(),
+            vec![
+                (
+                    String::from(TRANSACTION_STATUS_METADATA_KEY),
+                    TransactionStatus::Settled.to_string()
+                ),
+                (String::from(TOKEN_METADATA_KEY), String::new()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_offline_keeps_reference_if_swap_needed() {
+        let mut ctx = wallet_ctx();
+        let pid = Uuid::new_v4();
+
+        ctx.debit
+            .expect_send_proofs_offline()
+            .times(1)
+            .returning(|_| Err(Error::OfflineSendNotPossible));
+
+        let wlt = wallet(ctx);
+        wlt.current_payment.lock().await.insert(
+            pid,
+            PayReference {
+                request_id: pid,
+                unit: CurrencyUnit::Sat,
+                amount: cashu::Amount::ZERO,
+                fees: cashu::Amount::ZERO,
+                ptype: WalletPaymentType::Token,
+                memo: None,
+                conditions: None,
+                scheme: SecretScheme::Deterministic,
+            },
+        );
+
+        let err = wlt.send_offline(pid, None, 123).await.unwrap_err();
+        assert!(matches!(err, Error::OfflineSendNotPossible));
+        assert!(wlt.current_payment.lock().await.contains_key(&pid));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_onchain_payment_cancels_melt() {
+        let mut ctx = wallet_ctx();
+        let pid = Uuid::new_v4();
+
+        ctx.debit
+            .expect_cancel_melt()
+            .withf(move |rid| *rid == pid)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let wlt = wallet(ctx);
+        wlt.current_payment.lock().await.insert(
+            pid,
+            PayReference {
+                request_id: pid,
+                unit: CurrencyUnit::Sat,
+                amount: cashu::Amount::ZERO,
+                fees: cashu::Amount::ZERO,
+                ptype: WalletPaymentType::OnChain,
+                memo: None,
+                conditions: None,
+                scheme: SecretScheme::Deterministic,
+            },
+        );
+
+        wlt.cancel_payment(pid).await.expect("cancel works");
+        assert!(wlt.current_payment.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_payment_keeps_other_prepared_payments() {
+        let mut ctx = wallet_ctx();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+
+        ctx.debit
+            .expect_cancel_send()
+            .withf(move |rid| *rid == first)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let wlt = wallet(ctx);
+        for pid in [first, second] {
+            wlt.current_payment.lock().await.insert(
+                pid,
+                PayReference {
+                    request_id: pid,
+                    unit: CurrencyUnit::Sat,
+                    amount: cashu::Amount::ZERO,
+                    fees: cashu::Amount::ZERO,
+                    ptype: WalletPaymentType::Token,
+                    memo: None,
+                    conditions: None,
+                    scheme: SecretScheme::Deterministic,
+                },
+            );
+        }
+
+        wlt.cancel_payment(first).await.expect("cancel works");
+        let current = wlt.current_payment.lock().await;
+        assert_eq!(current.len(), 1);
+        assert!(current.contains_key(&second));
+    }
+
+    #[tokio::test]
+    async fn test_recheck_keeps_proofs_of_pending_txs() {
+        let mut ctx = wallet_ctx();
+        let held = cashu::SecretKey::generate().public_key();
+        let mut pending_tx = reclaimable_tx(Amount::from(8u64));
+        pending_tx.ys = vec![held];
+        let mut settled_tx = reclaimable_tx(Amount::from(4u64));
+        settled_tx.ys = vec![cashu::SecretKey::generate().public_key()];
+        settled_tx.metadata.insert(
+            String::from(TRANSACTION_STATUS_METADATA_KEY),
+            TransactionStatus::Settled.to_string(),
+        );
+
+        ctx.tx_repo
+            .expect_list_txs()
+            .times(1)
+            .returning(move || Ok(vec![pending_tx.clone(), settled_tx.clone()]));
+        ctx.debit
+            .expect_recheck()
+            .withf(move |keep, _| keep == [held])
+            .times(1)
+            .returning(|_, _| {
+                Ok(RecheckSummary {
+                    deleted: 2,
+                    restored: 1,
+                    pending: 0,
+                })
+            });
+
+        let wlt = wallet(ctx);
+        let summary = wlt.recheck().await.unwrap();
+        assert_eq!(summary.deleted, 2);
+        assert_eq!(summary.restored, 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_token_payment_releases_send() {
+        let mut ctx = wallet_ctx();
+        let pid = Uuid::new_v4();
+
+        ctx.debit
+            .expect_cancel_send()
+            .withf(move |rid| *rid == pid)
+            .times(1)
+            .returning(|_| Ok(()));
+        ctx.debit.expect_cancel_melt().never();
+
+        let wlt = wallet(ctx);
+        wlt.current_payment.lock().await.insert(
+            pid,
+            PayReference {
+                request_id: pid,
+                unit: CurrencyUnit::Sat,
+                amount: cashu::Amount::ZERO,
+                fees: cashu::Amount::ZERO,
+                ptype: WalletPaymentType::Token,
+                memo: None,
+                conditions: None,
+                scheme: SecretScheme::Deterministic,
+            },
+        );
+
+        wlt.cancel_payment(pid).await.expect("cancel works");
+        assert!(wlt.current_payment.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mint_onchain_uses_debit() {
+        let mut ctx = wallet_ctx();
+
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(|| Ok(vec![]));
+
+        ctx.debit.expect_mint_onchain().times(1).returning(
+            |_amount, _keysets_info, _client, _clowder_id| {
+                Ok(MintSummary {
+                    quote_id: Uuid::new_v4(),
+                    amount: bitcoin::Amount::from_sat(1000),
+                    address: valid_payment_address_testnet(),
+                    expiry: 0,
+                })
+            },
+        );
+
+        let wlt = wallet(ctx);
+        let _ = wlt
+            .mint_onchain(bitcoin::Amount::from_sat(1000))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mint_stores_incoming_tx() {
+        let mut ctx = wallet_ctx();
+
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(1)
+            .returning(|| Ok(vec![]));
+        ctx.client
+            .expect_mint_url()
+            .returning(|| cashu::MintUrl::from_str("https://mint.example").unwrap());
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit
+            .expect_mint()
+            .times(1)
+            .returning(|_, _, _| Ok((Amount::from(21u64), vec![])));
+        ctx.tx_repo.expect_store_tx().times(1).returning(|tx| {
+            assert_eq!(tx.amount, Amount::from(21u64));
+            assert_eq!(tx.quote_id, Some(String::from("q-1")));
+            assert!(matches!(tx.direction, TransactionDirection::Incoming));
+            assert!(matches!(
+                get_payment_type(&tx.metadata),
+                PaymentType::Bolt11
+            ));
+            Ok(TransactionId::new(vec![]))
+        });
+
+        let wlt = wallet(ctx);
+        wlt.mint(String::from("q-1"), 123).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_search_txs_forwards_filter() {
+        let mut ctx = wallet_ctx();
+        let tx_id = TransactionId::new(vec![]);
+
+        ctx.tx_repo
+            .expect_query()
+            .times(1)
+            .withf(|filter| {
+                matches!(filter.direction, Some(TransactionDirection::Incoming))
+                    && filter.memo_contains.as_deref() == Some("coffee")
+                    && filter.unit.is_none()
+            })
+            .returning(move |_| Ok(vec![tx_id]));
+
+        let wlt = wallet(ctx);
+        let filter = TxFilter {
+            direction: Some(TransactionDirection::Incoming),
+            memo_contains: Some(String::from("coffee")),
+            ..Default::default()
+        };
+        assert_eq!(wlt.search_txs(filter).await.unwrap(), vec![tx_id]);
+    }
+
+    #[tokio::test]
+    async fn test_list_transactions_pages_newest_first() {
+        let mut ctx = wallet_ctx();
+
+        ctx.tx_repo.expect_list_txs().times(1).returning(|| {
+            Ok([1, 3, 2]
+                .into_iter()
+                .map(|timestamp| Transaction {
+                    timestamp,
+                    ..reclaimable_tx(Amount::from(timestamp))
+                })
+                .collect())
+        });
+
+        // no refresh requested, so the mint is never asked
+        let wlt = wallet(ctx);
+        let page = wlt.list_transactions(1, 1, false).await.unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].timestamp, 2);
+    }
+
+    #[tokio::test]
+    async fn test_stats() {
+        let mut ctx = wallet_ctx();
+        let debit = crate::pocket::PocketStats {
+            unspent: 3,
+            pending: 1,
+            reserved: 0,
+            total: 6,
+            keysets: 2,
+            random: 1,
+        };
+        ctx.debit
+            .expect_stats()
+            .times(1)
+            .returning(move || Ok(debit));
+        ctx.tx_repo.expect_list_txs().times(1).returning(|| {
+            Ok([7, 3, 5]
+                .into_iter()
+                .map(|timestamp| Transaction {
+                    timestamp,
+                    ..reclaimable_tx(Amount::from(timestamp))
+                })
+                .collect())
+        });
+
+        let wlt = wallet(ctx);
+        let stats = wlt.stats().await.unwrap();
+        assert_eq!(stats.debit, debit);
+        assert_eq!(stats.transactions, 3);
+        assert_eq!(stats.oldest_tx, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_list_transactions_refreshes_pending() {
+        let mut ctx = wallet_ctx();
+        let tx = reclaimable_tx(Amount::from(10u64));
+        let tx_clone = tx.clone();
+
+        ctx.tx_repo
+            .expect_list_txs()
+            .times(1)
+            .returning(move || Ok(vec![tx_clone.clone()]));
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(1)
+            .returning(move |_| Ok(tx.clone()));
+        ctx.client
+            .expect_post_check_state()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![cashu::ProofState {
+                    y: cashu::SecretKey::generate().public_key(),
+                    state: cashu::State::Spent,
+                    witness: None,
+                }])
+            });
+        ctx.tx_repo
+            .expect_update_metadata()
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+
+        let wlt = wallet(ctx);
+        let page = wlt.list_transactions(0, 10, true).await.unwrap();
+        assert_eq!(page.len(), 1);
+        assert!(matches!(
+            get_transaction_status(&page[0].metadata),
+            TransactionStatus::Settled
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_pending_transactions() {
+        let mut ctx = wallet_ctx();
+        let y = cashu::SecretKey::generate().public_key();
+        let pending = Transaction {
+            ys: vec![y],
+            ..reclaimable_tx(Amount::from(10u64))
+        };
+        let incoming = Transaction {
+            direction: TransactionDirection::Incoming,
+            ..reclaimable_tx(Amount::from(20u64))
+        };
+
+        ctx.tx_repo
+            .expect_list_txs()
+            .times(1)
+            .returning(move || Ok(vec![pending.clone(), incoming.clone()]));
+        ctx.debit
+            .expect_pending()
+            .times(1)
+            .withf(move |ys| ys == [y])
+            .returning(|ys| Ok(ys.to_vec()));
+
+        let wlt = wallet(ctx);
+        let pending = wlt.list_pending_transactions().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].tx.amount, Amount::from(10u64));
+        assert_eq!(pending[0].pending_ys, vec![y]);
+        assert!(!pending[0].unsent);
+    }
+
+    #[tokio::test]
+    async fn test_retry_pending_refreshes_if_nothing_to_resend() {
+        let mut ctx = wallet_ctx();
+        let tx = reclaimable_tx(Amount::from(10u64));
+
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(2)
+            .returning(move |_| Ok(tx.clone()));
+        ctx.client
+            .expect_post_check_state()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![cashu::ProofState {
+                    y: cashu::SecretKey::generate().public_key(),
+                    state: cashu::State::Spent,
+                    witness: None,
+                }])
+            });
+        ctx.tx_repo
+            .expect_update_metadata()
+            .times(1)
+            .withf(|_, key, _| key == TRANSACTION_STATUS_METADATA_KEY)
+            .returning(|_, _, _| Ok(None));
+
+        let wlt = wallet(ctx);
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let retried = wlt
+            .retry_pending(TransactionId::new(vec![]), &nostr_cl, &http_cl)
+            .await
+            .unwrap();
+        assert!(retried);
+    }
+
+    fn nut18_tx() -> Transaction {
+        let mut tx = reclaimable_tx(Amount::from(10u64));
+        tx.metadata.insert(
+            String::from(PAYMENT_TYPE_METADATA_KEY),
+            PaymentType::Cdk18.to_string(),
+        );
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_confirm_nut18_delivery_settles_redeemed_payment() {
+        let mut ctx = wallet_ctx();
+        let tx = nut18_tx();
+
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(2)
+            .returning(move |_| Ok(tx.clone()));
+        ctx.client
+            .expect_post_check_state()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![cashu::ProofState {
+                    y: cashu::SecretKey::generate().public_key(),
+                    state: cashu::State::Spent,
+                    witness: None,
+                }])
+            });
+        ctx.tx_repo
+            .expect_update_metadata()
+            .times(1)
+            .withf(|_, key, value| {
+                key == TRANSACTION_STATUS_METADATA_KEY
+                    && value == &TransactionStatus::Settled.to_string()
+            })
+            .returning(|_, _, _| Ok(None));
+
+        let wlt = wallet(ctx);
+        let delivered = wlt
+            .confirm_nut18_delivery(TransactionId::new(vec![]), std::time::Duration::ZERO)
+            .await
+            .unwrap();
+        assert!(delivered);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_nut18_delivery_times_out_while_unspent() {
+        let mut ctx = wallet_ctx();
+        let tx = nut18_tx();
+
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(2)
+            .returning(move |_| Ok(tx.clone()));
+        ctx.client
+            .expect_post_check_state()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![cashu::ProofState {
+                    y: cashu::SecretKey::generate().public_key(),
+                    state: cashu::State::Unspent,
+                    witness: None,
+                }])
+            });
+        ctx.tx_repo.expect_update_metadata().never();
+
+        let wlt = wallet(ctx);
+        let delivered = wlt
+            .confirm_nut18_delivery(TransactionId::new(vec![]), std::time::Duration::ZERO)
+            .await
+            .unwrap();
+        assert!(!delivered);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_nut18_delivery_rejects_token_payment() {
+        let mut ctx = wallet_ctx();
+        let tx = reclaimable_tx(Amount::from(10u64));
 
-        ctx.debit.expect_mint_onchain().times(1).returning(
-            |_amount, _keysets_info, _client, _clowder_id| {
-                Ok(MintSummary {
-                    quote_id: Uuid::new_v4(),
-                    amount: bitcoin::Amount::from_sat(1000),
-                    address: valid_payment_address_testnet(),
-                    expiry: 0,
-                })
-            },
-        );
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(1)
+            .returning(move |_| Ok(tx.clone()));
+        ctx.client.expect_post_check_state().never();
 
         let wlt = wallet(ctx);
-        let _ = wlt.mint(bitcoin::Amount::from_sat(1000)).await.unwrap();
+        let res = wlt
+            .confirm_nut18_delivery(TransactionId::new(vec![]), std::time::Duration::ZERO)
+            .await;
+        assert!(matches!(res, Err(Error::Unsupported(_))));
+    }
+
+    #[test]
+    fn test_nut18_resend_emptied_once_sent() {
+        let resend = Nut18Resend {
+            transport: cashu::Transport {
+                _type: cashu::TransportType::HttpPost,
+                target: String::from("https://receiver.example"),
+                tags: None,
+            },
+            fallbacks: vec![],
+            payload: cashu::PaymentRequestPayload {
+                id: None,
+                memo: None,
+                mint: cashu::MintUrl::from_str("https://mint.example").unwrap(),
+                unit: CurrencyUnit::Sat,
+                proofs: vec![],
+            },
+        };
+        let mut metas = HashMap::from([(
+            String::from(NUT18_RESEND_METADATA_KEY),
+            serde_json::to_string(&resend).unwrap(),
+        )]);
+        let loaded = get_nut18_resend(&metas).expect("resend is kept");
+        assert_eq!(loaded.transport.target, resend.transport.target);
+
+        metas.insert(String::from(NUT18_RESEND_METADATA_KEY), String::new());
+        assert!(get_nut18_resend(&metas).is_none());
     }
 
     #[tokio::test]
@@ -1382,6 +3584,46 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_export_receipt_of_received_tx() {
+        let mut ctx = wallet_ctx();
+        let tx_id = TransactionId::new(vec![]);
+        let tx = Transaction {
+            mint_url: cashu::MintUrl::from_str("https://mint.example").unwrap(),
+            direction: TransactionDirection::Incoming,
+            fee: Amount::from(1u64),
+            amount: Amount::from(21u64),
+            memo: Some(String::from("coffee")),
+            metadata: HashMap::from([(
+                String::from(TRANSACTION_STATUS_METADATA_KEY),
+                TransactionStatus::Settled.to_string(),
+            )]),
+            timestamp: 123,
+            unit: CurrencyUnit::Sat,
+            ys: vec![],
+            quote_id: None,
+        };
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(1)
+            .returning(move |_| Ok(tx.clone()));
+        let wlt = wallet(ctx);
+
+        let receipt = wlt.export_receipt(tx_id).await.unwrap();
+        assert_eq!(receipt.tx_id, tx_id.to_string());
+        assert_eq!(receipt.amount, Amount::from(21u64));
+        assert_eq!(receipt.unit, CurrencyUnit::Sat);
+        assert_eq!(receipt.fee, Amount::from(1u64));
+        assert_eq!(receipt.timestamp, 123);
+        assert_eq!(receipt.direction, TransactionDirection::Incoming);
+        assert_eq!(
+            receipt.mint_url,
+            cashu::MintUrl::from_str("https://mint.example").unwrap()
+        );
+        assert_eq!(receipt.memo, Some(String::from("coffee")));
+        assert!(matches!(receipt.status, TransactionStatus::Settled));
+    }
+
     #[tokio::test]
     async fn test_reclaim_tx_sets_settled_if_nothing_reclaimed() {
         let mut ctx = wallet_ctx();
@@ -1476,6 +3718,55 @@ mod tests {
         assert_eq!(amount, Amount::from(10u64));
     }
 
+    #[tokio::test]
+    async fn test_reclaim_funds_cancels_pending_sends() {
+        let mut ctx = wallet_ctx();
+        let pending = reclaimable_tx(Amount::from(10u64));
+        let mut received = reclaimable_tx(Amount::from(5u64));
+        received.direction = TransactionDirection::Incoming;
+
+        let listed = vec![pending.clone(), received];
+        ctx.tx_repo
+            .expect_list_txs()
+            .times(1)
+            .returning(move || Ok(listed.clone()));
+        ctx.client
+            .expect_get_mint_keysets()
+            .times(2) // reclaim + balance
+            .returning(|| Ok(vec![]));
+        ctx.client
+            .expect_post_check_state()
+            .times(1)
+            .returning(|_| Ok(vec![]));
+        ctx.tx_repo
+            .expect_load_tx()
+            .times(2)
+            .returning(move |_| Ok(pending.clone()));
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit
+            .expect_reclaim_proofs()
+            .times(1)
+            .returning(|_, _, _, _| Ok(Amount::from(10u64)));
+        ctx.tx_repo
+            .expect_update_metadata()
+            .times(1)
+            .withf(|_, key, value| {
+                key == TRANSACTION_STATUS_METADATA_KEY
+                    && value == &TransactionStatus::Canceled.to_string()
+            })
+            .returning(|_, _, _| Ok(None));
+        ctx.debit.expect_balance().times(1).returning(|_| {
+            Ok(PocketBalance {
+                debit: Amount::from(10u64),
+                ..Default::default()
+            })
+        });
+
+        let wlt = wallet(ctx);
+        let balance = wlt.reclaim_funds().await.unwrap();
+        assert_eq!(balance.total, Amount::from(10u64));
+    }
+
     #[tokio::test]
     async fn test_reclaim_tx_sets_canceled_and_fee_if_partially_reclaimed() {
         let mut ctx = wallet_ctx();
@@ -1527,4 +3818,637 @@ mod tests {
 
         assert_eq!(amount, Amount::from(7u64));
     }
+
+    ///////////////////////////////////////////// round trip
+    async fn db_wallet(id: &str, client: MockMintConnector) -> Wallet {
+        let seed = bip39::Mnemonic::generate(12).unwrap().to_seed("");
+        db_wallet_with_seed(id, client, seed).await
+    }
+
+    async fn db_wallet_with_seed(
+        id: &str,
+        client: MockMintConnector,
+        seed: bcr_wallet_core::types::Seed,
+    ) -> Wallet {
+        let (tx_db, (pocket_db, mintmelt_db)) = in_memory_wallet_dbs(id).await;
+        let debit = crate::pocket::debit::Pocket::new(
+            CurrencyUnit::Sat,
+            Arc::new(pocket_db),
+            Arc::new(mintmelt_db),
+            seed,
+        );
+        let mut wlt = wallet(wallet_ctx());
+        wlt.client = Arc::new(client);
+        wlt.tx_repo = Box::new(tx_db);
+        wlt.debit = Box::new(debit);
+        wlt.id = id.to_owned();
+        wlt
+    }
+
+    #[tokio::test]
+    async fn test_export_import_proofs_encrypted_round_trip() {
+        use bcr_wallet_persistence::PocketRepository;
+        use cashu::nut07 as cdk07;
+
+        let mint_url = cashu::MintUrl::from_str("https://mint.example").unwrap();
+        let backup_wallet =
+            |pdb: Arc<dyn PocketRepository>,
+             mdb: bcr_wallet_persistence::redb::mintmelt::MintMeltDB| {
+                let mut ctx = wallet_ctx();
+                let url = mint_url.clone();
+                ctx.client.expect_mint_url().returning(move || url.clone());
+                let mut wlt = wallet(ctx);
+                let seed = bip39::Mnemonic::generate(12).unwrap().to_seed("");
+                wlt.debit = Box::new(crate::pocket::debit::Pocket::new(
+                    CurrencyUnit::Sat,
+                    pdb,
+                    Arc::new(mdb),
+                    seed,
+                ));
+                wlt
+            };
+
+        let (_, (alice_pdb, alice_mdb)) = in_memory_wallet_dbs("alice").await;
+        let alice_pdb: Arc<dyn PocketRepository> = Arc::new(alice_pdb);
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [cashu::Amount::from(8u64), cashu::Amount::from(16u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let unspent_y = alice_pdb.store_new(proofs[0].clone()).await.unwrap();
+        let pending_y = alice_pdb
+            .store_pendingspent(proofs[1].clone())
+            .await
+            .unwrap();
+        let alice = backup_wallet(alice_pdb, alice_mdb);
+        let blob = alice.export_proofs_encrypted("passphrase").await.unwrap();
+
+        let (_, (bob_pdb, bob_mdb)) = in_memory_wallet_dbs("bob").await;
+        let bob_pdb: Arc<dyn PocketRepository> = Arc::new(bob_pdb);
+        let bob = backup_wallet(bob_pdb.clone(), bob_mdb);
+        assert!(bob.import_proofs_encrypted(&blob, "wrong").await.is_err());
+        let imported = bob
+            .import_proofs_encrypted(&blob, "passphrase")
+            .await
+            .unwrap();
+        assert_eq!(imported, 2);
+
+        let (proof, state) = bob_pdb.load_proof(unspent_y).await.unwrap();
+        assert_eq!(proof, proofs[0]);
+        assert_eq!(state, cdk07::State::Unspent);
+        let (proof, state) = bob_pdb.load_proof(pending_y).await.unwrap();
+        assert_eq!(proof, proofs[1]);
+        assert_eq!(state, cdk07::State::PendingSpent);
+
+        // importing twice does not duplicate anything
+        let imported = bob
+            .import_proofs_encrypted(&blob, "passphrase")
+            .await
+            .unwrap();
+        assert_eq!(imported, 0);
+    }
+
+    #[tokio::test]
+    async fn test_export_import_backup_round_trip() {
+        let mint = FakeMint::new();
+        let client = mint.connector();
+        let alice = db_wallet("alice", client).await;
+        let mint_url = mint.mint_url();
+        let proofs = mint.proofs(&[Amount::from(8u64), Amount::from(16u64)]);
+        let token = Token::new_cashu(mint_url, proofs, None, CurrencyUnit::Sat);
+        let received = alice.receive_token(token, 100).await.unwrap();
+        let blob = alice.export_backup("passphrase").await.unwrap();
+
+        let backup = util::decode_wallet_backup(&blob, "passphrase").unwrap();
+        assert_eq!(backup.version, WALLET_BACKUP_VERSION);
+        assert_eq!(backup.config.wallet_id, "alice");
+        assert_eq!(backup.transactions.len(), 1);
+        assert!(util::decode_wallet_backup(&blob, "wrong").is_err());
+
+        // a fresh db, as when restoring on another device
+        let bob = db_wallet("bob", MockMintConnector::new()).await;
+        let err = bob.import_backup(backup.clone()).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidBackup(_)));
+
+        let restored = db_wallet("alice", MockMintConnector::new()).await;
+        let imported = restored.import_backup(backup).await.unwrap();
+        assert_eq!(
+            imported,
+            alice.debit.backup_proofs().await.unwrap().unspent.len()
+        );
+        let tx = restored.load_tx(received.tx_id).await.unwrap();
+        assert_eq!(tx.amount, received.received);
+    }
+
+    #[tokio::test]
+    async fn test_export_all_as_token() {
+        let mut ctx = wallet_ctx();
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(
+            &keyset,
+            &[Amount::from(8u64), Amount::from(16u64)],
+        );
+        let unspent = proofs.clone();
+        ctx.client
+            .expect_mint_url()
+            .returning(|| cashu::MintUrl::from_str("https://mint.example").unwrap());
+        ctx.debit.expect_unit().returning(|| CurrencyUnit::Sat);
+        ctx.debit.expect_backup_proofs().returning(move || {
+            Ok(crate::pocket::PocketBackup {
+                unspent: unspent.clone(),
+                ..Default::default()
+            })
+        });
+        ctx.debit.expect_send_proofs().never();
+        let wlt = wallet(ctx);
+
+        let token = wlt.export_all_as_token(CurrencyUnit::Sat).await.unwrap();
+        assert_eq!(token.value().unwrap(), Amount::from(24u64));
+        assert_eq!(token.unit(), Some(CurrencyUnit::Sat));
+
+        let err = wlt
+            .export_all_as_token(CurrencyUnit::Usd)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidCurrencyUnit(_)));
+    }
+
+    #[tokio::test]
+    async fn test_receive_send_receive_round_trip() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        let bob = db_wallet("bob", mint.connector()).await;
+        let mint_url = mint.mint_url();
+
+        // fund alice
+        let proofs = mint.proofs(&[Amount::from(8u64), Amount::from(16u64)]);
+        let funding = Token::new_cashu(mint_url.clone(), proofs, None, CurrencyUnit::Sat);
+        alice.receive_token(funding, 100).await.unwrap();
+        assert_eq!(alice.balance().await.unwrap().total, Amount::from(24u64));
+
+        // alice sends to bob
+        let summary = alice
+            .prepare_pay_by_token(
+                Amount::from(8u64),
+                CurrencyUnit::Sat,
+                None,
+                None,
+                SecretScheme::Deterministic,
+            )
+            .await
+            .unwrap();
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let token = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await
+            .unwrap()
+            .token
+            .expect("pay by token returns the token");
+        let balance = alice.balance().await.unwrap();
+        assert_eq!(balance.total, Amount::from(16u64));
+        assert_eq!(balance.debit_pending, Amount::from(8u64));
+
+        let bob_txid = bob.receive_token(token.clone(), 102).await.unwrap().tx_id;
+        assert_eq!(bob.balance().await.unwrap().total, Amount::from(8u64));
+        assert!(mint.state().swaps >= 2);
+
+        // the sent token can't be received twice
+        let err = bob.receive_token(token, 103).await.unwrap_err();
+        assert!(matches!(err, Error::AlreadyReceived(Some(id)) if id == bob_txid));
+        assert_eq!(bob.balance().await.unwrap().total, Amount::from(8u64));
+        assert_eq!(alice.list_txs().await.unwrap().len(), 2);
+        assert_eq!(bob.list_txs().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reclaimed_token_cannot_be_received() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        let bob = db_wallet("bob", mint.connector()).await;
+        alice
+            .receive_token(mint.token(&[Amount::from(8u64), Amount::from(16u64)]), 100)
+            .await
+            .unwrap();
+
+        let summary = alice
+            .prepare_pay_by_token(
+                Amount::from(8u64),
+                CurrencyUnit::Sat,
+                None,
+                None,
+                SecretScheme::Deterministic,
+            )
+            .await
+            .unwrap();
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let result = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await
+            .unwrap();
+        assert_eq!(result.amount, Amount::from(8u64));
+        assert_eq!(result.fees, summary.fees);
+        assert_eq!(result.unit, CurrencyUnit::Sat);
+        let tx_id = result.tx_id;
+        let token = result.token.expect("pay by token returns the token");
+
+        let reclaimed = alice.reclaim_tx(tx_id).await.unwrap();
+        assert_eq!(reclaimed, Amount::from(8u64));
+        assert_eq!(alice.balance().await.unwrap().total, Amount::from(24u64));
+
+        // the mint already saw the sent proofs
+        assert!(bob.receive_token(token, 102).await.is_err());
+        assert_eq!(bob.balance().await.unwrap().total, Amount::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_pay_locked_token_keeps_the_locked_proofs() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        alice
+            .receive_token(mint.token(&[Amount::from(8u64), Amount::from(16u64)]), 100)
+            .await
+            .unwrap();
+
+        let receiver = cashu::SecretKey::generate().public_key();
+        let conditions = cashu::SpendingConditions::new_p2pk(receiver, None);
+        let summary = alice
+            .prepare_pay_by_token(
+                Amount::from(8u64),
+                CurrencyUnit::Sat,
+                None,
+                Some(conditions),
+                SecretScheme::Deterministic,
+            )
+            .await
+            .unwrap();
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let result = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await
+            .unwrap();
+        assert_eq!(result.amount, Amount::from(8u64));
+
+        // the transaction tracks the locked proofs, the swapped inputs no longer count
+        let tx = alice.load_tx(result.tx_id).await.unwrap();
+        let pending = alice.debit.pending(&tx.ys).await.unwrap();
+        assert_eq!(pending.len(), tx.ys.len());
+        let balance = alice.balance().await.unwrap();
+        assert_eq!(balance.debit_pending, Amount::from(8u64));
+        assert_eq!(balance.total, Amount::from(24u64));
+    }
+
+    #[tokio::test]
+    async fn test_pay_locked_nut18_sends_the_request_amount() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        alice
+            .receive_token(mint.token(&[Amount::from(8u64), Amount::from(16u64)]), 100)
+            .await
+            .unwrap();
+
+        let receiver = cashu::SecretKey::generate().public_key();
+        let request = cdk18::PaymentRequest {
+            payment_id: Some(String::from("p-1")),
+            amount: Some(Amount::from(8u64)),
+            mints: None,
+            unit: Some(CurrencyUnit::Sat),
+            single_use: Some(true),
+            description: None,
+            nut10: Some(cdk18::Nut10SecretRequest::new(
+                cashu::nut10::Kind::P2PK,
+                receiver.to_string(),
+                None::<Vec<Vec<String>>>,
+            )),
+            transports: vec![cdk18::Transport {
+                _type: cdk18::TransportType::HttpPost,
+                target: String::from("http://127.0.0.1:9/pay"),
+                tags: None,
+            }],
+        };
+        let summary = alice.prepare_pay(request.to_string()).await.unwrap();
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        // nothing listens on the transport, the payment is kept to be sent again
+        let paid = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await;
+        assert!(paid.is_err());
+
+        let pending = alice.list_pending_transactions().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert!(pending[0].unsent);
+        assert_eq!(pending[0].pending_ys.len(), pending[0].tx.ys.len());
+        let resend = get_nut18_resend(&pending[0].tx.metadata).unwrap();
+        assert_eq!(
+            resend.payload.proofs.total_amount().unwrap(),
+            Amount::from(8u64)
+        );
+        assert!(
+            resend
+                .payload
+                .proofs
+                .iter()
+                .all(|proof| cashu::nut10::Secret::try_from(&proof.secret).is_ok())
+        );
+        let balance = alice.balance().await.unwrap();
+        assert_eq!(balance.debit_pending, Amount::from(8u64));
+        assert_eq!(balance.total, Amount::from(24u64));
+    }
+
+    #[tokio::test]
+    async fn test_pay_locked_token_reverts_inputs_if_lock_fails() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        alice
+            .receive_token(mint.token(&[Amount::from(8u64), Amount::from(16u64)]), 100)
+            .await
+            .unwrap();
+
+        let receiver = cashu::SecretKey::generate().public_key();
+        let conditions = cashu::SpendingConditions::new_p2pk(receiver, None);
+        let summary = alice
+            .prepare_pay_by_token(
+                Amount::from(8u64),
+                CurrencyUnit::Sat,
+                None,
+                Some(conditions),
+                SecretScheme::Deterministic,
+            )
+            .await
+            .unwrap();
+        mint.state().fail_swaps = true;
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let paid = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await;
+        assert!(paid.is_err());
+
+        let balance = alice.balance().await.unwrap();
+        assert_eq!(balance.debit_pending, Amount::ZERO);
+        assert_eq!(balance.debit_available, Amount::from(24u64));
+        assert!(
+            alice
+                .list_txs()
+                .await
+                .unwrap()
+                .iter()
+                .all(|tx| { matches!(tx.direction, TransactionDirection::Incoming) })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_funds_cancels_pending_sends() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        alice
+            .receive_token(mint.token(&[Amount::from(8u64), Amount::from(16u64)]), 100)
+            .await
+            .unwrap();
+
+        let summary = alice
+            .prepare_pay_by_token(
+                Amount::from(8u64),
+                CurrencyUnit::Sat,
+                None,
+                None,
+                SecretScheme::Deterministic,
+            )
+            .await
+            .unwrap();
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let tx_id = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await
+            .unwrap()
+            .tx_id;
+        assert_eq!(
+            alice.balance().await.unwrap().debit_pending,
+            Amount::from(8u64)
+        );
+
+        let balance = alice.reclaim_funds().await.unwrap();
+        assert_eq!(balance.total, Amount::from(24u64));
+        assert_eq!(balance.debit_pending, Amount::ZERO);
+        let tx = alice.load_tx(tx_id).await.unwrap();
+        assert_eq!(
+            get_transaction_status(&tx.metadata),
+            TransactionStatus::Canceled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_max_sweeps_the_balance() {
+        let mint = FakeMint::new();
+        let alice = db_wallet("alice", mint.connector()).await;
+        let bob = db_wallet("bob", mint.connector()).await;
+        alice
+            .receive_token(
+                mint.token(&[Amount::from(1u64), Amount::from(8u64), Amount::from(16u64)]),
+                100,
+            )
+            .await
+            .unwrap();
+        let swaps = mint.state().swaps;
+
+        let summary = alice
+            .prepare_send_max(CurrencyUnit::Sat, None, SecretScheme::Deterministic)
+            .await
+            .unwrap();
+        assert_eq!(summary.amount, Amount::from(25u64));
+        assert_eq!(summary.fees, Amount::ZERO);
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let token = alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await
+            .unwrap()
+            .token;
+        assert_eq!(mint.state().swaps, swaps);
+        assert_eq!(alice.balance().await.unwrap().total, Amount::ZERO);
+
+        let token = token.expect("pay by token returns the token");
+        bob.receive_token(token, 102).await.unwrap();
+        assert_eq!(bob.balance().await.unwrap().total, Amount::from(25u64));
+    }
+
+    #[tokio::test]
+    async fn test_receive_token_mint_url_spelled_differently() {
+        let mint = FakeMint::new();
+        let wlt = db_wallet("alice", mint.connector()).await;
+
+        for url in ["https://mint.example/", "https://Mint.EXAMPLE"] {
+            let mint_url = cashu::MintUrl::from_str(url).unwrap();
+            let proofs = mint.proofs(&[Amount::from(8u64)]);
+            let token = Token::new_cashu(mint_url, proofs, None, CurrencyUnit::Sat);
+            wlt.receive_token(token, 100).await.unwrap();
+        }
+        assert_eq!(wlt.balance().await.unwrap().total, Amount::from(16u64));
+    }
+
+    #[tokio::test]
+    async fn test_receive_token_skips_known_proofs() {
+        let mint = FakeMint::new();
+        let client = mint.connector();
+        let wlt = db_wallet("alice", client).await;
+        let mint_url = mint.mint_url();
+
+        let first = mint.proofs(&[Amount::from(8u64), Amount::from(16u64)]);
+        let token = Token::new_cashu(mint_url.clone(), first.clone(), None, CurrencyUnit::Sat);
+        let result = wlt.receive_token(token.clone(), 100).await.unwrap();
+        assert_eq!(result.received, Amount::from(24u64));
+        assert_eq!(result.stored, Amount::from(24u64));
+        assert_eq!(result.fee, Amount::ZERO);
+        assert_eq!(result.unit, CurrencyUnit::Sat);
+
+        let err = wlt.receive_token(token, 101).await.unwrap_err();
+        assert!(matches!(err, Error::AlreadyReceived(Some(id)) if id == result.tx_id));
+        assert_eq!(wlt.balance().await.unwrap().total, Amount::from(24u64));
+
+        // only the novel proof of an overlapping token is imported
+        let mut overlapping = mint.proofs(&[Amount::from(4u64)]);
+        overlapping.push(first[0].clone());
+        let token = Token::new_cashu(mint_url, overlapping, None, CurrencyUnit::Sat);
+        let result = wlt.receive_token(token, 102).await.unwrap();
+        assert_eq!(result.received, Amount::from(4u64));
+        assert_eq!(wlt.balance().await.unwrap().total, Amount::from(28u64));
+    }
+
+    #[tokio::test]
+    async fn test_receive_token_of_held_proofs_has_no_prior_tx() {
+        let mint = FakeMint::new();
+        let wlt = db_wallet("alice", mint.connector()).await;
+        wlt.receive_token(mint.token(&[Amount::from(8u64)]), 100)
+            .await
+            .unwrap();
+
+        // the swapped proofs are held by the pocket, no incoming transaction records them
+        let held = wlt.debit.backup_proofs().await.unwrap().unspent;
+        let token = Token::new_cashu(mint.mint_url(), held, None, CurrencyUnit::Sat);
+        let err = wlt.receive_token(token, 101).await.unwrap_err();
+        assert!(matches!(err, Error::AlreadyReceived(None)));
+    }
+
+    #[tokio::test]
+    async fn test_receive_token_drops_zero_amount_proofs() {
+        let mint = FakeMint::new();
+        let client = mint.connector();
+        let wlt = db_wallet("alice", client).await;
+        let mint_url = mint.mint_url();
+
+        let dust: Vec<cashu::Proof> = mint
+            .proofs(&[Amount::from(1u64); 3])
+            .into_iter()
+            .map(|mut proof| {
+                proof.amount = Amount::ZERO;
+                proof
+            })
+            .collect();
+        let token = Token::new_cashu(mint_url.clone(), dust.clone(), None, CurrencyUnit::Sat);
+        let err = wlt.receive_token(token, 100).await.unwrap_err();
+        assert!(matches!(err, Error::EmptyToken(_)));
+
+        let mut proofs = mint.proofs(&[Amount::from(8u64)]);
+        proofs.extend(dust);
+        let token = Token::new_cashu(mint_url, proofs, None, CurrencyUnit::Sat);
+        let result = wlt.receive_token(token, 101).await.unwrap();
+        assert_eq!(result.received, Amount::from(8u64));
+        assert_eq!(wlt.balance().await.unwrap().total, Amount::from(8u64));
+    }
+
+    #[tokio::test]
+    async fn test_receive_token_emits_events() {
+        let mint = FakeMint::new();
+        let client = mint.connector();
+        let wlt = db_wallet("alice", client).await;
+        let mut events = wlt.subscribe();
+
+        let proofs = mint.proofs(&[Amount::from(8u64), Amount::from(16u64)]);
+        let mint_url = mint.mint_url();
+        let token = Token::new_cashu(mint_url, proofs, None, CurrencyUnit::Sat);
+        let txid = wlt.receive_token(token, 100).await.unwrap().tx_id;
+
+        match events.try_recv().unwrap() {
+            WalletEvent::ProofsReceived { amount, ys } => {
+                assert_eq!(amount, Amount::from(24u64));
+                assert!(!ys.is_empty());
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            WalletEvent::TransactionStored(id) if id == txid
+        ));
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            WalletEvent::BalanceChanged
+        ));
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_restore_local_proofs_after_wiping_the_db() {
+        let mint = FakeMint::new();
+        let seed = bip39::Mnemonic::generate(12).unwrap().to_seed("");
+        let mint_url = mint.mint_url();
+
+        let alice = db_wallet_with_seed("alice", mint.connector(), seed).await;
+        let proofs = mint.proofs(&[Amount::from(8u64), Amount::from(16u64), Amount::from(32u64)]);
+        let funding = Token::new_cashu(mint_url, proofs, None, CurrencyUnit::Sat);
+        alice.receive_token(funding, 100).await.unwrap();
+        assert_eq!(alice.balance().await.unwrap().total, Amount::from(56u64));
+
+        // same seed, empty db
+        let restored = db_wallet_with_seed("alice", mint.connector(), seed).await;
+        assert_eq!(restored.balance().await.unwrap().total, Amount::ZERO);
+
+        restored.restore_local_proofs(None).await.unwrap();
+        assert_eq!(restored.balance().await.unwrap().total, Amount::from(56u64));
+
+        // restoring again doesn't double count
+        restored.restore_local_proofs(None).await.unwrap();
+        assert_eq!(restored.balance().await.unwrap().total, Amount::from(56u64));
+    }
+
+    #[tokio::test]
+    async fn test_restore_skips_random_secrets_but_finds_the_change() {
+        let mint = FakeMint::new();
+        let seed = bip39::Mnemonic::generate(12).unwrap().to_seed("");
+        let alice = db_wallet_with_seed("alice", mint.connector(), seed).await;
+        alice
+            .receive_token(mint.token(&[Amount::from(8u64), Amount::from(16u64)]), 100)
+            .await
+            .unwrap();
+
+        // 8 is split into 5 random for the token and 3 derived change
+        let summary = alice
+            .prepare_pay_by_token(
+                Amount::from(5u64),
+                CurrencyUnit::Sat,
+                None,
+                None,
+                SecretScheme::Random,
+            )
+            .await
+            .unwrap();
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        alice
+            .pay(summary.request_id, &nostr_cl, &http_cl, 101)
+            .await
+            .unwrap();
+        let balance = alice.balance().await.unwrap();
+        assert_eq!(balance.debit_pending, Amount::from(5u64));
+        assert_eq!(balance.total, Amount::from(24u64));
+        assert!(alice.stats().await.unwrap().debit.random > 0);
+
+        // same seed, empty db: the random proofs sent in the token are not found
+        let restored = db_wallet_with_seed("alice", mint.connector(), seed).await;
+        restored.restore_local_proofs(None).await.unwrap();
+        let balance = restored.balance().await.unwrap();
+        assert_eq!(balance.total, Amount::from(19u64));
+        assert_eq!(balance.debit_pending, Amount::ZERO);
+    }
 }
@@ -1,8 +1,12 @@
 use bcr_common::{
     cashu::{self, Amount, CurrencyUnit},
+    cdk_common::wallet::{Transaction, TransactionDirection, TransactionId},
+    wallet::Token,
     wire::common as wire_common,
 };
+use bcr_wallet_core::types::{SecretScheme, TransactionStatus};
 use bitcoin::secp256k1;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -13,7 +17,8 @@ pub struct SwapConfig {
 
 pub enum WalletPaymentType {
     Cdk18 {
-        transport: cashu::Transport,
+        // ordered by preference, the next one is tried if sending fails
+        transports: Vec<cashu::Transport>,
         id: Option<String>,
     },
     OnChain,
@@ -23,18 +28,45 @@ pub enum WalletPaymentType {
 pub struct PayReference {
     pub request_id: Uuid,
     pub unit: CurrencyUnit,
+    // what the receiver gets
+    pub amount: Amount,
     pub fees: Amount,
     pub ptype: WalletPaymentType,
     pub memo: Option<String>,
+    pub conditions: Option<cashu::SpendingConditions>,
+    pub scheme: SecretScheme,
+}
+
+// what a payment sent, the amount is the sum of the sent proofs
+#[derive(Debug, Clone)]
+pub struct SendResult {
+    pub tx_id: TransactionId,
+    pub token: Option<Token>,
+    pub amount: Amount,
+    pub fees: Amount,
+    pub unit: CurrencyUnit,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct WalletBalance {
-    pub debit: cashu::Amount,
-    pub credit: cashu::Amount,
+    pub debit_available: cashu::Amount,
+    pub debit_pending: cashu::Amount,
+    pub debit_reserved: cashu::Amount,
+    pub credit_available: cashu::Amount,
+    pub credit_pending: cashu::Amount,
+    pub credit_reserved: cashu::Amount,
+    // available debit and credit
     pub total: cashu::Amount,
 }
 
+// the balance of all the wallets using the same unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitBalance {
+    pub unit: CurrencyUnit,
+    pub credit: Amount,
+    pub debit: Amount,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletDetailedBalanceEntry {
     pub kid: cashu::Id,
@@ -42,8 +74,161 @@ pub struct WalletDetailedBalanceEntry {
     pub amount: cashu::Amount,
 }
 
+// the swap fees are taken from what was received, before storing the proofs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiveResult {
+    pub tx_id: TransactionId,
+    pub received: Amount,
+    pub stored: Amount,
+    pub fee: Amount,
+    pub unit: CurrencyUnit,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletProtestResult {
     pub status: wire_common::ProtestStatus,
     pub result: Option<(cashu::Amount, Vec<cashu::PublicKey>)>,
 }
+
+// a self-contained summary of a transaction, to be shared as proof of payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Receipt {
+    pub tx_id: String,
+    pub amount: Amount,
+    pub unit: CurrencyUnit,
+    pub fee: Amount,
+    pub timestamp: u64,
+    pub direction: TransactionDirection,
+    pub mint_url: cashu::MintUrl,
+    pub memo: Option<String>,
+    pub status: TransactionStatus,
+}
+
+// what a NUT-18 payment request asks for, before preparing the payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentRequestInfo {
+    pub amount: Option<Amount>,
+    pub unit: Option<CurrencyUnit>,
+    pub mints: Option<Vec<cashu::MintUrl>>,
+    pub transports: Vec<String>,
+    pub has_spending_conditions: bool,
+}
+
+// a NUT-18 payment whose transport failed, kept in the transaction metadata to send it again
+// WARN: holds the sent proofs
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Nut18Resend {
+    pub transport: cashu::Transport,
+    // tried after `transport`, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallbacks: Vec<cashu::Transport>,
+    pub payload: cashu::PaymentRequestPayload,
+}
+
+pub const NUT18_RESEND_METADATA_KEY: &str = "nut18::resend";
+// the value is emptied once the payment got through
+pub fn get_nut18_resend(metas: &HashMap<String, String>) -> Option<Nut18Resend> {
+    let resend = metas.get(NUT18_RESEND_METADATA_KEY)?;
+    serde_json::from_str(resend).ok()
+}
+
+// an outgoing transaction not settled yet
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    pub tx: Transaction,
+    // the proofs of the transaction still pending-spent in the pocket
+    pub pending_ys: Vec<cashu::PublicKey>,
+    // the transport failed, `retry_pending` sends it again
+    pub unsent: bool,
+}
+
+// WARN: holds spendable secrets, only ever stored encrypted
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProofBackup {
+    pub mint_url: cashu::MintUrl,
+    pub unit: CurrencyUnit,
+    pub debit: crate::pocket::PocketBackup,
+}
+
+// the current schema of `WalletBackup`, older ones are migrated on import
+pub const WALLET_BACKUP_VERSION: u32 = 1;
+
+// everything of a wallet the seed alone can't restore
+// WARN: holds spendable secrets
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WalletBackup {
+    pub version: u32,
+    pub config: bcr_wallet_core::types::WalletConfig,
+    pub debit: crate::pocket::PocketBackup,
+    pub transactions: Vec<Transaction>,
+    pub melt_commitments: Vec<crate::pocket::debit::MeltCommitmentBackup>,
+}
+
+// a keyset counter out of step with the indices the mint already signed
+// one behind is moved to `next_unused`, one ahead only leaves a gap and keeps `old`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterAdjustment {
+    pub kid: cashu::Id,
+    pub old: u32,
+    pub new: u32,
+    // the index after the last one the mint signed
+    pub next_unused: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LocalDbCleanup {
+    pub proofs_removed: usize,
+    pub counters_removed: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WalletStats {
+    pub debit: crate::pocket::PocketStats,
+    pub transactions: usize,
+    // timestamp of the oldest transaction, if any
+    pub oldest_tx: Option<u64>,
+}
+
+// what the wallet mint advertises in its NUT-06 info
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MintCapabilities {
+    pub mint_enabled: bool,
+    pub melt_enabled: bool,
+    pub token_state_check: bool,
+    pub restore: bool,
+    pub p2pk: bool,
+    pub dleq: bool,
+    pub htlc: bool,
+    pub mpp: bool,
+    pub websocket: bool,
+}
+
+impl From<&cashu::MintInfo> for MintCapabilities {
+    fn from(info: &cashu::MintInfo) -> Self {
+        let nuts = &info.nuts;
+        Self {
+            mint_enabled: !nuts.nut04.disabled,
+            melt_enabled: !nuts.nut05.disabled,
+            token_state_check: nuts.nut07.supported,
+            restore: nuts.nut09.supported,
+            p2pk: nuts.nut11.supported,
+            dleq: nuts.nut12.supported,
+            htlc: nuts.nut14.supported,
+            mpp: !nuts.nut15.methods.is_empty(),
+            websocket: !nuts.nut17.supported.is_empty(),
+        }
+    }
+}
+
+// pushed to subscribers whenever the wallet state changes
+#[derive(Debug, Clone)]
+pub enum WalletEvent {
+    ProofsReceived {
+        amount: Amount,
+        ys: Vec<cashu::PublicKey>,
+    },
+    TransactionStored(TransactionId),
+    BalanceChanged,
+}
+
+pub type WalletEventCallback = std::sync::Arc<dyn Fn(WalletEvent) + Send + Sync + 'static>;
@@ -1,14 +1,16 @@
 use crate::{
     ClowderMintConnector,
     error::{Error, Result},
-    wallet::types::SwapConfig,
+    wallet::types::{SwapConfig, WALLET_BACKUP_VERSION, WalletBackup},
 };
 use bcr_common::{
     cashu::{self, HTLCWitness, Proof, ProofsMethods},
     cdk_common,
+    wallet::Token,
     wire::keys::ProofFingerprint,
 };
 use bitcoin::{hashes::sha256::Hash as Sha256, secp256k1};
+use nostr_sdk::nips::nip19::{FromBech32, Nip19Profile};
 use secp256k1::schnorr::Signature;
 
 //////////////////////////////////// utils
@@ -113,6 +115,305 @@ pub async fn htlc_lock(
     Ok(proofs)
 }
 
+// NUT-02 input fee for the given proofs, rounded up to the next full unit
+pub fn input_fee(proofs: &[cashu::Proof], infos: &[cashu::KeySetInfo]) -> Result<cashu::Amount> {
+    let mut fee_ppk = 0u64;
+    for proof in proofs {
+        let info = infos
+            .iter()
+            .find(|info| info.id == proof.keyset_id)
+            .ok_or(Error::UnknownKeysetId(proof.keyset_id))?;
+        fee_ppk += info.input_fee_ppk;
+    }
+    Ok(cashu::Amount::from(fee_ppk.div_ceil(1000)))
+}
+
+// Estimates the fee of swapping `amount` into locked proofs, assuming the inputs are
+// in standard denominations of the active keyset
+// the smallest fee that covers the inputs it adds to, any excess comes back as change
+pub fn estimate_lock_fee(
+    amount: cashu::Amount,
+    unit: &cashu::CurrencyUnit,
+    infos: &[cashu::KeySetInfo],
+) -> Result<cashu::Amount> {
+    let active = infos
+        .iter()
+        .find(|info| info.active && &info.unit == unit && info.final_expiry.is_none())
+        .ok_or(Error::NoActiveKeyset)?;
+    let fee_for = |amount: cashu::Amount| {
+        let inputs = amount.split().len() as u64;
+        cashu::Amount::from((inputs * active.input_fee_ppk).div_ceil(1000))
+    };
+    let mut fee = cashu::Amount::ZERO;
+    while fee_for(amount + fee) > fee {
+        fee += cashu::Amount::ONE;
+    }
+    Ok(fee)
+}
+
+// Swaps the given proofs into proofs locked to the given spending conditions
+// the NUT-02 fee of the actual inputs is deducted, the locked amount is `expected`
+// and what is left goes to the `change` outputs, returns the locked and the change proofs
+pub async fn lock_proofs(
+    client: &dyn ClowderMintConnector,
+    proofs: Vec<cashu::Proof>,
+    conditions: &cashu::SpendingConditions,
+    expected: cashu::Amount,
+    change: Option<cashu::PreMintSecrets>,
+    infos: &[cashu::KeySetInfo],
+    unit: cashu::CurrencyUnit,
+    swap_config: SwapConfig,
+) -> Result<(Vec<cashu::Proof>, Vec<cashu::Proof>)> {
+    tracing::debug!("Locking proofs to spending conditions");
+    let total = proofs.total_amount()?;
+    let fee = input_fee(&proofs, infos)?;
+    let change_amount = match &change {
+        Some(change) => change.total_amount()?,
+        None => cashu::Amount::ZERO,
+    };
+    let needed = expected + change_amount + fee;
+    if total != needed {
+        return Err(Error::SendAmountMismatch {
+            got: total.checked_sub(fee).unwrap_or(cashu::Amount::ZERO),
+            expected: expected + change_amount,
+        });
+    }
+
+    let active_keyset_id = infos
+        .iter()
+        .find(|info| info.active && info.unit == unit && info.final_expiry.is_none())
+        .ok_or(Error::NoActiveKeyset)?
+        .id;
+
+    let split_target = cashu::amount::SplitTarget::None;
+    let premints = cashu::PreMintSecrets::with_conditions(
+        active_keyset_id,
+        expected,
+        &split_target,
+        conditions,
+    )?;
+    let mut outputs = premints.blinded_messages();
+    if let Some(change) = &change {
+        outputs.extend(change.blinded_messages());
+    }
+
+    let mut signatures = crate::pocket::committed_swap(
+        client,
+        None,
+        proofs,
+        outputs,
+        &swap_config,
+        std::collections::HashMap::new(),
+    )
+    .await?;
+
+    let keyset = client.get_mint_keyset(active_keyset_id).await?;
+    let change_signatures = signatures.split_off(premints.len());
+    let locked = crate::pocket::unblind_proofs(&keyset, signatures, premints);
+    let change = match change {
+        Some(change) => {
+            let change_keyset = if change.keyset_id == active_keyset_id {
+                keyset
+            } else {
+                client.get_mint_keyset(change.keyset_id).await?
+            };
+            crate::pocket::unblind_proofs(&change_keyset, change_signatures, change)
+        }
+        None => Vec::new(),
+    };
+
+    Ok((locked, change))
+}
+
+// Parses the NUT-10 spending conditions of a NUT-18 payment request
+// P2PK and HTLC conditions are supported, as long as the required signatures can be provided
+pub fn nut18_conditions(
+    nut10: Option<&cashu::nut18::Nut10SecretRequest>,
+) -> Result<Option<cashu::SpendingConditions>> {
+    let Some(request) = nut10 else {
+        return Ok(None);
+    };
+    let secret = cashu::nut10::Secret::from(request.clone());
+    let conditions = cashu::SpendingConditions::try_from(secret).map_err(|e| {
+        tracing::warn!("unsupported NUT-10 spending conditions: {e}");
+        Error::SpendingConditions
+    })?;
+    let (data_keys, inner) = match &conditions {
+        cashu::SpendingConditions::P2PKConditions { conditions, .. } => (1, conditions),
+        cashu::SpendingConditions::HTLCConditions { conditions, .. } => (0, conditions),
+    };
+    if let Some(inner) = inner
+        && let Some(num_sigs) = inner.num_sigs
+    {
+        let keys = data_keys + inner.pubkeys.as_ref().map_or(0, Vec::len) as u64;
+        if num_sigs > keys {
+            tracing::warn!("NUT-10 spending conditions require {num_sigs} of {keys} signatures");
+            return Err(Error::SpendingConditions);
+        }
+    }
+    Ok(Some(conditions))
+}
+
+// Converts a V3 token into a V4 one, V3 tokens without unit are in sat
+pub fn token_from_v3(v3: cashu::nut00::TokenV3) -> Result<Token> {
+    let mint_urls = v3.mint_urls();
+    let [mint_url] = mint_urls.as_slice() else {
+        if mint_urls.is_empty() {
+            return Err(Error::EmptyToken(String::from("V3 token without mint")));
+        }
+        return Err(Error::InterMint);
+    };
+    let unit = v3.unit.clone().unwrap_or(cashu::CurrencyUnit::Sat);
+    Ok(Token::new_cashu(
+        mint_url.clone(),
+        v3.proofs(),
+        v3.memo.clone(),
+        unit,
+    ))
+}
+
+// A mint may list the same keyset more than once, we keep the first one, or the active one
+// if they only differ in the active flag
+pub fn dedup_keyset_infos(infos: Vec<cashu::KeySetInfo>) -> Result<Vec<cashu::KeySetInfo>> {
+    let mut res: Vec<cashu::KeySetInfo> = Vec::with_capacity(infos.len());
+    for info in infos {
+        let Some(existing) = res.iter_mut().find(|e| e.id == info.id) else {
+            res.push(info);
+            continue;
+        };
+        let mut normalized = info.clone();
+        normalized.active = existing.active;
+        if normalized != *existing {
+            return Err(Error::DuplicateKeysetId(info.id));
+        }
+        tracing::warn!("mint listed keyset {} more than once", info.id);
+        existing.active |= info.active;
+    }
+    Ok(res)
+}
+
+// the BOLT11 currency prefix of the invoices issued on the given network
+fn bolt11_currency(network: bitcoin::Network) -> &'static str {
+    match network {
+        bitcoin::Network::Bitcoin => "bc",
+        bitcoin::Network::Signet => "tbs",
+        bitcoin::Network::Regtest => "bcrt",
+        _ => "tb",
+    }
+}
+
+pub fn bolt11_network(invoice: &str) -> Option<bitcoin::Network> {
+    let invoice = invoice.to_lowercase();
+    let rest = invoice.strip_prefix("ln")?;
+    // longest prefixes first, `bcrt` starts with `bc` and `tbs` with `tb`
+    if rest.starts_with("bcrt") {
+        Some(bitcoin::Network::Regtest)
+    } else if rest.starts_with("bc") {
+        Some(bitcoin::Network::Bitcoin)
+    } else if rest.starts_with("tbs") {
+        Some(bitcoin::Network::Signet)
+    } else if rest.starts_with("tb") {
+        Some(bitcoin::Network::Testnet)
+    } else {
+        None
+    }
+}
+
+/// Checks the network of the mint against ours, using the invoice of a throwaway mint quote
+/// A mint that can't issue a quote is not rejected, as it doesn't tell us its network
+pub async fn check_mint_network(
+    client: &dyn ClowderMintConnector,
+    unit: cashu::CurrencyUnit,
+    network: bitcoin::Network,
+) -> Result<()> {
+    let request = cashu::MintQuoteBolt11Request {
+        amount: cashu::Amount::from(1u64),
+        unit,
+        description: None,
+        pubkey: None,
+    };
+    let invoice = match client.post_mint_quote_bolt11(request).await {
+        Ok(response) => response.request,
+        Err(e) => {
+            tracing::warn!("Couldn't fetch a mint quote to check the mint network: {e}");
+            return Ok(());
+        }
+    };
+    let Some(theirs) = bolt11_network(&invoice) else {
+        tracing::warn!("Couldn't read the network of the mint quote invoice");
+        return Ok(());
+    };
+    if bolt11_currency(theirs) != bolt11_currency(network) {
+        return Err(Error::InvalidNetwork(network, theirs));
+    }
+    Ok(())
+}
+
+// Returns the id of the nostr event, if sent over nostr
+pub async fn send_nut18_payload(
+    nostr_cl: &nostr_sdk::Client,
+    http_cl: &reqwest::Client,
+    transport: &cashu::Transport,
+    payload: &cashu::PaymentRequestPayload,
+) -> Result<Option<String>> {
+    match transport._type {
+        cashu::TransportType::HttpPost => {
+            let url = reqwest::Url::parse(&transport.target)?;
+            let response = http_cl.post(url).json(payload).send().await?;
+            response.error_for_status()?;
+            Ok(None)
+        }
+        cashu::TransportType::Nostr => {
+            let payload = serde_json::to_string(payload)?;
+            let receiver = Nip19Profile::from_bech32(&transport.target)?;
+            let output = nostr_cl
+                .send_private_msg_to(
+                    receiver.relays,
+                    receiver.public_key,
+                    payload,
+                    std::iter::empty(),
+                )
+                .await?;
+            Ok(Some(output.id().to_string()))
+        }
+    }
+}
+
+// tries the transports in order, until one of them gets the payload through
+// Returns the id of the nostr event, if sent over nostr
+pub async fn send_nut18_payload_with_fallback(
+    nostr_cl: &nostr_sdk::Client,
+    http_cl: &reqwest::Client,
+    transports: &[cashu::Transport],
+    payload: &cashu::PaymentRequestPayload,
+) -> Result<Option<String>> {
+    let mut last_err = Error::NoTransport;
+    for transport in transports {
+        match send_nut18_payload(nostr_cl, http_cl, transport, payload).await {
+            Ok(event_id) => return Ok(event_id),
+            Err(e) => {
+                tracing::warn!("NUT-18 transport {} failed: {e}", transport.target);
+                last_err = e;
+            }
+        }
+    }
+    Err(last_err)
+}
+
+// the same mint, even if one url has a trailing slash or an upper case host
+pub fn same_mint(a: &cashu::MintUrl, b: &cashu::MintUrl) -> bool {
+    fn canonical(url: &cashu::MintUrl) -> String {
+        let url = url.to_string();
+        // the parsed url has a lower case scheme and host
+        let url = match url::Url::parse(&url) {
+            Ok(parsed) => String::from(parsed.as_str()),
+            Err(_) => url.to_lowercase(),
+        };
+        String::from(url.trim_end_matches('/'))
+    }
+    canonical(a) == canonical(b)
+}
+
 pub fn tx_can_be_refreshed(tx: &cdk_common::wallet::Transaction) -> bool {
     // Only refresh outgoing transactions
     if matches!(
@@ -129,3 +430,394 @@ pub fn tx_can_be_refreshed(tx: &cdk_common::wallet::Transaction) -> bool {
     }
     true
 }
+
+const BACKUP_MAGIC: &[u8; 4] = b"BCRB";
+const BACKUP_VERSION: u8 = 1;
+const BACKUP_SALT_LEN: usize = 16;
+const BACKUP_NONCE_LEN: usize = 24;
+const BACKUP_HEADER_LEN: usize = BACKUP_MAGIC.len() + 1 + BACKUP_SALT_LEN + BACKUP_NONCE_LEN;
+// scrypt cost, 2^14 iterations
+const BACKUP_SCRYPT_LOG_N: u8 = 14;
+
+fn backup_key(passphrase: &str, salt: &[u8]) -> Result<chacha20poly1305::Key> {
+    let params = scrypt::Params::new(BACKUP_SCRYPT_LOG_N, 8, 1, 32)
+        .map_err(|e| Error::InvalidBackup(e.to_string()))?;
+    let mut key = chacha20poly1305::Key::default();
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, key.as_mut_slice())
+        .map_err(|e| Error::InvalidBackup(e.to_string()))?;
+    Ok(key)
+}
+
+// magic | version | salt | nonce | XChaCha20-Poly1305 ciphertext
+pub fn encrypt_backup(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce, aead::Aead};
+
+    let salt: [u8; BACKUP_SALT_LEN] = rand::random();
+    let nonce: [u8; BACKUP_NONCE_LEN] = rand::random();
+    let cipher = XChaCha20Poly1305::new(&backup_key(passphrase, &salt)?);
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), plaintext)
+        .map_err(|e| Error::InvalidBackup(e.to_string()))?;
+
+    let mut blob = Vec::with_capacity(BACKUP_HEADER_LEN + ciphertext.len());
+    blob.extend_from_slice(BACKUP_MAGIC);
+    blob.push(BACKUP_VERSION);
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&ciphertext);
+    Ok(blob)
+}
+
+pub fn decrypt_backup(blob: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce, aead::Aead};
+
+    if blob.len() < BACKUP_HEADER_LEN || !blob.starts_with(BACKUP_MAGIC) {
+        return Err(Error::InvalidBackup(String::from("not a proof backup")));
+    }
+    let (header, ciphertext) = blob.split_at(BACKUP_HEADER_LEN);
+    let version = header[BACKUP_MAGIC.len()];
+    if version != BACKUP_VERSION {
+        return Err(Error::InvalidBackup(format!("unknown version {version}")));
+    }
+    let (salt, nonce) = header[BACKUP_MAGIC.len() + 1..].split_at(BACKUP_SALT_LEN);
+
+    let cipher = XChaCha20Poly1305::new(&backup_key(passphrase, salt)?);
+    cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::InvalidBackup(String::from("wrong passphrase or corrupted backup")))
+}
+
+// only the version is read first, so a backup of an older schema can be migrated
+pub fn decode_wallet_backup(blob: &[u8], passphrase: &str) -> Result<WalletBackup> {
+    #[derive(serde::Deserialize)]
+    struct Versioned {
+        version: u32,
+    }
+
+    let plaintext = decrypt_backup(blob, passphrase)?;
+    let Versioned { version } = serde_json::from_slice(&plaintext)?;
+    match version {
+        WALLET_BACKUP_VERSION => Ok(serde_json::from_slice(&plaintext)?),
+        _ => Err(Error::InvalidBackup(format!(
+            "unknown wallet backup version {version}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_utils::tests::MockMintConnector;
+    use bcr_common::core_tests;
+    use std::str::FromStr;
+
+    fn active_info(info: impl Into<cashu::KeySetInfo>, input_fee_ppk: u64) -> cashu::KeySetInfo {
+        let mut info: cashu::KeySetInfo = info.into();
+        info.input_fee_ppk = input_fee_ppk;
+        info.active = true;
+        info.final_expiry = None;
+        info
+    }
+
+    fn quote_response(request: &str) -> cashu::MintQuoteBolt11Response<String> {
+        cashu::MintQuoteBolt11Response {
+            quote: String::from("q-1"),
+            request: String::from(request),
+            amount: Some(cashu::Amount::from(1u64)),
+            unit: Some(cashu::CurrencyUnit::Sat),
+            state: cashu::MintQuoteState::Unpaid,
+            expiry: None,
+            pubkey: None,
+        }
+    }
+
+    #[test]
+    fn bolt11_network_from_prefix() {
+        assert_eq!(
+            bolt11_network("lnbc10n1p..."),
+            Some(bitcoin::Network::Bitcoin)
+        );
+        assert_eq!(
+            bolt11_network("LNTB10N1P..."),
+            Some(bitcoin::Network::Testnet)
+        );
+        assert_eq!(
+            bolt11_network("lntbs10n1p..."),
+            Some(bitcoin::Network::Signet)
+        );
+        assert_eq!(
+            bolt11_network("lnbcrt10n1p..."),
+            Some(bitcoin::Network::Regtest)
+        );
+        assert_eq!(bolt11_network("bitcoin:bc1q..."), None);
+    }
+
+    #[tokio::test]
+    async fn check_mint_network_matching() {
+        let mut client = MockMintConnector::new();
+        client
+            .expect_post_mint_quote_bolt11()
+            .times(1)
+            .returning(|_| Ok(quote_response("lntb10n1p...")));
+        check_mint_network(&client, cashu::CurrencyUnit::Sat, bitcoin::Network::Testnet)
+            .await
+            .expect("same network");
+    }
+
+    #[tokio::test]
+    async fn check_mint_network_mismatch() {
+        let mut client = MockMintConnector::new();
+        client
+            .expect_post_mint_quote_bolt11()
+            .times(1)
+            .returning(|_| Ok(quote_response("lnbc10n1p...")));
+        let err = check_mint_network(&client, cashu::CurrencyUnit::Sat, bitcoin::Network::Testnet)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidNetwork(bitcoin::Network::Testnet, bitcoin::Network::Bitcoin)
+        ));
+    }
+
+    #[tokio::test]
+    async fn check_mint_network_skipped_without_quote() {
+        let mut client = MockMintConnector::new();
+        client
+            .expect_post_mint_quote_bolt11()
+            .times(1)
+            .returning(|_| Err(Error::Unsupported(String::from("bolt11"))));
+        check_mint_network(&client, cashu::CurrencyUnit::Sat, bitcoin::Network::Testnet)
+            .await
+            .expect("unknown network is not rejected");
+    }
+
+    #[tokio::test]
+    async fn send_nut18_payload_with_fallback_returns_the_last_error() {
+        let payload = cashu::PaymentRequestPayload {
+            id: None,
+            memo: None,
+            unit: cashu::CurrencyUnit::Sat,
+            mint: cashu::MintUrl::from_str("https://mint.example").unwrap(),
+            proofs: vec![],
+        };
+        let transports = vec![cashu::Transport {
+            _type: cashu::TransportType::HttpPost,
+            target: String::from("not an url"),
+            tags: None,
+        }];
+        let nostr_cl = nostr_sdk::Client::new(nostr_sdk::Keys::generate());
+        let http_cl = reqwest::Client::new();
+        let res =
+            send_nut18_payload_with_fallback(&nostr_cl, &http_cl, &transports, &payload).await;
+        assert!(matches!(res, Err(Error::Url(_))));
+        let res = send_nut18_payload_with_fallback(&nostr_cl, &http_cl, &[], &payload).await;
+        assert!(matches!(res, Err(Error::NoTransport)));
+    }
+
+    #[test]
+    fn same_mint_ignores_trailing_slash_and_host_case() {
+        let url = |s: &str| cashu::MintUrl::from_str(s).unwrap();
+        assert!(same_mint(
+            &url("https://mint.example.com/"),
+            &url("https://mint.example.com")
+        ));
+        assert!(same_mint(
+            &url("https://Mint.Example.COM"),
+            &url("https://mint.example.com/")
+        ));
+        assert!(same_mint(
+            &url("https://mint.example.com/sat/"),
+            &url("https://mint.example.com/sat")
+        ));
+        assert!(!same_mint(
+            &url("https://mint.example.com"),
+            &url("https://other.example.com")
+        ));
+        assert!(!same_mint(
+            &url("https://mint.example.com/sat"),
+            &url("https://mint.example.com/usd")
+        ));
+    }
+
+    #[test]
+    fn backup_encryption_round_trip() {
+        let blob = encrypt_backup(b"proofs", "passphrase").unwrap();
+        assert_eq!(decrypt_backup(&blob, "passphrase").unwrap(), b"proofs");
+    }
+
+    #[test]
+    fn backup_decryption_fails_with_wrong_passphrase() {
+        let blob = encrypt_backup(b"proofs", "passphrase").unwrap();
+        let res = decrypt_backup(&blob, "wrong");
+        assert!(matches!(res, Err(Error::InvalidBackup(_))));
+    }
+
+    #[test]
+    fn backup_decryption_detects_tampering() {
+        let mut blob = encrypt_backup(b"proofs", "passphrase").unwrap();
+        let last = blob.len() - 1;
+        blob[last] ^= 0x01;
+        let res = decrypt_backup(&blob, "passphrase");
+        assert!(matches!(res, Err(Error::InvalidBackup(_))));
+    }
+
+    #[test]
+    fn decode_wallet_backup_rejects_unknown_version() {
+        let plaintext = serde_json::to_vec(&serde_json::json!({ "version": 99 })).unwrap();
+        let blob = encrypt_backup(&plaintext, "passphrase").unwrap();
+        let res = decode_wallet_backup(&blob, "passphrase");
+        assert!(matches!(res, Err(Error::InvalidBackup(_))));
+    }
+
+    #[test]
+    fn dedup_keyset_infos_prefers_active() {
+        let info = active_info(core_tests::generate_random_ecash_keyset().0, 0);
+        let other = active_info(core_tests::generate_random_ecash_keyset().0, 0);
+        let mut inactive = info.clone();
+        inactive.active = false;
+
+        let res = dedup_keyset_infos(vec![inactive, other.clone(), info.clone()]).unwrap();
+        assert_eq!(res, vec![info, other]);
+    }
+
+    #[test]
+    fn dedup_keyset_infos_conflict() {
+        let info = active_info(core_tests::generate_random_ecash_keyset().0, 0);
+        let mut conflicting = info.clone();
+        conflicting.input_fee_ppk = 100;
+
+        let err = dedup_keyset_infos(vec![info.clone(), conflicting]).unwrap_err();
+        assert!(matches!(err, Error::DuplicateKeysetId(id) if id == info.id));
+    }
+
+    #[test]
+    fn input_fee_rounds_up() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let info = active_info(info, 100);
+        let amounts = [cashu::Amount::from(8u64), cashu::Amount::from(2u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+
+        let fee = input_fee(&proofs, &[info]).unwrap();
+        assert_eq!(fee, cashu::Amount::ONE);
+    }
+
+    #[test]
+    fn input_fee_unknown_keyset() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let (other, _) = core_tests::generate_random_ecash_keyset();
+        let other = active_info(other, 100);
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[cashu::Amount::ONE]);
+
+        let err = input_fee(&proofs, &[other]).unwrap_err();
+        assert!(matches!(err, Error::UnknownKeysetId(_)));
+    }
+
+    #[test]
+    fn estimate_lock_fee_no_fees() {
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let info = active_info(info, 0);
+        let unit = info.unit.clone();
+        let fee = estimate_lock_fee(cashu::Amount::from(15u64), &unit, &[info]).unwrap();
+        assert_eq!(fee, cashu::Amount::ZERO);
+    }
+
+    #[test]
+    fn estimate_lock_fee_with_fees() {
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let info = active_info(info, 1000);
+        let unit = info.unit.clone();
+        // 15 = 8 + 4 + 2 + 1 -> 4 fee, but 16 is a single input -> 1 fee
+        let fee = estimate_lock_fee(cashu::Amount::from(15u64), &unit, &[info]).unwrap();
+        assert_eq!(fee, cashu::Amount::ONE);
+    }
+
+    #[test]
+    fn estimate_lock_fee_covers_its_own_inputs() {
+        let (info, _) = core_tests::generate_random_ecash_keyset();
+        let info = active_info(info, 1000);
+        let unit = info.unit.clone();
+        // 6 = 4 + 2 -> 2 fee, 8 -> 1 fee, but 7 = 4 + 2 + 1 -> 3 fee
+        let fee = estimate_lock_fee(cashu::Amount::from(6u64), &unit, &[info]).unwrap();
+        assert_eq!(fee, cashu::Amount::from(2u64));
+    }
+
+    #[test]
+    fn nut18_conditions_none() {
+        assert!(nut18_conditions(None).unwrap().is_none());
+    }
+
+    #[test]
+    fn nut18_conditions_p2pk() {
+        let pk = cashu::SecretKey::generate().public_key();
+        let request = cashu::nut18::Nut10SecretRequest::new(
+            cashu::nut10::Kind::P2PK,
+            pk.to_string(),
+            None::<Vec<Vec<String>>>,
+        );
+        let conditions = nut18_conditions(Some(&request)).unwrap().unwrap();
+        match conditions {
+            cashu::SpendingConditions::P2PKConditions { data, .. } => assert_eq!(data, pk),
+            other => panic!("expected P2PK conditions, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn nut18_conditions_unsatisfiable_multisig() {
+        let pk = cashu::SecretKey::generate().public_key();
+        let request = cashu::nut18::Nut10SecretRequest::new(
+            cashu::nut10::Kind::P2PK,
+            pk.to_string(),
+            Some(vec![vec![String::from("n_sigs"), String::from("2")]]),
+        );
+        let err = nut18_conditions(Some(&request)).unwrap_err();
+        assert!(matches!(err, Error::SpendingConditions));
+    }
+
+    #[test]
+    fn nut18_conditions_invalid_data() {
+        let request = cashu::nut18::Nut10SecretRequest::new(
+            cashu::nut10::Kind::P2PK,
+            "not-a-pubkey",
+            None::<Vec<Vec<String>>>,
+        );
+        let err = nut18_conditions(Some(&request)).unwrap_err();
+        assert!(matches!(err, Error::SpendingConditions));
+    }
+
+    #[test]
+    fn token_from_v3_single_mint() {
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let info = active_info(info, 0);
+        let proofs =
+            core_tests::generate_random_ecash_proofs(&keyset, &[cashu::Amount::from(8u64)]);
+        let mint_url = cashu::MintUrl::from_str("https://mint.example").unwrap();
+        let v3 = cashu::nut00::TokenV3::new(
+            mint_url.clone(),
+            proofs.clone(),
+            Some(String::from("memo")),
+            None,
+        )
+        .unwrap();
+
+        let token = token_from_v3(v3).unwrap();
+        assert_eq!(token.mint_url(), mint_url);
+        assert_eq!(token.unit(), Some(cashu::CurrencyUnit::Sat));
+        assert_eq!(token.memo().clone(), Some(String::from("memo")));
+        assert_eq!(token.proofs(&[info]).unwrap(), proofs);
+    }
+
+    #[test]
+    fn token_from_v3_multi_mint() {
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[cashu::Amount::ONE]);
+        let mint_url = cashu::MintUrl::from_str("https://mint.example").unwrap();
+        let mut v3 = cashu::nut00::TokenV3::new(mint_url, proofs, None, None).unwrap();
+        let mut other = v3.token[0].clone();
+        other.mint = cashu::MintUrl::from_str("https://other.example").unwrap();
+        v3.token.push(other);
+
+        let err = token_from_v3(v3).unwrap_err();
+        assert!(matches!(err, Error::InterMint));
+    }
+}
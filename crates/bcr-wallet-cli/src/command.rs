@@ -33,8 +33,14 @@ pub async fn cmd_info(app_state: &AppState) -> Result<String> {
         res.push_str(&format!("Name: {name}\n"));
         res.push_str(&format!("Wallet ID: {id}\n"));
         res.push_str(&format!("Mint URL: {mint_url}\n"));
-        res.push_str(&format!("Debit Balance: {} {}\n", balance.debit, unit));
-        res.push_str(&format!("Credit Balance: {} {}\n", balance.credit, unit));
+        res.push_str(&format!(
+            "Debit Balance: {} {} ({} pending, {} reserved)\n",
+            balance.debit_available, unit, balance.debit_pending, balance.debit_reserved
+        ));
+        res.push_str(&format!(
+            "Credit Balance: {} {} ({} pending, {} reserved)\n",
+            balance.credit_available, unit, balance.credit_pending, balance.credit_reserved
+        ));
         res.push_str(&format!("Total Balance: {} {}\n", balance.total, unit));
 
         if !dev_mode_detailed_balance.is_empty() {
@@ -124,7 +130,10 @@ pub async fn cmd_receive(
     id: usize,
 ) -> Result<String> {
     let mut res = String::new();
-    let swapped = app_state.wallet_receive_token(id, token.to_owned()).await?;
+    let swapped = app_state
+        .wallet_receive_token(id, token.to_owned())
+        .await?
+        .tx_id;
     let tx = app_state.wallet_load_tx(id, &swapped.to_string()).await?;
     push_break(&mut res);
     push_break(&mut res);
@@ -219,8 +228,15 @@ pub async fn cmd_pay_by_token(
     push_break(&mut res);
     res.push_str(&format!("Transaction ID: {}", result.tx_id));
     push_break(&mut res);
+    res.push_str(&format!(
+        "Sent: {} {}, Fees: {}",
+        result.amount, result.unit, result.fees
+    ));
+    push_break(&mut res);
     push_break(&mut res);
-    res.push_str(&format!("Token: {}", result.token));
+    if let Some(token) = result.token {
+        res.push_str(&format!("Token: {token}"));
+    }
 
     Ok(res)
 }
@@ -241,7 +257,7 @@ pub async fn cmd_send_payment(
         &payment_summary.amount, &payment_summary.unit, &payment_summary.fees,
     );
 
-    let tx_id = app_state
+    let result = app_state
         .wallet_pay(id, payment_summary.request_id.to_string())
         .await?;
 
@@ -257,7 +273,12 @@ pub async fn cmd_send_payment(
         &payment_summary.unit, &payment_summary.amount, &payment_summary.fees
     ));
     push_break(&mut res);
-    res.push_str(&format!("Transaction ID: {tx_id}"));
+    res.push_str(&format!("Transaction ID: {}", result.tx_id));
+    push_break(&mut res);
+    res.push_str(&format!(
+        "Sent: {} {}, Fees: {}",
+        result.amount, result.unit, result.fees
+    ));
 
     Ok(res)
 }
@@ -332,7 +353,7 @@ pub async fn cmd_melt(
 pub async fn cmd_mint(app_state: &AppState, name: &str, id: usize, amount: u64) -> Result<String> {
     let mut res = String::new();
 
-    let mint_summary = app_state.wallet_mint(id, amount).await?;
+    let mint_summary = app_state.wallet_mint_onchain(id, amount).await?;
 
     push_break(&mut res);
     push_break(&mut res);
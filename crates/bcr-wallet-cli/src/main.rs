@@ -1,7 +1,12 @@
 use std::{path::PathBuf, str::FromStr};
 
 use anyhow::Result;
-use bcr_wallet_api::{AppState, config::AppStateConfig, generate_random_mnemonic, is_valid_token};
+use bcr_wallet_api::{
+    AppState,
+    config::{AppStateConfig, DEFAULT_KEYSETS_TTL_SECONDS},
+    generate_random_mnemonic, is_valid_token,
+};
+use bcr_wallet_core::types::Redacted;
 use clap::{Parser, Subcommand};
 use nostr_sdk::RelayUrl;
 use serde::{Deserialize, Serialize};
@@ -16,7 +21,9 @@ mod command;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletSettings {
     pub mint_url: bcr_common::cashu::MintUrl,
-    pub mnemonic: bip39::Mnemonic,
+    pub mnemonic: Redacted<bip39::Mnemonic>,
+    #[serde(default)]
+    pub passphrase: Option<Redacted<String>>,
     pub log_level: String,
     pub db_path: PathBuf,
     pub network: bitcoin::Network,
@@ -124,8 +131,10 @@ async fn main() -> Result<()> {
         network: settings.network,
         nostr_relays: settings.nostr_relays.clone(),
         mnemonic: settings.mnemonic.clone(),
+        passphrase: settings.passphrase.clone(),
         default_mint_url: settings.mint_url.clone(),
         swap_expiry: chrono::TimeDelta::minutes(15),
+        keysets_ttl: chrono::TimeDelta::seconds(DEFAULT_KEYSETS_TTL_SECONDS),
         dev_mode: true,
     };
     let app_state = AppState::initialize(app_state_cfg).await?;
@@ -1,5 +1,5 @@
 use bcr_common::{
-    cashu::{Amount, CurrencyUnit, KeySetInfo, MintUrl},
+    cashu::{Amount, CurrencyUnit, Id, KeySetInfo, MintUrl, SpendingConditions},
     cdk_common::wallet::TransactionId,
 };
 use bitcoin::{address::NetworkUnchecked, secp256k1};
@@ -8,8 +8,53 @@ use uuid::Uuid;
 
 pub type Seed = [u8; 64];
 
+// a secret that never shows up in logs, `expose` where the value is really needed
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Redacted<T>(T);
+
+impl<T> Redacted<T> {
+    pub fn new(secret: T) -> Self {
+        Self(secret)
+    }
+
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Redacted<T> {
+    fn from(secret: T) -> Self {
+        Self(secret)
+    }
+}
+
+impl<T> std::fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl<T> std::fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
 pub type PaymentResultCallback = Arc<dyn Fn(Option<TransactionId>) + Send + Sync + 'static>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestoreProgress {
+    pub keyset_id: Id,
+    // counter of the first secret in the batch
+    pub batch_start: u32,
+    pub restored_in_batch: usize,
+    // restored so far for this keyset
+    pub cumulative: usize,
+}
+
+pub type RestoreProgressCallback = Arc<dyn Fn(RestoreProgress) + Send + Sync + 'static>;
+
 #[derive(Default, Debug, Clone)]
 pub struct SendSummary {
     pub request_id: Uuid,
@@ -17,6 +62,45 @@ pub struct SendSummary {
     pub unit: CurrencyUnit,
     pub swap_fees: Amount,
     pub send_fees: Amount,
+    // the local proofs don't add up to the amount, one of them is swapped first
+    pub needs_swap: bool,
+    // set if the sent proofs are locked to these conditions
+    pub conditions: Option<SpendingConditions>,
+    pub scheme: SecretScheme,
+}
+
+/// How the secrets of newly created proofs are derived
+/// random secrets are unlinkable, but can't be restored from the seed (NUT-13)
+#[derive(strum::EnumString, strum::Display, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SecretScheme {
+    #[default]
+    Deterministic,
+    Random,
+}
+
+/// Which local proofs a send prefers, before falling back to a swap
+#[derive(
+    strum::EnumString,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+)]
+pub enum CoinSelectionStrategy {
+    // greedy from the largest proof, fewer and bigger proofs in the token
+    LargestFirst,
+    // greedy from the smallest proof, spending the small change first
+    SmallestFirst,
+    // searches for any exact subset
+    #[default]
+    MinimizeSwaps,
+    // searches for the exact subset with the fewest proofs
+    MinimizeProofCount,
 }
 
 impl SendSummary {
@@ -32,6 +116,7 @@ impl SendSummary {
 pub struct WalletConfig {
     pub wallet_id: String,
     pub name: String,
+    // fixed at creation, loading a wallet with a differently configured network fails
     pub network: bitcoin::Network,
     pub mint: MintUrl,
     pub mint_keyset_infos: Vec<KeySetInfo>,
@@ -39,6 +124,9 @@ pub struct WalletConfig {
     pub debit: CurrencyUnit,
     pub pub_key: secp256k1::PublicKey,
     pub betas: Vec<MintUrl>,
+    pub coin_selection: CoinSelectionStrategy,
+    // keeps the token of a send in its transaction, opt-in as it holds spendable proofs
+    pub keep_sent_tokens: bool,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -68,6 +156,16 @@ pub struct MintSummary {
     pub expiry: u64,
 }
 
+#[derive(Debug, Clone)]
+pub struct MintQuoteSummary {
+    pub quote_id: String,
+    // the bolt11 invoice to pay
+    pub request: String,
+    pub amount: Amount,
+    pub unit: CurrencyUnit,
+    pub expiry: Option<u64>,
+}
+
 #[derive(strum::EnumString, strum::Display, Debug, Clone, Copy, Default)]
 pub enum PaymentType {
     #[default]
@@ -76,6 +174,7 @@ pub enum PaymentType {
     Cdk18,
     OnChain,
     Swap,
+    Bolt11,
 }
 
 #[derive(Debug, Clone)]
@@ -87,9 +186,19 @@ pub struct PaymentSummary {
     pub reserved_fees: Amount,
     pub expiry: u64,
     pub ptype: PaymentType,
+    pub conditions: Option<SpendingConditions>,
 }
 
-#[derive(strum::Display, strum::EnumString, Debug, Clone, Copy, Default)]
+#[derive(
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+)]
 pub enum TransactionStatus {
     #[default]
     NotApplicable,
@@ -114,6 +223,23 @@ pub fn get_payment_type(metas: &HashMap<String, String>) -> PaymentType {
     PaymentType::from_str(ptype).unwrap_or(PaymentType::NotApplicable)
 }
 
+pub const SECRET_SCHEME_METADATA_KEY: &str = "secret_scheme";
+pub fn get_secret_scheme(metas: &HashMap<String, String>) -> SecretScheme {
+    let Some(scheme) = metas.get(SECRET_SCHEME_METADATA_KEY) else {
+        return SecretScheme::default();
+    };
+    SecretScheme::from_str(scheme).unwrap_or_default()
+}
+
+pub const TOKEN_METADATA_KEY: &str = "token";
+// emptied once the transaction is no longer pending
+pub fn get_token(metas: &HashMap<String, String>) -> Option<String> {
+    metas
+        .get(TOKEN_METADATA_KEY)
+        .filter(|token| !token.is_empty())
+        .cloned()
+}
+
 pub const BTC_ALPHA_TX_ID_TYPE_METADATA_KEY: &str = "btc_alpha_tx_id";
 pub fn get_btc_alpha_tx_id(metas: &HashMap<String, String>) -> Option<bitcoin::Txid> {
     let tx_id = metas.get(BTC_ALPHA_TX_ID_TYPE_METADATA_KEY)?;
@@ -136,6 +262,7 @@ impl std::convert::From<SendSummary> for PaymentSummary {
             reserved_fees: Amount::ZERO,
             expiry: 0,
             ptype: PaymentType::Token,
+            conditions: value.conditions,
         }
     }
 }
@@ -150,6 +277,7 @@ impl std::convert::From<MeltSummary> for PaymentSummary {
             reserved_fees: value.reserved_fees,
             expiry: value.expiry,
             ptype: PaymentType::OnChain,
+            conditions: None,
         }
     }
 }
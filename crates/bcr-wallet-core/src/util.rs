@@ -15,8 +15,9 @@ pub fn build_wallet_id(seed: &Seed) -> String {
         .to_string()
 }
 
-pub fn seed_from_mnemonic(mnemonic: &bip39::Mnemonic) -> Seed {
-    mnemonic.to_seed("")
+// the passphrase is empty if none was set
+pub fn seed_from_mnemonic(mnemonic: &bip39::Mnemonic, passphrase: &str) -> Seed {
+    mnemonic.to_seed(passphrase)
 }
 
 pub fn keypair_from_seed(seed: Seed) -> Keypair {
@@ -24,7 +25,19 @@ pub fn keypair_from_seed(seed: Seed) -> Keypair {
     Keypair::from_seckey_slice(SECP256K1, key).expect("key to be correct size")
 }
 
-pub fn keypair_from_mnemonic(mnemonic: &bip39::Mnemonic) -> Keypair {
-    let seed = seed_from_mnemonic(mnemonic);
+pub fn keypair_from_mnemonic(mnemonic: &bip39::Mnemonic, passphrase: &str) -> Keypair {
+    let seed = seed_from_mnemonic(mnemonic, passphrase);
     keypair_from_seed(seed)
 }
+
+pub const TEASER_SIZE: usize = 20;
+
+// the start of a token or request for errors and logs, never the whole secret
+pub fn teaser(s: &str) -> String {
+    let mut chars = s.chars();
+    let mut teaser: String = chars.by_ref().take(TEASER_SIZE).collect();
+    if chars.next().is_some() {
+        teaser.push('…');
+    }
+    teaser
+}
@@ -1,5 +1,5 @@
 use bcr_wallet_core::types::{
-    PaymentResultCallback, get_btc_alpha_tx_id, get_btc_beta_tx_id, get_payment_type,
+    PaymentResultCallback, Redacted, get_btc_alpha_tx_id, get_btc_beta_tx_id, get_payment_type,
     get_transaction_status,
 };
 use nostr_sdk::RelayUrl;
@@ -13,7 +13,11 @@ use bcr_common::{
     cashu::{self, MintUrl},
     cdk_common,
 };
-use bcr_wallet_api::{AppState, config::AppStateConfig, error::Error as BcrWalletError};
+use bcr_wallet_api::{
+    AppState,
+    config::{AppStateConfig, DEFAULT_KEYSETS_TTL_SECONDS},
+    error::Error as BcrWalletError,
+};
 use flutter_rust_bridge::{DartFnFuture, JoinHandle, frb};
 use log::{error, info};
 use tokio::sync::Mutex;
@@ -65,7 +69,7 @@ async fn reset_runtime(rt: &mut WalletRuntime) {
 
 // ------------------------------------------------------------- Initialization
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct WalletFfiConfig {
     // Path to the DB file
     pub db_folder_path: String,
@@ -81,6 +85,8 @@ pub struct WalletFfiConfig {
     pub bitcoin_network: String,
     // The mnemonic to use
     pub mnemonic: String,
+    // The optional BIP39 passphrase, needed on every start, it is never stored
+    pub passphrase: Option<String>,
     // The nostr relays to use
     pub nostr_relays: Vec<String>,
     // Swap commitment expiry in minutes
@@ -89,6 +95,25 @@ pub struct WalletFfiConfig {
     pub dev_mode: bool,
 }
 
+// the mnemonic is left out, so the config can be logged
+impl std::fmt::Debug for WalletFfiConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WalletFfiConfig")
+            .field("db_folder_path", &self.db_folder_path)
+            .field("log_level", &self.log_level)
+            .field("job_interval_secs", &self.job_interval_secs)
+            .field("job_initial_delay_secs", &self.job_initial_delay_secs)
+            .field("default_mint_url", &self.default_mint_url)
+            .field("bitcoin_network", &self.bitcoin_network)
+            .field("mnemonic", &Redacted::new(&self.mnemonic))
+            .field("passphrase", &self.passphrase.as_ref().map(Redacted::new))
+            .field("nostr_relays", &self.nostr_relays)
+            .field("swap_expiry_minutes", &self.swap_expiry_minutes)
+            .field("dev_mode", &self.dev_mode)
+            .finish()
+    }
+}
+
 #[frb]
 pub async fn init_wallet_ffi(conf: WalletFfiConfig) {
     info!("Initializing Rust Wallet FFI");
@@ -131,8 +156,10 @@ pub async fn init_wallet_ffi(conf: WalletFfiConfig) {
         db_path: parsed_path,
         network: parsed_network,
         nostr_relays: parsed_nostr_relays,
-        mnemonic: parsed_mnemonic,
+        mnemonic: Redacted::new(parsed_mnemonic),
+        passphrase: conf.passphrase.map(Redacted::new),
         swap_expiry,
+        keysets_ttl: chrono::TimeDelta::seconds(DEFAULT_KEYSETS_TTL_SECONDS),
         default_mint_url: parsed_url,
         dev_mode: conf.dev_mode,
     };
@@ -245,6 +272,29 @@ pub async fn wallet_restore() -> Result<RestoreWalletResponse, WalletError> {
     Ok(RestoreWalletResponse { wallet_id })
 }
 
+// WARN: the backup holds spendable ecash, protected only by the passphrase
+#[frb]
+pub async fn export_wallet_backup(
+    req: ExportWalletBackupRequest,
+) -> Result<ExportWalletBackupResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let backup = app_state
+        .wallet_export_backup(req.wallet_id, req.passphrase)
+        .await?;
+    Ok(ExportWalletBackupResponse { backup })
+}
+
+#[frb]
+pub async fn import_wallet_backup(
+    req: ImportWalletBackupRequest,
+) -> Result<RestoreWalletResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let wallet_id = app_state
+        .purse_import_wallet_backup(req.backup, req.passphrase)
+        .await?;
+    Ok(RestoreWalletResponse { wallet_id })
+}
+
 #[frb]
 pub async fn wallet_delete(req: WalletRequest) -> Result<(), WalletError> {
     let app_state = get_app_state().await;
@@ -282,8 +332,8 @@ pub async fn wallet_get_balance(req: WalletRequest) -> Result<WalletBalanceRespo
     let app_state = get_app_state().await;
     let balance = app_state.wallet_balance(req.wallet_id).await?;
     Ok(WalletBalanceResponse {
-        debit: u64::from(balance.debit),
-        credit: u64::from(balance.credit),
+        debit: u64::from(balance.debit_available),
+        credit: u64::from(balance.credit_available),
         total: u64::from(balance.total),
     })
 }
@@ -291,13 +341,17 @@ pub async fn wallet_get_balance(req: WalletRequest) -> Result<WalletBalanceRespo
 #[frb]
 pub async fn wallet_receive(
     req: WalletReceiveRequest,
-) -> Result<WalletTransactionIdResponse, WalletError> {
+) -> Result<WalletReceiveResponse, WalletError> {
     let app_state = get_app_state().await;
-    let tx_id = app_state
+    let result = app_state
         .wallet_receive_token(req.wallet_id, req.token)
         .await?;
-    Ok(WalletTransactionIdResponse {
-        tx_id: tx_id.to_string(),
+    Ok(WalletReceiveResponse {
+        tx_id: result.tx_id.to_string(),
+        received: u64::from(result.received),
+        stored: u64::from(result.stored),
+        fee: u64::from(result.fee),
+        unit: result.unit.to_string(),
     })
 }
 
@@ -332,6 +386,162 @@ pub async fn wallet_refresh_transactions(
     Ok(WalletRefreshTransactionsResponse { updated })
 }
 
+#[frb]
+pub async fn wallet_get_pending_transactions(
+    req: WalletRequest,
+) -> Result<WalletPendingTransactionsResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let pending = app_state.wallet_list_pending_txs(req.wallet_id).await?;
+    Ok(WalletPendingTransactionsResponse {
+        txs: pending
+            .into_iter()
+            .map(|p| PendingTransaction {
+                transaction: p.tx.into(),
+                pending_proofs: p.pending_ys.len(),
+                unsent: p.unsent,
+            })
+            .collect(),
+    })
+}
+
+#[frb]
+pub async fn wallet_retry_pending_transaction(
+    req: WalletTransactionRequest,
+) -> Result<WalletRetryPendingTransactionResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let retried = app_state
+        .wallet_retry_pending_tx(req.wallet_id, &req.tx_id)
+        .await?;
+    Ok(WalletRetryPendingTransactionResponse { retried })
+}
+
+#[frb]
+pub async fn wallet_confirm_nut18_delivery(
+    req: WalletConfirmNut18DeliveryRequest,
+) -> Result<WalletConfirmNut18DeliveryResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let delivered = app_state
+        .wallet_confirm_nut18_delivery(req.wallet_id, &req.tx_id, req.timeout_secs)
+        .await?;
+    Ok(WalletConfirmNut18DeliveryResponse { delivered })
+}
+
+#[frb]
+pub async fn wallet_get_transaction_token(
+    req: WalletTransactionRequest,
+) -> Result<WalletTransactionTokenResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let token = app_state
+        .wallet_get_tx_token(req.wallet_id, &req.tx_id)
+        .await?;
+    Ok(WalletTransactionTokenResponse { token })
+}
+
+#[frb]
+pub async fn wallet_get_nprofile(
+    req: WalletRequest,
+) -> Result<WalletNprofileResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let nprofile = app_state.wallet_get_nprofile(req.wallet_id).await?;
+    Ok(WalletNprofileResponse { nprofile })
+}
+
+#[frb]
+pub async fn wallet_clean_local_db(
+    req: WalletCleanLocalDbRequest,
+) -> Result<WalletCleanLocalDbResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let cleanup = app_state
+        .wallet_clean_local_db(req.wallet_id, req.prune_counters)
+        .await?;
+    Ok(WalletCleanLocalDbResponse {
+        cleaned_proofs: cleanup.proofs_removed as u32,
+        pruned_counters: cleanup.counters_removed as u32,
+    })
+}
+
+#[frb]
+pub async fn wallet_release_reservations(
+    req: WalletRequest,
+) -> Result<WalletReleaseReservationsResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let released = app_state.wallet_release_reservations(req.wallet_id).await?;
+    Ok(WalletReleaseReservationsResponse {
+        released: released as u32,
+    })
+}
+
+#[frb]
+pub async fn wallet_recheck(req: WalletRequest) -> Result<WalletRecheckResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let summary = app_state.wallet_recheck(req.wallet_id).await?;
+    Ok(WalletRecheckResponse {
+        deleted: summary.deleted as u32,
+        restored: summary.restored as u32,
+        pending: summary.pending as u32,
+    })
+}
+
+#[frb]
+pub async fn wallet_stats(req: WalletRequest) -> Result<WalletStatsResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let stats = app_state.wallet_stats(req.wallet_id).await?;
+    Ok(WalletStatsResponse {
+        unspent_proofs: stats.debit.unspent as u32,
+        pending_proofs: stats.debit.pending as u32,
+        reserved_proofs: stats.debit.reserved as u32,
+        total_proofs: stats.debit.total as u32,
+        keysets: stats.debit.keysets as u32,
+        random_proofs: stats.debit.random as u32,
+        transactions: stats.transactions as u32,
+        oldest_tx: stats.oldest_tx,
+    })
+}
+
+// WARN: the token stays spendable by the wallet, spending both double-spends
+#[frb]
+pub async fn wallet_export_balance_token(
+    req: WalletExportBalanceTokenRequest,
+) -> Result<WalletExportBalanceTokenResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let unit = cashu::CurrencyUnit::from_str(&req.unit)
+        .map_err(|_| BcrWalletError::InvalidCurrencyUnit(req.unit.clone()))?;
+    let token = app_state
+        .wallet_export_balance_token(req.wallet_id, unit)
+        .await?;
+    Ok(WalletExportBalanceTokenResponse { token })
+}
+
+#[frb]
+pub async fn wallet_mint_capabilities(
+    req: WalletRequest,
+) -> Result<WalletMintCapabilitiesResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let caps = app_state.wallet_mint_capabilities(req.wallet_id).await?;
+    Ok(WalletMintCapabilitiesResponse {
+        mint_enabled: caps.mint_enabled,
+        melt_enabled: caps.melt_enabled,
+        token_state_check: caps.token_state_check,
+        restore: caps.restore,
+        p2pk: caps.p2pk,
+        dleq: caps.dleq,
+        htlc: caps.htlc,
+        mpp: caps.mpp,
+        websocket: caps.websocket,
+    })
+}
+
+#[frb]
+pub async fn wallet_set_keep_sent_tokens(
+    req: WalletKeepSentTokensRequest,
+) -> Result<(), WalletError> {
+    let app_state = get_app_state().await;
+    app_state
+        .wallet_set_keep_sent_tokens(req.wallet_id, req.keep)
+        .await?;
+    Ok(())
+}
+
 #[frb]
 pub async fn wallet_reclaim_transaction(
     req: WalletReclaimTransactionRequest,
@@ -345,6 +555,19 @@ pub async fn wallet_reclaim_transaction(
     })
 }
 
+#[frb]
+pub async fn wallet_reclaim_funds(
+    req: WalletRequest,
+) -> Result<WalletBalanceResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let balance = app_state.wallet_reclaim_funds(req.wallet_id).await?;
+    Ok(WalletBalanceResponse {
+        debit: u64::from(balance.debit_available),
+        credit: u64::from(balance.credit_available),
+        total: u64::from(balance.total),
+    })
+}
+
 #[frb]
 pub async fn wallet_recover_pending_stale_proofs(
     req: WalletRequest,
@@ -393,9 +616,13 @@ pub async fn wallet_melt(
 }
 
 #[frb]
-pub async fn wallet_mint(req: WalletMintRequest) -> Result<WalletMintSummaryResponse, WalletError> {
+pub async fn wallet_mint_onchain(
+    req: WalletMintRequest,
+) -> Result<WalletMintSummaryResponse, WalletError> {
     let app_state = get_app_state().await;
-    let mint_summary = app_state.wallet_mint(req.wallet_id, req.amount).await?;
+    let mint_summary = app_state
+        .wallet_mint_onchain(req.wallet_id, req.amount)
+        .await?;
     Ok(WalletMintSummaryResponse {
         quote_id: mint_summary.quote_id.to_string(),
         amount: mint_summary.amount.to_sat(),
@@ -428,11 +655,15 @@ pub async fn wallet_prepare_payment(
 }
 
 #[frb]
-pub async fn wallet_pay(req: WalletPayRequest) -> Result<WalletTransactionIdResponse, WalletError> {
+pub async fn wallet_pay(req: WalletPayRequest) -> Result<WalletSendResultResponse, WalletError> {
     let app_state = get_app_state().await;
-    let tx_id = app_state.wallet_pay(req.wallet_id, req.rid).await?;
-    Ok(WalletTransactionIdResponse {
-        tx_id: tx_id.to_string(),
+    let res = app_state.wallet_pay(req.wallet_id, req.rid).await?;
+    Ok(WalletSendResultResponse {
+        tx_id: res.tx_id.to_string(),
+        token: res.token.map(|token| token.to_string()),
+        amount: u64::from(res.amount),
+        fees: u64::from(res.fees),
+        unit: res.unit.to_string(),
     })
 }
 
@@ -459,6 +690,31 @@ pub async fn wallet_prepare_pay_by_token(
     })
 }
 
+#[frb]
+pub async fn wallet_prepare_send_max(
+    req: WalletPrepareSendMaxRequest,
+) -> Result<WalletPreparePaymentResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let unit = cashu::CurrencyUnit::from_str(&req.unit)
+        .map_err(|_| BcrWalletError::InvalidCurrencyUnit(req.unit.clone()))?;
+    let payment_summary = app_state
+        .wallet_prepare_send_max(req.wallet_id, unit, req.description)
+        .await?;
+    Ok(WalletPreparePaymentResponse {
+        payment_summary: PaymentSummary {
+            request_id: payment_summary.request_id.to_string(),
+            unit: payment_summary.unit.to_string(),
+            amount: u64::from(payment_summary.amount),
+            fees: u64::from(payment_summary.fees),
+            reserved_fees: u64::from(payment_summary.reserved_fees),
+            expiry: payment_summary.expiry,
+            ptype: PaymentType::from(bcr_wallet_core::types::PaymentType::from(
+                payment_summary.ptype,
+            )),
+        },
+    })
+}
+
 #[frb]
 pub async fn wallet_pay_by_token(
     req: WalletPaymentByTokenRequest,
@@ -467,9 +723,16 @@ pub async fn wallet_pay_by_token(
     let res = app_state
         .wallet_pay_by_token(req.wallet_id, req.rid)
         .await?;
+    // only a prepared token payment yields a token
+    let token = res
+        .token
+        .ok_or_else(|| WalletError::internal(format!("payment {} returned no token", res.tx_id)))?;
     Ok(WalletPaymentByTokenResponse {
         tx_id: res.tx_id.to_string(),
-        token: res.token.to_string(),
+        token: token.to_string(),
+        amount: u64::from(res.amount),
+        fees: u64::from(res.fees),
+        unit: res.unit.to_string(),
     })
 }
 
@@ -614,6 +877,22 @@ pub async fn wallet_get_ids() -> Result<WalletsIdsResponse, WalletError> {
     Ok(WalletsIdsResponse { ids })
 }
 
+#[frb]
+pub async fn wallet_get_total_balance() -> Result<TotalBalanceResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let balances = app_state.purse_total_balance().await?;
+    Ok(TotalBalanceResponse {
+        balances: balances
+            .into_iter()
+            .map(|b| UnitBalance {
+                unit: b.unit.to_string(),
+                debit: u64::from(b.debit),
+                credit: u64::from(b.credit),
+            })
+            .collect(),
+    })
+}
+
 #[frb]
 pub async fn wallet_dev_mode_get_detailed_balance(
     req: WalletRequest,
@@ -642,6 +921,18 @@ pub async fn generate_random_mnemonic(
     Ok(MnemonicResponse { mnemonic })
 }
 
+#[frb]
+pub async fn validate_mnemonic(
+    req: ValidateMnemonicRequest,
+) -> Result<ValidateMnemonicResponse, WalletError> {
+    let check = bcr_wallet_api::validate_mnemonic(&req.mnemonic);
+    Ok(ValidateMnemonicResponse {
+        valid: check.valid,
+        word_count: check.word_count,
+        error: check.error,
+    })
+}
+
 #[frb]
 pub async fn is_valid_token(req: IsValidTokenRequest) -> Result<IsValidTokenResponse, WalletError> {
     let token = bcr_wallet_api::is_valid_token(&req.token)?;
@@ -690,6 +981,232 @@ pub async fn wallet_mint_is_rabid(req: WalletRequest) -> Result<MintIsRabidRespo
     Ok(MintIsRabidResponse { rabid: is_rabid })
 }
 
+#[frb]
+pub async fn wallet_list_tx_since(
+    req: WalletTransactionsSinceRequest,
+) -> Result<WalletTransactionsSinceResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let (changes, seq) = app_state
+        .wallet_list_tx_since(req.wallet_id, req.seq)
+        .await?;
+    Ok(WalletTransactionsSinceResponse {
+        txs: changes.updated.into_iter().map(|t| t.into()).collect(),
+        deleted: changes.deleted.iter().map(|id| id.to_string()).collect(),
+        seq,
+    })
+}
+
+#[frb]
+pub async fn wallet_prepare_send_locked(
+    req: WalletPrepareSendLockedRequest,
+) -> Result<WalletPreparePaymentResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let unit = cashu::CurrencyUnit::from_str(&req.unit)
+        .map_err(|_| BcrWalletError::InvalidCurrencyUnit(req.unit.clone()))?;
+    let payment_summary = app_state
+        .wallet_prepare_send_locked(
+            req.wallet_id,
+            req.amount,
+            unit,
+            req.pubkey,
+            req.locktime,
+            req.refund_key,
+            req.description,
+        )
+        .await?;
+    Ok(WalletPreparePaymentResponse {
+        payment_summary: PaymentSummary {
+            request_id: payment_summary.request_id.to_string(),
+            unit: payment_summary.unit.to_string(),
+            amount: u64::from(payment_summary.amount),
+            fees: u64::from(payment_summary.fees),
+            reserved_fees: u64::from(payment_summary.reserved_fees),
+            expiry: payment_summary.expiry,
+            ptype: PaymentType::from(bcr_wallet_core::types::PaymentType::from(
+                payment_summary.ptype,
+            )),
+        },
+    })
+}
+
+#[frb]
+pub async fn wallet_receive_multisig_token(
+    req: WalletReceiveRequest,
+) -> Result<WalletReceiveMultisigTokenResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let (tx_id, amount, ys) = app_state
+        .wallet_receive_multisig_token(req.wallet_id, req.token)
+        .await?;
+    Ok(WalletReceiveMultisigTokenResponse {
+        tx_id: tx_id.to_string(),
+        amount: u64::from(amount),
+        ys: ys.into_iter().map(|y| y.to_string()).collect(),
+    })
+}
+
+#[frb]
+pub async fn wallet_partial_sign_proof(
+    req: WalletPartialSignProofRequest,
+) -> Result<WalletPartialSignProofResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let witness = app_state
+        .wallet_partial_sign_proof(req.wallet_id, req.y)
+        .await?;
+    let witness = serde_json::to_string(&witness).map_err(BcrWalletError::SerdeJson)?;
+    Ok(WalletPartialSignProofResponse { witness })
+}
+
+#[frb]
+pub async fn wallet_combine_partial_signatures(
+    req: WalletCombinePartialSignaturesRequest,
+) -> Result<WalletCombinePartialSignaturesResponse, WalletError> {
+    let witnesses = req
+        .witnesses
+        .iter()
+        .map(|witness| serde_json::from_str(witness))
+        .collect::<std::result::Result<Vec<cashu::Witness>, _>>()
+        .map_err(BcrWalletError::SerdeJson)?;
+    let app_state = get_app_state().await;
+    let spendable = app_state
+        .wallet_combine_partial_signatures(req.wallet_id, req.y, witnesses)
+        .await?;
+    Ok(WalletCombinePartialSignaturesResponse { spendable })
+}
+
+#[frb]
+pub async fn wallet_prepare_mint(
+    req: WalletMintRequest,
+) -> Result<WalletMintQuoteResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let quote = app_state
+        .wallet_prepare_mint(req.wallet_id, req.amount)
+        .await?;
+    Ok(WalletMintQuoteResponse {
+        quote_id: quote.quote_id,
+        request: quote.request,
+        amount: u64::from(quote.amount),
+        unit: quote.unit.to_string(),
+        expiry: quote.expiry,
+    })
+}
+
+#[frb]
+pub async fn wallet_mint(
+    req: WalletMintBolt11Request,
+) -> Result<WalletTransactionIdResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let tx_id = app_state.wallet_mint(req.wallet_id, req.quote_id).await?;
+    Ok(WalletTransactionIdResponse {
+        tx_id: tx_id.to_string(),
+    })
+}
+
+#[frb]
+pub async fn wallet_get_keyset_fees(
+    req: WalletRequest,
+) -> Result<WalletKeysetFeesResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let fees = app_state.wallet_keyset_fees(req.wallet_id).await?;
+    Ok(WalletKeysetFeesResponse {
+        fees: fees
+            .into_iter()
+            .map(|(kid, input_fee_ppk)| KeysetFee {
+                kid: kid.to_string(),
+                input_fee_ppk,
+            })
+            .collect(),
+    })
+}
+
+#[frb]
+pub async fn wallet_export_receipt(
+    req: WalletTransactionRequest,
+) -> Result<WalletExportReceiptResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let receipt = app_state
+        .wallet_export_receipt(req.wallet_id, &req.tx_id)
+        .await?;
+    Ok(WalletExportReceiptResponse { receipt })
+}
+
+#[frb]
+pub async fn wallet_get_credit_balance_by_keyset(
+    req: WalletRequest,
+) -> Result<WalletDevModeDetailedBalanceResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let balance = app_state
+        .wallet_credit_balance_by_keyset(req.wallet_id)
+        .await?;
+    Ok(WalletDevModeDetailedBalanceResponse {
+        entries: balance
+            .into_iter()
+            .map(|entry| WalletDevModeDetailedBalanceEntry {
+                kid: entry.kid.to_string(),
+                final_expiry: entry.final_expiry,
+                amount: u64::from(entry.amount),
+            })
+            .collect(),
+    })
+}
+
+#[frb]
+pub async fn wallet_inspect_payment_request(
+    req: WalletPreparePaymentRequest,
+) -> Result<WalletInspectPaymentRequestResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let info = app_state
+        .wallet_inspect_payment_request(req.wallet_id, req.input)
+        .await?;
+    Ok(WalletInspectPaymentRequestResponse { info })
+}
+
+#[frb]
+pub async fn wallet_restore_keyset(
+    req: WalletRestoreKeysetRequest,
+) -> Result<WalletRestoreKeysetResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let keyset_id = cashu::Id::from_str(&req.keyset_id)
+        .map_err(|e| WalletError::bad_request(e.to_string(), WalletErrorCode::UnknownKeysetId))?;
+    let restored = app_state
+        .wallet_restore_keyset(req.wallet_id, keyset_id)
+        .await?;
+    Ok(WalletRestoreKeysetResponse {
+        restored: restored as u32,
+    })
+}
+
+// WARN: the blob holds spendable ecash, protected only by the passphrase
+#[frb]
+pub async fn wallet_export_proofs_encrypted(
+    req: WalletExportProofsEncryptedRequest,
+) -> Result<WalletExportProofsEncryptedResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let blob = app_state
+        .wallet_export_proofs_encrypted(req.wallet_id, req.passphrase)
+        .await?;
+    Ok(WalletExportProofsEncryptedResponse { blob })
+}
+
+#[frb]
+pub async fn wallet_import_proofs_encrypted(
+    req: WalletImportProofsEncryptedRequest,
+) -> Result<WalletImportProofsEncryptedResponse, WalletError> {
+    let app_state = get_app_state().await;
+    let imported = app_state
+        .wallet_import_proofs_encrypted(req.wallet_id, req.blob, req.passphrase)
+        .await?;
+    Ok(WalletImportProofsEncryptedResponse {
+        imported: imported as u32,
+    })
+}
+
+#[frb]
+pub async fn wallet_rename(req: WalletRenameRequest) -> Result<(), WalletError> {
+    let app_state = get_app_state().await;
+    app_state.wallet_rename(req.wallet_id, req.name).await?;
+    Ok(())
+}
+
 // -------------------------------------------------------------- Data types
 #[derive(Debug, Clone)]
 pub struct AddWalletResponse {
@@ -701,6 +1218,24 @@ pub struct RestoreWalletResponse {
     pub wallet_id: usize,
 }
 
+// no Debug, the passphrase must not end up in logs
+#[derive(Clone)]
+pub struct ExportWalletBackupRequest {
+    pub wallet_id: usize,
+    pub passphrase: String,
+}
+
+#[derive(Clone)]
+pub struct ExportWalletBackupResponse {
+    pub backup: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct ImportWalletBackupRequest {
+    pub backup: Vec<u8>,
+    pub passphrase: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletRequest {
     pub wallet_id: usize,
@@ -752,11 +1287,30 @@ pub struct WalletReceiveRequest {
     pub token: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct WalletReceiveResponse {
+    pub tx_id: String,
+    pub received: u64,
+    pub stored: u64,
+    pub fee: u64,
+    pub unit: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletTransactionIdResponse {
     pub tx_id: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct WalletSendResultResponse {
+    pub tx_id: String,
+    // only set when paying by token
+    pub token: Option<String>,
+    pub amount: u64,
+    pub fees: u64,
+    pub unit: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletTransactionIdsResponse {
     pub tx_ids: Vec<String>,
@@ -814,6 +1368,7 @@ pub enum PaymentType {
     Cdk18,
     OnChain,
     Swap,
+    Bolt11,
 }
 
 impl std::convert::From<bcr_wallet_core::types::PaymentType> for PaymentType {
@@ -824,6 +1379,7 @@ impl std::convert::From<bcr_wallet_core::types::PaymentType> for PaymentType {
             bcr_wallet_core::types::PaymentType::Cdk18 => PaymentType::Cdk18,
             bcr_wallet_core::types::PaymentType::OnChain => PaymentType::OnChain,
             bcr_wallet_core::types::PaymentType::Swap => PaymentType::Swap,
+            bcr_wallet_core::types::PaymentType::Bolt11 => PaymentType::Bolt11,
         }
     }
 }
@@ -949,6 +1505,79 @@ pub struct WalletRefreshTransactionsResponse {
     pub updated: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct WalletNprofileResponse {
+    pub nprofile: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletExportBalanceTokenRequest {
+    pub wallet_id: usize,
+    pub unit: String,
+}
+
+// no Debug, the token is spendable
+#[derive(Clone)]
+pub struct WalletExportBalanceTokenResponse {
+    pub token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletMintCapabilitiesResponse {
+    pub mint_enabled: bool,
+    pub melt_enabled: bool,
+    pub token_state_check: bool,
+    pub restore: bool,
+    pub p2pk: bool,
+    pub dleq: bool,
+    pub htlc: bool,
+    pub mpp: bool,
+    pub websocket: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletTransactionTokenResponse {
+    // none unless the wallet keeps its sent tokens and the transaction is pending
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletKeepSentTokensRequest {
+    pub wallet_id: usize,
+    pub keep: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    pub transaction: Transaction,
+    // proofs of the transaction still pending-spent in the wallet
+    pub pending_proofs: usize,
+    // the payment didn't reach the receiver, retrying sends it again
+    pub unsent: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletPendingTransactionsResponse {
+    pub txs: Vec<PendingTransaction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletRetryPendingTransactionResponse {
+    pub retried: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletConfirmNut18DeliveryRequest {
+    pub wallet_id: usize,
+    pub tx_id: String,
+    pub timeout_secs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletConfirmNut18DeliveryResponse {
+    pub delivered: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletPrepareMeltRequest {
     pub wallet_id: usize,
@@ -1047,9 +1676,42 @@ pub struct PaymentSummary {
     pub ptype: PaymentType,
 }
 
+#[derive(Debug, Clone)]
+pub struct WalletCleanLocalDbRequest {
+    pub wallet_id: usize,
+    // also removes the counters of keysets the mint no longer lists and without stored proofs
+    pub prune_counters: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletCleanLocalDbResponse {
     pub cleaned_proofs: u32,
+    pub pruned_counters: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletReleaseReservationsResponse {
+    pub released: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletRecheckResponse {
+    pub deleted: u32,
+    pub restored: u32,
+    pub pending: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletStatsResponse {
+    pub unspent_proofs: u32,
+    pub pending_proofs: u32,
+    pub reserved_proofs: u32,
+    pub total_proofs: u32,
+    pub keysets: u32,
+    // only a backup brings these back, restoring from the seed can't
+    pub random_proofs: u32,
+    pub transactions: u32,
+    pub oldest_tx: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -1062,6 +1724,19 @@ pub struct WalletsIdsResponse {
     pub ids: Vec<usize>,
 }
 
+#[derive(Debug, Clone)]
+pub struct UnitBalance {
+    pub unit: String,
+    pub debit: u64,
+    pub credit: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TotalBalanceResponse {
+    // one entry per unit, wallets of different units are not summed up
+    pub balances: Vec<UnitBalance>,
+}
+
 #[derive(Debug, Clone)]
 pub struct MnemonicRequest {
     pub length: u32,
@@ -1085,6 +1760,20 @@ pub struct MnemonicResponse {
     pub mnemonic: String,
 }
 
+// no Debug, the mnemonic must not end up in logs
+#[derive(Clone)]
+pub struct ValidateMnemonicRequest {
+    pub mnemonic: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidateMnemonicResponse {
+    pub valid: bool,
+    pub word_count: u32,
+    // why the mnemonic is not valid
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct StatusResponse {
     pub app_version: String,
@@ -1124,6 +1813,13 @@ pub struct WalletPreparePaymentByTokenRequest {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct WalletPrepareSendMaxRequest {
+    pub wallet_id: usize,
+    pub unit: String,
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletPaymentByTokenRequest {
     pub wallet_id: usize,
@@ -1134,6 +1830,153 @@ pub struct WalletPaymentByTokenRequest {
 pub struct WalletPaymentByTokenResponse {
     pub tx_id: String,
     pub token: String,
+    pub amount: u64,
+    pub fees: u64,
+    pub unit: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletTransactionsSinceRequest {
+    pub wallet_id: usize,
+    // the marker returned by the previous call, 0 for every transaction
+    pub seq: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletTransactionsSinceResponse {
+    pub txs: Vec<Transaction>,
+    // ids of the transactions deleted since the marker
+    pub deleted: Vec<String>,
+    // the marker to pass on the next call
+    pub seq: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletPrepareSendLockedRequest {
+    pub wallet_id: usize,
+    pub amount: u64,
+    pub unit: String,
+    // the key the token gets locked to
+    pub pubkey: String,
+    // after which the refund key can spend the token too
+    pub locktime: Option<u64>,
+    pub refund_key: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletReceiveMultisigTokenResponse {
+    pub tx_id: String,
+    pub amount: u64,
+    // the locked proofs, to be signed with `wallet_partial_sign_proof`
+    pub ys: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletPartialSignProofRequest {
+    pub wallet_id: usize,
+    pub y: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletPartialSignProofResponse {
+    // the witness as JSON
+    pub witness: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletCombinePartialSignaturesRequest {
+    pub wallet_id: usize,
+    pub y: String,
+    // the witnesses of the other parties as JSON, as `wallet_partial_sign_proof` returns them
+    pub witnesses: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletCombinePartialSignaturesResponse {
+    // enough parties signed, the proof is part of the balance now
+    pub spendable: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletMintQuoteResponse {
+    pub quote_id: String,
+    // the bolt11 invoice to pay
+    pub request: String,
+    pub amount: u64,
+    pub unit: String,
+    pub expiry: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletMintBolt11Request {
+    pub wallet_id: usize,
+    pub quote_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeysetFee {
+    pub kid: String,
+    // in parts per thousand of each input
+    pub input_fee_ppk: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletKeysetFeesResponse {
+    pub fees: Vec<KeysetFee>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletExportReceiptResponse {
+    // the receipt as JSON
+    pub receipt: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletInspectPaymentRequestResponse {
+    // what the request asks for, as JSON
+    pub info: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletRestoreKeysetRequest {
+    pub wallet_id: usize,
+    pub keyset_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletRestoreKeysetResponse {
+    pub restored: u32,
+}
+
+// no Debug, the passphrase must not end up in logs
+#[derive(Clone)]
+pub struct WalletExportProofsEncryptedRequest {
+    pub wallet_id: usize,
+    pub passphrase: String,
+}
+
+#[derive(Clone)]
+pub struct WalletExportProofsEncryptedResponse {
+    pub blob: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct WalletImportProofsEncryptedRequest {
+    pub wallet_id: usize,
+    pub blob: Vec<u8>,
+    pub passphrase: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletImportProofsEncryptedResponse {
+    pub imported: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletRenameRequest {
+    pub wallet_id: usize,
+    pub name: String,
 }
 
 // -------------------------------------------------------------- Errors
@@ -1250,6 +2093,9 @@ impl From<BcrWalletError> for WalletError {
             BcrWalletError::InsufficientBalance(_, _) => {
                 WalletError::bad_request(value.to_string(), WalletErrorCode::InsufficientBalance)
             }
+            BcrWalletError::InsufficientFundsForFee { .. } => {
+                WalletError::bad_request(value.to_string(), WalletErrorCode::InsufficientBalance)
+            }
             BcrWalletError::InvalidSplitTarget => WalletError::internal(value.to_string()),
             BcrWalletError::ExcessiveSplitting(_) => WalletError::internal(value.to_string()),
             BcrWalletError::WalletNotFound(id) => {
@@ -1282,6 +2128,9 @@ impl From<BcrWalletError> for WalletError {
             BcrWalletError::InvalidNetwork(_, _) => {
                 WalletError::bad_request(value.to_string(), WalletErrorCode::InvalidNetwork)
             }
+            BcrWalletError::NetworkImmutable(_, _, _) => {
+                WalletError::bad_request(value.to_string(), WalletErrorCode::InvalidNetwork)
+            }
             BcrWalletError::MissingAmount => {
                 WalletError::bad_request(value.to_string(), WalletErrorCode::MissingAmount)
             }
@@ -1332,6 +2181,23 @@ impl From<BcrWalletError> for WalletError {
             BcrWalletError::Database(_) => WalletError::internal(value.to_string()),
             BcrWalletError::Swap(_) => WalletError::internal(value.to_string()),
             BcrWalletError::NoBetas => WalletError::internal(value.to_string()),
+            BcrWalletError::DuplicateKeysetId(_) => WalletError::internal(value.to_string()),
+            BcrWalletError::InvalidBackup(_) => WalletError::internal(value.to_string()),
+            BcrWalletError::PartialCompletion { .. } => WalletError::internal(value.to_string()),
+            BcrWalletError::RestoreInterrupted { .. } => WalletError::internal(value.to_string()),
+            BcrWalletError::SendAmountMismatch { .. } => WalletError::internal(value.to_string()),
+            BcrWalletError::SwapUnderSigned { .. } => WalletError::internal(value.to_string()),
+            BcrWalletError::OfflineSendNotPossible => WalletError {
+                kind: WalletErrorKind::Unsupported,
+                code: WalletErrorCode::Unsupported,
+                msg: value.to_string(),
+            },
+            BcrWalletError::AlreadyReceived(_) => {
+                WalletError::bad_request(value.to_string(), WalletErrorCode::InvalidToken)
+            }
+            BcrWalletError::InvalidProof(_) => {
+                WalletError::bad_request(value.to_string(), WalletErrorCode::InvalidToken)
+            }
             BcrWalletError::NoDevMode => {
                 WalletError::bad_request(value.to_string(), WalletErrorCode::NoDevMode)
             }
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -930769447;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 859003024;
 
 // Section: executor
 
@@ -97,6 +97,42 @@ fn wire__crate__api__WalletPaymentCheckHandle_cancel_impl(
         },
     )
 }
+fn wire__crate__api__export_wallet_backup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_wallet_backup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::ExportWalletBackupRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::export_wallet_backup(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__generate_random_mnemonic_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -133,6 +169,42 @@ fn wire__crate__api__generate_random_mnemonic_impl(
         },
     )
 }
+fn wire__crate__api__import_wallet_backup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_wallet_backup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::ImportWalletBackupRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::import_wallet_backup(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -370,6 +442,42 @@ fn wire__crate__api__transaction_status_default_impl(
         },
     )
 }
+fn wire__crate__api__validate_mnemonic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_mnemonic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::ValidateMnemonicRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::validate_mnemonic(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__wallet_add_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -457,6 +565,117 @@ let api_result_callback = decode_DartFn_Inputs_wallet_maybe_transaction_id_respo
                     })().await)
                 } })
 }
+fn wire__crate__api__wallet_clean_local_db_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_clean_local_db",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletCleanLocalDbRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_clean_local_db(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_combine_partial_signatures_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_combine_partial_signatures",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletCombinePartialSignaturesRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_combine_partial_signatures(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_confirm_nut18_delivery_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_confirm_nut18_delivery",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletConfirmNut18DeliveryRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_confirm_nut18_delivery(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__wallet_delete_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -668,7 +887,7 @@ fn wire__crate__api__wallet_error_not_found_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_balance_impl(
+fn wire__crate__api__wallet_export_balance_token_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -676,7 +895,7 @@ fn wire__crate__api__wallet_get_balance_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_balance",
+            debug_name: "wallet_export_balance_token",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -690,12 +909,13 @@ fn wire__crate__api__wallet_get_balance_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            let api_req =
+                <crate::api::WalletExportBalanceTokenRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_balance(api_req).await?;
+                        let output_ok = crate::api::wallet_export_balance_token(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -704,7 +924,7 @@ fn wire__crate__api__wallet_get_balance_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_currency_unit_impl(
+fn wire__crate__api__wallet_export_proofs_encrypted_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -712,7 +932,7 @@ fn wire__crate__api__wallet_get_currency_unit_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_currency_unit",
+            debug_name: "wallet_export_proofs_encrypted",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -726,12 +946,13 @@ fn wire__crate__api__wallet_get_currency_unit_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            let api_req =
+                <crate::api::WalletExportProofsEncryptedRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_currency_unit(api_req).await?;
+                        let output_ok = crate::api::wallet_export_proofs_encrypted(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -740,7 +961,7 @@ fn wire__crate__api__wallet_get_currency_unit_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_ids_impl(
+fn wire__crate__api__wallet_export_receipt_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -748,7 +969,7 @@ fn wire__crate__api__wallet_get_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_ids",
+            debug_name: "wallet_export_receipt",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -762,11 +983,12 @@ fn wire__crate__api__wallet_get_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletTransactionRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_ids().await?;
+                        let output_ok = crate::api::wallet_export_receipt(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -775,7 +997,7 @@ fn wire__crate__api__wallet_get_ids_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_mint_url_impl(
+fn wire__crate__api__wallet_get_balance_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -783,7 +1005,7 @@ fn wire__crate__api__wallet_get_mint_url_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_mint_url",
+            debug_name: "wallet_get_balance",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -802,7 +1024,7 @@ fn wire__crate__api__wallet_get_mint_url_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_mint_url(api_req).await?;
+                        let output_ok = crate::api::wallet_get_balance(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -811,7 +1033,7 @@ fn wire__crate__api__wallet_get_mint_url_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_name_impl(
+fn wire__crate__api__wallet_get_credit_balance_by_keyset_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -819,7 +1041,7 @@ fn wire__crate__api__wallet_get_name_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_name",
+            debug_name: "wallet_get_credit_balance_by_keyset",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -838,7 +1060,8 @@ fn wire__crate__api__wallet_get_name_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_name(api_req).await?;
+                        let output_ok =
+                            crate::api::wallet_get_credit_balance_by_keyset(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -847,7 +1070,7 @@ fn wire__crate__api__wallet_get_name_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_status_impl(
+fn wire__crate__api__wallet_get_currency_unit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -855,7 +1078,7 @@ fn wire__crate__api__wallet_get_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_status",
+            debug_name: "wallet_get_currency_unit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -869,11 +1092,12 @@ fn wire__crate__api__wallet_get_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_status().await?;
+                        let output_ok = crate::api::wallet_get_currency_unit(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -882,7 +1106,7 @@ fn wire__crate__api__wallet_get_status_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_transaction_ids_impl(
+fn wire__crate__api__wallet_get_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -890,7 +1114,7 @@ fn wire__crate__api__wallet_get_transaction_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_transaction_ids",
+            debug_name: "wallet_get_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -904,12 +1128,11 @@ fn wire__crate__api__wallet_get_transaction_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_transaction_ids(api_req).await?;
+                        let output_ok = crate::api::wallet_get_ids().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -918,7 +1141,7 @@ fn wire__crate__api__wallet_get_transaction_ids_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_transactions_impl(
+fn wire__crate__api__wallet_get_keyset_fees_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -926,7 +1149,7 @@ fn wire__crate__api__wallet_get_transactions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_transactions",
+            debug_name: "wallet_get_keyset_fees",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -945,7 +1168,7 @@ fn wire__crate__api__wallet_get_transactions_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_get_transactions(api_req).await?;
+                        let output_ok = crate::api::wallet_get_keyset_fees(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -954,7 +1177,7 @@ fn wire__crate__api__wallet_get_transactions_impl(
         },
     )
 }
-fn wire__crate__api__wallet_load_transaction_impl(
+fn wire__crate__api__wallet_get_mint_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -962,7 +1185,7 @@ fn wire__crate__api__wallet_load_transaction_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_load_transaction",
+            debug_name: "wallet_get_mint_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -976,12 +1199,12 @@ fn wire__crate__api__wallet_load_transaction_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletTransactionRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_load_transaction(api_req).await?;
+                        let output_ok = crate::api::wallet_get_mint_url(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -990,7 +1213,7 @@ fn wire__crate__api__wallet_load_transaction_impl(
         },
     )
 }
-fn wire__crate__api__wallet_melt_impl(
+fn wire__crate__api__wallet_get_name_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -998,7 +1221,7 @@ fn wire__crate__api__wallet_melt_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_melt",
+            debug_name: "wallet_get_name",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1012,12 +1235,12 @@ fn wire__crate__api__wallet_melt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletPayRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_melt(api_req).await?;
+                        let output_ok = crate::api::wallet_get_name(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1026,7 +1249,7 @@ fn wire__crate__api__wallet_melt_impl(
         },
     )
 }
-fn wire__crate__api__wallet_migrate_rabid_impl(
+fn wire__crate__api__wallet_get_nprofile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1034,7 +1257,7 @@ fn wire__crate__api__wallet_migrate_rabid_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_migrate_rabid",
+            debug_name: "wallet_get_nprofile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1048,11 +1271,12 @@ fn wire__crate__api__wallet_migrate_rabid_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_migrate_rabid().await?;
+                        let output_ok = crate::api::wallet_get_nprofile(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1061,7 +1285,7 @@ fn wire__crate__api__wallet_migrate_rabid_impl(
         },
     )
 }
-fn wire__crate__api__wallet_mint_impl(
+fn wire__crate__api__wallet_get_pending_transactions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1069,7 +1293,7 @@ fn wire__crate__api__wallet_mint_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_mint",
+            debug_name: "wallet_get_pending_transactions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1083,12 +1307,13 @@ fn wire__crate__api__wallet_mint_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletMintRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_mint(api_req).await?;
+                        let output_ok =
+                            crate::api::wallet_get_pending_transactions(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1097,7 +1322,7 @@ fn wire__crate__api__wallet_mint_impl(
         },
     )
 }
-fn wire__crate__api__wallet_mint_is_offline_impl(
+fn wire__crate__api__wallet_get_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1105,7 +1330,7 @@ fn wire__crate__api__wallet_mint_is_offline_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_mint_is_offline",
+            debug_name: "wallet_get_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1119,12 +1344,11 @@ fn wire__crate__api__wallet_mint_is_offline_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_mint_is_offline(api_req).await?;
+                        let output_ok = crate::api::wallet_get_status().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1133,7 +1357,7 @@ fn wire__crate__api__wallet_mint_is_offline_impl(
         },
     )
 }
-fn wire__crate__api__wallet_mint_is_rabid_impl(
+fn wire__crate__api__wallet_get_total_balance_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1141,7 +1365,7 @@ fn wire__crate__api__wallet_mint_is_rabid_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_mint_is_rabid",
+            debug_name: "wallet_get_total_balance",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1155,12 +1379,11 @@ fn wire__crate__api__wallet_mint_is_rabid_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_mint_is_rabid(api_req).await?;
+                        let output_ok = crate::api::wallet_get_total_balance().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1169,7 +1392,7 @@ fn wire__crate__api__wallet_mint_is_rabid_impl(
         },
     )
 }
-fn wire__crate__api__wallet_pay_impl(
+fn wire__crate__api__wallet_get_transaction_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1177,7 +1400,7 @@ fn wire__crate__api__wallet_pay_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_pay",
+            debug_name: "wallet_get_transaction_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1191,12 +1414,12 @@ fn wire__crate__api__wallet_pay_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletPayRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_pay(api_req).await?;
+                        let output_ok = crate::api::wallet_get_transaction_ids(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1205,7 +1428,7 @@ fn wire__crate__api__wallet_pay_impl(
         },
     )
 }
-fn wire__crate__api__wallet_pay_by_token_impl(
+fn wire__crate__api__wallet_get_transaction_token_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1213,7 +1436,7 @@ fn wire__crate__api__wallet_pay_by_token_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_pay_by_token",
+            debug_name: "wallet_get_transaction_token",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1227,12 +1450,12 @@ fn wire__crate__api__wallet_pay_by_token_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletPaymentByTokenRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletTransactionRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_pay_by_token(api_req).await?;
+                        let output_ok = crate::api::wallet_get_transaction_token(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1241,7 +1464,7 @@ fn wire__crate__api__wallet_pay_by_token_impl(
         },
     )
 }
-fn wire__crate__api__wallet_prepare_melt_impl(
+fn wire__crate__api__wallet_get_transactions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1249,7 +1472,7 @@ fn wire__crate__api__wallet_prepare_melt_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_prepare_melt",
+            debug_name: "wallet_get_transactions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1263,12 +1486,12 @@ fn wire__crate__api__wallet_prepare_melt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletPrepareMeltRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_prepare_melt(api_req).await?;
+                        let output_ok = crate::api::wallet_get_transactions(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1277,7 +1500,7 @@ fn wire__crate__api__wallet_prepare_melt_impl(
         },
     )
 }
-fn wire__crate__api__wallet_prepare_pay_by_token_impl(
+fn wire__crate__api__wallet_import_proofs_encrypted_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1285,7 +1508,7 @@ fn wire__crate__api__wallet_prepare_pay_by_token_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_prepare_pay_by_token",
+            debug_name: "wallet_import_proofs_encrypted",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1300,12 +1523,12 @@ fn wire__crate__api__wallet_prepare_pay_by_token_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_req =
-                <crate::api::WalletPreparePaymentByTokenRequest>::sse_decode(&mut deserializer);
+                <crate::api::WalletImportProofsEncryptedRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_prepare_pay_by_token(api_req).await?;
+                        let output_ok = crate::api::wallet_import_proofs_encrypted(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1314,7 +1537,7 @@ fn wire__crate__api__wallet_prepare_pay_by_token_impl(
         },
     )
 }
-fn wire__crate__api__wallet_prepare_payment_impl(
+fn wire__crate__api__wallet_inspect_payment_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1322,7 +1545,7 @@ fn wire__crate__api__wallet_prepare_payment_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_prepare_payment",
+            debug_name: "wallet_inspect_payment_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1341,7 +1564,7 @@ fn wire__crate__api__wallet_prepare_payment_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_prepare_payment(api_req).await?;
+                        let output_ok = crate::api::wallet_inspect_payment_request(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1350,7 +1573,7 @@ fn wire__crate__api__wallet_prepare_payment_impl(
         },
     )
 }
-fn wire__crate__api__wallet_prepare_payment_request_impl(
+fn wire__crate__api__wallet_list_tx_since_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1358,7 +1581,7 @@ fn wire__crate__api__wallet_prepare_payment_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_prepare_payment_request",
+            debug_name: "wallet_list_tx_since",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1373,12 +1596,12 @@ fn wire__crate__api__wallet_prepare_payment_request_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_req =
-                <crate::api::WalletPreparePaymentReqRequest>::sse_decode(&mut deserializer);
+                <crate::api::WalletTransactionsSinceRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_prepare_payment_request(api_req).await?;
+                        let output_ok = crate::api::wallet_list_tx_since(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1387,7 +1610,7 @@ fn wire__crate__api__wallet_prepare_payment_request_impl(
         },
     )
 }
-fn wire__crate__api__wallet_protest_melt_impl(
+fn wire__crate__api__wallet_load_transaction_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1395,7 +1618,7 @@ fn wire__crate__api__wallet_protest_melt_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_protest_melt",
+            debug_name: "wallet_load_transaction",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1409,12 +1632,12 @@ fn wire__crate__api__wallet_protest_melt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletProtestMeltRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletTransactionRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_protest_melt(api_req).await?;
+                        let output_ok = crate::api::wallet_load_transaction(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1423,7 +1646,7 @@ fn wire__crate__api__wallet_protest_melt_impl(
         },
     )
 }
-fn wire__crate__api__wallet_protest_mint_impl(
+fn wire__crate__api__wallet_melt_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1431,7 +1654,7 @@ fn wire__crate__api__wallet_protest_mint_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_protest_mint",
+            debug_name: "wallet_melt",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1445,12 +1668,12 @@ fn wire__crate__api__wallet_protest_mint_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletProtestMintRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletPayRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_protest_mint(api_req).await?;
+                        let output_ok = crate::api::wallet_melt(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1459,7 +1682,7 @@ fn wire__crate__api__wallet_protest_mint_impl(
         },
     )
 }
-fn wire__crate__api__wallet_protest_swap_impl(
+fn wire__crate__api__wallet_migrate_rabid_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1467,7 +1690,7 @@ fn wire__crate__api__wallet_protest_swap_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_protest_swap",
+            debug_name: "wallet_migrate_rabid",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1481,12 +1704,11 @@ fn wire__crate__api__wallet_protest_swap_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletProtestSwapRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_protest_swap(api_req).await?;
+                        let output_ok = crate::api::wallet_migrate_rabid().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1495,7 +1717,7 @@ fn wire__crate__api__wallet_protest_swap_impl(
         },
     )
 }
-fn wire__crate__api__wallet_receive_impl(
+fn wire__crate__api__wallet_mint_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1503,7 +1725,7 @@ fn wire__crate__api__wallet_receive_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_receive",
+            debug_name: "wallet_mint",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1517,12 +1739,12 @@ fn wire__crate__api__wallet_receive_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletReceiveRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletMintBolt11Request>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_receive(api_req).await?;
+                        let output_ok = crate::api::wallet_mint(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1531,7 +1753,7 @@ fn wire__crate__api__wallet_receive_impl(
         },
     )
 }
-fn wire__crate__api__wallet_reclaim_transaction_impl(
+fn wire__crate__api__wallet_mint_capabilities_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1539,7 +1761,7 @@ fn wire__crate__api__wallet_reclaim_transaction_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_reclaim_transaction",
+            debug_name: "wallet_mint_capabilities",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1553,13 +1775,12 @@ fn wire__crate__api__wallet_reclaim_transaction_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req =
-                <crate::api::WalletReclaimTransactionRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_reclaim_transaction(api_req).await?;
+                        let output_ok = crate::api::wallet_mint_capabilities(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1568,7 +1789,7 @@ fn wire__crate__api__wallet_reclaim_transaction_impl(
         },
     )
 }
-fn wire__crate__api__wallet_recover_pending_stale_proofs_impl(
+fn wire__crate__api__wallet_mint_is_offline_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1576,7 +1797,7 @@ fn wire__crate__api__wallet_recover_pending_stale_proofs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_recover_pending_stale_proofs",
+            debug_name: "wallet_mint_is_offline",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1595,8 +1816,7 @@ fn wire__crate__api__wallet_recover_pending_stale_proofs_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::wallet_recover_pending_stale_proofs(api_req).await?;
+                        let output_ok = crate::api::wallet_mint_is_offline(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1605,7 +1825,7 @@ fn wire__crate__api__wallet_recover_pending_stale_proofs_impl(
         },
     )
 }
-fn wire__crate__api__wallet_refresh_transaction_impl(
+fn wire__crate__api__wallet_mint_is_rabid_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1613,7 +1833,7 @@ fn wire__crate__api__wallet_refresh_transaction_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_refresh_transaction",
+            debug_name: "wallet_mint_is_rabid",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1627,13 +1847,12 @@ fn wire__crate__api__wallet_refresh_transaction_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req =
-                <crate::api::WalletRefreshTransactionRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_refresh_transaction(api_req).await?;
+                        let output_ok = crate::api::wallet_mint_is_rabid(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1642,7 +1861,7 @@ fn wire__crate__api__wallet_refresh_transaction_impl(
         },
     )
 }
-fn wire__crate__api__wallet_refresh_transactions_impl(
+fn wire__crate__api__wallet_mint_onchain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1650,7 +1869,7 @@ fn wire__crate__api__wallet_refresh_transactions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_refresh_transactions",
+            debug_name: "wallet_mint_onchain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1664,12 +1883,12 @@ fn wire__crate__api__wallet_refresh_transactions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            let api_req = <crate::api::WalletMintRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_refresh_transactions(api_req).await?;
+                        let output_ok = crate::api::wallet_mint_onchain(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1678,7 +1897,7 @@ fn wire__crate__api__wallet_refresh_transactions_impl(
         },
     )
 }
-fn wire__crate__api__wallet_restore_impl(
+fn wire__crate__api__wallet_partial_sign_proof_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1686,7 +1905,7 @@ fn wire__crate__api__wallet_restore_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_restore",
+            debug_name: "wallet_partial_sign_proof",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1700,11 +1919,13 @@ fn wire__crate__api__wallet_restore_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletPartialSignProofRequest>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::WalletError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_restore().await?;
+                        let output_ok = crate::api::wallet_partial_sign_proof(api_req).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1713,1672 +1934,3900 @@ fn wire__crate__api__wallet_restore_impl(
         },
     )
 }
-
-// Section: related_funcs
-
-fn decode_DartFn_Inputs_wallet_maybe_transaction_id_response_Output_unit_AnyhowException(
-    dart_opaque: flutter_rust_bridge::DartOpaque,
-) -> impl Fn(crate::api::WalletMaybeTransactionIdResponse) -> flutter_rust_bridge::DartFnFuture<()>
-{
-    use flutter_rust_bridge::IntoDart;
-
-    async fn body(
-        dart_opaque: flutter_rust_bridge::DartOpaque,
-        arg0: crate::api::WalletMaybeTransactionIdResponse,
-    ) -> () {
-        let args = vec![arg0.into_into_dart().into_dart()];
-        let message = FLUTTER_RUST_BRIDGE_HANDLER
-            .dart_fn_invoke(dart_opaque, args)
-            .await;
-
-        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-        let action = deserializer.cursor.read_u8().unwrap();
-        let ans = match action {
-            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
-            1 => std::result::Result::Err(
-                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
-            ),
-            _ => unreachable!(),
-        };
-        deserializer.end();
-        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
-        ans
-    }
-
-    move |arg0: crate::api::WalletMaybeTransactionIdResponse| {
-        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
-            dart_opaque.clone(),
-            arg0,
-        ))
-    }
-}
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for WalletPaymentCheckHandle {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__wallet_pay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_pay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletPayRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_pay(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for flutter_rust_bridge::DartOpaque {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
-    }
+fn wire__crate__api__wallet_pay_by_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_pay_by_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletPaymentByTokenRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_pay_by_token(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+fn wire__crate__api__wallet_prepare_melt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_melt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletPrepareMeltRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_melt(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_prepare_mint_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_mint",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletMintRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_mint(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_prepare_pay_by_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_pay_by_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletPreparePaymentByTokenRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_pay_by_token(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_prepare_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletPreparePaymentRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_payment(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_prepare_payment_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_payment_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletPreparePaymentReqRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_payment_request(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_prepare_send_locked_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_send_locked",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletPrepareSendLockedRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_send_locked(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_prepare_send_max_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_prepare_send_max",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletPrepareSendMaxRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_prepare_send_max(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_protest_melt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_protest_melt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletProtestMeltRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_protest_melt(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_protest_mint_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_protest_mint",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletProtestMintRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_protest_mint(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_protest_swap_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_protest_swap",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletProtestSwapRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_protest_swap(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_receive_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_receive",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletReceiveRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_receive(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_receive_multisig_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_receive_multisig_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletReceiveRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_receive_multisig_token(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_recheck_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_recheck",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_recheck(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_reclaim_funds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_reclaim_funds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_reclaim_funds(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_reclaim_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_reclaim_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletReclaimTransactionRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_reclaim_transaction(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_recover_pending_stale_proofs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_recover_pending_stale_proofs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_recover_pending_stale_proofs(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_refresh_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_refresh_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req =
+                <crate::api::WalletRefreshTransactionRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_refresh_transaction(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_refresh_transactions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_refresh_transactions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_refresh_transactions(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_release_reservations_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_release_reservations",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_release_reservations(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_rename_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_rename",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRenameRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_rename(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_restore_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_restore",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_restore().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_restore_keyset_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_restore_keyset",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRestoreKeysetRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_restore_keyset(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_retry_pending_transaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_retry_pending_transaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletTransactionRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_retry_pending_transaction(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_set_keep_sent_tokens_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_set_keep_sent_tokens",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletKeepSentTokensRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_set_keep_sent_tokens(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_req = <crate::api::WalletRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::WalletError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_stats(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+fn decode_DartFn_Inputs_wallet_maybe_transaction_id_response_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::WalletMaybeTransactionIdResponse) -> flutter_rust_bridge::DartFnFuture<()>
+{
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::WalletMaybeTransactionIdResponse,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::WalletMaybeTransactionIdResponse| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for WalletPaymentCheckHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <usize>::sse_decode(deserializer);
         return decode_rust_opaque_moi(inner);
     }
 }
 
-impl SseDecode for String {
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::AddWalletResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        return crate::api::AddWalletResponse {
+            wallet_id: var_walletId,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::ExportWalletBackupRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_passphrase = <String>::sse_decode(deserializer);
+        return crate::api::ExportWalletBackupRequest {
+            wallet_id: var_walletId,
+            passphrase: var_passphrase,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ExportWalletBackupResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backup = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::ExportWalletBackupResponse { backup: var_backup };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::ImportWalletBackupRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backup = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_passphrase = <String>::sse_decode(deserializer);
+        return crate::api::ImportWalletBackupRequest {
+            backup: var_backup,
+            passphrase: var_passphrase,
+        };
+    }
+}
+
+impl SseDecode for crate::api::IsValidTokenRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <String>::sse_decode(deserializer);
+        return crate::api::IsValidTokenRequest { token: var_token };
+    }
+}
+
+impl SseDecode for crate::api::IsValidTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        let mut var_unit = <Option<String>>::sse_decode(deserializer);
+        return crate::api::IsValidTokenResponse {
+            amount: var_amount,
+            memo: var_memo,
+            mint_url: var_mintUrl,
+            unit: var_unit,
+        };
+    }
+}
+
+impl SseDecode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::KeysetFee {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kid = <String>::sse_decode(deserializer);
+        let mut var_inputFeePpk = <u64>::sse_decode(deserializer);
+        return crate::api::KeysetFee {
+            kid: var_kid,
+            input_fee_ppk: var_inputFeePpk,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::KeysetFee> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::KeysetFee>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PendingTransaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PendingTransaction>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<usize>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::Transaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::Transaction>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::UnitBalance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::UnitBalance>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WalletDevModeDetailedBalanceEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WalletDevModeDetailedBalanceEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::MeltTx {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_alphaTxId = <Option<String>>::sse_decode(deserializer);
+        let mut var_betaTxId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::MeltTx {
+            alpha_tx_id: var_alphaTxId,
+            beta_tx_id: var_betaTxId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::MigrateRabidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_migratedToMint = <Option<String>>::sse_decode(deserializer);
+        return crate::api::MigrateRabidResponse {
+            migrated_to_mint: var_migratedToMint,
+        };
+    }
+}
+
+impl SseDecode for crate::api::MintIsOfflineResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offline = <bool>::sse_decode(deserializer);
+        return crate::api::MintIsOfflineResponse {
+            offline: var_offline,
+        };
+    }
+}
+
+impl SseDecode for crate::api::MintIsRabidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rabid = <bool>::sse_decode(deserializer);
+        return crate::api::MintIsRabidResponse { rabid: var_rabid };
+    }
+}
+
+impl SseDecode for crate::api::MnemonicRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_length = <u32>::sse_decode(deserializer);
+        return crate::api::MnemonicRequest { length: var_length };
+    }
+}
+
+impl SseDecode for crate::api::MnemonicResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        return crate::api::MnemonicResponse {
+            mnemonic: var_mnemonic,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::PaymentRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_request = <String>::sse_decode(deserializer);
+        let mut var_pId = <String>::sse_decode(deserializer);
+        return crate::api::PaymentRequest {
+            request: var_request,
+            p_id: var_pId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PaymentSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_requestId = <String>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fees = <u64>::sse_decode(deserializer);
+        let mut var_reservedFees = <u64>::sse_decode(deserializer);
+        let mut var_expiry = <u64>::sse_decode(deserializer);
+        let mut var_ptype = <crate::api::PaymentType>::sse_decode(deserializer);
+        return crate::api::PaymentSummary {
+            request_id: var_requestId,
+            unit: var_unit,
+            amount: var_amount,
+            fees: var_fees,
+            reserved_fees: var_reservedFees,
+            expiry: var_expiry,
+            ptype: var_ptype,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PaymentType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::PaymentType::NotApplicable,
+            1 => crate::api::PaymentType::Token,
+            2 => crate::api::PaymentType::Cdk18,
+            3 => crate::api::PaymentType::OnChain,
+            4 => crate::api::PaymentType::Swap,
+            5 => crate::api::PaymentType::Bolt11,
+            _ => unreachable!("Invalid variant for PaymentType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::PendingTransaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_transaction = <crate::api::Transaction>::sse_decode(deserializer);
+        let mut var_pendingProofs = <usize>::sse_decode(deserializer);
+        let mut var_unsent = <bool>::sse_decode(deserializer);
+        return crate::api::PendingTransaction {
+            transaction: var_transaction,
+            pending_proofs: var_pendingProofs,
+            unsent: var_unsent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ProtestStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::ProtestStatus::Resolved,
+            1 => crate::api::ProtestStatus::Rabid,
+            _ => unreachable!("Invalid variant for ProtestStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::RestoreWalletResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        return crate::api::RestoreWalletResponse {
+            wallet_id: var_walletId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::StatusResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_appVersion = <String>::sse_decode(deserializer);
+        return crate::api::StatusResponse {
+            app_version: var_appVersion,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TotalBalanceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_balances = <Vec<crate::api::UnitBalance>>::sse_decode(deserializer);
+        return crate::api::TotalBalanceResponse {
+            balances: var_balances,
+        };
+    }
+}
+
+impl SseDecode for crate::api::Transaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fees = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_tstamp = <u64>::sse_decode(deserializer);
+        let mut var_direction = <crate::api::TransactionDirection>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        let mut var_ptype = <crate::api::PaymentType>::sse_decode(deserializer);
+        let mut var_status = <crate::api::TransactionStatus>::sse_decode(deserializer);
+        let mut var_meltTx = <crate::api::MeltTx>::sse_decode(deserializer);
+        let mut var_quoteId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::Transaction {
+            id: var_id,
+            amount: var_amount,
+            fees: var_fees,
+            unit: var_unit,
+            tstamp: var_tstamp,
+            direction: var_direction,
+            memo: var_memo,
+            ptype: var_ptype,
+            status: var_status,
+            melt_tx: var_meltTx,
+            quote_id: var_quoteId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TransactionDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TransactionDirection::Incoming,
+            1 => crate::api::TransactionDirection::Outgoing,
+            _ => unreachable!("Invalid variant for TransactionDirection: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TransactionStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TransactionStatus::NotApplicable,
+            1 => crate::api::TransactionStatus::Pending,
+            2 => crate::api::TransactionStatus::Settled,
+            3 => crate::api::TransactionStatus::Canceled,
+            _ => unreachable!("Invalid variant for TransactionStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::UnitBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_debit = <u64>::sse_decode(deserializer);
+        let mut var_credit = <u64>::sse_decode(deserializer);
+        return crate::api::UnitBalance {
+            unit: var_unit,
+            debit: var_debit,
+            credit: var_credit,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::ValidateMnemonicRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        return crate::api::ValidateMnemonicRequest {
+            mnemonic: var_mnemonic,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ValidateMnemonicResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_valid = <bool>::sse_decode(deserializer);
+        let mut var_wordCount = <u32>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::ValidateMnemonicResponse {
+            valid: var_valid,
+            word_count: var_wordCount,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletBalanceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_debit = <u64>::sse_decode(deserializer);
+        let mut var_credit = <u64>::sse_decode(deserializer);
+        let mut var_total = <u64>::sse_decode(deserializer);
+        return crate::api::WalletBalanceResponse {
+            debit: var_debit,
+            credit: var_credit,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletCheckPendingMintsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::WalletCheckPendingMintsResponse { tx_ids: var_txIds };
+    }
+}
+
+impl SseDecode for crate::api::WalletCheckReceivedPaymentRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_maxWaitSec = <u64>::sse_decode(deserializer);
+        let mut var_pId = <String>::sse_decode(deserializer);
+        return crate::api::WalletCheckReceivedPaymentRequest {
+            wallet_id: var_walletId,
+            max_wait_sec: var_maxWaitSec,
+            p_id: var_pId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletCleanLocalDbRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_pruneCounters = <bool>::sse_decode(deserializer);
+        return crate::api::WalletCleanLocalDbRequest {
+            wallet_id: var_walletId,
+            prune_counters: var_pruneCounters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletCleanLocalDbResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cleanedProofs = <u32>::sse_decode(deserializer);
+        let mut var_prunedCounters = <u32>::sse_decode(deserializer);
+        return crate::api::WalletCleanLocalDbResponse {
+            cleaned_proofs: var_cleanedProofs,
+            pruned_counters: var_prunedCounters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletCombinePartialSignaturesRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_y = <String>::sse_decode(deserializer);
+        let mut var_witnesses = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::WalletCombinePartialSignaturesRequest {
+            wallet_id: var_walletId,
+            y: var_y,
+            witnesses: var_witnesses,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletCombinePartialSignaturesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spendable = <bool>::sse_decode(deserializer);
+        return crate::api::WalletCombinePartialSignaturesResponse {
+            spendable: var_spendable,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletConfirmNut18DeliveryRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_txId = <String>::sse_decode(deserializer);
+        let mut var_timeoutSecs = <u64>::sse_decode(deserializer);
+        return crate::api::WalletConfirmNut18DeliveryRequest {
+            wallet_id: var_walletId,
+            tx_id: var_txId,
+            timeout_secs: var_timeoutSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletConfirmNut18DeliveryResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_delivered = <bool>::sse_decode(deserializer);
+        return crate::api::WalletConfirmNut18DeliveryResponse {
+            delivered: var_delivered,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletCurrencyUnitResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_unit = <String>::sse_decode(deserializer);
+        return crate::api::WalletCurrencyUnitResponse { unit: var_unit };
+    }
+}
+
+impl SseDecode for crate::api::WalletDevModeDetailedBalanceEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kid = <String>::sse_decode(deserializer);
+        let mut var_finalExpiry = <Option<u64>>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        return crate::api::WalletDevModeDetailedBalanceEntry {
+            kid: var_kid,
+            final_expiry: var_finalExpiry,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletDevModeDetailedBalanceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entries =
+            <Vec<crate::api::WalletDevModeDetailedBalanceEntry>>::sse_decode(deserializer);
+        return crate::api::WalletDevModeDetailedBalanceResponse {
+            entries: var_entries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::WalletErrorKind>::sse_decode(deserializer);
+        let mut var_code = <crate::api::WalletErrorCode>::sse_decode(deserializer);
+        let mut var_msg = <String>::sse_decode(deserializer);
+        return crate::api::WalletError {
+            kind: var_kind,
+            code: var_code,
+            msg: var_msg,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::WalletErrorCode::Internal,
+            1 => crate::api::WalletErrorCode::Network,
+            2 => crate::api::WalletErrorCode::WalletNotFound,
+            3 => crate::api::WalletErrorCode::EmptyToken,
+            4 => crate::api::WalletErrorCode::InvalidToken,
+            5 => crate::api::WalletErrorCode::CashuMintUrl,
+            6 => crate::api::WalletErrorCode::Url,
+            7 => crate::api::WalletErrorCode::InsufficientBalance,
+            8 => crate::api::WalletErrorCode::NoActiveKeyset,
+            9 => crate::api::WalletErrorCode::UnknownKeysetId,
+            10 => crate::api::WalletErrorCode::InvalidCurrencyUnit,
+            11 => crate::api::WalletErrorCode::NoPrepareRef,
+            12 => crate::api::WalletErrorCode::InactiveKeyset,
+            13 => crate::api::WalletErrorCode::NoDebitCurrencyInMint,
+            14 => crate::api::WalletErrorCode::InvalidNetwork,
+            15 => crate::api::WalletErrorCode::MissingAmount,
+            16 => crate::api::WalletErrorCode::UnknownPaymentRequest,
+            17 => crate::api::WalletErrorCode::Unsupported,
+            18 => crate::api::WalletErrorCode::TransactionCantBeReclaimed,
+            19 => crate::api::WalletErrorCode::InsufficientOnChainMeltAmount,
+            20 => crate::api::WalletErrorCode::InsufficientOnChainMintAmount,
+            21 => crate::api::WalletErrorCode::NoDevMode,
+            22 => crate::api::WalletErrorCode::InvalidBitcoinAddress,
+            23 => crate::api::WalletErrorCode::InvalidMintUrl,
+            24 => crate::api::WalletErrorCode::InvalidMnemonic,
+            25 => crate::api::WalletErrorCode::WalletAlreadyExists,
+            _ => unreachable!("Invalid variant for WalletErrorCode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::WalletErrorKind::BadRequest,
+            1 => crate::api::WalletErrorKind::NotFound,
+            2 => crate::api::WalletErrorKind::Network,
+            3 => crate::api::WalletErrorKind::Internal,
+            4 => crate::api::WalletErrorKind::Initialization,
+            5 => crate::api::WalletErrorKind::Unsupported,
+            _ => unreachable!("Invalid variant for WalletErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletExportBalanceTokenRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        return crate::api::WalletExportBalanceTokenRequest {
+            wallet_id: var_walletId,
+            unit: var_unit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletExportBalanceTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <String>::sse_decode(deserializer);
+        return crate::api::WalletExportBalanceTokenResponse { token: var_token };
+    }
+}
+
+impl SseDecode for crate::api::WalletExportProofsEncryptedRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_passphrase = <String>::sse_decode(deserializer);
+        return crate::api::WalletExportProofsEncryptedRequest {
+            wallet_id: var_walletId,
+            passphrase: var_passphrase,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletExportProofsEncryptedResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blob = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::WalletExportProofsEncryptedResponse { blob: var_blob };
+    }
+}
+
+impl SseDecode for crate::api::WalletExportReceiptResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_receipt = <String>::sse_decode(deserializer);
+        return crate::api::WalletExportReceiptResponse {
+            receipt: var_receipt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletFfiConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dbFolderPath = <String>::sse_decode(deserializer);
+        let mut var_logLevel = <String>::sse_decode(deserializer);
+        let mut var_jobIntervalSecs = <u64>::sse_decode(deserializer);
+        let mut var_jobInitialDelaySecs = <u64>::sse_decode(deserializer);
+        let mut var_defaultMintUrl = <String>::sse_decode(deserializer);
+        let mut var_bitcoinNetwork = <String>::sse_decode(deserializer);
+        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        let mut var_passphrase = <Option<String>>::sse_decode(deserializer);
+        let mut var_nostrRelays = <Vec<String>>::sse_decode(deserializer);
+        let mut var_swapExpiryMinutes = <u32>::sse_decode(deserializer);
+        let mut var_devMode = <bool>::sse_decode(deserializer);
+        return crate::api::WalletFfiConfig {
+            db_folder_path: var_dbFolderPath,
+            log_level: var_logLevel,
+            job_interval_secs: var_jobIntervalSecs,
+            job_initial_delay_secs: var_jobInitialDelaySecs,
+            default_mint_url: var_defaultMintUrl,
+            bitcoin_network: var_bitcoinNetwork,
+            mnemonic: var_mnemonic,
+            passphrase: var_passphrase,
+            nostr_relays: var_nostrRelays,
+            swap_expiry_minutes: var_swapExpiryMinutes,
+            dev_mode: var_devMode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletImportProofsEncryptedRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_blob = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_passphrase = <String>::sse_decode(deserializer);
+        return crate::api::WalletImportProofsEncryptedRequest {
+            wallet_id: var_walletId,
+            blob: var_blob,
+            passphrase: var_passphrase,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletImportProofsEncryptedResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imported = <u32>::sse_decode(deserializer);
+        return crate::api::WalletImportProofsEncryptedResponse {
+            imported: var_imported,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletInspectPaymentRequestResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_info = <String>::sse_decode(deserializer);
+        return crate::api::WalletInspectPaymentRequestResponse { info: var_info };
+    }
+}
+
+impl SseDecode for crate::api::WalletKeepSentTokensRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_keep = <bool>::sse_decode(deserializer);
+        return crate::api::WalletKeepSentTokensRequest {
+            wallet_id: var_walletId,
+            keep: var_keep,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletKeysetFeesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fees = <Vec<crate::api::KeysetFee>>::sse_decode(deserializer);
+        return crate::api::WalletKeysetFeesResponse { fees: var_fees };
+    }
+}
+
+impl SseDecode for crate::api::WalletMaybeTransactionIdResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletMaybeTransactionIdResponse { tx_id: var_txId };
+    }
+}
+
+impl SseDecode for crate::api::WalletMintBolt11Request {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_quoteId = <String>::sse_decode(deserializer);
+        return crate::api::WalletMintBolt11Request {
+            wallet_id: var_walletId,
+            quote_id: var_quoteId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletMintCapabilitiesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintEnabled = <bool>::sse_decode(deserializer);
+        let mut var_meltEnabled = <bool>::sse_decode(deserializer);
+        let mut var_tokenStateCheck = <bool>::sse_decode(deserializer);
+        let mut var_restore = <bool>::sse_decode(deserializer);
+        let mut var_p2Pk = <bool>::sse_decode(deserializer);
+        let mut var_dleq = <bool>::sse_decode(deserializer);
+        let mut var_htlc = <bool>::sse_decode(deserializer);
+        let mut var_mpp = <bool>::sse_decode(deserializer);
+        let mut var_websocket = <bool>::sse_decode(deserializer);
+        return crate::api::WalletMintCapabilitiesResponse {
+            mint_enabled: var_mintEnabled,
+            melt_enabled: var_meltEnabled,
+            token_state_check: var_tokenStateCheck,
+            restore: var_restore,
+            p2pk: var_p2Pk,
+            dleq: var_dleq,
+            htlc: var_htlc,
+            mpp: var_mpp,
+            websocket: var_websocket,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletMintQuoteResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_quoteId = <String>::sse_decode(deserializer);
+        let mut var_request = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_expiry = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::WalletMintQuoteResponse {
+            quote_id: var_quoteId,
+            request: var_request,
+            amount: var_amount,
+            unit: var_unit,
+            expiry: var_expiry,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletMintRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        return crate::api::WalletMintRequest {
+            wallet_id: var_walletId,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletMintSummaryResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_quoteId = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_expiry = <u64>::sse_decode(deserializer);
+        return crate::api::WalletMintSummaryResponse {
+            quote_id: var_quoteId,
+            amount: var_amount,
+            address: var_address,
+            expiry: var_expiry,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletMintUrlResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mintUrl = <String>::sse_decode(deserializer);
+        return crate::api::WalletMintUrlResponse {
+            mint_url: var_mintUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletNameResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::api::WalletNameResponse { name: var_name };
+    }
+}
+
+impl SseDecode for crate::api::WalletNprofileResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nprofile = <String>::sse_decode(deserializer);
+        return crate::api::WalletNprofileResponse {
+            nprofile: var_nprofile,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPartialSignProofRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_y = <String>::sse_decode(deserializer);
+        return crate::api::WalletPartialSignProofRequest {
+            wallet_id: var_walletId,
+            y: var_y,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPartialSignProofResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_witness = <String>::sse_decode(deserializer);
+        return crate::api::WalletPartialSignProofResponse {
+            witness: var_witness,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPayRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_rid = <String>::sse_decode(deserializer);
+        return crate::api::WalletPayRequest {
+            wallet_id: var_walletId,
+            rid: var_rid,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPaymentByTokenRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_rid = <String>::sse_decode(deserializer);
+        return crate::api::WalletPaymentByTokenRequest {
+            wallet_id: var_walletId,
+            rid: var_rid,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPaymentByTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txId = <String>::sse_decode(deserializer);
+        let mut var_token = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fees = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        return crate::api::WalletPaymentByTokenResponse {
+            tx_id: var_txId,
+            token: var_token,
+            amount: var_amount,
+            fees: var_fees,
+            unit: var_unit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPendingTransactionsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txs = <Vec<crate::api::PendingTransaction>>::sse_decode(deserializer);
+        return crate::api::WalletPendingTransactionsResponse { txs: var_txs };
+    }
+}
+
+impl SseDecode for crate::api::WalletPrepareMeltRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletPrepareMeltRequest {
+            wallet_id: var_walletId,
+            amount: var_amount,
+            address: var_address,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPreparePaymentByTokenRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletPreparePaymentByTokenRequest {
+            wallet_id: var_walletId,
+            amount: var_amount,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPreparePaymentReqRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletPreparePaymentReqRequest {
+            wallet_id: var_walletId,
+            amount: var_amount,
+            unit: var_unit,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPreparePaymentReqResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentRequest = <crate::api::PaymentRequest>::sse_decode(deserializer);
+        return crate::api::WalletPreparePaymentReqResponse {
+            payment_request: var_paymentRequest,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPreparePaymentRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_input = <String>::sse_decode(deserializer);
+        return crate::api::WalletPreparePaymentRequest {
+            wallet_id: var_walletId,
+            input: var_input,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPreparePaymentResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentSummary = <crate::api::PaymentSummary>::sse_decode(deserializer);
+        return crate::api::WalletPreparePaymentResponse {
+            payment_summary: var_paymentSummary,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPrepareSendLockedRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_locktime = <Option<u64>>::sse_decode(deserializer);
+        let mut var_refundKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletPrepareSendLockedRequest {
+            wallet_id: var_walletId,
+            amount: var_amount,
+            unit: var_unit,
+            pubkey: var_pubkey,
+            locktime: var_locktime,
+            refund_key: var_refundKey,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletPrepareSendMaxRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletPrepareSendMaxRequest {
+            wallet_id: var_walletId,
+            unit: var_unit,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletProtestMeltRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_quoteId = <String>::sse_decode(deserializer);
+        return crate::api::WalletProtestMeltRequest {
+            wallet_id: var_walletId,
+            quote_id: var_quoteId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletProtestMeltResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <crate::api::ProtestStatus>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::WalletProtestMeltResponse {
+            status: var_status,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletProtestMintRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_quoteId = <String>::sse_decode(deserializer);
+        return crate::api::WalletProtestMintRequest {
+            wallet_id: var_walletId,
+            quote_id: var_quoteId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletProtestMintResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <crate::api::ProtestStatus>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::WalletProtestMintResponse {
+            status: var_status,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletProtestSwapRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_commitmentSig = <String>::sse_decode(deserializer);
+        return crate::api::WalletProtestSwapRequest {
+            wallet_id: var_walletId,
+            commitment_sig: var_commitmentSig,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletProtestSwapResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <crate::api::ProtestStatus>::sse_decode(deserializer);
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::WalletProtestSwapResponse {
+            status: var_status,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletReceiveMultisigTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txId = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_ys = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::WalletReceiveMultisigTokenResponse {
+            tx_id: var_txId,
+            amount: var_amount,
+            ys: var_ys,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletReceiveRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_token = <String>::sse_decode(deserializer);
+        return crate::api::WalletReceiveRequest {
+            wallet_id: var_walletId,
+            token: var_token,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletReceiveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txId = <String>::sse_decode(deserializer);
+        let mut var_received = <u64>::sse_decode(deserializer);
+        let mut var_stored = <u64>::sse_decode(deserializer);
+        let mut var_fee = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        return crate::api::WalletReceiveResponse {
+            tx_id: var_txId,
+            received: var_received,
+            stored: var_stored,
+            fee: var_fee,
+            unit: var_unit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletRecheckResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_deleted = <u32>::sse_decode(deserializer);
+        let mut var_restored = <u32>::sse_decode(deserializer);
+        let mut var_pending = <u32>::sse_decode(deserializer);
+        return crate::api::WalletRecheckResponse {
+            deleted: var_deleted,
+            restored: var_restored,
+            pending: var_pending,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletReclaimTransactionRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_txId = <String>::sse_decode(deserializer);
+        return crate::api::WalletReclaimTransactionRequest {
+            wallet_id: var_walletId,
+            tx_id: var_txId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletReclaimTransactionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        return crate::api::WalletReclaimTransactionResponse { amount: var_amount };
+    }
+}
+
+impl SseDecode for crate::api::WalletRecoverStaleTransactionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        return crate::api::WalletRecoverStaleTransactionResponse { amount: var_amount };
+    }
+}
+
+impl SseDecode for crate::api::WalletRefreshTransactionRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_txId = <String>::sse_decode(deserializer);
+        return crate::api::WalletRefreshTransactionRequest {
+            wallet_id: var_walletId,
+            tx_id: var_txId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WalletRefreshTransactionResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        let mut var_updated = <bool>::sse_decode(deserializer);
+        return crate::api::WalletRefreshTransactionResponse {
+            updated: var_updated,
+        };
     }
 }
 
-impl SseDecode for crate::api::AddWalletResponse {
+impl SseDecode for crate::api::WalletRefreshTransactionsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        return crate::api::AddWalletResponse {
-            wallet_id: var_walletId,
+        let mut var_updated = <usize>::sse_decode(deserializer);
+        return crate::api::WalletRefreshTransactionsResponse {
+            updated: var_updated,
         };
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for crate::api::WalletReleaseReservationsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        let mut var_released = <u32>::sse_decode(deserializer);
+        return crate::api::WalletReleaseReservationsResponse {
+            released: var_released,
+        };
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for crate::api::WalletRenameRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::api::WalletRenameRequest {
+            wallet_id: var_walletId,
+            name: var_name,
+        };
     }
 }
 
-impl SseDecode for crate::api::IsValidTokenRequest {
+impl SseDecode for crate::api::WalletRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_token = <String>::sse_decode(deserializer);
-        return crate::api::IsValidTokenRequest { token: var_token };
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        return crate::api::WalletRequest {
+            wallet_id: var_walletId,
+        };
     }
 }
 
-impl SseDecode for crate::api::IsValidTokenResponse {
+impl SseDecode for crate::api::WalletRestoreKeysetRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_memo = <Option<String>>::sse_decode(deserializer);
-        let mut var_mintUrl = <String>::sse_decode(deserializer);
-        let mut var_unit = <Option<String>>::sse_decode(deserializer);
-        return crate::api::IsValidTokenResponse {
-            amount: var_amount,
-            memo: var_memo,
-            mint_url: var_mintUrl,
-            unit: var_unit,
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_keysetId = <String>::sse_decode(deserializer);
+        return crate::api::WalletRestoreKeysetRequest {
+            wallet_id: var_walletId,
+            keyset_id: var_keysetId,
         };
     }
 }
 
-impl SseDecode for isize {
+impl SseDecode for crate::api::WalletRestoreKeysetResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+        let mut var_restored = <u32>::sse_decode(deserializer);
+        return crate::api::WalletRestoreKeysetResponse {
+            restored: var_restored,
+        };
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for crate::api::WalletRetryPendingTransactionResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_retried = <bool>::sse_decode(deserializer);
+        return crate::api::WalletRetryPendingTransactionResponse {
+            retried: var_retried,
+        };
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for crate::api::WalletSendResultResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_txId = <String>::sse_decode(deserializer);
+        let mut var_token = <Option<String>>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fees = <u64>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        return crate::api::WalletSendResultResponse {
+            tx_id: var_txId,
+            token: var_token,
+            amount: var_amount,
+            fees: var_fees,
+            unit: var_unit,
+        };
     }
 }
 
-impl SseDecode for Vec<usize> {
+impl SseDecode for crate::api::WalletStatsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<usize>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_unspentProofs = <u32>::sse_decode(deserializer);
+        let mut var_pendingProofs = <u32>::sse_decode(deserializer);
+        let mut var_reservedProofs = <u32>::sse_decode(deserializer);
+        let mut var_totalProofs = <u32>::sse_decode(deserializer);
+        let mut var_keysets = <u32>::sse_decode(deserializer);
+        let mut var_randomProofs = <u32>::sse_decode(deserializer);
+        let mut var_transactions = <u32>::sse_decode(deserializer);
+        let mut var_oldestTx = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::WalletStatsResponse {
+            unspent_proofs: var_unspentProofs,
+            pending_proofs: var_pendingProofs,
+            reserved_proofs: var_reservedProofs,
+            total_proofs: var_totalProofs,
+            keysets: var_keysets,
+            random_proofs: var_randomProofs,
+            transactions: var_transactions,
+            oldest_tx: var_oldestTx,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::Transaction> {
+impl SseDecode for crate::api::WalletTransactionIdResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::Transaction>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_txId = <String>::sse_decode(deserializer);
+        return crate::api::WalletTransactionIdResponse { tx_id: var_txId };
     }
 }
 
-impl SseDecode for Vec<crate::api::WalletDevModeDetailedBalanceEntry> {
+impl SseDecode for crate::api::WalletTransactionIdsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::WalletDevModeDetailedBalanceEntry>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        let mut var_txIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::WalletTransactionIdsResponse { tx_ids: var_txIds };
     }
 }
 
-impl SseDecode for crate::api::MeltTx {
+impl SseDecode for crate::api::WalletTransactionRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_alphaTxId = <Option<String>>::sse_decode(deserializer);
-        let mut var_betaTxId = <Option<String>>::sse_decode(deserializer);
-        return crate::api::MeltTx {
-            alpha_tx_id: var_alphaTxId,
-            beta_tx_id: var_betaTxId,
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_txId = <String>::sse_decode(deserializer);
+        return crate::api::WalletTransactionRequest {
+            wallet_id: var_walletId,
+            tx_id: var_txId,
         };
     }
 }
 
-impl SseDecode for crate::api::MigrateRabidResponse {
+impl SseDecode for crate::api::WalletTransactionResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_migratedToMint = <Option<String>>::sse_decode(deserializer);
-        return crate::api::MigrateRabidResponse {
-            migrated_to_mint: var_migratedToMint,
+        let mut var_transaction = <crate::api::Transaction>::sse_decode(deserializer);
+        return crate::api::WalletTransactionResponse {
+            transaction: var_transaction,
         };
     }
 }
 
-impl SseDecode for crate::api::MintIsOfflineResponse {
+impl SseDecode for crate::api::WalletTransactionTokenResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_offline = <bool>::sse_decode(deserializer);
-        return crate::api::MintIsOfflineResponse {
-            offline: var_offline,
-        };
+        let mut var_token = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WalletTransactionTokenResponse { token: var_token };
     }
 }
 
-impl SseDecode for crate::api::MintIsRabidResponse {
+impl SseDecode for crate::api::WalletTransactionsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_rabid = <bool>::sse_decode(deserializer);
-        return crate::api::MintIsRabidResponse { rabid: var_rabid };
+        let mut var_txs = <Vec<crate::api::Transaction>>::sse_decode(deserializer);
+        return crate::api::WalletTransactionsResponse { txs: var_txs };
     }
 }
 
-impl SseDecode for crate::api::MnemonicRequest {
+impl SseDecode for crate::api::WalletTransactionsSinceRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_length = <u32>::sse_decode(deserializer);
-        return crate::api::MnemonicRequest { length: var_length };
+        let mut var_walletId = <usize>::sse_decode(deserializer);
+        let mut var_seq = <u64>::sse_decode(deserializer);
+        return crate::api::WalletTransactionsSinceRequest {
+            wallet_id: var_walletId,
+            seq: var_seq,
+        };
     }
 }
 
-impl SseDecode for crate::api::MnemonicResponse {
+impl SseDecode for crate::api::WalletTransactionsSinceResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mnemonic = <String>::sse_decode(deserializer);
-        return crate::api::MnemonicResponse {
-            mnemonic: var_mnemonic,
+        let mut var_txs = <Vec<crate::api::Transaction>>::sse_decode(deserializer);
+        let mut var_deleted = <Vec<String>>::sse_decode(deserializer);
+        let mut var_seq = <u64>::sse_decode(deserializer);
+        return crate::api::WalletTransactionsSinceResponse {
+            txs: var_txs,
+            deleted: var_deleted,
+            seq: var_seq,
         };
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseDecode for crate::api::WalletsIdsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        let mut var_ids = <Vec<usize>>::sse_decode(deserializer);
+        return crate::api::WalletsIdsResponse { ids: var_ids };
     }
 }
 
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__WalletPaymentCheckHandle_cancel_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__export_wallet_backup_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__generate_random_mnemonic_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__import_wallet_backup_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__init_wallet_ffi_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__is_valid_token_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__payment_type_default_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__protest_status_default_impl(port, ptr, rust_vec_len, data_len),
+        10 => {
+            wire__crate__api__transaction_direction_default_impl(port, ptr, rust_vec_len, data_len)
         }
+        11 => wire__crate__api__transaction_status_default_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__validate_mnemonic_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__wallet_add_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__wallet_check_pending_mints_impl(port, ptr, rust_vec_len, data_len),
+        15 => {
+            wire__crate__api__wallet_check_received_payment_impl(port, ptr, rust_vec_len, data_len)
+        }
+        16 => wire__crate__api__wallet_clean_local_db_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__wallet_combine_partial_signatures_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => {
+            wire__crate__api__wallet_confirm_nut18_delivery_impl(port, ptr, rust_vec_len, data_len)
+        }
+        19 => wire__crate__api__wallet_delete_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__wallet_dev_mode_get_detailed_balance_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__wallet_error_bad_request_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__wallet_error_internal_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__wallet_error_network_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__wallet_error_not_found_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__wallet_export_balance_token_impl(port, ptr, rust_vec_len, data_len),
+        26 => {
+            wire__crate__api__wallet_export_proofs_encrypted_impl(port, ptr, rust_vec_len, data_len)
+        }
+        27 => wire__crate__api__wallet_export_receipt_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__wallet_get_balance_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__wallet_get_credit_balance_by_keyset_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__wallet_get_currency_unit_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__wallet_get_ids_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__wallet_get_keyset_fees_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__wallet_get_mint_url_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__wallet_get_name_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__wallet_get_nprofile_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__wallet_get_pending_transactions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__wallet_get_status_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__wallet_get_total_balance_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__wallet_get_transaction_ids_impl(port, ptr, rust_vec_len, data_len),
+        40 => {
+            wire__crate__api__wallet_get_transaction_token_impl(port, ptr, rust_vec_len, data_len)
+        }
+        41 => wire__crate__api__wallet_get_transactions_impl(port, ptr, rust_vec_len, data_len),
+        42 => {
+            wire__crate__api__wallet_import_proofs_encrypted_impl(port, ptr, rust_vec_len, data_len)
+        }
+        43 => {
+            wire__crate__api__wallet_inspect_payment_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        44 => wire__crate__api__wallet_list_tx_since_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__wallet_load_transaction_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__wallet_melt_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__wallet_migrate_rabid_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__wallet_mint_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__wallet_mint_capabilities_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__wallet_mint_is_offline_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__wallet_mint_is_rabid_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__wallet_mint_onchain_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__wallet_partial_sign_proof_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__wallet_pay_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__wallet_pay_by_token_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__wallet_prepare_melt_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__wallet_prepare_mint_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__wallet_prepare_pay_by_token_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__wallet_prepare_payment_impl(port, ptr, rust_vec_len, data_len),
+        60 => {
+            wire__crate__api__wallet_prepare_payment_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        61 => wire__crate__api__wallet_prepare_send_locked_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__wallet_prepare_send_max_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__wallet_protest_melt_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__wallet_protest_mint_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__wallet_protest_swap_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__wallet_receive_impl(port, ptr, rust_vec_len, data_len),
+        67 => {
+            wire__crate__api__wallet_receive_multisig_token_impl(port, ptr, rust_vec_len, data_len)
+        }
+        68 => wire__crate__api__wallet_recheck_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__wallet_reclaim_funds_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__wallet_reclaim_transaction_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__wallet_recover_pending_stale_proofs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__wallet_refresh_transaction_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__wallet_refresh_transactions_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__wallet_release_reservations_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__wallet_rename_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__wallet_restore_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__wallet_restore_keyset_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__wallet_retry_pending_transaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => wire__crate__api__wallet_set_keep_sent_tokens_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__wallet_stats_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for crate::api::PaymentRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_request = <String>::sse_decode(deserializer);
-        let mut var_pId = <String>::sse_decode(deserializer);
-        return crate::api::PaymentRequest {
-            request: var_request,
-            p_id: var_pId,
-        };
-    }
-}
-
-impl SseDecode for crate::api::PaymentSummary {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_requestId = <String>::sse_decode(deserializer);
-        let mut var_unit = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_fees = <u64>::sse_decode(deserializer);
-        let mut var_reservedFees = <u64>::sse_decode(deserializer);
-        let mut var_expiry = <u64>::sse_decode(deserializer);
-        let mut var_ptype = <crate::api::PaymentType>::sse_decode(deserializer);
-        return crate::api::PaymentSummary {
-            request_id: var_requestId,
-            unit: var_unit,
-            amount: var_amount,
-            fees: var_fees,
-            reserved_fees: var_reservedFees,
-            expiry: var_expiry,
-            ptype: var_ptype,
-        };
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for crate::api::PaymentType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::PaymentType::NotApplicable,
-            1 => crate::api::PaymentType::Token,
-            2 => crate::api::PaymentType::Cdk18,
-            3 => crate::api::PaymentType::OnChain,
-            4 => crate::api::PaymentType::Swap,
-            _ => unreachable!("Invalid variant for PaymentType: {}", inner),
-        };
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<WalletPaymentCheckHandle> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<WalletPaymentCheckHandle>
+{
+}
 
-impl SseDecode for crate::api::ProtestStatus {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::ProtestStatus::Resolved,
-            1 => crate::api::ProtestStatus::Rabid,
-            _ => unreachable!("Invalid variant for ProtestStatus: {}", inner),
-        };
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<WalletPaymentCheckHandle>>
+    for WalletPaymentCheckHandle
+{
+    fn into_into_dart(self) -> FrbWrapper<WalletPaymentCheckHandle> {
+        self.into()
     }
 }
 
-impl SseDecode for crate::api::RestoreWalletResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        return crate::api::RestoreWalletResponse {
-            wallet_id: var_walletId,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AddWalletResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.wallet_id.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::StatusResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_appVersion = <String>::sse_decode(deserializer);
-        return crate::api::StatusResponse {
-            app_version: var_appVersion,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AddWalletResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AddWalletResponse>
+    for crate::api::AddWalletResponse
+{
+    fn into_into_dart(self) -> crate::api::AddWalletResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::Transaction {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_fees = <u64>::sse_decode(deserializer);
-        let mut var_unit = <String>::sse_decode(deserializer);
-        let mut var_tstamp = <u64>::sse_decode(deserializer);
-        let mut var_direction = <crate::api::TransactionDirection>::sse_decode(deserializer);
-        let mut var_memo = <Option<String>>::sse_decode(deserializer);
-        let mut var_ptype = <crate::api::PaymentType>::sse_decode(deserializer);
-        let mut var_status = <crate::api::TransactionStatus>::sse_decode(deserializer);
-        let mut var_meltTx = <crate::api::MeltTx>::sse_decode(deserializer);
-        let mut var_quoteId = <Option<String>>::sse_decode(deserializer);
-        return crate::api::Transaction {
-            id: var_id,
-            amount: var_amount,
-            fees: var_fees,
-            unit: var_unit,
-            tstamp: var_tstamp,
-            direction: var_direction,
-            memo: var_memo,
-            ptype: var_ptype,
-            status: var_status,
-            melt_tx: var_meltTx,
-            quote_id: var_quoteId,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ExportWalletBackupRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.passphrase.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::TransactionDirection {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::TransactionDirection::Incoming,
-            1 => crate::api::TransactionDirection::Outgoing,
-            _ => unreachable!("Invalid variant for TransactionDirection: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ExportWalletBackupRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ExportWalletBackupRequest>
+    for crate::api::ExportWalletBackupRequest
+{
+    fn into_into_dart(self) -> crate::api::ExportWalletBackupRequest {
+        self
     }
 }
-
-impl SseDecode for crate::api::TransactionStatus {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::TransactionStatus::NotApplicable,
-            1 => crate::api::TransactionStatus::Pending,
-            2 => crate::api::TransactionStatus::Settled,
-            3 => crate::api::TransactionStatus::Canceled,
-            _ => unreachable!("Invalid variant for TransactionStatus: {}", inner),
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ExportWalletBackupResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.backup.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ExportWalletBackupResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ExportWalletBackupResponse>
+    for crate::api::ExportWalletBackupResponse
+{
+    fn into_into_dart(self) -> crate::api::ExportWalletBackupResponse {
+        self
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ImportWalletBackupRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backup.into_into_dart().into_dart(),
+            self.passphrase.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ImportWalletBackupRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ImportWalletBackupRequest>
+    for crate::api::ImportWalletBackupRequest
+{
+    fn into_into_dart(self) -> crate::api::ImportWalletBackupRequest {
+        self
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IsValidTokenRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.token.into_into_dart().into_dart()].into_dart()
+    }
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::IsValidTokenRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IsValidTokenRequest>
+    for crate::api::IsValidTokenRequest
+{
+    fn into_into_dart(self) -> crate::api::IsValidTokenRequest {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletBalanceResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_debit = <u64>::sse_decode(deserializer);
-        let mut var_credit = <u64>::sse_decode(deserializer);
-        let mut var_total = <u64>::sse_decode(deserializer);
-        return crate::api::WalletBalanceResponse {
-            debit: var_debit,
-            credit: var_credit,
-            total: var_total,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IsValidTokenResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.amount.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+            self.mint_url.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletCheckPendingMintsResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txIds = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::WalletCheckPendingMintsResponse { tx_ids: var_txIds };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::IsValidTokenResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IsValidTokenResponse>
+    for crate::api::IsValidTokenResponse
+{
+    fn into_into_dart(self) -> crate::api::IsValidTokenResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletCheckReceivedPaymentRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_maxWaitSec = <u64>::sse_decode(deserializer);
-        let mut var_pId = <String>::sse_decode(deserializer);
-        return crate::api::WalletCheckReceivedPaymentRequest {
-            wallet_id: var_walletId,
-            max_wait_sec: var_maxWaitSec,
-            p_id: var_pId,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::KeysetFee {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kid.into_into_dart().into_dart(),
+            self.input_fee_ppk.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletCurrencyUnitResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_unit = <String>::sse_decode(deserializer);
-        return crate::api::WalletCurrencyUnitResponse { unit: var_unit };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::KeysetFee {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::KeysetFee> for crate::api::KeysetFee {
+    fn into_into_dart(self) -> crate::api::KeysetFee {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MeltTx {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.alpha_tx_id.into_into_dart().into_dart(),
+            self.beta_tx_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MeltTx {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MeltTx> for crate::api::MeltTx {
+    fn into_into_dart(self) -> crate::api::MeltTx {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletDevModeDetailedBalanceEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_kid = <String>::sse_decode(deserializer);
-        let mut var_finalExpiry = <Option<u64>>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        return crate::api::WalletDevModeDetailedBalanceEntry {
-            kid: var_kid,
-            final_expiry: var_finalExpiry,
-            amount: var_amount,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MigrateRabidResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.migrated_to_mint.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletDevModeDetailedBalanceResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_entries =
-            <Vec<crate::api::WalletDevModeDetailedBalanceEntry>>::sse_decode(deserializer);
-        return crate::api::WalletDevModeDetailedBalanceResponse {
-            entries: var_entries,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::MigrateRabidResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MigrateRabidResponse>
+    for crate::api::MigrateRabidResponse
+{
+    fn into_into_dart(self) -> crate::api::MigrateRabidResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_kind = <crate::api::WalletErrorKind>::sse_decode(deserializer);
-        let mut var_code = <crate::api::WalletErrorCode>::sse_decode(deserializer);
-        let mut var_msg = <String>::sse_decode(deserializer);
-        return crate::api::WalletError {
-            kind: var_kind,
-            code: var_code,
-            msg: var_msg,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MintIsOfflineResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.offline.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletErrorCode {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::WalletErrorCode::Internal,
-            1 => crate::api::WalletErrorCode::Network,
-            2 => crate::api::WalletErrorCode::WalletNotFound,
-            3 => crate::api::WalletErrorCode::EmptyToken,
-            4 => crate::api::WalletErrorCode::InvalidToken,
-            5 => crate::api::WalletErrorCode::CashuMintUrl,
-            6 => crate::api::WalletErrorCode::Url,
-            7 => crate::api::WalletErrorCode::InsufficientBalance,
-            8 => crate::api::WalletErrorCode::NoActiveKeyset,
-            9 => crate::api::WalletErrorCode::UnknownKeysetId,
-            10 => crate::api::WalletErrorCode::InvalidCurrencyUnit,
-            11 => crate::api::WalletErrorCode::NoPrepareRef,
-            12 => crate::api::WalletErrorCode::InactiveKeyset,
-            13 => crate::api::WalletErrorCode::NoDebitCurrencyInMint,
-            14 => crate::api::WalletErrorCode::InvalidNetwork,
-            15 => crate::api::WalletErrorCode::MissingAmount,
-            16 => crate::api::WalletErrorCode::UnknownPaymentRequest,
-            17 => crate::api::WalletErrorCode::Unsupported,
-            18 => crate::api::WalletErrorCode::TransactionCantBeReclaimed,
-            19 => crate::api::WalletErrorCode::InsufficientOnChainMeltAmount,
-            20 => crate::api::WalletErrorCode::InsufficientOnChainMintAmount,
-            21 => crate::api::WalletErrorCode::NoDevMode,
-            22 => crate::api::WalletErrorCode::InvalidBitcoinAddress,
-            23 => crate::api::WalletErrorCode::InvalidMintUrl,
-            24 => crate::api::WalletErrorCode::InvalidMnemonic,
-            25 => crate::api::WalletErrorCode::WalletAlreadyExists,
-            _ => unreachable!("Invalid variant for WalletErrorCode: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::MintIsOfflineResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MintIsOfflineResponse>
+    for crate::api::MintIsOfflineResponse
+{
+    fn into_into_dart(self) -> crate::api::MintIsOfflineResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletErrorKind {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::WalletErrorKind::BadRequest,
-            1 => crate::api::WalletErrorKind::NotFound,
-            2 => crate::api::WalletErrorKind::Network,
-            3 => crate::api::WalletErrorKind::Internal,
-            4 => crate::api::WalletErrorKind::Initialization,
-            5 => crate::api::WalletErrorKind::Unsupported,
-            _ => unreachable!("Invalid variant for WalletErrorKind: {}", inner),
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MintIsRabidResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.rabid.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletFfiConfig {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_dbFolderPath = <String>::sse_decode(deserializer);
-        let mut var_logLevel = <String>::sse_decode(deserializer);
-        let mut var_jobIntervalSecs = <u64>::sse_decode(deserializer);
-        let mut var_jobInitialDelaySecs = <u64>::sse_decode(deserializer);
-        let mut var_defaultMintUrl = <String>::sse_decode(deserializer);
-        let mut var_bitcoinNetwork = <String>::sse_decode(deserializer);
-        let mut var_mnemonic = <String>::sse_decode(deserializer);
-        let mut var_nostrRelays = <Vec<String>>::sse_decode(deserializer);
-        let mut var_swapExpiryMinutes = <u32>::sse_decode(deserializer);
-        let mut var_devMode = <bool>::sse_decode(deserializer);
-        return crate::api::WalletFfiConfig {
-            db_folder_path: var_dbFolderPath,
-            log_level: var_logLevel,
-            job_interval_secs: var_jobIntervalSecs,
-            job_initial_delay_secs: var_jobInitialDelaySecs,
-            default_mint_url: var_defaultMintUrl,
-            bitcoin_network: var_bitcoinNetwork,
-            mnemonic: var_mnemonic,
-            nostr_relays: var_nostrRelays,
-            swap_expiry_minutes: var_swapExpiryMinutes,
-            dev_mode: var_devMode,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::MintIsRabidResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MintIsRabidResponse>
+    for crate::api::MintIsRabidResponse
+{
+    fn into_into_dart(self) -> crate::api::MintIsRabidResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletMaybeTransactionIdResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txId = <Option<String>>::sse_decode(deserializer);
-        return crate::api::WalletMaybeTransactionIdResponse { tx_id: var_txId };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MnemonicRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.length.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletMintRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        return crate::api::WalletMintRequest {
-            wallet_id: var_walletId,
-            amount: var_amount,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MnemonicRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MnemonicRequest>
+    for crate::api::MnemonicRequest
+{
+    fn into_into_dart(self) -> crate::api::MnemonicRequest {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletMintSummaryResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_quoteId = <String>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_address = <String>::sse_decode(deserializer);
-        let mut var_expiry = <u64>::sse_decode(deserializer);
-        return crate::api::WalletMintSummaryResponse {
-            quote_id: var_quoteId,
-            amount: var_amount,
-            address: var_address,
-            expiry: var_expiry,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MnemonicResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.mnemonic.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletMintUrlResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mintUrl = <String>::sse_decode(deserializer);
-        return crate::api::WalletMintUrlResponse {
-            mint_url: var_mintUrl,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MnemonicResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MnemonicResponse>
+    for crate::api::MnemonicResponse
+{
+    fn into_into_dart(self) -> crate::api::MnemonicResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletNameResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        return crate::api::WalletNameResponse { name: var_name };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PaymentRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.request.into_into_dart().into_dart(),
+            self.p_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletPayRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_rid = <String>::sse_decode(deserializer);
-        return crate::api::WalletPayRequest {
-            wallet_id: var_walletId,
-            rid: var_rid,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PaymentRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PaymentRequest> for crate::api::PaymentRequest {
+    fn into_into_dart(self) -> crate::api::PaymentRequest {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletPaymentByTokenRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_rid = <String>::sse_decode(deserializer);
-        return crate::api::WalletPaymentByTokenRequest {
-            wallet_id: var_walletId,
-            rid: var_rid,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PaymentSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.request_id.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fees.into_into_dart().into_dart(),
+            self.reserved_fees.into_into_dart().into_dart(),
+            self.expiry.into_into_dart().into_dart(),
+            self.ptype.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletPaymentByTokenResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txId = <String>::sse_decode(deserializer);
-        let mut var_token = <String>::sse_decode(deserializer);
-        return crate::api::WalletPaymentByTokenResponse {
-            tx_id: var_txId,
-            token: var_token,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PaymentSummary {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PaymentSummary> for crate::api::PaymentSummary {
+    fn into_into_dart(self) -> crate::api::PaymentSummary {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletPrepareMeltRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_address = <String>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        return crate::api::WalletPrepareMeltRequest {
-            wallet_id: var_walletId,
-            amount: var_amount,
-            address: var_address,
-            description: var_description,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PaymentType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NotApplicable => 0.into_dart(),
+            Self::Token => 1.into_dart(),
+            Self::Cdk18 => 2.into_dart(),
+            Self::OnChain => 3.into_dart(),
+            Self::Swap => 4.into_dart(),
+            Self::Bolt11 => 5.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::api::WalletPreparePaymentByTokenRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        return crate::api::WalletPreparePaymentByTokenRequest {
-            wallet_id: var_walletId,
-            amount: var_amount,
-            description: var_description,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PaymentType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PaymentType> for crate::api::PaymentType {
+    fn into_into_dart(self) -> crate::api::PaymentType {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletPreparePaymentReqRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        let mut var_unit = <String>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        return crate::api::WalletPreparePaymentReqRequest {
-            wallet_id: var_walletId,
-            amount: var_amount,
-            unit: var_unit,
-            description: var_description,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PendingTransaction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.transaction.into_into_dart().into_dart(),
+            self.pending_proofs.into_into_dart().into_dart(),
+            self.unsent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletPreparePaymentReqResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_paymentRequest = <crate::api::PaymentRequest>::sse_decode(deserializer);
-        return crate::api::WalletPreparePaymentReqResponse {
-            payment_request: var_paymentRequest,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PendingTransaction
+{
 }
-
-impl SseDecode for crate::api::WalletPreparePaymentRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_input = <String>::sse_decode(deserializer);
-        return crate::api::WalletPreparePaymentRequest {
-            wallet_id: var_walletId,
-            input: var_input,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PendingTransaction>
+    for crate::api::PendingTransaction
+{
+    fn into_into_dart(self) -> crate::api::PendingTransaction {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletPreparePaymentResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_paymentSummary = <crate::api::PaymentSummary>::sse_decode(deserializer);
-        return crate::api::WalletPreparePaymentResponse {
-            payment_summary: var_paymentSummary,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ProtestStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Resolved => 0.into_dart(),
+            Self::Rabid => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::api::WalletProtestMeltRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_quoteId = <String>::sse_decode(deserializer);
-        return crate::api::WalletProtestMeltRequest {
-            wallet_id: var_walletId,
-            quote_id: var_quoteId,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ProtestStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ProtestStatus> for crate::api::ProtestStatus {
+    fn into_into_dart(self) -> crate::api::ProtestStatus {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletProtestMeltResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_status = <crate::api::ProtestStatus>::sse_decode(deserializer);
-        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::WalletProtestMeltResponse {
-            status: var_status,
-            amount: var_amount,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RestoreWalletResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.wallet_id.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletProtestMintRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_quoteId = <String>::sse_decode(deserializer);
-        return crate::api::WalletProtestMintRequest {
-            wallet_id: var_walletId,
-            quote_id: var_quoteId,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RestoreWalletResponse
+{
 }
-
-impl SseDecode for crate::api::WalletProtestMintResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_status = <crate::api::ProtestStatus>::sse_decode(deserializer);
-        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::WalletProtestMintResponse {
-            status: var_status,
-            amount: var_amount,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RestoreWalletResponse>
+    for crate::api::RestoreWalletResponse
+{
+    fn into_into_dart(self) -> crate::api::RestoreWalletResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletProtestSwapRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_commitmentSig = <String>::sse_decode(deserializer);
-        return crate::api::WalletProtestSwapRequest {
-            wallet_id: var_walletId,
-            commitment_sig: var_commitmentSig,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::StatusResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.app_version.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletProtestSwapResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_status = <crate::api::ProtestStatus>::sse_decode(deserializer);
-        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::WalletProtestSwapResponse {
-            status: var_status,
-            amount: var_amount,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatusResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::StatusResponse> for crate::api::StatusResponse {
+    fn into_into_dart(self) -> crate::api::StatusResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletReceiveRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_token = <String>::sse_decode(deserializer);
-        return crate::api::WalletReceiveRequest {
-            wallet_id: var_walletId,
-            token: var_token,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TotalBalanceResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.balances.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletReclaimTransactionRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_txId = <String>::sse_decode(deserializer);
-        return crate::api::WalletReclaimTransactionRequest {
-            wallet_id: var_walletId,
-            tx_id: var_txId,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TotalBalanceResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TotalBalanceResponse>
+    for crate::api::TotalBalanceResponse
+{
+    fn into_into_dart(self) -> crate::api::TotalBalanceResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletReclaimTransactionResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        return crate::api::WalletReclaimTransactionResponse { amount: var_amount };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::Transaction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fees.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.tstamp.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+            self.ptype.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.melt_tx.into_into_dart().into_dart(),
+            self.quote_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletRecoverStaleTransactionResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_amount = <u64>::sse_decode(deserializer);
-        return crate::api::WalletRecoverStaleTransactionResponse { amount: var_amount };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Transaction {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::Transaction> for crate::api::Transaction {
+    fn into_into_dart(self) -> crate::api::Transaction {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletRefreshTransactionRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_txId = <String>::sse_decode(deserializer);
-        return crate::api::WalletRefreshTransactionRequest {
-            wallet_id: var_walletId,
-            tx_id: var_txId,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TransactionDirection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Incoming => 0.into_dart(),
+            Self::Outgoing => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::api::WalletRefreshTransactionResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_updated = <bool>::sse_decode(deserializer);
-        return crate::api::WalletRefreshTransactionResponse {
-            updated: var_updated,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TransactionDirection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TransactionDirection>
+    for crate::api::TransactionDirection
+{
+    fn into_into_dart(self) -> crate::api::TransactionDirection {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletRefreshTransactionsResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_updated = <usize>::sse_decode(deserializer);
-        return crate::api::WalletRefreshTransactionsResponse {
-            updated: var_updated,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TransactionStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NotApplicable => 0.into_dart(),
+            Self::Pending => 1.into_dart(),
+            Self::Settled => 2.into_dart(),
+            Self::Canceled => 3.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::api::WalletRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        return crate::api::WalletRequest {
-            wallet_id: var_walletId,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TransactionStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TransactionStatus>
+    for crate::api::TransactionStatus
+{
+    fn into_into_dart(self) -> crate::api::TransactionStatus {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletTransactionIdResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txId = <String>::sse_decode(deserializer);
-        return crate::api::WalletTransactionIdResponse { tx_id: var_txId };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::UnitBalance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.unit.into_into_dart().into_dart(),
+            self.debit.into_into_dart().into_dart(),
+            self.credit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletTransactionIdsResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txIds = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::WalletTransactionIdsResponse { tx_ids: var_txIds };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::UnitBalance {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::UnitBalance> for crate::api::UnitBalance {
+    fn into_into_dart(self) -> crate::api::UnitBalance {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletTransactionRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_walletId = <usize>::sse_decode(deserializer);
-        let mut var_txId = <String>::sse_decode(deserializer);
-        return crate::api::WalletTransactionRequest {
-            wallet_id: var_walletId,
-            tx_id: var_txId,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ValidateMnemonicRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.mnemonic.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletTransactionResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_transaction = <crate::api::Transaction>::sse_decode(deserializer);
-        return crate::api::WalletTransactionResponse {
-            transaction: var_transaction,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ValidateMnemonicRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ValidateMnemonicRequest>
+    for crate::api::ValidateMnemonicRequest
+{
+    fn into_into_dart(self) -> crate::api::ValidateMnemonicRequest {
+        self
     }
 }
-
-impl SseDecode for crate::api::WalletTransactionsResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txs = <Vec<crate::api::Transaction>>::sse_decode(deserializer);
-        return crate::api::WalletTransactionsResponse { txs: var_txs };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ValidateMnemonicResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.valid.into_into_dart().into_dart(),
+            self.word_count.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WalletsIdsResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ids = <Vec<usize>>::sse_decode(deserializer);
-        return crate::api::WalletsIdsResponse { ids: var_ids };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ValidateMnemonicResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ValidateMnemonicResponse>
+    for crate::api::ValidateMnemonicResponse
+{
+    fn into_into_dart(self) -> crate::api::ValidateMnemonicResponse {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__WalletPaymentCheckHandle_cancel_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__generate_random_mnemonic_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__init_wallet_ffi_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__is_valid_token_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__payment_type_default_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__protest_status_default_impl(port, ptr, rust_vec_len, data_len),
-        8 => {
-            wire__crate__api__transaction_direction_default_impl(port, ptr, rust_vec_len, data_len)
-        }
-        9 => wire__crate__api__transaction_status_default_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__wallet_add_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__wallet_check_pending_mints_impl(port, ptr, rust_vec_len, data_len),
-        12 => {
-            wire__crate__api__wallet_check_received_payment_impl(port, ptr, rust_vec_len, data_len)
-        }
-        13 => wire__crate__api__wallet_delete_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__wallet_dev_mode_get_detailed_balance_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        15 => wire__crate__api__wallet_error_bad_request_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__wallet_error_internal_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__wallet_error_network_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__wallet_error_not_found_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__wallet_get_balance_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__wallet_get_currency_unit_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__wallet_get_ids_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__wallet_get_mint_url_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__wallet_get_name_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__wallet_get_status_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__wallet_get_transaction_ids_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__wallet_get_transactions_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__wallet_load_transaction_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__wallet_melt_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__wallet_migrate_rabid_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__wallet_mint_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__wallet_mint_is_offline_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__wallet_mint_is_rabid_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__wallet_pay_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__wallet_pay_by_token_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__wallet_prepare_melt_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__wallet_prepare_pay_by_token_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__wallet_prepare_payment_impl(port, ptr, rust_vec_len, data_len),
-        38 => {
-            wire__crate__api__wallet_prepare_payment_request_impl(port, ptr, rust_vec_len, data_len)
-        }
-        39 => wire__crate__api__wallet_protest_melt_impl(port, ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__wallet_protest_mint_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__wallet_protest_swap_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__wallet_receive_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__wallet_reclaim_transaction_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__wallet_recover_pending_stale_proofs_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        45 => wire__crate__api__wallet_refresh_transaction_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__wallet_refresh_transactions_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__wallet_restore_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletBalanceResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.debit.into_into_dart().into_dart(),
+            self.credit.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletBalanceResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletBalanceResponse>
+    for crate::api::WalletBalanceResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletBalanceResponse {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<WalletPaymentCheckHandle> {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCheckPendingMintsResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [self.tx_ids.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<WalletPaymentCheckHandle>
+    for crate::api::WalletCheckPendingMintsResponse
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<WalletPaymentCheckHandle>>
-    for WalletPaymentCheckHandle
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCheckPendingMintsResponse>
+    for crate::api::WalletCheckPendingMintsResponse
 {
-    fn into_into_dart(self) -> FrbWrapper<WalletPaymentCheckHandle> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::WalletCheckPendingMintsResponse {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::AddWalletResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCheckReceivedPaymentRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.wallet_id.into_into_dart().into_dart()].into_dart()
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.max_wait_sec.into_into_dart().into_dart(),
+            self.p_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AddWalletResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::AddWalletResponse>
-    for crate::api::AddWalletResponse
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletCheckReceivedPaymentRequest
 {
-    fn into_into_dart(self) -> crate::api::AddWalletResponse {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCheckReceivedPaymentRequest>
+    for crate::api::WalletCheckReceivedPaymentRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletCheckReceivedPaymentRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::IsValidTokenRequest {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCleanLocalDbRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.token.into_into_dart().into_dart()].into_dart()
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.prune_counters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::IsValidTokenRequest
+    for crate::api::WalletCleanLocalDbRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::IsValidTokenRequest>
-    for crate::api::IsValidTokenRequest
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCleanLocalDbRequest>
+    for crate::api::WalletCleanLocalDbRequest
 {
-    fn into_into_dart(self) -> crate::api::IsValidTokenRequest {
+    fn into_into_dart(self) -> crate::api::WalletCleanLocalDbRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::IsValidTokenResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCleanLocalDbResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.amount.into_into_dart().into_dart(),
-            self.memo.into_into_dart().into_dart(),
-            self.mint_url.into_into_dart().into_dart(),
-            self.unit.into_into_dart().into_dart(),
+            self.cleaned_proofs.into_into_dart().into_dart(),
+            self.pruned_counters.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::IsValidTokenResponse
+    for crate::api::WalletCleanLocalDbResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::IsValidTokenResponse>
-    for crate::api::IsValidTokenResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCleanLocalDbResponse>
+    for crate::api::WalletCleanLocalDbResponse
 {
-    fn into_into_dart(self) -> crate::api::IsValidTokenResponse {
+    fn into_into_dart(self) -> crate::api::WalletCleanLocalDbResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::MeltTx {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCombinePartialSignaturesRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.alpha_tx_id.into_into_dart().into_dart(),
-            self.beta_tx_id.into_into_dart().into_dart(),
+            self.wallet_id.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.witnesses.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MeltTx {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::MeltTx> for crate::api::MeltTx {
-    fn into_into_dart(self) -> crate::api::MeltTx {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletCombinePartialSignaturesRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCombinePartialSignaturesRequest>
+    for crate::api::WalletCombinePartialSignaturesRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletCombinePartialSignaturesRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::MigrateRabidResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCombinePartialSignaturesResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.migrated_to_mint.into_into_dart().into_dart()].into_dart()
+        [self.spendable.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::MigrateRabidResponse
+    for crate::api::WalletCombinePartialSignaturesResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::MigrateRabidResponse>
-    for crate::api::MigrateRabidResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCombinePartialSignaturesResponse>
+    for crate::api::WalletCombinePartialSignaturesResponse
 {
-    fn into_into_dart(self) -> crate::api::MigrateRabidResponse {
+    fn into_into_dart(self) -> crate::api::WalletCombinePartialSignaturesResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::MintIsOfflineResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletConfirmNut18DeliveryRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.offline.into_into_dart().into_dart()].into_dart()
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.tx_id.into_into_dart().into_dart(),
+            self.timeout_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::MintIsOfflineResponse
+    for crate::api::WalletConfirmNut18DeliveryRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::MintIsOfflineResponse>
-    for crate::api::MintIsOfflineResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletConfirmNut18DeliveryRequest>
+    for crate::api::WalletConfirmNut18DeliveryRequest
 {
-    fn into_into_dart(self) -> crate::api::MintIsOfflineResponse {
+    fn into_into_dart(self) -> crate::api::WalletConfirmNut18DeliveryRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::MintIsRabidResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletConfirmNut18DeliveryResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.rabid.into_into_dart().into_dart()].into_dart()
+        [self.delivered.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::MintIsRabidResponse
+    for crate::api::WalletConfirmNut18DeliveryResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::MintIsRabidResponse>
-    for crate::api::MintIsRabidResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletConfirmNut18DeliveryResponse>
+    for crate::api::WalletConfirmNut18DeliveryResponse
 {
-    fn into_into_dart(self) -> crate::api::MintIsRabidResponse {
+    fn into_into_dart(self) -> crate::api::WalletConfirmNut18DeliveryResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::MnemonicRequest {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletCurrencyUnitResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.length.into_into_dart().into_dart()].into_dart()
+        [self.unit.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MnemonicRequest {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::MnemonicRequest>
-    for crate::api::MnemonicRequest
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletCurrencyUnitResponse
 {
-    fn into_into_dart(self) -> crate::api::MnemonicRequest {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCurrencyUnitResponse>
+    for crate::api::WalletCurrencyUnitResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletCurrencyUnitResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::MnemonicResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletDevModeDetailedBalanceEntry {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.mnemonic.into_into_dart().into_dart()].into_dart()
+        [
+            self.kid.into_into_dart().into_dart(),
+            self.final_expiry.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MnemonicResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::MnemonicResponse>
-    for crate::api::MnemonicResponse
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletDevModeDetailedBalanceEntry
 {
-    fn into_into_dart(self) -> crate::api::MnemonicResponse {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletDevModeDetailedBalanceEntry>
+    for crate::api::WalletDevModeDetailedBalanceEntry
+{
+    fn into_into_dart(self) -> crate::api::WalletDevModeDetailedBalanceEntry {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::PaymentRequest {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletDevModeDetailedBalanceResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.request.into_into_dart().into_dart(),
-            self.p_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        [self.entries.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PaymentRequest {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PaymentRequest> for crate::api::PaymentRequest {
-    fn into_into_dart(self) -> crate::api::PaymentRequest {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletDevModeDetailedBalanceResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletDevModeDetailedBalanceResponse>
+    for crate::api::WalletDevModeDetailedBalanceResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletDevModeDetailedBalanceResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::PaymentSummary {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletError {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.request_id.into_into_dart().into_dart(),
-            self.unit.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-            self.fees.into_into_dart().into_dart(),
-            self.reserved_fees.into_into_dart().into_dart(),
-            self.expiry.into_into_dart().into_dart(),
-            self.ptype.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.code.into_into_dart().into_dart(),
+            self.msg.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PaymentSummary {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PaymentSummary> for crate::api::PaymentSummary {
-    fn into_into_dart(self) -> crate::api::PaymentSummary {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletError> for crate::api::WalletError {
+    fn into_into_dart(self) -> crate::api::WalletError {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::PaymentType {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletErrorCode {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::NotApplicable => 0.into_dart(),
-            Self::Token => 1.into_dart(),
-            Self::Cdk18 => 2.into_dart(),
-            Self::OnChain => 3.into_dart(),
-            Self::Swap => 4.into_dart(),
+            Self::Internal => 0.into_dart(),
+            Self::Network => 1.into_dart(),
+            Self::WalletNotFound => 2.into_dart(),
+            Self::EmptyToken => 3.into_dart(),
+            Self::InvalidToken => 4.into_dart(),
+            Self::CashuMintUrl => 5.into_dart(),
+            Self::Url => 6.into_dart(),
+            Self::InsufficientBalance => 7.into_dart(),
+            Self::NoActiveKeyset => 8.into_dart(),
+            Self::UnknownKeysetId => 9.into_dart(),
+            Self::InvalidCurrencyUnit => 10.into_dart(),
+            Self::NoPrepareRef => 11.into_dart(),
+            Self::InactiveKeyset => 12.into_dart(),
+            Self::NoDebitCurrencyInMint => 13.into_dart(),
+            Self::InvalidNetwork => 14.into_dart(),
+            Self::MissingAmount => 15.into_dart(),
+            Self::UnknownPaymentRequest => 16.into_dart(),
+            Self::Unsupported => 17.into_dart(),
+            Self::TransactionCantBeReclaimed => 18.into_dart(),
+            Self::InsufficientOnChainMeltAmount => 19.into_dart(),
+            Self::InsufficientOnChainMintAmount => 20.into_dart(),
+            Self::NoDevMode => 21.into_dart(),
+            Self::InvalidBitcoinAddress => 22.into_dart(),
+            Self::InvalidMintUrl => 23.into_dart(),
+            Self::InvalidMnemonic => 24.into_dart(),
+            Self::WalletAlreadyExists => 25.into_dart(),
             _ => unreachable!(),
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PaymentType {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PaymentType> for crate::api::PaymentType {
-    fn into_into_dart(self) -> crate::api::PaymentType {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletErrorCode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletErrorCode>
+    for crate::api::WalletErrorCode
+{
+    fn into_into_dart(self) -> crate::api::WalletErrorCode {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::ProtestStatus {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletErrorKind {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::Resolved => 0.into_dart(),
-            Self::Rabid => 1.into_dart(),
+            Self::BadRequest => 0.into_dart(),
+            Self::NotFound => 1.into_dart(),
+            Self::Network => 2.into_dart(),
+            Self::Internal => 3.into_dart(),
+            Self::Initialization => 4.into_dart(),
+            Self::Unsupported => 5.into_dart(),
             _ => unreachable!(),
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ProtestStatus {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::ProtestStatus> for crate::api::ProtestStatus {
-    fn into_into_dart(self) -> crate::api::ProtestStatus {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletErrorKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletErrorKind>
+    for crate::api::WalletErrorKind
+{
+    fn into_into_dart(self) -> crate::api::WalletErrorKind {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::RestoreWalletResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletExportBalanceTokenRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.wallet_id.into_into_dart().into_dart()].into_dart()
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::RestoreWalletResponse
+    for crate::api::WalletExportBalanceTokenRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::RestoreWalletResponse>
-    for crate::api::RestoreWalletResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletExportBalanceTokenRequest>
+    for crate::api::WalletExportBalanceTokenRequest
 {
-    fn into_into_dart(self) -> crate::api::RestoreWalletResponse {
+    fn into_into_dart(self) -> crate::api::WalletExportBalanceTokenRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::StatusResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletExportBalanceTokenResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.app_version.into_into_dart().into_dart()].into_dart()
+        [self.token.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatusResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::StatusResponse> for crate::api::StatusResponse {
-    fn into_into_dart(self) -> crate::api::StatusResponse {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletExportBalanceTokenResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletExportBalanceTokenResponse>
+    for crate::api::WalletExportBalanceTokenResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletExportBalanceTokenResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::Transaction {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletExportProofsEncryptedRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-            self.fees.into_into_dart().into_dart(),
-            self.unit.into_into_dart().into_dart(),
-            self.tstamp.into_into_dart().into_dart(),
-            self.direction.into_into_dart().into_dart(),
-            self.memo.into_into_dart().into_dart(),
-            self.ptype.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-            self.melt_tx.into_into_dart().into_dart(),
-            self.quote_id.into_into_dart().into_dart(),
+            self.wallet_id.into_into_dart().into_dart(),
+            self.passphrase.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Transaction {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::Transaction> for crate::api::Transaction {
-    fn into_into_dart(self) -> crate::api::Transaction {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletExportProofsEncryptedRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletExportProofsEncryptedRequest>
+    for crate::api::WalletExportProofsEncryptedRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletExportProofsEncryptedRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::TransactionDirection {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletExportProofsEncryptedResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Incoming => 0.into_dart(),
-            Self::Outgoing => 1.into_dart(),
-            _ => unreachable!(),
-        }
+        [self.blob.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::TransactionDirection
+    for crate::api::WalletExportProofsEncryptedResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::TransactionDirection>
-    for crate::api::TransactionDirection
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletExportProofsEncryptedResponse>
+    for crate::api::WalletExportProofsEncryptedResponse
 {
-    fn into_into_dart(self) -> crate::api::TransactionDirection {
+    fn into_into_dart(self) -> crate::api::WalletExportProofsEncryptedResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::TransactionStatus {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletExportReceiptResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::NotApplicable => 0.into_dart(),
-            Self::Pending => 1.into_dart(),
-            Self::Settled => 2.into_dart(),
-            Self::Canceled => 3.into_dart(),
-            _ => unreachable!(),
-        }
+        [self.receipt.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TransactionStatus {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::TransactionStatus>
-    for crate::api::TransactionStatus
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletExportReceiptResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletExportReceiptResponse>
+    for crate::api::WalletExportReceiptResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletExportReceiptResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletFfiConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.db_folder_path.into_into_dart().into_dart(),
+            self.log_level.into_into_dart().into_dart(),
+            self.job_interval_secs.into_into_dart().into_dart(),
+            self.job_initial_delay_secs.into_into_dart().into_dart(),
+            self.default_mint_url.into_into_dart().into_dart(),
+            self.bitcoin_network.into_into_dart().into_dart(),
+            self.mnemonic.into_into_dart().into_dart(),
+            self.passphrase.into_into_dart().into_dart(),
+            self.nostr_relays.into_into_dart().into_dart(),
+            self.swap_expiry_minutes.into_into_dart().into_dart(),
+            self.dev_mode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletFfiConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletFfiConfig>
+    for crate::api::WalletFfiConfig
 {
-    fn into_into_dart(self) -> crate::api::TransactionStatus {
+    fn into_into_dart(self) -> crate::api::WalletFfiConfig {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletBalanceResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletImportProofsEncryptedRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.debit.into_into_dart().into_dart(),
-            self.credit.into_into_dart().into_dart(),
-            self.total.into_into_dart().into_dart(),
+            self.wallet_id.into_into_dart().into_dart(),
+            self.blob.into_into_dart().into_dart(),
+            self.passphrase.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletBalanceResponse
+    for crate::api::WalletImportProofsEncryptedRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletBalanceResponse>
-    for crate::api::WalletBalanceResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletImportProofsEncryptedRequest>
+    for crate::api::WalletImportProofsEncryptedRequest
 {
-    fn into_into_dart(self) -> crate::api::WalletBalanceResponse {
+    fn into_into_dart(self) -> crate::api::WalletImportProofsEncryptedRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletCheckPendingMintsResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletImportProofsEncryptedResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.tx_ids.into_into_dart().into_dart()].into_dart()
+        [self.imported.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletCheckPendingMintsResponse
+    for crate::api::WalletImportProofsEncryptedResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCheckPendingMintsResponse>
-    for crate::api::WalletCheckPendingMintsResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletImportProofsEncryptedResponse>
+    for crate::api::WalletImportProofsEncryptedResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletCheckPendingMintsResponse {
+    fn into_into_dart(self) -> crate::api::WalletImportProofsEncryptedResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletCheckReceivedPaymentRequest {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletInspectPaymentRequestResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.wallet_id.into_into_dart().into_dart(),
-            self.max_wait_sec.into_into_dart().into_dart(),
-            self.p_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        [self.info.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletCheckReceivedPaymentRequest
+    for crate::api::WalletInspectPaymentRequestResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCheckReceivedPaymentRequest>
-    for crate::api::WalletCheckReceivedPaymentRequest
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletInspectPaymentRequestResponse>
+    for crate::api::WalletInspectPaymentRequestResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletCheckReceivedPaymentRequest {
+    fn into_into_dart(self) -> crate::api::WalletInspectPaymentRequestResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletCurrencyUnitResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletKeepSentTokensRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.unit.into_into_dart().into_dart()].into_dart()
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.keep.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletCurrencyUnitResponse
+    for crate::api::WalletKeepSentTokensRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletCurrencyUnitResponse>
-    for crate::api::WalletCurrencyUnitResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletKeepSentTokensRequest>
+    for crate::api::WalletKeepSentTokensRequest
 {
-    fn into_into_dart(self) -> crate::api::WalletCurrencyUnitResponse {
+    fn into_into_dart(self) -> crate::api::WalletKeepSentTokensRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletDevModeDetailedBalanceEntry {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletKeysetFeesResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.kid.into_into_dart().into_dart(),
-            self.final_expiry.into_into_dart().into_dart(),
-            self.amount.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        [self.fees.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletDevModeDetailedBalanceEntry
+    for crate::api::WalletKeysetFeesResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletDevModeDetailedBalanceEntry>
-    for crate::api::WalletDevModeDetailedBalanceEntry
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletKeysetFeesResponse>
+    for crate::api::WalletKeysetFeesResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletDevModeDetailedBalanceEntry {
+    fn into_into_dart(self) -> crate::api::WalletKeysetFeesResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletDevModeDetailedBalanceResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletMaybeTransactionIdResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.entries.into_into_dart().into_dart()].into_dart()
+        [self.tx_id.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletDevModeDetailedBalanceResponse
+    for crate::api::WalletMaybeTransactionIdResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletDevModeDetailedBalanceResponse>
-    for crate::api::WalletDevModeDetailedBalanceResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletMaybeTransactionIdResponse>
+    for crate::api::WalletMaybeTransactionIdResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletDevModeDetailedBalanceResponse {
+    fn into_into_dart(self) -> crate::api::WalletMaybeTransactionIdResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletError {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletMintBolt11Request {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.kind.into_into_dart().into_dart(),
-            self.code.into_into_dart().into_dart(),
-            self.msg.into_into_dart().into_dart(),
+            self.wallet_id.into_into_dart().into_dart(),
+            self.quote_id.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletError> for crate::api::WalletError {
-    fn into_into_dart(self) -> crate::api::WalletError {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletErrorCode {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Internal => 0.into_dart(),
-            Self::Network => 1.into_dart(),
-            Self::WalletNotFound => 2.into_dart(),
-            Self::EmptyToken => 3.into_dart(),
-            Self::InvalidToken => 4.into_dart(),
-            Self::CashuMintUrl => 5.into_dart(),
-            Self::Url => 6.into_dart(),
-            Self::InsufficientBalance => 7.into_dart(),
-            Self::NoActiveKeyset => 8.into_dart(),
-            Self::UnknownKeysetId => 9.into_dart(),
-            Self::InvalidCurrencyUnit => 10.into_dart(),
-            Self::NoPrepareRef => 11.into_dart(),
-            Self::InactiveKeyset => 12.into_dart(),
-            Self::NoDebitCurrencyInMint => 13.into_dart(),
-            Self::InvalidNetwork => 14.into_dart(),
-            Self::MissingAmount => 15.into_dart(),
-            Self::UnknownPaymentRequest => 16.into_dart(),
-            Self::Unsupported => 17.into_dart(),
-            Self::TransactionCantBeReclaimed => 18.into_dart(),
-            Self::InsufficientOnChainMeltAmount => 19.into_dart(),
-            Self::InsufficientOnChainMintAmount => 20.into_dart(),
-            Self::NoDevMode => 21.into_dart(),
-            Self::InvalidBitcoinAddress => 22.into_dart(),
-            Self::InvalidMintUrl => 23.into_dart(),
-            Self::InvalidMnemonic => 24.into_dart(),
-            Self::WalletAlreadyExists => 25.into_dart(),
-            _ => unreachable!(),
-        }
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletErrorCode {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletErrorCode>
-    for crate::api::WalletErrorCode
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletMintBolt11Request
 {
-    fn into_into_dart(self) -> crate::api::WalletErrorCode {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletErrorKind {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::BadRequest => 0.into_dart(),
-            Self::NotFound => 1.into_dart(),
-            Self::Network => 2.into_dart(),
-            Self::Internal => 3.into_dart(),
-            Self::Initialization => 4.into_dart(),
-            Self::Unsupported => 5.into_dart(),
-            _ => unreachable!(),
-        }
-    }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletErrorKind {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletErrorKind>
-    for crate::api::WalletErrorKind
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletMintBolt11Request>
+    for crate::api::WalletMintBolt11Request
 {
-    fn into_into_dart(self) -> crate::api::WalletErrorKind {
+    fn into_into_dart(self) -> crate::api::WalletMintBolt11Request {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletFfiConfig {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletMintCapabilitiesResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.db_folder_path.into_into_dart().into_dart(),
-            self.log_level.into_into_dart().into_dart(),
-            self.job_interval_secs.into_into_dart().into_dart(),
-            self.job_initial_delay_secs.into_into_dart().into_dart(),
-            self.default_mint_url.into_into_dart().into_dart(),
-            self.bitcoin_network.into_into_dart().into_dart(),
-            self.mnemonic.into_into_dart().into_dart(),
-            self.nostr_relays.into_into_dart().into_dart(),
-            self.swap_expiry_minutes.into_into_dart().into_dart(),
-            self.dev_mode.into_into_dart().into_dart(),
+            self.mint_enabled.into_into_dart().into_dart(),
+            self.melt_enabled.into_into_dart().into_dart(),
+            self.token_state_check.into_into_dart().into_dart(),
+            self.restore.into_into_dart().into_dart(),
+            self.p2pk.into_into_dart().into_dart(),
+            self.dleq.into_into_dart().into_dart(),
+            self.htlc.into_into_dart().into_dart(),
+            self.mpp.into_into_dart().into_dart(),
+            self.websocket.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WalletFfiConfig {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletFfiConfig>
-    for crate::api::WalletFfiConfig
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletMintCapabilitiesResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletFfiConfig {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletMintCapabilitiesResponse>
+    for crate::api::WalletMintCapabilitiesResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletMintCapabilitiesResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletMaybeTransactionIdResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletMintQuoteResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.tx_id.into_into_dart().into_dart()].into_dart()
+        [
+            self.quote_id.into_into_dart().into_dart(),
+            self.request.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.expiry.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletMaybeTransactionIdResponse
+    for crate::api::WalletMintQuoteResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletMaybeTransactionIdResponse>
-    for crate::api::WalletMaybeTransactionIdResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletMintQuoteResponse>
+    for crate::api::WalletMintQuoteResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletMaybeTransactionIdResponse {
+    fn into_into_dart(self) -> crate::api::WalletMintQuoteResponse {
         self
     }
 }
@@ -3458,6 +5907,61 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletNameResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletNprofileResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.nprofile.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletNprofileResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletNprofileResponse>
+    for crate::api::WalletNprofileResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletNprofileResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPartialSignProofRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletPartialSignProofRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPartialSignProofRequest>
+    for crate::api::WalletPartialSignProofRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletPartialSignProofRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPartialSignProofResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.witness.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletPartialSignProofResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPartialSignProofResponse>
+    for crate::api::WalletPartialSignProofResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletPartialSignProofResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletPayRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3502,6 +6006,9 @@ impl flutter_rust_bridge::IntoDart for crate::api::WalletPaymentByTokenResponse
         [
             self.tx_id.into_into_dart().into_dart(),
             self.token.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fees.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3518,6 +6025,23 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPaymentByTokenResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPendingTransactionsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.txs.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletPendingTransactionsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPendingTransactionsResponse>
+    for crate::api::WalletPendingTransactionsResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletPendingTransactionsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletPrepareMeltRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3598,45 +6122,93 @@ impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
 impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPreparePaymentReqResponse>
     for crate::api::WalletPreparePaymentReqResponse
 {
-    fn into_into_dart(self) -> crate::api::WalletPreparePaymentReqResponse {
+    fn into_into_dart(self) -> crate::api::WalletPreparePaymentReqResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPreparePaymentRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.input.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletPreparePaymentRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPreparePaymentRequest>
+    for crate::api::WalletPreparePaymentRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletPreparePaymentRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPreparePaymentResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.payment_summary.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletPreparePaymentResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPreparePaymentResponse>
+    for crate::api::WalletPreparePaymentResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletPreparePaymentResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletPreparePaymentRequest {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPrepareSendLockedRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.wallet_id.into_into_dart().into_dart(),
-            self.input.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.locktime.into_into_dart().into_dart(),
+            self.refund_key.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletPreparePaymentRequest
+    for crate::api::WalletPrepareSendLockedRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPreparePaymentRequest>
-    for crate::api::WalletPreparePaymentRequest
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPrepareSendLockedRequest>
+    for crate::api::WalletPrepareSendLockedRequest
 {
-    fn into_into_dart(self) -> crate::api::WalletPreparePaymentRequest {
+    fn into_into_dart(self) -> crate::api::WalletPrepareSendLockedRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WalletPreparePaymentResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::WalletPrepareSendMaxRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.payment_summary.into_into_dart().into_dart()].into_dart()
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WalletPreparePaymentResponse
+    for crate::api::WalletPrepareSendMaxRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPreparePaymentResponse>
-    for crate::api::WalletPreparePaymentResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletPrepareSendMaxRequest>
+    for crate::api::WalletPrepareSendMaxRequest
 {
-    fn into_into_dart(self) -> crate::api::WalletPreparePaymentResponse {
+    fn into_into_dart(self) -> crate::api::WalletPrepareSendMaxRequest {
         self
     }
 }
@@ -3767,6 +6339,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletProtestSwapResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletReceiveMultisigTokenResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tx_id.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.ys.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletReceiveMultisigTokenResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletReceiveMultisigTokenResponse>
+    for crate::api::WalletReceiveMultisigTokenResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletReceiveMultisigTokenResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletReceiveRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3788,6 +6382,52 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletReceiveRequest>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletReceiveResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tx_id.into_into_dart().into_dart(),
+            self.received.into_into_dart().into_dart(),
+            self.stored.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletReceiveResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletReceiveResponse>
+    for crate::api::WalletReceiveResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletReceiveResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletRecheckResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.deleted.into_into_dart().into_dart(),
+            self.restored.into_into_dart().into_dart(),
+            self.pending.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletRecheckResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRecheckResponse>
+    for crate::api::WalletRecheckResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletRecheckResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletReclaimTransactionRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3898,6 +6538,44 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRefreshTransactionsResp
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletReleaseReservationsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.released.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletReleaseReservationsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletReleaseReservationsResponse>
+    for crate::api::WalletReleaseReservationsResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletReleaseReservationsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletRenameRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletRenameRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRenameRequest>
+    for crate::api::WalletRenameRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletRenameRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.wallet_id.into_into_dart().into_dart()].into_dart()
@@ -3910,6 +6588,112 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRequest> for crate::api
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletRestoreKeysetRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.keyset_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletRestoreKeysetRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRestoreKeysetRequest>
+    for crate::api::WalletRestoreKeysetRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletRestoreKeysetRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletRestoreKeysetResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.restored.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletRestoreKeysetResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRestoreKeysetResponse>
+    for crate::api::WalletRestoreKeysetResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletRestoreKeysetResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletRetryPendingTransactionResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.retried.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletRetryPendingTransactionResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletRetryPendingTransactionResponse>
+    for crate::api::WalletRetryPendingTransactionResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletRetryPendingTransactionResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletSendResultResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tx_id.into_into_dart().into_dart(),
+            self.token.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fees.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletSendResultResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletSendResultResponse>
+    for crate::api::WalletSendResultResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletSendResultResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletStatsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.unspent_proofs.into_into_dart().into_dart(),
+            self.pending_proofs.into_into_dart().into_dart(),
+            self.reserved_proofs.into_into_dart().into_dart(),
+            self.total_proofs.into_into_dart().into_dart(),
+            self.keysets.into_into_dart().into_dart(),
+            self.random_proofs.into_into_dart().into_dart(),
+            self.transactions.into_into_dart().into_dart(),
+            self.oldest_tx.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletStatsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletStatsResponse>
+    for crate::api::WalletStatsResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletStatsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionIdResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.tx_id.into_into_dart().into_dart()].into_dart()
@@ -3982,6 +6766,23 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionTokenResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.token.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletTransactionTokenResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionTokenResponse>
+    for crate::api::WalletTransactionTokenResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletTransactionTokenResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionsResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.txs.into_into_dart().into_dart()].into_dart()
@@ -3999,6 +6800,49 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionsResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionsSinceRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_id.into_into_dart().into_dart(),
+            self.seq.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletTransactionsSinceRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionsSinceRequest>
+    for crate::api::WalletTransactionsSinceRequest
+{
+    fn into_into_dart(self) -> crate::api::WalletTransactionsSinceRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WalletTransactionsSinceResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.txs.into_into_dart().into_dart(),
+            self.deleted.into_into_dart().into_dart(),
+            self.seq.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WalletTransactionsSinceResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WalletTransactionsSinceResponse>
+    for crate::api::WalletTransactionsSinceResponse
+{
+    fn into_into_dart(self) -> crate::api::WalletTransactionsSinceResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WalletsIdsResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.ids.into_into_dart().into_dart()].into_dart()
@@ -4076,6 +6920,21 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::ExportWalletBackupRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.passphrase, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ExportWalletBackupResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.backup, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4083,6 +6942,14 @@ impl SseEncode for i32 {
     }
 }
 
+impl SseEncode for crate::api::ImportWalletBackupRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.backup, serializer);
+        <String>::sse_encode(self.passphrase, serializer);
+    }
+}
+
 impl SseEncode for crate::api::IsValidTokenRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4110,6 +6977,14 @@ impl SseEncode for isize {
     }
 }
 
+impl SseEncode for crate::api::KeysetFee {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kid, serializer);
+        <u64>::sse_encode(self.input_fee_ppk, serializer);
+    }
+}
+
 impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4120,6 +6995,26 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::KeysetFee> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::KeysetFee>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::PendingTransaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PendingTransaction>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4150,6 +7045,16 @@ impl SseEncode for Vec<crate::api::Transaction> {
     }
 }
 
+impl SseEncode for Vec<crate::api::UnitBalance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::UnitBalance>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::WalletDevModeDetailedBalanceEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4254,6 +7159,7 @@ impl SseEncode for crate::api::PaymentType {
                 crate::api::PaymentType::Cdk18 => 2,
                 crate::api::PaymentType::OnChain => 3,
                 crate::api::PaymentType::Swap => 4,
+                crate::api::PaymentType::Bolt11 => 5,
                 _ => {
                     unimplemented!("");
                 }
@@ -4263,6 +7169,15 @@ impl SseEncode for crate::api::PaymentType {
     }
 }
 
+impl SseEncode for crate::api::PendingTransaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::Transaction>::sse_encode(self.transaction, serializer);
+        <usize>::sse_encode(self.pending_proofs, serializer);
+        <bool>::sse_encode(self.unsent, serializer);
+    }
+}
+
 impl SseEncode for crate::api::ProtestStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4293,6 +7208,13 @@ impl SseEncode for crate::api::StatusResponse {
     }
 }
 
+impl SseEncode for crate::api::TotalBalanceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::UnitBalance>>::sse_encode(self.balances, serializer);
+    }
+}
+
 impl SseEncode for crate::api::Transaction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4370,6 +7292,15 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::UnitBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.unit, serializer);
+        <u64>::sse_encode(self.debit, serializer);
+        <u64>::sse_encode(self.credit, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4380,6 +7311,22 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::ValidateMnemonicRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mnemonic, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ValidateMnemonicResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.valid, serializer);
+        <u32>::sse_encode(self.word_count, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletBalanceResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4405,6 +7352,54 @@ impl SseEncode for crate::api::WalletCheckReceivedPaymentRequest {
     }
 }
 
+impl SseEncode for crate::api::WalletCleanLocalDbRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <bool>::sse_encode(self.prune_counters, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletCleanLocalDbResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.cleaned_proofs, serializer);
+        <u32>::sse_encode(self.pruned_counters, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletCombinePartialSignaturesRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.y, serializer);
+        <Vec<String>>::sse_encode(self.witnesses, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletCombinePartialSignaturesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.spendable, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletConfirmNut18DeliveryRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.tx_id, serializer);
+        <u64>::sse_encode(self.timeout_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletConfirmNut18DeliveryResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.delivered, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletCurrencyUnitResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4497,6 +7492,43 @@ impl SseEncode for crate::api::WalletErrorKind {
     }
 }
 
+impl SseEncode for crate::api::WalletExportBalanceTokenRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.unit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletExportBalanceTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.token, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletExportProofsEncryptedRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.passphrase, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletExportProofsEncryptedResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.blob, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletExportReceiptResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.receipt, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletFfiConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4507,12 +7539,51 @@ impl SseEncode for crate::api::WalletFfiConfig {
         <String>::sse_encode(self.default_mint_url, serializer);
         <String>::sse_encode(self.bitcoin_network, serializer);
         <String>::sse_encode(self.mnemonic, serializer);
+        <Option<String>>::sse_encode(self.passphrase, serializer);
         <Vec<String>>::sse_encode(self.nostr_relays, serializer);
         <u32>::sse_encode(self.swap_expiry_minutes, serializer);
         <bool>::sse_encode(self.dev_mode, serializer);
     }
 }
 
+impl SseEncode for crate::api::WalletImportProofsEncryptedRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <Vec<u8>>::sse_encode(self.blob, serializer);
+        <String>::sse_encode(self.passphrase, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletImportProofsEncryptedResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.imported, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletInspectPaymentRequestResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.info, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletKeepSentTokensRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <bool>::sse_encode(self.keep, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletKeysetFeesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::KeysetFee>>::sse_encode(self.fees, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletMaybeTransactionIdResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4520,6 +7591,40 @@ impl SseEncode for crate::api::WalletMaybeTransactionIdResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletMintBolt11Request {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.quote_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletMintCapabilitiesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.mint_enabled, serializer);
+        <bool>::sse_encode(self.melt_enabled, serializer);
+        <bool>::sse_encode(self.token_state_check, serializer);
+        <bool>::sse_encode(self.restore, serializer);
+        <bool>::sse_encode(self.p2pk, serializer);
+        <bool>::sse_encode(self.dleq, serializer);
+        <bool>::sse_encode(self.htlc, serializer);
+        <bool>::sse_encode(self.mpp, serializer);
+        <bool>::sse_encode(self.websocket, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletMintQuoteResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.quote_id, serializer);
+        <String>::sse_encode(self.request, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.unit, serializer);
+        <Option<u64>>::sse_encode(self.expiry, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletMintRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4552,6 +7657,28 @@ impl SseEncode for crate::api::WalletNameResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletNprofileResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.nprofile, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletPartialSignProofRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.y, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletPartialSignProofResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.witness, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletPayRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4573,6 +7700,16 @@ impl SseEncode for crate::api::WalletPaymentByTokenResponse {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.tx_id, serializer);
         <String>::sse_encode(self.token, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.fees, serializer);
+        <String>::sse_encode(self.unit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletPendingTransactionsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::PendingTransaction>>::sse_encode(self.txs, serializer);
     }
 }
 
@@ -4627,6 +7764,28 @@ impl SseEncode for crate::api::WalletPreparePaymentResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletPrepareSendLockedRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.unit, serializer);
+        <String>::sse_encode(self.pubkey, serializer);
+        <Option<u64>>::sse_encode(self.locktime, serializer);
+        <Option<String>>::sse_encode(self.refund_key, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletPrepareSendMaxRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.unit, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletProtestMeltRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4675,6 +7834,15 @@ impl SseEncode for crate::api::WalletProtestSwapResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletReceiveMultisigTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tx_id, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <Vec<String>>::sse_encode(self.ys, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletReceiveRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4683,6 +7851,26 @@ impl SseEncode for crate::api::WalletReceiveRequest {
     }
 }
 
+impl SseEncode for crate::api::WalletReceiveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tx_id, serializer);
+        <u64>::sse_encode(self.received, serializer);
+        <u64>::sse_encode(self.stored, serializer);
+        <u64>::sse_encode(self.fee, serializer);
+        <String>::sse_encode(self.unit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletRecheckResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.deleted, serializer);
+        <u32>::sse_encode(self.restored, serializer);
+        <u32>::sse_encode(self.pending, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletReclaimTransactionRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4727,6 +7915,21 @@ impl SseEncode for crate::api::WalletRefreshTransactionsResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletReleaseReservationsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.released, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletRenameRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4734,6 +7937,53 @@ impl SseEncode for crate::api::WalletRequest {
     }
 }
 
+impl SseEncode for crate::api::WalletRestoreKeysetRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <String>::sse_encode(self.keyset_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletRestoreKeysetResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.restored, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletRetryPendingTransactionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.retried, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletSendResultResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tx_id, serializer);
+        <Option<String>>::sse_encode(self.token, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.fees, serializer);
+        <String>::sse_encode(self.unit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletStatsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.unspent_proofs, serializer);
+        <u32>::sse_encode(self.pending_proofs, serializer);
+        <u32>::sse_encode(self.reserved_proofs, serializer);
+        <u32>::sse_encode(self.total_proofs, serializer);
+        <u32>::sse_encode(self.keysets, serializer);
+        <u32>::sse_encode(self.random_proofs, serializer);
+        <u32>::sse_encode(self.transactions, serializer);
+        <Option<u64>>::sse_encode(self.oldest_tx, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletTransactionIdResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4763,6 +8013,13 @@ impl SseEncode for crate::api::WalletTransactionResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletTransactionTokenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.token, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletTransactionsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4770,6 +8027,23 @@ impl SseEncode for crate::api::WalletTransactionsResponse {
     }
 }
 
+impl SseEncode for crate::api::WalletTransactionsSinceRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.wallet_id, serializer);
+        <u64>::sse_encode(self.seq, serializer);
+    }
+}
+
+impl SseEncode for crate::api::WalletTransactionsSinceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::Transaction>>::sse_encode(self.txs, serializer);
+        <Vec<String>>::sse_encode(self.deleted, serializer);
+        <u64>::sse_encode(self.seq, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WalletsIdsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
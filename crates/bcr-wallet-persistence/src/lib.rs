@@ -7,7 +7,7 @@ pub mod test_utils;
 use crate::error::Result;
 use async_trait::async_trait;
 use bcr_common::cashu::{self, nut00 as cdk00, nut01 as cdk01, nut07 as cdk07};
-use bcr_common::cdk_common::wallet::{Transaction, TransactionId};
+use bcr_common::cdk_common::wallet::{Transaction, TransactionDirection, TransactionId};
 use bcr_wallet_core::{SendSync, types::WalletConfig};
 use bitcoin::secp256k1;
 use std::collections::HashMap;
@@ -41,6 +41,10 @@ pub struct MeltCommitmentRecord {
 #[async_trait]
 pub trait PocketRepository: SendSync {
     async fn store_new(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey>;
+    // stores all proofs as unspent in a single transaction
+    async fn store_new_batch(&self, proofs: Vec<cdk00::Proof>) -> Result<Vec<cdk01::PublicKey>>;
+    // as store_new_batch, for proofs whose secrets are not derived from the seed
+    async fn store_random_batch(&self, proofs: Vec<cdk00::Proof>) -> Result<Vec<cdk01::PublicKey>>;
     async fn store_pendingspent(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey>;
     async fn load_proof(&self, y: cdk01::PublicKey) -> Result<(cdk00::Proof, cdk07::State)>;
     async fn load_proofs(
@@ -49,16 +53,30 @@ pub trait PocketRepository: SendSync {
     ) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>>;
     async fn delete_proof(&self, y: cdk01::PublicKey) -> Result<Option<cdk00::Proof>>;
     async fn list_unspent(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>>;
+    // the unspent amount of each keyset, without loading the proofs
+    async fn sum_unspent(&self) -> Result<HashMap<cashu::Id, cashu::Amount>>;
     async fn list_pending(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>>;
     async fn list_reserved(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>>;
+    // proofs reserved before the given unix timestamp, or with no reservation time recorded
+    async fn list_reserved_before(&self, tstamp: u64) -> Result<Vec<cdk01::PublicKey>>;
     async fn list_spent(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>>;
     async fn list_all(&self) -> Result<Vec<cdk01::PublicKey>>;
+    // not spent proofs with random secrets, which only a backup brings back
+    async fn list_random(&self) -> Result<Vec<cdk01::PublicKey>>;
+    // unspent or reserved proofs
     async fn mark_as_pendingspent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof>;
+    // held back for a prepared send, so no other send selects it
+    async fn mark_as_reserved(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof>;
+    async fn revert_reserved_to_unspent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof>;
     async fn mark_pending_as_spent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof>;
     async fn revert_pendingspent_to_unspent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof>;
 
     async fn counter(&self, kid: cashu::Id) -> Result<u32>;
     async fn increment_counter(&self, kid: cashu::Id, old: u32, increment: u32) -> Result<()>;
+    async fn list_counters(&self) -> Result<Vec<cashu::Id>>;
+    // deletes the counter only if no proof of the keyset is stored, locked ones included
+    // returns whether it was deleted
+    async fn delete_unused_counter(&self, kid: cashu::Id) -> Result<bool>;
 
     async fn store_commitment(&self, record: SwapCommitmentRecord) -> Result<()>;
     async fn load_commitment(
@@ -67,6 +85,13 @@ pub trait PocketRepository: SendSync {
     ) -> Result<SwapCommitmentRecord>;
     async fn delete_commitment(&self, commitment: secp256k1::schnorr::Signature) -> Result<()>;
     async fn list_commitments(&self) -> Result<Vec<SwapCommitmentRecord>>;
+
+    // proofs locked to a multisig, which can't be spent by the wallet alone
+    async fn store_locked(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey>;
+    async fn load_locked(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof>;
+    async fn list_locked(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>>;
+    // moves a locked proof, signed by enough parties, to the unspent ones
+    async fn release_locked(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey>;
 }
 
 ///////////////////////////////////////////// PurseRepository
@@ -77,6 +102,52 @@ pub trait PurseRepository: SendSync {
     async fn load(&self, wallet_id: &str) -> Result<WalletConfig>;
     async fn delete(&self, wallet_id: &str) -> Result<()>;
     async fn list_ids(&self) -> Result<Vec<String>>;
+    // the wallet of each purse index, a deleted wallet leaves None so indices are never reused
+    async fn add_slot(&self, wallet_id: &str) -> Result<usize>;
+    async fn clear_slot(&self, idx: usize) -> Result<()>;
+    async fn list_slots(&self) -> Result<Vec<Option<String>>>;
+}
+
+///////////////////////////////////////////// TxFilter
+// unset fields match every transaction
+#[derive(Debug, Clone, Default)]
+pub struct TxFilter {
+    pub direction: Option<TransactionDirection>,
+    pub unit: Option<cashu::CurrencyUnit>,
+    // case-insensitive substring of the memo
+    pub memo_contains: Option<String>,
+}
+
+impl TxFilter {
+    pub fn matches(&self, tx: &Transaction) -> bool {
+        if let Some(direction) = self.direction
+            && direction != tx.direction
+        {
+            return false;
+        }
+        if let Some(ref unit) = self.unit
+            && *unit != tx.unit
+        {
+            return false;
+        }
+        if let Some(ref needle) = self.memo_contains {
+            let Some(ref memo) = tx.memo else {
+                return false;
+            };
+            if !memo.to_lowercase().contains(&needle.to_lowercase()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+///////////////////////////////////////////// TxChanges
+// the writes after a sequence marker, oldest write first
+#[derive(Debug, Clone, Default)]
+pub struct TxChanges {
+    pub updated: Vec<Transaction>,
+    pub deleted: Vec<TransactionId>,
 }
 
 ///////////////////////////////////////////// TransactionRepository
@@ -87,8 +158,19 @@ pub trait TransactionRepository: SendSync {
     async fn load_tx(&self, tx_id: TransactionId) -> Result<Transaction>;
     #[allow(dead_code)]
     async fn delete_tx(&self, tx_id: TransactionId) -> Result<()>;
+    // the incoming transactions recording any of the given ys, by y
+    async fn find_incoming(
+        &self,
+        ys: Vec<cdk01::PublicKey>,
+    ) -> Result<HashMap<cdk01::PublicKey, TransactionId>>;
     async fn list_tx_ids(&self) -> Result<Vec<TransactionId>>;
     async fn list_txs(&self) -> Result<Vec<Transaction>>;
+    // transactions written or deleted after the given sequence marker (0 returns all stored)
+    async fn list_since(&self, seq: u64) -> Result<TxChanges>;
+    // sequence marker of the latest write
+    async fn current_seq(&self) -> Result<u64>;
+    // ids of the transactions matching the filter, newest first
+    async fn query(&self, filter: TxFilter) -> Result<Vec<TransactionId>>;
     async fn update_metadata(
         &self,
         tx_id: TransactionId,
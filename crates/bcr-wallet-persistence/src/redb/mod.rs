@@ -5,6 +5,7 @@ pub mod transaction;
 
 use crate::error::Result;
 pub use ::redb::Database;
+use ::redb::TableHandle;
 use bcr_common::cashu::CurrencyUnit;
 use std::path::Path;
 use std::sync::Arc;
@@ -32,3 +33,62 @@ pub async fn build_wallet_dbs(
     let mintmeltdb = mintmelt::MintMeltDB::new(db.clone(), wallet_id, debit)?;
     Ok((txdb, (debitdb, mintmeltdb)))
 }
+
+// Drops every table of the wallet, returns how many were removed
+pub async fn delete_wallet_dbs(wallet_id: &str, db: Arc<Database>) -> Result<usize> {
+    let prefix = format!("{wallet_id}_");
+    tokio::task::spawn_blocking(move || -> Result<usize> {
+        let write_txn = db.begin_write()?;
+        let tables: Vec<_> = write_txn
+            .list_tables()?
+            .filter(|table| table.name().starts_with(&prefix))
+            .collect();
+        let mut deleted = 0;
+        for table in tables {
+            if write_txn.delete_table(table)? {
+                deleted += 1;
+            }
+        }
+        write_txn.commit()?;
+        Ok(deleted)
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PocketRepository;
+    use bcr_common::{cashu::Amount, core_tests};
+    use redb::{Builder, ReadableDatabase, backends::InMemoryBackend};
+
+    #[tokio::test]
+    async fn test_delete_wallet_dbs() {
+        let db = Arc::new(
+            Builder::new()
+                .create_with_backend(InMemoryBackend::new())
+                .expect("can create in-memory redb"),
+        );
+        let (keep, delete) = (String::from("wallet-keep"), String::from("wallet-gone"));
+        let (_, keyset) = core_tests::generate_random_ecash_keyset();
+        for id in [&keep, &delete] {
+            let (_, (pocket, _)) = build_wallet_dbs(0, id, &CurrencyUnit::Sat, db.clone())
+                .await
+                .unwrap();
+            let proofs = core_tests::generate_random_ecash_proofs(&keyset, &[Amount::from(8u64)]);
+            pocket.store_new(proofs[0].clone()).await.unwrap();
+        }
+
+        let deleted = delete_wallet_dbs(&delete, db.clone()).await.unwrap();
+        assert!(deleted > 0);
+
+        let read_txn = db.begin_read().unwrap();
+        let names: Vec<String> = read_txn
+            .list_tables()
+            .unwrap()
+            .map(|table| table.name().to_owned())
+            .collect();
+        assert!(names.iter().all(|name| !name.starts_with(&delete)));
+        assert!(names.iter().any(|name| name.starts_with(&keep)));
+    }
+}
@@ -85,11 +85,21 @@ struct ProofEntry {
     witness: Option<cdk00::Witness>,
     dleq: Option<cdk12::ProofDleq>,
     state: cdk07::State,
+    // unix seconds, entries stored before these were recorded decode as 0
+    #[serde(default)]
+    created_at: u64,
+    // entries stored while only the reservation time was recorded keep it as their last change
+    #[serde(default, alias = "reserved_at")]
+    state_changed_at: u64,
+    // not derived from the seed, restoring can't find it
+    #[serde(default)]
+    random_secret: bool,
 }
 
 impl std::convert::From<cdk00::Proof> for ProofEntry {
     fn from(proof: cdk00::Proof) -> Self {
         let y = proof.y().expect("Hash to curve should not fail");
+        let now = chrono::Utc::now().timestamp() as u64;
         ProofEntry {
             y,
             amount: proof.amount,
@@ -99,6 +109,9 @@ impl std::convert::From<cdk00::Proof> for ProofEntry {
             witness: proof.witness,
             dleq: proof.dleq,
             state: cdk07::State::Unspent,
+            created_at: now,
+            state_changed_at: now,
+            random_secret: false,
         }
     }
 }
@@ -116,6 +129,14 @@ impl std::convert::From<ProofEntry> for cdk00::Proof {
     }
 }
 
+// the fields of a ProofEntry needed to sum it up, the others are skipped while decoding
+#[derive(serde::Deserialize)]
+struct AmountEntry {
+    amount: bcr_common::cashu::Amount,
+    keyset_id: cdk02::Id,
+    state: cdk07::State,
+}
+
 ///////////////////////////////////////////// CounterEntry
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct CounterEntry {
@@ -129,12 +150,14 @@ pub struct PocketDB {
     proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
     counter_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
     commitment_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+    locked_proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
 }
 
 impl PocketDB {
     const PROOF_BASE_DB_NAME: &'static str = "proofs";
     const COUNTER_BASE_DB_NAME: &'static str = "counters";
     const COMMITMENT_BASE_DB_NAME: &'static str = "commitments";
+    const LOCKED_PROOF_BASE_DB_NAME: &'static str = "locked_proofs";
 
     pub fn new(db: Arc<Database>, wallet_id: &str, unit: &CurrencyUnit) -> Result<Self> {
         // Leak once to get static string, because of dynamically generated table names
@@ -147,14 +170,20 @@ impl PocketDB {
             format!("{wallet_id}_{unit}_{}", Self::COMMITMENT_BASE_DB_NAME).into_boxed_str(),
         );
 
+        let locked_proof_name: &'static str = Box::leak(
+            format!("{wallet_id}_{unit}_{}", Self::LOCKED_PROOF_BASE_DB_NAME).into_boxed_str(),
+        );
+
         let proof_table = TableDefinition::new(proof_name);
         let counter_table = TableDefinition::new(counter_name);
         let commitment_table = TableDefinition::new(commitment_name);
+        let locked_proof_table = TableDefinition::new(locked_proof_name);
         Ok(Self {
             db,
             proof_table,
             counter_table,
             commitment_table,
+            locked_proof_table,
         })
     }
 
@@ -180,6 +209,33 @@ impl PocketDB {
         Ok(y)
     }
 
+    fn store_new_batch_sync(
+        db: Arc<Database>,
+        proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        proofs: Vec<cdk00::Proof>,
+        random_secret: bool,
+    ) -> Result<Vec<cdk01::PublicKey>> {
+        let mut ys = Vec::with_capacity(proofs.len());
+
+        let write_txn = db.begin_write()?;
+
+        {
+            let mut table = write_txn.open_table(proof_table)?;
+
+            for proof in proofs {
+                let mut entry = ProofEntry::from(proof);
+                entry.random_secret = random_secret;
+                let mut serialized = Vec::new();
+                ciborium::into_writer(&entry, &mut serialized)?;
+                table.insert(entry.y.to_bytes().as_slice(), serialized)?;
+                ys.push(entry.y);
+            }
+        }
+
+        write_txn.commit()?;
+        Ok(ys)
+    }
+
     fn store_pendingspent_sync(
         db: Arc<Database>,
         proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
@@ -254,6 +310,33 @@ impl PocketDB {
         }
     }
 
+    fn release_locked_sync(
+        db: Arc<Database>,
+        locked_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        proof: cdk00::Proof,
+    ) -> Result<cdk01::PublicKey> {
+        let entry = ProofEntry::from(proof);
+        let y = entry.y;
+
+        let write_txn = db.begin_write()?;
+
+        {
+            let mut locked = write_txn.open_table(locked_table)?;
+            if locked.remove(y.to_bytes().as_slice())?.is_none() {
+                return Err(Error::ProofNotFound(y));
+            }
+
+            let mut table = write_txn.open_table(proof_table)?;
+            let mut serialized = Vec::new();
+            ciborium::into_writer(&entry, &mut serialized)?;
+            table.insert(y.to_bytes().as_slice(), serialized)?;
+        }
+
+        write_txn.commit()?;
+        Ok(y)
+    }
+
     fn delete_proof_sync(
         db: Arc<Database>,
         proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
@@ -323,6 +406,29 @@ impl PocketDB {
         }
     }
 
+    fn sum_sync(
+        db: Arc<Database>,
+        proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        state: cdk07::State,
+    ) -> Result<HashMap<cdk02::Id, bcr_common::cashu::Amount>> {
+        let read_txn = db.begin_read()?;
+
+        match read_txn.open_table(proof_table) {
+            Ok(table) => {
+                let mut res = HashMap::new();
+                for (_, v) in table.range::<&[u8]>(..)?.flatten() {
+                    let entry: AmountEntry = ciborium::from_reader(v.value().as_slice())?;
+                    if entry.state == state {
+                        *res.entry(entry.keyset_id).or_default() += entry.amount;
+                    }
+                }
+                Ok(res)
+            }
+            Err(TableError::TableDoesNotExist(_)) => Ok(HashMap::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn update_entry_state_sync(
         db: Arc<Database>,
         proof_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
@@ -343,6 +449,7 @@ impl PocketDB {
                 }
 
                 proof.state = new_state;
+                proof.state_changed_at = chrono::Utc::now().timestamp() as u64;
 
                 let mut serialized = Vec::new();
                 ciborium::into_writer(&proof, &mut serialized)?;
@@ -402,6 +509,52 @@ impl PocketDB {
         Ok(entry)
     }
 
+    fn list_counters_sync(
+        db: Arc<Database>,
+        counter_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+    ) -> Result<Vec<cdk02::Id>> {
+        let read_txn = db.begin_read()?;
+
+        match read_txn.open_table(counter_table) {
+            Ok(table) => {
+                let mut res = Vec::new();
+                for (_, v) in table.range::<&[u8]>(..)?.flatten() {
+                    let counter: CounterEntry = ciborium::from_reader(v.value().as_slice())?;
+                    res.push(counter.kid);
+                }
+                Ok(res)
+            }
+            Err(TableError::TableDoesNotExist(_)) => Ok(vec![]),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // proofs are checked in the same transaction, so none can be stored in between
+    fn delete_unused_counter_sync(
+        db: Arc<Database>,
+        counter_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        proof_tables: [TableDefinition<'static, &'static [u8], Vec<u8>>; 2],
+        kid: cdk02::Id,
+    ) -> Result<bool> {
+        let write_txn = db.begin_write()?;
+        for proof_table in proof_tables {
+            let table = write_txn.open_table(proof_table)?;
+            for (_, v) in table.range::<&[u8]>(..)?.flatten() {
+                let entry: AmountEntry = ciborium::from_reader(v.value().as_slice())?;
+                if entry.keyset_id == kid {
+                    return Ok(false);
+                }
+            }
+        }
+        let removed = {
+            let mut table = write_txn.open_table(counter_table)?;
+            table.remove(kid.to_bytes().as_slice())?.is_some()
+        };
+
+        write_txn.commit()?;
+        Ok(removed)
+    }
+
     fn increment_counter_sync(
         db: Arc<Database>,
         counter_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
@@ -562,6 +715,18 @@ impl PocketRepository for PocketDB {
         spawn_blocking(move || Self::store_new_sync(db_clone, table, proof)).await?
     }
 
+    async fn store_new_batch(&self, proofs: Vec<cdk00::Proof>) -> Result<Vec<cdk01::PublicKey>> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        spawn_blocking(move || Self::store_new_batch_sync(db_clone, table, proofs, false)).await?
+    }
+
+    async fn store_random_batch(&self, proofs: Vec<cdk00::Proof>) -> Result<Vec<cdk01::PublicKey>> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        spawn_blocking(move || Self::store_new_batch_sync(db_clone, table, proofs, true)).await?
+    }
+
     async fn store_pendingspent(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey> {
         let db_clone = self.db.clone();
         let table = self.proof_table;
@@ -611,6 +776,12 @@ impl PocketRepository for PocketDB {
             .collect())
     }
 
+    async fn sum_unspent(&self) -> Result<HashMap<cdk02::Id, bcr_common::cashu::Amount>> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        spawn_blocking(move || Self::sum_sync(db_clone, table, cdk07::State::Unspent)).await?
+    }
+
     async fn list_spent(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
         let db_clone = self.db.clone();
         let table = self.proof_table;
@@ -658,12 +829,37 @@ impl PocketRepository for PocketDB {
             .collect())
     }
 
+    async fn list_reserved_before(&self, tstamp: u64) -> Result<Vec<cdk01::PublicKey>> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        let reserved =
+            spawn_blocking(move || Self::list_sync(db_clone, table, Some(cdk07::State::Reserved)))
+                .await??;
+        let ys = reserved
+            .into_iter()
+            .filter(|entry| entry.state_changed_at < tstamp)
+            .map(|entry| entry.y)
+            .collect();
+        Ok(ys)
+    }
+
     async fn list_all(&self) -> Result<Vec<cdk01::PublicKey>> {
         let db_clone = self.db.clone();
         let table = self.proof_table;
         spawn_blocking(move || Self::list_keys_sync(db_clone, table)).await?
     }
 
+    async fn list_random(&self) -> Result<Vec<cdk01::PublicKey>> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        let list = spawn_blocking(move || Self::list_sync(db_clone, table, None)).await??;
+        Ok(list
+            .into_iter()
+            .filter(|entry| entry.random_secret && entry.state != cdk07::State::Spent)
+            .map(|entry| entry.y)
+            .collect())
+    }
+
     async fn mark_as_pendingspent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof> {
         let db_clone = self.db.clone();
         let table = self.proof_table;
@@ -672,7 +868,7 @@ impl PocketRepository for PocketDB {
                 db_clone,
                 table,
                 y,
-                &[cdk07::State::Unspent],
+                &[cdk07::State::Unspent, cdk07::State::Reserved],
                 cdk07::State::PendingSpent,
             )
         })
@@ -680,6 +876,38 @@ impl PocketRepository for PocketDB {
         Ok(proof.into())
     }
 
+    async fn mark_as_reserved(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        let proof = spawn_blocking(move || {
+            Self::update_entry_state_sync(
+                db_clone,
+                table,
+                y,
+                &[cdk07::State::Unspent],
+                cdk07::State::Reserved,
+            )
+        })
+        .await??;
+        Ok(proof.into())
+    }
+
+    async fn revert_reserved_to_unspent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof> {
+        let db_clone = self.db.clone();
+        let table = self.proof_table;
+        let proof = spawn_blocking(move || {
+            Self::update_entry_state_sync(
+                db_clone,
+                table,
+                y,
+                &[cdk07::State::Reserved],
+                cdk07::State::Unspent,
+            )
+        })
+        .await??;
+        Ok(proof.into())
+    }
+
     async fn mark_pending_as_spent(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof> {
         let db_clone = self.db.clone();
         let table = self.proof_table;
@@ -736,6 +964,20 @@ impl PocketRepository for PocketDB {
         spawn_blocking(move || Self::increment_counter_sync(db_clone, table, old, new)).await?
     }
 
+    async fn list_counters(&self) -> Result<Vec<bcr_common::cashu::Id>> {
+        let db_clone = self.db.clone();
+        let table = self.counter_table;
+        spawn_blocking(move || Self::list_counters_sync(db_clone, table)).await?
+    }
+
+    async fn delete_unused_counter(&self, kid: bcr_common::cashu::Id) -> Result<bool> {
+        let db_clone = self.db.clone();
+        let table = self.counter_table;
+        let proof_tables = [self.proof_table, self.locked_proof_table];
+        spawn_blocking(move || Self::delete_unused_counter_sync(db_clone, table, proof_tables, kid))
+            .await?
+    }
+
     async fn store_commitment(&self, record: crate::SwapCommitmentRecord) -> Result<()> {
         let db_clone = self.db.clone();
         let table = self.commitment_table;
@@ -762,6 +1004,40 @@ impl PocketRepository for PocketDB {
         let table = self.commitment_table;
         spawn_blocking(move || Self::list_commitments_sync(db_clone, table)).await?
     }
+
+    async fn store_locked(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey> {
+        let db_clone = self.db.clone();
+        let table = self.locked_proof_table;
+        spawn_blocking(move || Self::store_new_sync(db_clone, table, proof)).await?
+    }
+
+    async fn load_locked(&self, y: cdk01::PublicKey) -> Result<cdk00::Proof> {
+        let db_clone = self.db.clone();
+        let table = self.locked_proof_table;
+        let res = spawn_blocking(move || Self::load_proof_sync(db_clone, table, y)).await??;
+        let proof = res.ok_or(Error::ProofNotFound(y))?;
+        Ok(proof.into())
+    }
+
+    async fn list_locked(&self) -> Result<HashMap<cdk01::PublicKey, cdk00::Proof>> {
+        let db_clone = self.db.clone();
+        let table = self.locked_proof_table;
+        let list = spawn_blocking(move || Self::list_sync(db_clone, table, None)).await??;
+        Ok(list
+            .into_iter()
+            .map(|entry| (entry.y, cdk00::Proof::from(entry)))
+            .collect())
+    }
+
+    async fn release_locked(&self, proof: cdk00::Proof) -> Result<cdk01::PublicKey> {
+        let db_clone = self.db.clone();
+        let locked_table = self.locked_proof_table;
+        let proof_table = self.proof_table;
+        spawn_blocking(move || {
+            Self::release_locked_sync(db_clone, locked_table, proof_table, proof)
+        })
+        .await?
+    }
 }
 
 #[cfg(test)]
@@ -775,6 +1051,7 @@ mod tests {
         core_tests,
     };
     use redb::{Builder, backends::InMemoryBackend};
+    use std::collections::HashSet;
 
     fn get_db(wallet_id: &str, unit: CurrencyUnit) -> PocketDB {
         let in_mem = InMemoryBackend::new();
@@ -811,6 +1088,47 @@ mod tests {
         assert!(unspent.contains_key(&y));
     }
 
+    #[tokio::test]
+    async fn test_store_new_batch() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+
+        let proofs = vec![test_proof(), test_proof(), test_proof()];
+        let ys = repo
+            .store_new_batch(proofs.clone())
+            .await
+            .expect("store_new_batch works");
+        assert_eq!(ys.len(), 3);
+
+        let unspent = repo.list_unspent().await.expect("list_unspent works");
+        assert_eq!(unspent.len(), 3);
+        for (y, proof) in ys.iter().zip(proofs) {
+            assert_eq!(unspent.get(y), Some(&proof));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_random_batch() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+
+        repo.store_new(test_proof()).await.expect("store_new works");
+        let ys = repo
+            .store_random_batch(vec![test_proof(), test_proof()])
+            .await
+            .expect("store_random_batch works");
+        assert_eq!(repo.list_unspent().await.unwrap().len(), 3);
+
+        let random = repo.list_random().await.expect("list_random works");
+        assert_eq!(
+            random.into_iter().collect::<HashSet<_>>(),
+            ys.iter().copied().collect::<HashSet<_>>()
+        );
+
+        // the mark survives state changes, spent proofs are left out
+        repo.mark_as_pendingspent(ys[0]).await.unwrap();
+        repo.mark_pending_as_spent(ys[0]).await.unwrap();
+        assert_eq!(repo.list_random().await.unwrap(), vec![ys[1]]);
+    }
+
     #[tokio::test]
     async fn test_store_load_pendingspent() {
         let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
@@ -854,6 +1172,54 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_store_load_locked() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+
+        let mut proof = test_proof();
+        proof.witness = Some(cdk00::Witness::P2PKWitness(cashu::nut11::P2PKWitness {
+            signatures: vec![String::from("partial")],
+        }));
+        let y = repo
+            .store_locked(proof.clone())
+            .await
+            .expect("store_locked works");
+
+        let loaded = repo.load_locked(y).await.expect("load_locked works");
+        assert_eq!(loaded.witness, proof.witness);
+
+        let locked = repo.list_locked().await.expect("list_locked works");
+        assert!(locked.contains_key(&y));
+        // locked proofs are not part of the spendable ones
+        let unspent = repo.list_unspent().await.expect("list_unspent works");
+        assert!(unspent.is_empty());
+        let err = repo.load_proof(y).await.unwrap_err();
+        assert!(matches!(err, Error::ProofNotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_release_locked() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+
+        let proof = test_proof();
+        let y = repo
+            .store_locked(proof.clone())
+            .await
+            .expect("store_locked works");
+        repo.release_locked(proof.clone())
+            .await
+            .expect("release_locked works");
+
+        assert!(repo.list_locked().await.unwrap().is_empty());
+        let (loaded, state) = repo.load_proof(y).await.expect("load_proof works");
+        assert_eq!(loaded, proof);
+        assert_eq!(state, cdk07::State::Unspent);
+
+        // only stored locked proofs can be released
+        let err = repo.release_locked(proof).await.unwrap_err();
+        assert!(matches!(err, Error::ProofNotFound(_)));
+    }
+
     #[tokio::test]
     async fn test_mark_as_pendingspent() {
         let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
@@ -874,6 +1240,166 @@ mod tests {
         assert!(!unspent.contains_key(&y));
     }
 
+    #[tokio::test]
+    async fn test_mark_as_reserved_and_revert() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+
+        let y = repo.store_new(test_proof()).await.unwrap();
+        repo.mark_as_reserved(y)
+            .await
+            .expect("mark_as_reserved works");
+        let (_, state) = repo.load_proof(y).await.unwrap();
+        assert_eq!(state, cdk07::State::Reserved);
+        assert!(repo.list_reserved().await.unwrap().contains_key(&y));
+        assert!(!repo.list_unspent().await.unwrap().contains_key(&y));
+        // reserved twice means two sends picked the same proof
+        let err = repo.mark_as_reserved(y).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidProofState(k) if k == y));
+
+        repo.revert_reserved_to_unspent(y)
+            .await
+            .expect("revert_reserved_to_unspent works");
+        assert!(repo.list_unspent().await.unwrap().contains_key(&y));
+
+        repo.mark_as_reserved(y).await.unwrap();
+        repo.mark_as_pendingspent(y)
+            .await
+            .expect("reserved proofs get sent");
+        let (_, state) = repo.load_proof(y).await.unwrap();
+        assert_eq!(state, cdk07::State::PendingSpent);
+    }
+
+    #[test]
+    fn test_proof_entry_without_timestamps_decodes_as_zero() {
+        // the layout of the entries stored before the timestamps were added
+        #[derive(serde::Serialize)]
+        struct LegacyEntry {
+            y: cdk01::PublicKey,
+            amount: bcr_common::cashu::Amount,
+            keyset_id: cdk02::Id,
+            secret: Secret,
+            c: cdk01::PublicKey,
+            witness: Option<cdk00::Witness>,
+            dleq: Option<cdk12::ProofDleq>,
+            state: cdk07::State,
+        }
+        let proof = test_proof();
+        let legacy = LegacyEntry {
+            y: proof.y().unwrap(),
+            amount: proof.amount,
+            keyset_id: proof.keyset_id,
+            secret: proof.secret.clone(),
+            c: proof.c,
+            witness: None,
+            dleq: None,
+            state: cdk07::State::Unspent,
+        };
+        let mut serialized = Vec::new();
+        ciborium::into_writer(&legacy, &mut serialized).unwrap();
+        let entry: ProofEntry = ciborium::from_reader(serialized.as_slice()).unwrap();
+        assert_eq!(entry.created_at, 0);
+        assert_eq!(entry.state_changed_at, 0);
+    }
+
+    #[test]
+    fn test_proof_entry_with_reservation_time_keeps_it() {
+        // the layout of the entries stored while only the reservation time was recorded
+        #[derive(serde::Serialize)]
+        struct ReservedEntry {
+            y: cdk01::PublicKey,
+            amount: bcr_common::cashu::Amount,
+            keyset_id: cdk02::Id,
+            secret: Secret,
+            c: cdk01::PublicKey,
+            witness: Option<cdk00::Witness>,
+            dleq: Option<cdk12::ProofDleq>,
+            state: cdk07::State,
+            reserved_at: Option<u64>,
+        }
+        let proof = test_proof();
+        let reserved = ReservedEntry {
+            y: proof.y().unwrap(),
+            amount: proof.amount,
+            keyset_id: proof.keyset_id,
+            secret: proof.secret.clone(),
+            c: proof.c,
+            witness: None,
+            dleq: None,
+            state: cdk07::State::Reserved,
+            reserved_at: Some(1234),
+        };
+        let mut serialized = Vec::new();
+        ciborium::into_writer(&reserved, &mut serialized).unwrap();
+        let entry: ProofEntry = ciborium::from_reader(serialized.as_slice()).unwrap();
+        assert_eq!(entry.created_at, 0);
+        assert_eq!(entry.state_changed_at, 1234);
+    }
+
+    #[tokio::test]
+    async fn test_state_change_keeps_created_at() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+        let before = chrono::Utc::now().timestamp() as u64;
+
+        let y = repo.store_new(test_proof()).await.unwrap();
+        let entry = PocketDB::load_proof_sync(repo.db.clone(), repo.proof_table, y)
+            .unwrap()
+            .unwrap();
+        assert!(entry.created_at >= before);
+        assert_eq!(entry.state_changed_at, entry.created_at);
+
+        repo.mark_as_pendingspent(y).await.unwrap();
+        let changed = PocketDB::load_proof_sync(repo.db.clone(), repo.proof_table, y)
+            .unwrap()
+            .unwrap();
+        assert_eq!(changed.created_at, entry.created_at);
+        assert!(changed.state_changed_at >= entry.state_changed_at);
+    }
+
+    #[tokio::test]
+    async fn test_list_reserved_before() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+
+        let y = repo.store_new(test_proof()).await.unwrap();
+        let unspent = repo.store_new(test_proof()).await.unwrap();
+        repo.mark_as_reserved(y).await.unwrap();
+        let now = chrono::Utc::now().timestamp() as u64;
+
+        assert!(
+            repo.list_reserved_before(now - 60)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let stale = repo.list_reserved_before(now + 60).await.unwrap();
+        assert_eq!(stale, vec![y]);
+        assert!(!stale.contains(&unspent));
+
+        repo.revert_reserved_to_unspent(y).await.unwrap();
+        assert!(
+            repo.list_reserved_before(now + 60)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sum_unspent() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+        let (info, keyset) = core_tests::generate_random_ecash_keyset();
+        let amounts = [Amount::from(8u64), Amount::from(16u64), Amount::from(32u64)];
+        let proofs = core_tests::generate_random_ecash_proofs(&keyset, &amounts);
+        let ys = repo.store_new_batch(proofs).await.unwrap();
+        repo.mark_as_pendingspent(ys[2]).await.unwrap();
+        let other = repo.store_new(test_proof()).await.unwrap();
+        let (other, _) = repo.load_proof(other).await.unwrap();
+
+        let sums = repo.sum_unspent().await.expect("sum_unspent works");
+        assert_eq!(sums.len(), 2);
+        assert_eq!(sums[&info.id], Amount::from(24u64));
+        assert_eq!(sums[&other.keyset_id], Amount::from(16u64));
+    }
+
     #[tokio::test]
     async fn test_mark_as_spent() {
         let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
@@ -935,6 +1461,37 @@ mod tests {
         assert_eq!(c2, 5);
     }
 
+    #[tokio::test]
+    async fn test_delete_unused_counter_keeps_counters_of_stored_proofs() {
+        let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
+        let (_, used) = core_tests::generate_random_ecash_keyset();
+        let (_, locked) = core_tests::generate_random_ecash_keyset();
+        let (_, unused) = core_tests::generate_random_ecash_keyset();
+        for kid in [used.id, locked.id, unused.id] {
+            repo.counter(kid).await.expect("counter works");
+        }
+        let proof = core_tests::generate_random_ecash_proofs(&used, &[Amount::from(8u64)]);
+        repo.store_new(proof[0].clone()).await.expect("store works");
+        let proof = core_tests::generate_random_ecash_proofs(&locked, &[Amount::from(8u64)]);
+        repo.store_locked(proof[0].clone())
+            .await
+            .expect("store_locked works");
+
+        let mut counters = repo.list_counters().await.expect("list_counters works");
+        counters.sort();
+        let mut expected = vec![used.id, locked.id, unused.id];
+        expected.sort();
+        assert_eq!(counters, expected);
+
+        assert!(!repo.delete_unused_counter(used.id).await.unwrap());
+        assert!(!repo.delete_unused_counter(locked.id).await.unwrap());
+        assert!(repo.delete_unused_counter(unused.id).await.unwrap());
+        assert!(!repo.delete_unused_counter(unused.id).await.unwrap());
+        let counters = repo.list_counters().await.expect("list_counters works");
+        assert_eq!(counters.len(), 2);
+        assert!(!counters.contains(&unused.id));
+    }
+
     #[tokio::test]
     async fn test_store_load_delete_commitment() {
         let repo = get_db(&wallet_id(), CurrencyUnit::Sat);
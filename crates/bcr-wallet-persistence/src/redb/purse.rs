@@ -4,9 +4,9 @@ use crate::{
 };
 use async_trait::async_trait;
 use bcr_common::cashu::{CurrencyUnit, MintUrl};
-use bcr_wallet_core::types::WalletConfig;
+use bcr_wallet_core::types::{CoinSelectionStrategy, WalletConfig};
 use bitcoin::secp256k1;
-use redb::{Database, ReadableDatabase, TableDefinition, TableError};
+use redb::{Database, ReadableDatabase, ReadableTable, TableDefinition, TableError};
 use std::sync::Arc;
 use tokio::task::spawn_blocking;
 
@@ -22,6 +22,11 @@ struct WalletEntry {
     pub_key: secp256k1::PublicKey,
     debit: CurrencyUnit,
     betas: Vec<MintUrl>,
+    // missing in entries stored before it was configurable
+    #[serde(default)]
+    coin_selection: CoinSelectionStrategy,
+    #[serde(default)]
+    keep_sent_tokens: bool,
 }
 impl std::convert::From<WalletConfig> for WalletEntry {
     fn from(wallet: WalletConfig) -> Self {
@@ -35,6 +40,8 @@ impl std::convert::From<WalletConfig> for WalletEntry {
             pub_key: wallet.pub_key,
             debit: wallet.debit,
             betas: wallet.betas,
+            coin_selection: wallet.coin_selection,
+            keep_sent_tokens: wallet.keep_sent_tokens,
         }
     }
 }
@@ -50,12 +57,16 @@ impl std::convert::From<WalletEntry> for WalletConfig {
             pub_key: wallet.pub_key,
             debit: wallet.debit,
             betas: wallet.betas,
+            coin_selection: wallet.coin_selection,
+            keep_sent_tokens: wallet.keep_sent_tokens,
         }
     }
 }
 
 ///////////////////////////////////////////// PurseDB
 const WALLET_TABLE: TableDefinition<&[u8], Vec<u8>> = TableDefinition::new("wallets");
+// purse index -> wallet id, None once the wallet is deleted
+const SLOT_TABLE: TableDefinition<u64, Vec<u8>> = TableDefinition::new("purse_slots");
 pub struct PurseDB {
     db: Arc<Database>,
 }
@@ -113,6 +124,59 @@ impl PurseDB {
         Ok(())
     }
 
+    fn add_slot_sync(db: Arc<Database>, wallet_id: &str) -> Result<usize> {
+        let entry: Option<String> = Some(wallet_id.to_owned());
+        let write_txn = db.begin_write()?;
+
+        let idx = {
+            let mut table = write_txn.open_table(SLOT_TABLE)?;
+            let idx = match table.last()? {
+                Some((k, _)) => k.value() + 1,
+                None => 0,
+            };
+            let mut serialized = Vec::new();
+            ciborium::into_writer(&entry, &mut serialized)?;
+            table.insert(idx, serialized)?;
+            idx
+        };
+
+        write_txn.commit()?;
+        Ok(idx as usize)
+    }
+
+    fn clear_slot_sync(db: Arc<Database>, idx: usize) -> Result<()> {
+        let entry: Option<String> = None;
+        let write_txn = db.begin_write()?;
+
+        {
+            let mut table = write_txn.open_table(SLOT_TABLE)?;
+            let mut serialized = Vec::new();
+            ciborium::into_writer(&entry, &mut serialized)?;
+            table.insert(idx as u64, serialized)?;
+        }
+
+        write_txn.commit()?;
+        Ok(())
+    }
+
+    fn list_slots_sync(db: Arc<Database>) -> Result<Vec<Option<String>>> {
+        let read_txn = db.begin_read()?;
+
+        match read_txn.open_table(SLOT_TABLE) {
+            Ok(table) => {
+                let mut res = Vec::new();
+                for (k, v) in table.range::<u64>(..)?.flatten() {
+                    let entry: Option<String> = ciborium::from_reader(v.value().as_slice())?;
+                    res.resize(k.value() as usize, None);
+                    res.push(entry);
+                }
+                Ok(res)
+            }
+            Err(TableError::TableDoesNotExist(_)) => Ok(vec![]),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn list_ids_sync(db: Arc<Database>) -> Result<Vec<String>> {
         let read_txn = db.begin_read()?;
 
@@ -156,6 +220,22 @@ impl PurseRepository for PurseDB {
         let db_clone = self.db.clone();
         spawn_blocking(move || Self::list_ids_sync(db_clone)).await?
     }
+
+    async fn add_slot(&self, wallet_id: &str) -> Result<usize> {
+        let db_clone = self.db.clone();
+        let id = wallet_id.to_owned();
+        spawn_blocking(move || Self::add_slot_sync(db_clone, &id)).await?
+    }
+
+    async fn clear_slot(&self, idx: usize) -> Result<()> {
+        let db_clone = self.db.clone();
+        spawn_blocking(move || Self::clear_slot_sync(db_clone, idx)).await?
+    }
+
+    async fn list_slots(&self) -> Result<Vec<Option<String>>> {
+        let db_clone = self.db.clone();
+        spawn_blocking(move || Self::list_slots_sync(db_clone)).await?
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +275,8 @@ mod tests {
             pub_key: test_pub_key(),
             debit: CurrencyUnit::Sat,
             betas: vec![],
+            coin_selection: CoinSelectionStrategy::default(),
+            keep_sent_tokens: false,
         }
     }
 
@@ -262,6 +344,24 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_slots_keep_deleted_indices() {
+        let db = get_db();
+        assert!(db.list_slots().await.unwrap().is_empty());
+
+        assert_eq!(db.add_slot("w1").await.unwrap(), 0);
+        assert_eq!(db.add_slot("w2").await.unwrap(), 1);
+        db.clear_slot(1).await.unwrap();
+        // the index of a deleted wallet is not given out again
+        assert_eq!(db.add_slot("w3").await.unwrap(), 2);
+
+        let slots = db.list_slots().await.unwrap();
+        assert_eq!(
+            slots,
+            vec![Some("w1".to_owned()), None, Some("w3".to_owned())]
+        );
+    }
+
     #[tokio::test]
     async fn test_delete_missing_is_ok() {
         let db = get_db();
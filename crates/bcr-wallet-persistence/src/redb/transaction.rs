@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::{TransactionRepository, error::Result};
+use crate::{TransactionRepository, TxChanges, TxFilter, error::Result};
 use async_trait::async_trait;
 use bcr_common::cashu::{CurrencyUnit, MintUrl, nut01 as cdk01};
 use bcr_common::cdk_common::wallet::{Transaction, TransactionDirection, TransactionId};
@@ -21,6 +21,9 @@ struct TransactionEntry {
     pub memo: Option<String>,
     pub metadata: HashMap<String, String>,
     pub quote_id: Option<String>,
+    // local sequence number of the last write, 0 for entries stored before it was introduced
+    #[serde(default)]
+    pub seq: u64,
 }
 
 impl std::convert::From<Transaction> for TransactionEntry {
@@ -38,6 +41,7 @@ impl std::convert::From<Transaction> for TransactionEntry {
             memo: tx.memo,
             metadata: tx.metadata,
             quote_id: tx.quote_id,
+            seq: 0,
         }
     }
 }
@@ -62,33 +66,153 @@ impl std::convert::From<TransactionEntry> for Transaction {
 pub struct TransactionDB {
     db: Arc<Database>,
     transaction_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+    seq_table: TableDefinition<'static, &'static str, u64>,
+    // sequence number of the last write -> id of the written or deleted transaction
+    seq_index_table: TableDefinition<'static, u64, &'static str>,
+    // y of a received proof -> id of the incoming transaction recording it
+    incoming_ys_table: TableDefinition<'static, &'static [u8], &'static str>,
 }
 
 impl TransactionDB {
     const TRANSACTION_BASE_DB_NAME: &'static str = "transactions";
+    const SEQ_BASE_DB_NAME: &'static str = "transactions_seq";
+    const SEQ_INDEX_BASE_DB_NAME: &'static str = "transactions_seq_index";
+    const INCOMING_YS_BASE_DB_NAME: &'static str = "transactions_incoming_ys";
+    const SEQ_KEY: &'static str = "seq";
 
     pub fn new(db: Arc<Database>, wallet_id: &str) -> Result<Self> {
         // Leak once to get static string, because of dynamically generated table names
         let transaction_name: &'static str =
             Box::leak(format!("{wallet_id}_{}", Self::TRANSACTION_BASE_DB_NAME).into_boxed_str());
+        let seq_name: &'static str =
+            Box::leak(format!("{wallet_id}_{}", Self::SEQ_BASE_DB_NAME).into_boxed_str());
+        let seq_index_name: &'static str =
+            Box::leak(format!("{wallet_id}_{}", Self::SEQ_INDEX_BASE_DB_NAME).into_boxed_str());
+        let incoming_ys_name: &'static str =
+            Box::leak(format!("{wallet_id}_{}", Self::INCOMING_YS_BASE_DB_NAME).into_boxed_str());
         let transaction_table = TableDefinition::new(transaction_name);
+        let seq_table = TableDefinition::new(seq_name);
+        let seq_index_table = TableDefinition::new(seq_index_name);
+        let incoming_ys_table = TableDefinition::new(incoming_ys_name);
+        Self::build_incoming_ys_sync(&db, transaction_table, incoming_ys_table)?;
         Ok(Self {
             db,
             transaction_table,
+            seq_table,
+            seq_index_table,
+            incoming_ys_table,
         })
     }
 
+    // indexes the transactions stored before the index was introduced, once
+    fn build_incoming_ys_sync(
+        db: &Database,
+        tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        incoming_ys_table: TableDefinition<'static, &'static [u8], &'static str>,
+    ) -> Result<()> {
+        {
+            let read_txn = db.begin_read()?;
+            match read_txn.open_table(incoming_ys_table) {
+                Ok(_) => return Ok(()),
+                Err(TableError::TableDoesNotExist(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let write_txn = db.begin_write()?;
+        {
+            // creates the index, also when there is nothing to index yet
+            write_txn.open_table(incoming_ys_table)?;
+            let table = write_txn.open_table(tx_table)?;
+            for (_, v) in table.range::<&[u8]>(..)?.flatten() {
+                let tx: TransactionEntry = ciborium::from_reader(v.value().as_slice())?;
+                Self::index_incoming_ys_sync(&write_txn, incoming_ys_table, &tx)?;
+            }
+        }
+        write_txn.commit()?;
+        Ok(())
+    }
+
+    fn index_incoming_ys_sync(
+        write_txn: &redb::WriteTransaction,
+        incoming_ys_table: TableDefinition<'static, &'static [u8], &'static str>,
+        tx: &TransactionEntry,
+    ) -> Result<()> {
+        if !matches!(tx.direction, TransactionDirection::Incoming) {
+            return Ok(());
+        }
+        let mut index = write_txn.open_table(incoming_ys_table)?;
+        for y in tx.ys.iter() {
+            index.insert(y.to_bytes().as_slice(), tx.tx_id.as_str())?;
+        }
+        Ok(())
+    }
+
+    // bumps the sequence number within the given write transaction and returns the new value
+    fn next_seq_sync(
+        write_txn: &redb::WriteTransaction,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+    ) -> Result<u64> {
+        let mut table = write_txn.open_table(seq_table)?;
+        let current = table.get(Self::SEQ_KEY)?.map(|v| v.value()).unwrap_or(0);
+        let next = current + 1;
+        table.insert(Self::SEQ_KEY, next)?;
+        Ok(next)
+    }
+
+    // bumps the sequence number and moves the index entry of the transaction from `old_seq` to it
+    fn index_write_sync(
+        write_txn: &redb::WriteTransaction,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+        seq_index_table: TableDefinition<'static, u64, &'static str>,
+        tx_id: TransactionId,
+        old_seq: u64,
+    ) -> Result<u64> {
+        let next = Self::next_seq_sync(write_txn, seq_table)?;
+        let mut index = write_txn.open_table(seq_index_table)?;
+        if old_seq > 0 {
+            index.remove(old_seq)?;
+        }
+        index.insert(next, tx_id.to_string().as_str())?;
+        Ok(next)
+    }
+
+    fn current_seq_sync(
+        db: Arc<Database>,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+    ) -> Result<u64> {
+        let read_txn = db.begin_read()?;
+
+        match read_txn.open_table(seq_table) {
+            Ok(table) => Ok(table.get(Self::SEQ_KEY)?.map(|v| v.value()).unwrap_or(0)),
+            Err(TableError::TableDoesNotExist(_)) => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn store_tx_sync(
         db: Arc<Database>,
         tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+        seq_index_table: TableDefinition<'static, u64, &'static str>,
+        incoming_ys_table: TableDefinition<'static, &'static [u8], &'static str>,
         tx: Transaction,
     ) -> Result<TransactionId> {
         let id = tx.id();
-        let entry: TransactionEntry = tx.into();
+        let mut entry: TransactionEntry = tx.into();
         let write_txn = db.begin_write()?;
 
         {
             let mut table = write_txn.open_table(tx_table)?;
+            let old_seq = match table.get(id.as_bytes().as_slice())? {
+                Some(old) => {
+                    let old: TransactionEntry = ciborium::from_reader(old.value().as_slice())?;
+                    old.seq
+                }
+                None => 0,
+            };
+            entry.seq =
+                Self::index_write_sync(&write_txn, seq_table, seq_index_table, id, old_seq)?;
+            Self::index_incoming_ys_sync(&write_txn, incoming_ys_table, &entry)?;
 
             let mut serialized = Vec::new();
             ciborium::into_writer(&entry, &mut serialized)?;
@@ -125,19 +249,55 @@ impl TransactionDB {
     fn delete_tx_sync(
         db: Arc<Database>,
         tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+        seq_index_table: TableDefinition<'static, u64, &'static str>,
+        incoming_ys_table: TableDefinition<'static, &'static [u8], &'static str>,
         tx_id: TransactionId,
     ) -> Result<()> {
         let write_txn = db.begin_write()?;
 
         {
             let mut table = write_txn.open_table(tx_table)?;
-            table.remove(tx_id.as_bytes().as_slice())?;
+            let removed = table
+                .remove(tx_id.as_bytes().as_slice())?
+                .map(|v| v.value());
+            if let Some(removed) = removed {
+                // the index entry becomes a tombstone, so incremental syncs see the deletion
+                let removed: TransactionEntry = ciborium::from_reader(removed.as_slice())?;
+                Self::index_write_sync(&write_txn, seq_table, seq_index_table, tx_id, removed.seq)?;
+                let mut index = write_txn.open_table(incoming_ys_table)?;
+                for y in removed.ys.iter() {
+                    index.remove(y.to_bytes().as_slice())?;
+                }
+            }
         }
 
         write_txn.commit()?;
         Ok(())
     }
 
+    fn find_incoming_sync(
+        db: Arc<Database>,
+        incoming_ys_table: TableDefinition<'static, &'static [u8], &'static str>,
+        ys: Vec<cdk01::PublicKey>,
+    ) -> Result<HashMap<cdk01::PublicKey, TransactionId>> {
+        let read_txn = db.begin_read()?;
+
+        match read_txn.open_table(incoming_ys_table) {
+            Ok(index) => {
+                let mut res = HashMap::new();
+                for y in ys {
+                    if let Some(v) = index.get(y.to_bytes().as_slice())? {
+                        res.insert(y, TransactionId::from_str(v.value())?);
+                    }
+                }
+                Ok(res)
+            }
+            Err(TableError::TableDoesNotExist(_)) => Ok(HashMap::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn list_tx_ids_sync(
         db: Arc<Database>,
         tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
@@ -178,9 +338,62 @@ impl TransactionDB {
         }
     }
 
+    fn list_since_sync(
+        db: Arc<Database>,
+        tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        seq_index_table: TableDefinition<'static, u64, &'static str>,
+        seq: u64,
+    ) -> Result<TxChanges> {
+        if seq == 0 {
+            // entries stored before the index have no sequence number, so a full sync reads all
+            let mut updated = Self::list_txs_sync(db, tx_table)?;
+            updated.sort_by_key(|tx| tx.seq);
+            return Ok(TxChanges {
+                updated: updated.into_iter().map(Transaction::from).collect(),
+                deleted: vec![],
+            });
+        }
+
+        let read_txn = db.begin_read()?;
+        let index = match read_txn.open_table(seq_index_table) {
+            Ok(index) => index,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(TxChanges::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let table = read_txn.open_table(tx_table)?;
+        let mut changes = TxChanges::default();
+        for (_, v) in index.range((seq + 1)..)?.flatten() {
+            let tx_id = TransactionId::from_str(v.value())?;
+            match table.get(tx_id.as_bytes().as_slice())? {
+                Some(e) => {
+                    let tx: TransactionEntry = ciborium::from_reader(e.value().as_slice())?;
+                    changes.updated.push(tx.into());
+                }
+                None => changes.deleted.push(tx_id),
+            }
+        }
+        Ok(changes)
+    }
+
+    fn query_sync(
+        db: Arc<Database>,
+        tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        filter: TxFilter,
+    ) -> Result<Vec<TransactionId>> {
+        let mut matching: Vec<Transaction> = Self::list_txs_sync(db, tx_table)?
+            .into_iter()
+            .map(Transaction::from)
+            .filter(|tx| filter.matches(tx))
+            .collect();
+        matching.sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+        Ok(matching.iter().map(Transaction::id).collect())
+    }
+
     fn update_meta_sync(
         db: Arc<Database>,
         tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+        seq_index_table: TableDefinition<'static, u64, &'static str>,
         tx_id: TransactionId,
         k: String,
         v: String,
@@ -193,6 +406,8 @@ impl TransactionDB {
             if let Some(old_value) = old_value {
                 let mut tx: TransactionEntry = ciborium::from_reader(old_value.as_slice())?;
                 let old = tx.metadata.insert(k, v);
+                tx.seq =
+                    Self::index_write_sync(&write_txn, seq_table, seq_index_table, tx_id, tx.seq)?;
 
                 let mut serialized = Vec::new();
                 ciborium::into_writer(&tx, &mut serialized)?;
@@ -210,6 +425,8 @@ impl TransactionDB {
     fn update_fee_sync(
         db: Arc<Database>,
         tx_table: TableDefinition<'static, &'static [u8], Vec<u8>>,
+        seq_table: TableDefinition<'static, &'static str, u64>,
+        seq_index_table: TableDefinition<'static, u64, &'static str>,
         tx_id: TransactionId,
         fee_to_add: bcr_common::cashu::Amount,
     ) -> Result<()> {
@@ -222,6 +439,8 @@ impl TransactionDB {
             if let Some(old_value) = old_value {
                 let mut tx: TransactionEntry = ciborium::from_reader(old_value.as_slice())?;
                 tx.fee += fee_to_add;
+                tx.seq =
+                    Self::index_write_sync(&write_txn, seq_table, seq_index_table, tx_id, tx.seq)?;
 
                 let mut serialized = Vec::new();
                 ciborium::into_writer(&tx, &mut serialized)?;
@@ -239,7 +458,20 @@ impl TransactionRepository for TransactionDB {
     async fn store_tx(&self, tx: Transaction) -> Result<TransactionId> {
         let db_clone = self.db.clone();
         let table = self.transaction_table;
-        spawn_blocking(move || Self::store_tx_sync(db_clone, table, tx)).await?
+        let seq_table = self.seq_table;
+        let seq_index_table = self.seq_index_table;
+        let incoming_ys_table = self.incoming_ys_table;
+        spawn_blocking(move || {
+            Self::store_tx_sync(
+                db_clone,
+                table,
+                seq_table,
+                seq_index_table,
+                incoming_ys_table,
+                tx,
+            )
+        })
+        .await?
     }
 
     async fn load_tx(&self, tx_id: TransactionId) -> Result<Transaction> {
@@ -253,10 +485,32 @@ impl TransactionRepository for TransactionDB {
     async fn delete_tx(&self, tx_id: TransactionId) -> Result<()> {
         let db_clone = self.db.clone();
         let table = self.transaction_table;
-        spawn_blocking(move || Self::delete_tx_sync(db_clone, table, tx_id)).await??;
+        let seq_table = self.seq_table;
+        let seq_index_table = self.seq_index_table;
+        let incoming_ys_table = self.incoming_ys_table;
+        spawn_blocking(move || {
+            Self::delete_tx_sync(
+                db_clone,
+                table,
+                seq_table,
+                seq_index_table,
+                incoming_ys_table,
+                tx_id,
+            )
+        })
+        .await??;
         Ok(())
     }
 
+    async fn find_incoming(
+        &self,
+        ys: Vec<cdk01::PublicKey>,
+    ) -> Result<HashMap<cdk01::PublicKey, TransactionId>> {
+        let db_clone = self.db.clone();
+        let incoming_ys_table = self.incoming_ys_table;
+        spawn_blocking(move || Self::find_incoming_sync(db_clone, incoming_ys_table, ys)).await?
+    }
+
     async fn list_tx_ids(&self) -> Result<Vec<TransactionId>> {
         let db_clone = self.db.clone();
         let table = self.transaction_table;
@@ -270,6 +524,25 @@ impl TransactionRepository for TransactionDB {
         Ok(res.into_iter().map(|entry| entry.into()).collect())
     }
 
+    async fn list_since(&self, seq: u64) -> Result<TxChanges> {
+        let db_clone = self.db.clone();
+        let table = self.transaction_table;
+        let seq_index_table = self.seq_index_table;
+        spawn_blocking(move || Self::list_since_sync(db_clone, table, seq_index_table, seq)).await?
+    }
+
+    async fn current_seq(&self) -> Result<u64> {
+        let db_clone = self.db.clone();
+        let seq_table = self.seq_table;
+        spawn_blocking(move || Self::current_seq_sync(db_clone, seq_table)).await?
+    }
+
+    async fn query(&self, filter: TxFilter) -> Result<Vec<TransactionId>> {
+        let db_clone = self.db.clone();
+        let table = self.transaction_table;
+        spawn_blocking(move || Self::query_sync(db_clone, table, filter)).await?
+    }
+
     async fn update_metadata(
         &self,
         tx_id: TransactionId,
@@ -278,7 +551,12 @@ impl TransactionRepository for TransactionDB {
     ) -> Result<Option<String>> {
         let db_clone = self.db.clone();
         let table = self.transaction_table;
-        spawn_blocking(move || Self::update_meta_sync(db_clone, table, tx_id, k, v)).await?
+        let seq_table = self.seq_table;
+        let seq_index_table = self.seq_index_table;
+        spawn_blocking(move || {
+            Self::update_meta_sync(db_clone, table, seq_table, seq_index_table, tx_id, k, v)
+        })
+        .await?
     }
 
     async fn update_fee(
@@ -288,7 +566,19 @@ impl TransactionRepository for TransactionDB {
     ) -> Result<()> {
         let db_clone = self.db.clone();
         let table = self.transaction_table;
-        spawn_blocking(move || Self::update_fee_sync(db_clone, table, tx_id, fee_to_add)).await?
+        let seq_table = self.seq_table;
+        let seq_index_table = self.seq_index_table;
+        spawn_blocking(move || {
+            Self::update_fee_sync(
+                db_clone,
+                table,
+                seq_table,
+                seq_index_table,
+                tx_id,
+                fee_to_add,
+            )
+        })
+        .await?
     }
 }
 
@@ -397,6 +687,113 @@ mod tests {
         assert_eq!(txs.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_list_since() {
+        let repo = get_db(&wallet_id());
+        assert_eq!(repo.current_seq().await.unwrap(), 0);
+
+        let mut tx1 = test_tx();
+        tx1.ys = vec![cdk01::PublicKey::from(test_pub_key())];
+
+        let mut tx2 = test_tx();
+        tx2.ys = vec![cdk01::PublicKey::from(test_other_pub_key())];
+
+        let id1 = repo.store_tx(tx1).await.unwrap();
+        let marker = repo.current_seq().await.unwrap();
+        assert_eq!(marker, 1);
+
+        let id2 = repo.store_tx(tx2).await.unwrap();
+
+        let all = repo.list_since(0).await.unwrap();
+        assert_eq!(all.updated.len(), 2);
+
+        let newer = repo.list_since(marker).await.unwrap().updated;
+        assert_eq!(newer.len(), 1);
+        assert_eq!(newer[0].id(), id2);
+
+        // updating an older transaction moves it past the marker
+        let marker = repo.current_seq().await.unwrap();
+        repo.update_metadata(id1, "tag".to_string(), "value".to_string())
+            .await
+            .unwrap();
+        let newer = repo.list_since(marker).await.unwrap().updated;
+        assert_eq!(newer.len(), 1);
+        assert_eq!(newer[0].id(), id1);
+
+        let latest = repo.current_seq().await.unwrap();
+        let changes = repo.list_since(latest).await.unwrap();
+        assert!(changes.updated.is_empty());
+        assert!(changes.deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_since_reports_deletions() {
+        let repo = get_db(&wallet_id());
+
+        let mut tx1 = test_tx();
+        tx1.ys = vec![cdk01::PublicKey::from(test_pub_key())];
+        let mut tx2 = test_tx();
+        tx2.ys = vec![cdk01::PublicKey::from(test_other_pub_key())];
+        let id1 = repo.store_tx(tx1).await.unwrap();
+        let id2 = repo.store_tx(tx2).await.unwrap();
+
+        let marker = repo.current_seq().await.unwrap();
+        repo.delete_tx(id1).await.unwrap();
+        assert_eq!(repo.current_seq().await.unwrap(), marker + 1);
+
+        let changes = repo.list_since(marker).await.unwrap();
+        assert!(changes.updated.is_empty());
+        assert_eq!(changes.deleted, vec![id1]);
+
+        // a full sync only returns what is stored
+        let all = repo.list_since(0).await.unwrap();
+        assert_eq!(all.updated.len(), 1);
+        assert_eq!(all.updated[0].id(), id2);
+        assert!(all.deleted.is_empty());
+
+        // deleting a missing transaction writes nothing
+        repo.delete_tx(id1).await.unwrap();
+        assert_eq!(repo.current_seq().await.unwrap(), marker + 1);
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let repo = get_db(&wallet_id());
+
+        let mut incoming = test_tx();
+        incoming.ys = vec![cdk01::PublicKey::from(test_pub_key())];
+        incoming.direction = TransactionDirection::Incoming;
+        incoming.memo = Some("Coffee with Bob".to_string());
+        let mut outgoing = test_tx();
+        outgoing.ys = vec![cdk01::PublicKey::from(test_other_pub_key())];
+        outgoing.memo = None;
+        outgoing.timestamp = incoming.timestamp + 1;
+
+        let in_id = repo.store_tx(incoming).await.unwrap();
+        let out_id = repo.store_tx(outgoing).await.unwrap();
+
+        let all = repo.query(TxFilter::default()).await.unwrap();
+        assert_eq!(all, vec![out_id, in_id]);
+
+        let filter = TxFilter {
+            direction: Some(TransactionDirection::Outgoing),
+            ..Default::default()
+        };
+        assert_eq!(repo.query(filter).await.unwrap(), vec![out_id]);
+
+        let filter = TxFilter {
+            memo_contains: Some("coffee".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(repo.query(filter).await.unwrap(), vec![in_id]);
+
+        let filter = TxFilter {
+            unit: Some(CurrencyUnit::Usd),
+            ..Default::default()
+        };
+        assert!(repo.query(filter).await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_update_metadata_missing_returns_none() {
         let repo = get_db(&wallet_id());
@@ -454,6 +851,53 @@ mod tests {
         assert_eq!(loaded.fee, bcr_common::cashu::Amount::ONE,);
     }
 
+    #[tokio::test]
+    async fn test_find_incoming() {
+        let repo = get_db(&wallet_id());
+
+        let mut incoming = test_tx();
+        incoming.direction = TransactionDirection::Incoming;
+        incoming.ys = vec![cdk01::PublicKey::from(test_pub_key())];
+        let incoming_id = repo.store_tx(incoming).await.unwrap();
+        let mut outgoing = test_tx();
+        outgoing.direction = TransactionDirection::Outgoing;
+        outgoing.ys = vec![cdk01::PublicKey::from(test_other_pub_key())];
+        repo.store_tx(outgoing).await.unwrap();
+
+        let y = cdk01::PublicKey::from(test_pub_key());
+        let other_y = cdk01::PublicKey::from(test_other_pub_key());
+        let found = repo.find_incoming(vec![y, other_y]).await.unwrap();
+        assert_eq!(found, HashMap::from([(y, incoming_id)]));
+
+        repo.delete_tx(incoming_id).await.unwrap();
+        let found = repo.find_incoming(vec![y]).await.unwrap();
+        assert!(found.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_incoming_indexes_older_transactions() {
+        let in_mem = InMemoryBackend::new();
+        let db = Arc::new(
+            Builder::new()
+                .create_with_backend(in_mem)
+                .expect("can create in-memory redb"),
+        );
+        let repo = TransactionDB::new(db.clone(), "wallet").unwrap();
+        let mut incoming = test_tx();
+        incoming.direction = TransactionDirection::Incoming;
+        incoming.ys = vec![cdk01::PublicKey::from(test_pub_key())];
+        let incoming_id = repo.store_tx(incoming).await.unwrap();
+        // as stored before the index existed
+        let write_txn = db.begin_write().unwrap();
+        write_txn.delete_table(repo.incoming_ys_table).unwrap();
+        write_txn.commit().unwrap();
+
+        let repo = TransactionDB::new(db, "wallet").unwrap();
+        let y = cdk01::PublicKey::from(test_pub_key());
+        let found = repo.find_incoming(vec![y]).await.unwrap();
+        assert_eq!(found, HashMap::from([(y, incoming_id)]));
+    }
+
     #[tokio::test]
     async fn test_delete_removes() {
         let repo = get_db(&wallet_id());
@@ -29,4 +29,28 @@ pub mod tests {
         )
         .expect("valid key")
     }
+
+    // the sat dbs of a wallet, in memory so nothing is left behind
+    #[cfg(feature = "redb")]
+    pub async fn in_memory_wallet_dbs(
+        wallet_id: &str,
+    ) -> (
+        crate::redb::transaction::TransactionDB,
+        (
+            crate::redb::pocket::PocketDB,
+            crate::redb::mintmelt::MintMeltDB,
+        ),
+    ) {
+        let db = redb::Builder::new()
+            .create_with_backend(redb::backends::InMemoryBackend::new())
+            .expect("can create in-memory redb");
+        crate::redb::build_wallet_dbs(
+            0,
+            wallet_id,
+            &bcr_common::cashu::CurrencyUnit::Sat,
+            std::sync::Arc::new(db),
+        )
+        .await
+        .expect("can build wallet dbs")
+    }
 }
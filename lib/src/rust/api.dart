@@ -7,8 +7,8 @@ import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These functions are ignored because they are not marked as `pub`: `get_app_state`, `init_logging`, `init_panic_hook`, `new`, `reset_runtime`, `start_jobs`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `WalletCleanLocalDbResponse`, `WalletRuntime`, `WalletsNamesResponse`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `WalletRuntime`, `WalletsNamesResponse`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`
 
 Future<void> initWalletFfi({required WalletFfiConfig conf}) =>
     RustLib.instance.api.crateApiInitWalletFfi(conf: conf);
@@ -19,6 +19,14 @@ Future<AddWalletResponse> walletAdd() =>
 Future<RestoreWalletResponse> walletRestore() =>
     RustLib.instance.api.crateApiWalletRestore();
 
+Future<ExportWalletBackupResponse> exportWalletBackup({
+  required ExportWalletBackupRequest req,
+}) => RustLib.instance.api.crateApiExportWalletBackup(req: req);
+
+Future<RestoreWalletResponse> importWalletBackup({
+  required ImportWalletBackupRequest req,
+}) => RustLib.instance.api.crateApiImportWalletBackup(req: req);
+
 Future<void> walletDelete({required WalletRequest req}) =>
     RustLib.instance.api.crateApiWalletDelete(req: req);
 
@@ -35,7 +43,7 @@ Future<WalletCurrencyUnitResponse> walletGetCurrencyUnit({
 Future<WalletBalanceResponse> walletGetBalance({required WalletRequest req}) =>
     RustLib.instance.api.crateApiWalletGetBalance(req: req);
 
-Future<WalletTransactionIdResponse> walletReceive({
+Future<WalletReceiveResponse> walletReceive({
   required WalletReceiveRequest req,
 }) => RustLib.instance.api.crateApiWalletReceive(req: req);
 
@@ -51,10 +59,60 @@ Future<WalletRefreshTransactionsResponse> walletRefreshTransactions({
   required WalletRequest req,
 }) => RustLib.instance.api.crateApiWalletRefreshTransactions(req: req);
 
+Future<WalletPendingTransactionsResponse> walletGetPendingTransactions({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletGetPendingTransactions(req: req);
+
+Future<WalletRetryPendingTransactionResponse> walletRetryPendingTransaction({
+  required WalletTransactionRequest req,
+}) => RustLib.instance.api.crateApiWalletRetryPendingTransaction(req: req);
+
+Future<WalletConfirmNut18DeliveryResponse> walletConfirmNut18Delivery({
+  required WalletConfirmNut18DeliveryRequest req,
+}) => RustLib.instance.api.crateApiWalletConfirmNut18Delivery(req: req);
+
+Future<WalletTransactionTokenResponse> walletGetTransactionToken({
+  required WalletTransactionRequest req,
+}) => RustLib.instance.api.crateApiWalletGetTransactionToken(req: req);
+
+Future<WalletNprofileResponse> walletGetNprofile({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletGetNprofile(req: req);
+
+Future<WalletCleanLocalDbResponse> walletCleanLocalDb({
+  required WalletCleanLocalDbRequest req,
+}) => RustLib.instance.api.crateApiWalletCleanLocalDb(req: req);
+
+Future<WalletReleaseReservationsResponse> walletReleaseReservations({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletReleaseReservations(req: req);
+
+Future<WalletRecheckResponse> walletRecheck({required WalletRequest req}) =>
+    RustLib.instance.api.crateApiWalletRecheck(req: req);
+
+Future<WalletStatsResponse> walletStats({required WalletRequest req}) =>
+    RustLib.instance.api.crateApiWalletStats(req: req);
+
+Future<WalletExportBalanceTokenResponse> walletExportBalanceToken({
+  required WalletExportBalanceTokenRequest req,
+}) => RustLib.instance.api.crateApiWalletExportBalanceToken(req: req);
+
+Future<WalletMintCapabilitiesResponse> walletMintCapabilities({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletMintCapabilities(req: req);
+
+Future<void> walletSetKeepSentTokens({
+  required WalletKeepSentTokensRequest req,
+}) => RustLib.instance.api.crateApiWalletSetKeepSentTokens(req: req);
+
 Future<WalletReclaimTransactionResponse> walletReclaimTransaction({
   required WalletReclaimTransactionRequest req,
 }) => RustLib.instance.api.crateApiWalletReclaimTransaction(req: req);
 
+Future<WalletBalanceResponse> walletReclaimFunds({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletReclaimFunds(req: req);
+
 Future<WalletRecoverStaleTransactionResponse> walletRecoverPendingStaleProofs({
   required WalletRequest req,
 }) => RustLib.instance.api.crateApiWalletRecoverPendingStaleProofs(req: req);
@@ -67,22 +125,25 @@ Future<WalletTransactionIdResponse> walletMelt({
   required WalletPayRequest req,
 }) => RustLib.instance.api.crateApiWalletMelt(req: req);
 
-Future<WalletMintSummaryResponse> walletMint({
+Future<WalletMintSummaryResponse> walletMintOnchain({
   required WalletMintRequest req,
-}) => RustLib.instance.api.crateApiWalletMint(req: req);
+}) => RustLib.instance.api.crateApiWalletMintOnchain(req: req);
 
 Future<WalletPreparePaymentResponse> walletPreparePayment({
   required WalletPreparePaymentRequest req,
 }) => RustLib.instance.api.crateApiWalletPreparePayment(req: req);
 
-Future<WalletTransactionIdResponse> walletPay({
-  required WalletPayRequest req,
-}) => RustLib.instance.api.crateApiWalletPay(req: req);
+Future<WalletSendResultResponse> walletPay({required WalletPayRequest req}) =>
+    RustLib.instance.api.crateApiWalletPay(req: req);
 
 Future<WalletPreparePaymentResponse> walletPreparePayByToken({
   required WalletPreparePaymentByTokenRequest req,
 }) => RustLib.instance.api.crateApiWalletPreparePayByToken(req: req);
 
+Future<WalletPreparePaymentResponse> walletPrepareSendMax({
+  required WalletPrepareSendMaxRequest req,
+}) => RustLib.instance.api.crateApiWalletPrepareSendMax(req: req);
+
 Future<WalletPaymentByTokenResponse> walletPayByToken({
   required WalletPaymentByTokenRequest req,
 }) => RustLib.instance.api.crateApiWalletPayByToken(req: req);
@@ -127,6 +188,9 @@ Future<WalletTransactionsResponse> walletGetTransactions({
 Future<WalletsIdsResponse> walletGetIds() =>
     RustLib.instance.api.crateApiWalletGetIds();
 
+Future<TotalBalanceResponse> walletGetTotalBalance() =>
+    RustLib.instance.api.crateApiWalletGetTotalBalance();
+
 Future<WalletDevModeDetailedBalanceResponse> walletDevModeGetDetailedBalance({
   required WalletRequest req,
 }) => RustLib.instance.api.crateApiWalletDevModeGetDetailedBalance(req: req);
@@ -135,6 +199,10 @@ Future<MnemonicResponse> generateRandomMnemonic({
   required MnemonicRequest req,
 }) => RustLib.instance.api.crateApiGenerateRandomMnemonic(req: req);
 
+Future<ValidateMnemonicResponse> validateMnemonic({
+  required ValidateMnemonicRequest req,
+}) => RustLib.instance.api.crateApiValidateMnemonic(req: req);
+
 Future<IsValidTokenResponse> isValidToken({required IsValidTokenRequest req}) =>
     RustLib.instance.api.crateApiIsValidToken(req: req);
 
@@ -151,6 +219,65 @@ Future<MintIsOfflineResponse> walletMintIsOffline({
 Future<MintIsRabidResponse> walletMintIsRabid({required WalletRequest req}) =>
     RustLib.instance.api.crateApiWalletMintIsRabid(req: req);
 
+Future<WalletTransactionsSinceResponse> walletListTxSince({
+  required WalletTransactionsSinceRequest req,
+}) => RustLib.instance.api.crateApiWalletListTxSince(req: req);
+
+Future<WalletPreparePaymentResponse> walletPrepareSendLocked({
+  required WalletPrepareSendLockedRequest req,
+}) => RustLib.instance.api.crateApiWalletPrepareSendLocked(req: req);
+
+Future<WalletReceiveMultisigTokenResponse> walletReceiveMultisigToken({
+  required WalletReceiveRequest req,
+}) => RustLib.instance.api.crateApiWalletReceiveMultisigToken(req: req);
+
+Future<WalletPartialSignProofResponse> walletPartialSignProof({
+  required WalletPartialSignProofRequest req,
+}) => RustLib.instance.api.crateApiWalletPartialSignProof(req: req);
+
+Future<WalletCombinePartialSignaturesResponse> walletCombinePartialSignatures({
+  required WalletCombinePartialSignaturesRequest req,
+}) => RustLib.instance.api.crateApiWalletCombinePartialSignatures(req: req);
+
+Future<WalletMintQuoteResponse> walletPrepareMint({
+  required WalletMintRequest req,
+}) => RustLib.instance.api.crateApiWalletPrepareMint(req: req);
+
+Future<WalletTransactionIdResponse> walletMint({
+  required WalletMintBolt11Request req,
+}) => RustLib.instance.api.crateApiWalletMint(req: req);
+
+Future<WalletKeysetFeesResponse> walletGetKeysetFees({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletGetKeysetFees(req: req);
+
+Future<WalletExportReceiptResponse> walletExportReceipt({
+  required WalletTransactionRequest req,
+}) => RustLib.instance.api.crateApiWalletExportReceipt(req: req);
+
+Future<WalletDevModeDetailedBalanceResponse> walletGetCreditBalanceByKeyset({
+  required WalletRequest req,
+}) => RustLib.instance.api.crateApiWalletGetCreditBalanceByKeyset(req: req);
+
+Future<WalletInspectPaymentRequestResponse> walletInspectPaymentRequest({
+  required WalletPreparePaymentRequest req,
+}) => RustLib.instance.api.crateApiWalletInspectPaymentRequest(req: req);
+
+Future<WalletRestoreKeysetResponse> walletRestoreKeyset({
+  required WalletRestoreKeysetRequest req,
+}) => RustLib.instance.api.crateApiWalletRestoreKeyset(req: req);
+
+Future<WalletExportProofsEncryptedResponse> walletExportProofsEncrypted({
+  required WalletExportProofsEncryptedRequest req,
+}) => RustLib.instance.api.crateApiWalletExportProofsEncrypted(req: req);
+
+Future<WalletImportProofsEncryptedResponse> walletImportProofsEncrypted({
+  required WalletImportProofsEncryptedRequest req,
+}) => RustLib.instance.api.crateApiWalletImportProofsEncrypted(req: req);
+
+Future<void> walletRename({required WalletRenameRequest req}) =>
+    RustLib.instance.api.crateApiWalletRename(req: req);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WalletPaymentCheckHandle>>
 abstract class WalletPaymentCheckHandle implements RustOpaqueInterface {
   Future<void> cancel();
@@ -172,6 +299,64 @@ class AddWalletResponse {
           walletId == other.walletId;
 }
 
+class ExportWalletBackupRequest {
+  final BigInt walletId;
+  final String passphrase;
+
+  const ExportWalletBackupRequest({
+    required this.walletId,
+    required this.passphrase,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ passphrase.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportWalletBackupRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          passphrase == other.passphrase;
+}
+
+class ExportWalletBackupResponse {
+  final Uint8List backup;
+
+  const ExportWalletBackupResponse({required this.backup});
+
+  @override
+  int get hashCode => backup.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportWalletBackupResponse &&
+          runtimeType == other.runtimeType &&
+          backup == other.backup;
+}
+
+class ImportWalletBackupRequest {
+  final Uint8List backup;
+  final String passphrase;
+
+  const ImportWalletBackupRequest({
+    required this.backup,
+    required this.passphrase,
+  });
+
+  @override
+  int get hashCode => backup.hashCode ^ passphrase.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportWalletBackupRequest &&
+          runtimeType == other.runtimeType &&
+          backup == other.backup &&
+          passphrase == other.passphrase;
+}
+
 class IsValidTokenRequest {
   final String token;
 
@@ -216,6 +401,24 @@ class IsValidTokenResponse {
           unit == other.unit;
 }
 
+class KeysetFee {
+  final String kid;
+  final BigInt inputFeePpk;
+
+  const KeysetFee({required this.kid, required this.inputFeePpk});
+
+  @override
+  int get hashCode => kid.hashCode ^ inputFeePpk.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeysetFee &&
+          runtimeType == other.runtimeType &&
+          kid == other.kid &&
+          inputFeePpk == other.inputFeePpk;
+}
+
 class MeltTx {
   final String? alphaTxId;
   final String? betaTxId;
@@ -380,12 +583,38 @@ enum PaymentType {
   token,
   cdk18,
   onChain,
-  swap;
+  swap,
+  bolt11;
 
   static Future<PaymentType> default_() =>
       RustLib.instance.api.crateApiPaymentTypeDefault();
 }
 
+class PendingTransaction {
+  final Transaction transaction;
+  final BigInt pendingProofs;
+  final bool unsent;
+
+  const PendingTransaction({
+    required this.transaction,
+    required this.pendingProofs,
+    required this.unsent,
+  });
+
+  @override
+  int get hashCode =>
+      transaction.hashCode ^ pendingProofs.hashCode ^ unsent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PendingTransaction &&
+          runtimeType == other.runtimeType &&
+          transaction == other.transaction &&
+          pendingProofs == other.pendingProofs &&
+          unsent == other.unsent;
+}
+
 enum ProtestStatus {
   resolved,
   rabid;
@@ -426,6 +655,22 @@ class StatusResponse {
           appVersion == other.appVersion;
 }
 
+class TotalBalanceResponse {
+  final List<UnitBalance> balances;
+
+  const TotalBalanceResponse({required this.balances});
+
+  @override
+  int get hashCode => balances.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TotalBalanceResponse &&
+          runtimeType == other.runtimeType &&
+          balances == other.balances;
+}
+
 class Transaction {
   final String id;
   final BigInt amount;
@@ -503,6 +748,70 @@ enum TransactionStatus {
       RustLib.instance.api.crateApiTransactionStatusDefault();
 }
 
+class UnitBalance {
+  final String unit;
+  final BigInt debit;
+  final BigInt credit;
+
+  const UnitBalance({
+    required this.unit,
+    required this.debit,
+    required this.credit,
+  });
+
+  @override
+  int get hashCode => unit.hashCode ^ debit.hashCode ^ credit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UnitBalance &&
+          runtimeType == other.runtimeType &&
+          unit == other.unit &&
+          debit == other.debit &&
+          credit == other.credit;
+}
+
+class ValidateMnemonicRequest {
+  final String mnemonic;
+
+  const ValidateMnemonicRequest({required this.mnemonic});
+
+  @override
+  int get hashCode => mnemonic.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ValidateMnemonicRequest &&
+          runtimeType == other.runtimeType &&
+          mnemonic == other.mnemonic;
+}
+
+class ValidateMnemonicResponse {
+  final bool valid;
+  final int wordCount;
+  final String? error;
+
+  const ValidateMnemonicResponse({
+    required this.valid,
+    required this.wordCount,
+    this.error,
+  });
+
+  @override
+  int get hashCode => valid.hashCode ^ wordCount.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ValidateMnemonicResponse &&
+          runtimeType == other.runtimeType &&
+          valid == other.valid &&
+          wordCount == other.wordCount &&
+          error == other.error;
+}
+
 class WalletBalanceResponse {
   final BigInt debit;
   final BigInt credit;
@@ -567,6 +876,128 @@ class WalletCheckReceivedPaymentRequest {
           pId == other.pId;
 }
 
+class WalletCleanLocalDbRequest {
+  final BigInt walletId;
+  final bool pruneCounters;
+
+  const WalletCleanLocalDbRequest({
+    required this.walletId,
+    required this.pruneCounters,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ pruneCounters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletCleanLocalDbRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          pruneCounters == other.pruneCounters;
+}
+
+class WalletCleanLocalDbResponse {
+  final int cleanedProofs;
+  final int prunedCounters;
+
+  const WalletCleanLocalDbResponse({
+    required this.cleanedProofs,
+    required this.prunedCounters,
+  });
+
+  @override
+  int get hashCode => cleanedProofs.hashCode ^ prunedCounters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletCleanLocalDbResponse &&
+          runtimeType == other.runtimeType &&
+          cleanedProofs == other.cleanedProofs &&
+          prunedCounters == other.prunedCounters;
+}
+
+class WalletCombinePartialSignaturesRequest {
+  final BigInt walletId;
+  final String y;
+  final List<String> witnesses;
+
+  const WalletCombinePartialSignaturesRequest({
+    required this.walletId,
+    required this.y,
+    required this.witnesses,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ y.hashCode ^ witnesses.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletCombinePartialSignaturesRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          y == other.y &&
+          witnesses == other.witnesses;
+}
+
+class WalletCombinePartialSignaturesResponse {
+  final bool spendable;
+
+  const WalletCombinePartialSignaturesResponse({required this.spendable});
+
+  @override
+  int get hashCode => spendable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletCombinePartialSignaturesResponse &&
+          runtimeType == other.runtimeType &&
+          spendable == other.spendable;
+}
+
+class WalletConfirmNut18DeliveryRequest {
+  final BigInt walletId;
+  final String txId;
+  final BigInt timeoutSecs;
+
+  const WalletConfirmNut18DeliveryRequest({
+    required this.walletId,
+    required this.txId,
+    required this.timeoutSecs,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ txId.hashCode ^ timeoutSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletConfirmNut18DeliveryRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          txId == other.txId &&
+          timeoutSecs == other.timeoutSecs;
+}
+
+class WalletConfirmNut18DeliveryResponse {
+  final bool delivered;
+
+  const WalletConfirmNut18DeliveryResponse({required this.delivered});
+
+  @override
+  int get hashCode => delivered.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletConfirmNut18DeliveryResponse &&
+          runtimeType == other.runtimeType &&
+          delivered == other.delivered;
+}
+
 class WalletCurrencyUnitResponse {
   final String unit;
 
@@ -702,26 +1133,118 @@ enum WalletErrorKind {
   unsupported,
 }
 
-class WalletFfiConfig {
-  final String dbFolderPath;
-  final String logLevel;
-  final BigInt jobIntervalSecs;
-  final BigInt jobInitialDelaySecs;
-  final String defaultMintUrl;
-  final String bitcoinNetwork;
-  final String mnemonic;
-  final List<String> nostrRelays;
-  final int swapExpiryMinutes;
-  final bool devMode;
+class WalletExportBalanceTokenRequest {
+  final BigInt walletId;
+  final String unit;
 
-  const WalletFfiConfig({
-    required this.dbFolderPath,
-    required this.logLevel,
-    required this.jobIntervalSecs,
-    required this.jobInitialDelaySecs,
+  const WalletExportBalanceTokenRequest({
+    required this.walletId,
+    required this.unit,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ unit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletExportBalanceTokenRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          unit == other.unit;
+}
+
+class WalletExportBalanceTokenResponse {
+  final String token;
+
+  const WalletExportBalanceTokenResponse({required this.token});
+
+  @override
+  int get hashCode => token.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletExportBalanceTokenResponse &&
+          runtimeType == other.runtimeType &&
+          token == other.token;
+}
+
+class WalletExportProofsEncryptedRequest {
+  final BigInt walletId;
+  final String passphrase;
+
+  const WalletExportProofsEncryptedRequest({
+    required this.walletId,
+    required this.passphrase,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ passphrase.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletExportProofsEncryptedRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          passphrase == other.passphrase;
+}
+
+class WalletExportProofsEncryptedResponse {
+  final Uint8List blob;
+
+  const WalletExportProofsEncryptedResponse({required this.blob});
+
+  @override
+  int get hashCode => blob.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletExportProofsEncryptedResponse &&
+          runtimeType == other.runtimeType &&
+          blob == other.blob;
+}
+
+class WalletExportReceiptResponse {
+  final String receipt;
+
+  const WalletExportReceiptResponse({required this.receipt});
+
+  @override
+  int get hashCode => receipt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletExportReceiptResponse &&
+          runtimeType == other.runtimeType &&
+          receipt == other.receipt;
+}
+
+class WalletFfiConfig {
+  final String dbFolderPath;
+  final String logLevel;
+  final BigInt jobIntervalSecs;
+  final BigInt jobInitialDelaySecs;
+  final String defaultMintUrl;
+  final String bitcoinNetwork;
+  final String mnemonic;
+  final String? passphrase;
+  final List<String> nostrRelays;
+  final int swapExpiryMinutes;
+  final bool devMode;
+
+  const WalletFfiConfig({
+    required this.dbFolderPath,
+    required this.logLevel,
+    required this.jobIntervalSecs,
+    required this.jobInitialDelaySecs,
     required this.defaultMintUrl,
     required this.bitcoinNetwork,
     required this.mnemonic,
+    this.passphrase,
     required this.nostrRelays,
     required this.swapExpiryMinutes,
     required this.devMode,
@@ -736,6 +1259,7 @@ class WalletFfiConfig {
       defaultMintUrl.hashCode ^
       bitcoinNetwork.hashCode ^
       mnemonic.hashCode ^
+      passphrase.hashCode ^
       nostrRelays.hashCode ^
       swapExpiryMinutes.hashCode ^
       devMode.hashCode;
@@ -752,11 +1276,105 @@ class WalletFfiConfig {
           defaultMintUrl == other.defaultMintUrl &&
           bitcoinNetwork == other.bitcoinNetwork &&
           mnemonic == other.mnemonic &&
+          passphrase == other.passphrase &&
           nostrRelays == other.nostrRelays &&
           swapExpiryMinutes == other.swapExpiryMinutes &&
           devMode == other.devMode;
 }
 
+class WalletImportProofsEncryptedRequest {
+  final BigInt walletId;
+  final Uint8List blob;
+  final String passphrase;
+
+  const WalletImportProofsEncryptedRequest({
+    required this.walletId,
+    required this.blob,
+    required this.passphrase,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ blob.hashCode ^ passphrase.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletImportProofsEncryptedRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          blob == other.blob &&
+          passphrase == other.passphrase;
+}
+
+class WalletImportProofsEncryptedResponse {
+  final int imported;
+
+  const WalletImportProofsEncryptedResponse({required this.imported});
+
+  @override
+  int get hashCode => imported.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletImportProofsEncryptedResponse &&
+          runtimeType == other.runtimeType &&
+          imported == other.imported;
+}
+
+class WalletInspectPaymentRequestResponse {
+  final String info;
+
+  const WalletInspectPaymentRequestResponse({required this.info});
+
+  @override
+  int get hashCode => info.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletInspectPaymentRequestResponse &&
+          runtimeType == other.runtimeType &&
+          info == other.info;
+}
+
+class WalletKeepSentTokensRequest {
+  final BigInt walletId;
+  final bool keep;
+
+  const WalletKeepSentTokensRequest({
+    required this.walletId,
+    required this.keep,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ keep.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletKeepSentTokensRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          keep == other.keep;
+}
+
+class WalletKeysetFeesResponse {
+  final List<KeysetFee> fees;
+
+  const WalletKeysetFeesResponse({required this.fees});
+
+  @override
+  int get hashCode => fees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletKeysetFeesResponse &&
+          runtimeType == other.runtimeType &&
+          fees == other.fees;
+}
+
 class WalletMaybeTransactionIdResponse {
   final String? txId;
 
@@ -773,6 +1391,113 @@ class WalletMaybeTransactionIdResponse {
           txId == other.txId;
 }
 
+class WalletMintBolt11Request {
+  final BigInt walletId;
+  final String quoteId;
+
+  const WalletMintBolt11Request({
+    required this.walletId,
+    required this.quoteId,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ quoteId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletMintBolt11Request &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          quoteId == other.quoteId;
+}
+
+class WalletMintCapabilitiesResponse {
+  final bool mintEnabled;
+  final bool meltEnabled;
+  final bool tokenStateCheck;
+  final bool restore;
+  final bool p2Pk;
+  final bool dleq;
+  final bool htlc;
+  final bool mpp;
+  final bool websocket;
+
+  const WalletMintCapabilitiesResponse({
+    required this.mintEnabled,
+    required this.meltEnabled,
+    required this.tokenStateCheck,
+    required this.restore,
+    required this.p2Pk,
+    required this.dleq,
+    required this.htlc,
+    required this.mpp,
+    required this.websocket,
+  });
+
+  @override
+  int get hashCode =>
+      mintEnabled.hashCode ^
+      meltEnabled.hashCode ^
+      tokenStateCheck.hashCode ^
+      restore.hashCode ^
+      p2Pk.hashCode ^
+      dleq.hashCode ^
+      htlc.hashCode ^
+      mpp.hashCode ^
+      websocket.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletMintCapabilitiesResponse &&
+          runtimeType == other.runtimeType &&
+          mintEnabled == other.mintEnabled &&
+          meltEnabled == other.meltEnabled &&
+          tokenStateCheck == other.tokenStateCheck &&
+          restore == other.restore &&
+          p2Pk == other.p2Pk &&
+          dleq == other.dleq &&
+          htlc == other.htlc &&
+          mpp == other.mpp &&
+          websocket == other.websocket;
+}
+
+class WalletMintQuoteResponse {
+  final String quoteId;
+  final String request;
+  final BigInt amount;
+  final String unit;
+  final BigInt? expiry;
+
+  const WalletMintQuoteResponse({
+    required this.quoteId,
+    required this.request,
+    required this.amount,
+    required this.unit,
+    this.expiry,
+  });
+
+  @override
+  int get hashCode =>
+      quoteId.hashCode ^
+      request.hashCode ^
+      amount.hashCode ^
+      unit.hashCode ^
+      expiry.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletMintQuoteResponse &&
+          runtimeType == other.runtimeType &&
+          quoteId == other.quoteId &&
+          request == other.request &&
+          amount == other.amount &&
+          unit == other.unit &&
+          expiry == other.expiry;
+}
+
 class WalletMintRequest {
   final BigInt walletId;
   final BigInt amount;
@@ -851,6 +1576,59 @@ class WalletNameResponse {
           name == other.name;
 }
 
+class WalletNprofileResponse {
+  final String nprofile;
+
+  const WalletNprofileResponse({required this.nprofile});
+
+  @override
+  int get hashCode => nprofile.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletNprofileResponse &&
+          runtimeType == other.runtimeType &&
+          nprofile == other.nprofile;
+}
+
+class WalletPartialSignProofRequest {
+  final BigInt walletId;
+  final String y;
+
+  const WalletPartialSignProofRequest({
+    required this.walletId,
+    required this.y,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ y.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletPartialSignProofRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          y == other.y;
+}
+
+class WalletPartialSignProofResponse {
+  final String witness;
+
+  const WalletPartialSignProofResponse({required this.witness});
+
+  @override
+  int get hashCode => witness.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletPartialSignProofResponse &&
+          runtimeType == other.runtimeType &&
+          witness == other.witness;
+}
+
 class WalletPayRequest {
   final BigInt walletId;
   final String rid;
@@ -893,11 +1671,25 @@ class WalletPaymentByTokenRequest {
 class WalletPaymentByTokenResponse {
   final String txId;
   final String token;
+  final BigInt amount;
+  final BigInt fees;
+  final String unit;
 
-  const WalletPaymentByTokenResponse({required this.txId, required this.token});
+  const WalletPaymentByTokenResponse({
+    required this.txId,
+    required this.token,
+    required this.amount,
+    required this.fees,
+    required this.unit,
+  });
 
   @override
-  int get hashCode => txId.hashCode ^ token.hashCode;
+  int get hashCode =>
+      txId.hashCode ^
+      token.hashCode ^
+      amount.hashCode ^
+      fees.hashCode ^
+      unit.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -905,7 +1697,26 @@ class WalletPaymentByTokenResponse {
       other is WalletPaymentByTokenResponse &&
           runtimeType == other.runtimeType &&
           txId == other.txId &&
-          token == other.token;
+          token == other.token &&
+          amount == other.amount &&
+          fees == other.fees &&
+          unit == other.unit;
+}
+
+class WalletPendingTransactionsResponse {
+  final List<PendingTransaction> txs;
+
+  const WalletPendingTransactionsResponse({required this.txs});
+
+  @override
+  int get hashCode => txs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletPendingTransactionsResponse &&
+          runtimeType == other.runtimeType &&
+          txs == other.txs;
 }
 
 class WalletPrepareMeltRequest {
@@ -1048,6 +1859,73 @@ class WalletPreparePaymentResponse {
           paymentSummary == other.paymentSummary;
 }
 
+class WalletPrepareSendLockedRequest {
+  final BigInt walletId;
+  final BigInt amount;
+  final String unit;
+  final String pubkey;
+  final BigInt? locktime;
+  final String? refundKey;
+  final String? description;
+
+  const WalletPrepareSendLockedRequest({
+    required this.walletId,
+    required this.amount,
+    required this.unit,
+    required this.pubkey,
+    this.locktime,
+    this.refundKey,
+    this.description,
+  });
+
+  @override
+  int get hashCode =>
+      walletId.hashCode ^
+      amount.hashCode ^
+      unit.hashCode ^
+      pubkey.hashCode ^
+      locktime.hashCode ^
+      refundKey.hashCode ^
+      description.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletPrepareSendLockedRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          amount == other.amount &&
+          unit == other.unit &&
+          pubkey == other.pubkey &&
+          locktime == other.locktime &&
+          refundKey == other.refundKey &&
+          description == other.description;
+}
+
+class WalletPrepareSendMaxRequest {
+  final BigInt walletId;
+  final String unit;
+  final String? description;
+
+  const WalletPrepareSendMaxRequest({
+    required this.walletId,
+    required this.unit,
+    this.description,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ unit.hashCode ^ description.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletPrepareSendMaxRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          unit == other.unit &&
+          description == other.description;
+}
+
 class WalletProtestMeltRequest {
   final BigInt walletId;
   final String quoteId;
@@ -1165,6 +2043,30 @@ class WalletProtestSwapResponse {
           amount == other.amount;
 }
 
+class WalletReceiveMultisigTokenResponse {
+  final String txId;
+  final BigInt amount;
+  final List<String> ys;
+
+  const WalletReceiveMultisigTokenResponse({
+    required this.txId,
+    required this.amount,
+    required this.ys,
+  });
+
+  @override
+  int get hashCode => txId.hashCode ^ amount.hashCode ^ ys.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletReceiveMultisigTokenResponse &&
+          runtimeType == other.runtimeType &&
+          txId == other.txId &&
+          amount == other.amount &&
+          ys == other.ys;
+}
+
 class WalletReceiveRequest {
   final BigInt walletId;
   final String token;
@@ -1183,6 +2085,65 @@ class WalletReceiveRequest {
           token == other.token;
 }
 
+class WalletReceiveResponse {
+  final String txId;
+  final BigInt received;
+  final BigInt stored;
+  final BigInt fee;
+  final String unit;
+
+  const WalletReceiveResponse({
+    required this.txId,
+    required this.received,
+    required this.stored,
+    required this.fee,
+    required this.unit,
+  });
+
+  @override
+  int get hashCode =>
+      txId.hashCode ^
+      received.hashCode ^
+      stored.hashCode ^
+      fee.hashCode ^
+      unit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletReceiveResponse &&
+          runtimeType == other.runtimeType &&
+          txId == other.txId &&
+          received == other.received &&
+          stored == other.stored &&
+          fee == other.fee &&
+          unit == other.unit;
+}
+
+class WalletRecheckResponse {
+  final int deleted;
+  final int restored;
+  final int pending;
+
+  const WalletRecheckResponse({
+    required this.deleted,
+    required this.restored,
+    required this.pending,
+  });
+
+  @override
+  int get hashCode => deleted.hashCode ^ restored.hashCode ^ pending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletRecheckResponse &&
+          runtimeType == other.runtimeType &&
+          deleted == other.deleted &&
+          restored == other.restored &&
+          pending == other.pending;
+}
+
 class WalletReclaimTransactionRequest {
   final BigInt walletId;
   final String txId;
@@ -1289,6 +2250,40 @@ class WalletRefreshTransactionsResponse {
           updated == other.updated;
 }
 
+class WalletReleaseReservationsResponse {
+  final int released;
+
+  const WalletReleaseReservationsResponse({required this.released});
+
+  @override
+  int get hashCode => released.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletReleaseReservationsResponse &&
+          runtimeType == other.runtimeType &&
+          released == other.released;
+}
+
+class WalletRenameRequest {
+  final BigInt walletId;
+  final String name;
+
+  const WalletRenameRequest({required this.walletId, required this.name});
+
+  @override
+  int get hashCode => walletId.hashCode ^ name.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletRenameRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          name == other.name;
+}
+
 class WalletRequest {
   final BigInt walletId;
 
@@ -1305,6 +2300,141 @@ class WalletRequest {
           walletId == other.walletId;
 }
 
+class WalletRestoreKeysetRequest {
+  final BigInt walletId;
+  final String keysetId;
+
+  const WalletRestoreKeysetRequest({
+    required this.walletId,
+    required this.keysetId,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ keysetId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletRestoreKeysetRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          keysetId == other.keysetId;
+}
+
+class WalletRestoreKeysetResponse {
+  final int restored;
+
+  const WalletRestoreKeysetResponse({required this.restored});
+
+  @override
+  int get hashCode => restored.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletRestoreKeysetResponse &&
+          runtimeType == other.runtimeType &&
+          restored == other.restored;
+}
+
+class WalletRetryPendingTransactionResponse {
+  final bool retried;
+
+  const WalletRetryPendingTransactionResponse({required this.retried});
+
+  @override
+  int get hashCode => retried.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletRetryPendingTransactionResponse &&
+          runtimeType == other.runtimeType &&
+          retried == other.retried;
+}
+
+class WalletSendResultResponse {
+  final String txId;
+  final String? token;
+  final BigInt amount;
+  final BigInt fees;
+  final String unit;
+
+  const WalletSendResultResponse({
+    required this.txId,
+    this.token,
+    required this.amount,
+    required this.fees,
+    required this.unit,
+  });
+
+  @override
+  int get hashCode =>
+      txId.hashCode ^
+      token.hashCode ^
+      amount.hashCode ^
+      fees.hashCode ^
+      unit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletSendResultResponse &&
+          runtimeType == other.runtimeType &&
+          txId == other.txId &&
+          token == other.token &&
+          amount == other.amount &&
+          fees == other.fees &&
+          unit == other.unit;
+}
+
+class WalletStatsResponse {
+  final int unspentProofs;
+  final int pendingProofs;
+  final int reservedProofs;
+  final int totalProofs;
+  final int keysets;
+  final int randomProofs;
+  final int transactions;
+  final BigInt? oldestTx;
+
+  const WalletStatsResponse({
+    required this.unspentProofs,
+    required this.pendingProofs,
+    required this.reservedProofs,
+    required this.totalProofs,
+    required this.keysets,
+    required this.randomProofs,
+    required this.transactions,
+    this.oldestTx,
+  });
+
+  @override
+  int get hashCode =>
+      unspentProofs.hashCode ^
+      pendingProofs.hashCode ^
+      reservedProofs.hashCode ^
+      totalProofs.hashCode ^
+      keysets.hashCode ^
+      randomProofs.hashCode ^
+      transactions.hashCode ^
+      oldestTx.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletStatsResponse &&
+          runtimeType == other.runtimeType &&
+          unspentProofs == other.unspentProofs &&
+          pendingProofs == other.pendingProofs &&
+          reservedProofs == other.reservedProofs &&
+          totalProofs == other.totalProofs &&
+          keysets == other.keysets &&
+          randomProofs == other.randomProofs &&
+          transactions == other.transactions &&
+          oldestTx == other.oldestTx;
+}
+
 class WalletTransactionIdResponse {
   final String txId;
 
@@ -1371,6 +2501,22 @@ class WalletTransactionResponse {
           transaction == other.transaction;
 }
 
+class WalletTransactionTokenResponse {
+  final String? token;
+
+  const WalletTransactionTokenResponse({this.token});
+
+  @override
+  int get hashCode => token.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletTransactionTokenResponse &&
+          runtimeType == other.runtimeType &&
+          token == other.token;
+}
+
 class WalletTransactionsResponse {
   final List<Transaction> txs;
 
@@ -1387,6 +2533,51 @@ class WalletTransactionsResponse {
           txs == other.txs;
 }
 
+class WalletTransactionsSinceRequest {
+  final BigInt walletId;
+  final BigInt seq;
+
+  const WalletTransactionsSinceRequest({
+    required this.walletId,
+    required this.seq,
+  });
+
+  @override
+  int get hashCode => walletId.hashCode ^ seq.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletTransactionsSinceRequest &&
+          runtimeType == other.runtimeType &&
+          walletId == other.walletId &&
+          seq == other.seq;
+}
+
+class WalletTransactionsSinceResponse {
+  final List<Transaction> txs;
+  final List<String> deleted;
+  final BigInt seq;
+
+  const WalletTransactionsSinceResponse({
+    required this.txs,
+    required this.deleted,
+    required this.seq,
+  });
+
+  @override
+  int get hashCode => txs.hashCode ^ deleted.hashCode ^ seq.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletTransactionsSinceResponse &&
+          runtimeType == other.runtimeType &&
+          txs == other.txs &&
+          deleted == other.deleted &&
+          seq == other.seq;
+}
+
 class WalletsIdsResponse {
   final Uint64List ids;
 
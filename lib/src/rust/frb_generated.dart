@@ -66,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -930769447;
+  int get rustContentHash => 859003024;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -81,10 +81,18 @@ abstract class RustLibApi extends BaseApi {
     required WalletPaymentCheckHandle that,
   });
 
+  Future<ExportWalletBackupResponse> crateApiExportWalletBackup({
+    required ExportWalletBackupRequest req,
+  });
+
   Future<MnemonicResponse> crateApiGenerateRandomMnemonic({
     required MnemonicRequest req,
   });
 
+  Future<RestoreWalletResponse> crateApiImportWalletBackup({
+    required ImportWalletBackupRequest req,
+  });
+
   Future<void> crateApiInitApp();
 
   Future<void> crateApiInitWalletFfi({required WalletFfiConfig conf});
@@ -101,6 +109,10 @@ abstract class RustLibApi extends BaseApi {
 
   Future<TransactionStatus> crateApiTransactionStatusDefault();
 
+  Future<ValidateMnemonicResponse> crateApiValidateMnemonic({
+    required ValidateMnemonicRequest req,
+  });
+
   Future<AddWalletResponse> crateApiWalletAdd();
 
   Future<WalletCheckPendingMintsResponse> crateApiWalletCheckPendingMints({
@@ -113,6 +125,20 @@ abstract class RustLibApi extends BaseApi {
     resultCallback,
   });
 
+  Future<WalletCleanLocalDbResponse> crateApiWalletCleanLocalDb({
+    required WalletCleanLocalDbRequest req,
+  });
+
+  Future<WalletCombinePartialSignaturesResponse>
+  crateApiWalletCombinePartialSignatures({
+    required WalletCombinePartialSignaturesRequest req,
+  });
+
+  Future<WalletConfirmNut18DeliveryResponse>
+  crateApiWalletConfirmNut18Delivery({
+    required WalletConfirmNut18DeliveryRequest req,
+  });
+
   Future<void> crateApiWalletDelete({required WalletRequest req});
 
   Future<WalletDevModeDetailedBalanceResponse>
@@ -132,16 +158,36 @@ abstract class RustLibApi extends BaseApi {
     required WalletErrorCode code,
   });
 
+  Future<WalletExportBalanceTokenResponse> crateApiWalletExportBalanceToken({
+    required WalletExportBalanceTokenRequest req,
+  });
+
+  Future<WalletExportProofsEncryptedResponse>
+  crateApiWalletExportProofsEncrypted({
+    required WalletExportProofsEncryptedRequest req,
+  });
+
+  Future<WalletExportReceiptResponse> crateApiWalletExportReceipt({
+    required WalletTransactionRequest req,
+  });
+
   Future<WalletBalanceResponse> crateApiWalletGetBalance({
     required WalletRequest req,
   });
 
+  Future<WalletDevModeDetailedBalanceResponse>
+  crateApiWalletGetCreditBalanceByKeyset({required WalletRequest req});
+
   Future<WalletCurrencyUnitResponse> crateApiWalletGetCurrencyUnit({
     required WalletRequest req,
   });
 
   Future<WalletsIdsResponse> crateApiWalletGetIds();
 
+  Future<WalletKeysetFeesResponse> crateApiWalletGetKeysetFees({
+    required WalletRequest req,
+  });
+
   Future<WalletMintUrlResponse> crateApiWalletGetMintUrl({
     required WalletRequest req,
   });
@@ -150,16 +196,43 @@ abstract class RustLibApi extends BaseApi {
     required WalletRequest req,
   });
 
+  Future<WalletNprofileResponse> crateApiWalletGetNprofile({
+    required WalletRequest req,
+  });
+
+  Future<WalletPendingTransactionsResponse>
+  crateApiWalletGetPendingTransactions({required WalletRequest req});
+
   Future<StatusResponse> crateApiWalletGetStatus();
 
+  Future<TotalBalanceResponse> crateApiWalletGetTotalBalance();
+
   Future<WalletTransactionIdsResponse> crateApiWalletGetTransactionIds({
     required WalletRequest req,
   });
 
+  Future<WalletTransactionTokenResponse> crateApiWalletGetTransactionToken({
+    required WalletTransactionRequest req,
+  });
+
   Future<WalletTransactionsResponse> crateApiWalletGetTransactions({
     required WalletRequest req,
   });
 
+  Future<WalletImportProofsEncryptedResponse>
+  crateApiWalletImportProofsEncrypted({
+    required WalletImportProofsEncryptedRequest req,
+  });
+
+  Future<WalletInspectPaymentRequestResponse>
+  crateApiWalletInspectPaymentRequest({
+    required WalletPreparePaymentRequest req,
+  });
+
+  Future<WalletTransactionsSinceResponse> crateApiWalletListTxSince({
+    required WalletTransactionsSinceRequest req,
+  });
+
   Future<WalletTransactionResponse> crateApiWalletLoadTransaction({
     required WalletTransactionRequest req,
   });
@@ -170,8 +243,12 @@ abstract class RustLibApi extends BaseApi {
 
   Future<MigrateRabidResponse> crateApiWalletMigrateRabid();
 
-  Future<WalletMintSummaryResponse> crateApiWalletMint({
-    required WalletMintRequest req,
+  Future<WalletTransactionIdResponse> crateApiWalletMint({
+    required WalletMintBolt11Request req,
+  });
+
+  Future<WalletMintCapabilitiesResponse> crateApiWalletMintCapabilities({
+    required WalletRequest req,
   });
 
   Future<MintIsOfflineResponse> crateApiWalletMintIsOffline({
@@ -182,7 +259,15 @@ abstract class RustLibApi extends BaseApi {
     required WalletRequest req,
   });
 
-  Future<WalletTransactionIdResponse> crateApiWalletPay({
+  Future<WalletMintSummaryResponse> crateApiWalletMintOnchain({
+    required WalletMintRequest req,
+  });
+
+  Future<WalletPartialSignProofResponse> crateApiWalletPartialSignProof({
+    required WalletPartialSignProofRequest req,
+  });
+
+  Future<WalletSendResultResponse> crateApiWalletPay({
     required WalletPayRequest req,
   });
 
@@ -194,6 +279,10 @@ abstract class RustLibApi extends BaseApi {
     required WalletPrepareMeltRequest req,
   });
 
+  Future<WalletMintQuoteResponse> crateApiWalletPrepareMint({
+    required WalletMintRequest req,
+  });
+
   Future<WalletPreparePaymentResponse> crateApiWalletPreparePayByToken({
     required WalletPreparePaymentByTokenRequest req,
   });
@@ -206,6 +295,14 @@ abstract class RustLibApi extends BaseApi {
     required WalletPreparePaymentReqRequest req,
   });
 
+  Future<WalletPreparePaymentResponse> crateApiWalletPrepareSendLocked({
+    required WalletPrepareSendLockedRequest req,
+  });
+
+  Future<WalletPreparePaymentResponse> crateApiWalletPrepareSendMax({
+    required WalletPrepareSendMaxRequest req,
+  });
+
   Future<WalletProtestMeltResponse> crateApiWalletProtestMelt({
     required WalletProtestMeltRequest req,
   });
@@ -218,10 +315,21 @@ abstract class RustLibApi extends BaseApi {
     required WalletProtestSwapRequest req,
   });
 
-  Future<WalletTransactionIdResponse> crateApiWalletReceive({
+  Future<WalletReceiveResponse> crateApiWalletReceive({
     required WalletReceiveRequest req,
   });
 
+  Future<WalletReceiveMultisigTokenResponse>
+  crateApiWalletReceiveMultisigToken({required WalletReceiveRequest req});
+
+  Future<WalletRecheckResponse> crateApiWalletRecheck({
+    required WalletRequest req,
+  });
+
+  Future<WalletBalanceResponse> crateApiWalletReclaimFunds({
+    required WalletRequest req,
+  });
+
   Future<WalletReclaimTransactionResponse> crateApiWalletReclaimTransaction({
     required WalletReclaimTransactionRequest req,
   });
@@ -237,8 +345,29 @@ abstract class RustLibApi extends BaseApi {
     required WalletRequest req,
   });
 
+  Future<WalletReleaseReservationsResponse> crateApiWalletReleaseReservations({
+    required WalletRequest req,
+  });
+
+  Future<void> crateApiWalletRename({required WalletRenameRequest req});
+
   Future<RestoreWalletResponse> crateApiWalletRestore();
 
+  Future<WalletRestoreKeysetResponse> crateApiWalletRestoreKeyset({
+    required WalletRestoreKeysetRequest req,
+  });
+
+  Future<WalletRetryPendingTransactionResponse>
+  crateApiWalletRetryPendingTransaction({
+    required WalletTransactionRequest req,
+  });
+
+  Future<void> crateApiWalletSetKeepSentTokens({
+    required WalletKeepSentTokensRequest req,
+  });
+
+  Future<WalletStatsResponse> crateApiWalletStats({required WalletRequest req});
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_WalletPaymentCheckHandle;
 
@@ -293,6 +422,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<ExportWalletBackupResponse> crateApiExportWalletBackup({
+    required ExportWalletBackupRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_export_wallet_backup_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_export_wallet_backup_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiExportWalletBackupConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExportWalletBackupConstMeta =>
+      const TaskConstMeta(debugName: "export_wallet_backup", argNames: ["req"]);
+
   @override
   Future<MnemonicResponse> crateApiGenerateRandomMnemonic({
     required MnemonicRequest req,
@@ -305,7 +464,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 3,
             port: port_,
           );
         },
@@ -326,6 +485,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["req"],
       );
 
+  @override
+  Future<RestoreWalletResponse> crateApiImportWalletBackup({
+    required ImportWalletBackupRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_import_wallet_backup_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_restore_wallet_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiImportWalletBackupConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportWalletBackupConstMeta =>
+      const TaskConstMeta(debugName: "import_wallet_backup", argNames: ["req"]);
+
   @override
   Future<void> crateApiInitApp() {
     return handler.executeNormal(
@@ -335,7 +524,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 5,
             port: port_,
           );
         },
@@ -363,7 +552,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 6,
             port: port_,
           );
         },
@@ -393,7 +582,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 7,
             port: port_,
           );
         },
@@ -420,7 +609,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 8,
             port: port_,
           );
         },
@@ -447,7 +636,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 9,
             port: port_,
           );
         },
@@ -474,7 +663,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 10,
             port: port_,
           );
         },
@@ -504,7 +693,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 11,
             port: port_,
           );
         },
@@ -525,6 +714,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<ValidateMnemonicResponse> crateApiValidateMnemonic({
+    required ValidateMnemonicRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_validate_mnemonic_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_validate_mnemonic_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiValidateMnemonicConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiValidateMnemonicConstMeta =>
+      const TaskConstMeta(debugName: "validate_mnemonic", argNames: ["req"]);
+
   @override
   Future<AddWalletResponse> crateApiWalletAdd() {
     return handler.executeNormal(
@@ -534,7 +753,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 13,
             port: port_,
           );
         },
@@ -564,7 +783,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 14,
             port: port_,
           );
         },
@@ -606,7 +825,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 15,
             port: port_,
           );
         },
@@ -629,162 +848,176 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiWalletDelete({required WalletRequest req}) {
+  Future<WalletCleanLocalDbResponse> crateApiWalletCleanLocalDb({
+    required WalletCleanLocalDbRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_request(req, serializer);
+          sse_encode_box_autoadd_wallet_clean_local_db_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 16,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_wallet_clean_local_db_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletDeleteConstMeta,
+        constMeta: kCrateApiWalletCleanLocalDbConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletDeleteConstMeta =>
-      const TaskConstMeta(debugName: "wallet_delete", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletCleanLocalDbConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_clean_local_db",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletDevModeDetailedBalanceResponse>
-  crateApiWalletDevModeGetDetailedBalance({required WalletRequest req}) {
+  Future<WalletCombinePartialSignaturesResponse>
+  crateApiWalletCombinePartialSignatures({
+    required WalletCombinePartialSignaturesRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_request(req, serializer);
+          sse_encode_box_autoadd_wallet_combine_partial_signatures_request(
+            req,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 17,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_wallet_dev_mode_detailed_balance_response,
+              sse_decode_wallet_combine_partial_signatures_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletDevModeGetDetailedBalanceConstMeta,
+        constMeta: kCrateApiWalletCombinePartialSignaturesConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletDevModeGetDetailedBalanceConstMeta =>
+  TaskConstMeta get kCrateApiWalletCombinePartialSignaturesConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_dev_mode_get_detailed_balance",
+        debugName: "wallet_combine_partial_signatures",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletError> crateApiWalletErrorBadRequest({
-    required String msg,
-    required WalletErrorCode code,
+  Future<WalletConfirmNut18DeliveryResponse>
+  crateApiWalletConfirmNut18Delivery({
+    required WalletConfirmNut18DeliveryRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(msg, serializer);
-          sse_encode_wallet_error_code(code, serializer);
+          sse_encode_box_autoadd_wallet_confirm_nut_18_delivery_request(
+            req,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_error,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_wallet_confirm_nut_18_delivery_response,
+          decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletErrorBadRequestConstMeta,
-        argValues: [msg, code],
+        constMeta: kCrateApiWalletConfirmNut18DeliveryConstMeta,
+        argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletErrorBadRequestConstMeta =>
+  TaskConstMeta get kCrateApiWalletConfirmNut18DeliveryConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_error_bad_request",
-        argNames: ["msg", "code"],
+        debugName: "wallet_confirm_nut18_delivery",
+        argNames: ["req"],
       );
 
   @override
-  Future<WalletError> crateApiWalletErrorInternal({required String msg}) {
+  Future<void> crateApiWalletDelete({required WalletRequest req}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(msg, serializer);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_error,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletErrorInternalConstMeta,
-        argValues: [msg],
+        constMeta: kCrateApiWalletDeleteConstMeta,
+        argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletErrorInternalConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_error_internal",
-        argNames: ["msg"],
-      );
+  TaskConstMeta get kCrateApiWalletDeleteConstMeta =>
+      const TaskConstMeta(debugName: "wallet_delete", argNames: ["req"]);
 
   @override
-  Future<WalletError> crateApiWalletErrorNetwork({required String msg}) {
+  Future<WalletDevModeDetailedBalanceResponse>
+  crateApiWalletDevModeGetDetailedBalance({required WalletRequest req}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(msg, serializer);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 20,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_error,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_wallet_dev_mode_detailed_balance_response,
+          decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletErrorNetworkConstMeta,
-        argValues: [msg],
+        constMeta: kCrateApiWalletDevModeGetDetailedBalanceConstMeta,
+        argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletErrorNetworkConstMeta =>
-      const TaskConstMeta(debugName: "wallet_error_network", argNames: ["msg"]);
+  TaskConstMeta get kCrateApiWalletDevModeGetDetailedBalanceConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_dev_mode_get_detailed_balance",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletError> crateApiWalletErrorNotFound({
+  Future<WalletError> crateApiWalletErrorBadRequest({
     required String msg,
     required WalletErrorCode code,
   }) {
@@ -797,7 +1030,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 21,
             port: port_,
           );
         },
@@ -805,198 +1038,221 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_wallet_error,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWalletErrorNotFoundConstMeta,
+        constMeta: kCrateApiWalletErrorBadRequestConstMeta,
         argValues: [msg, code],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletErrorNotFoundConstMeta =>
+  TaskConstMeta get kCrateApiWalletErrorBadRequestConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_error_not_found",
+        debugName: "wallet_error_bad_request",
         argNames: ["msg", "code"],
       );
 
   @override
-  Future<WalletBalanceResponse> crateApiWalletGetBalance({
-    required WalletRequest req,
-  }) {
+  Future<WalletError> crateApiWalletErrorInternal({required String msg}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_request(req, serializer);
+          sse_encode_String(msg, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 22,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_balance_response,
-          decodeErrorData: sse_decode_wallet_error,
+          decodeSuccessData: sse_decode_wallet_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiWalletGetBalanceConstMeta,
-        argValues: [req],
+        constMeta: kCrateApiWalletErrorInternalConstMeta,
+        argValues: [msg],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetBalanceConstMeta =>
-      const TaskConstMeta(debugName: "wallet_get_balance", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletErrorInternalConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_error_internal",
+        argNames: ["msg"],
+      );
 
   @override
-  Future<WalletCurrencyUnitResponse> crateApiWalletGetCurrencyUnit({
-    required WalletRequest req,
-  }) {
+  Future<WalletError> crateApiWalletErrorNetwork({required String msg}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_request(req, serializer);
+          sse_encode_String(msg, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_currency_unit_response,
-          decodeErrorData: sse_decode_wallet_error,
+          decodeSuccessData: sse_decode_wallet_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiWalletGetCurrencyUnitConstMeta,
-        argValues: [req],
+        constMeta: kCrateApiWalletErrorNetworkConstMeta,
+        argValues: [msg],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetCurrencyUnitConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_get_currency_unit",
-        argNames: ["req"],
-      );
+  TaskConstMeta get kCrateApiWalletErrorNetworkConstMeta =>
+      const TaskConstMeta(debugName: "wallet_error_network", argNames: ["msg"]);
 
   @override
-  Future<WalletsIdsResponse> crateApiWalletGetIds() {
+  Future<WalletError> crateApiWalletErrorNotFound({
+    required String msg,
+    required WalletErrorCode code,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(msg, serializer);
+          sse_encode_wallet_error_code(code, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallets_ids_response,
-          decodeErrorData: sse_decode_wallet_error,
+          decodeSuccessData: sse_decode_wallet_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiWalletGetIdsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWalletErrorNotFoundConstMeta,
+        argValues: [msg, code],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetIdsConstMeta =>
-      const TaskConstMeta(debugName: "wallet_get_ids", argNames: []);
+  TaskConstMeta get kCrateApiWalletErrorNotFoundConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_error_not_found",
+        argNames: ["msg", "code"],
+      );
 
   @override
-  Future<WalletMintUrlResponse> crateApiWalletGetMintUrl({
-    required WalletRequest req,
+  Future<WalletExportBalanceTokenResponse> crateApiWalletExportBalanceToken({
+    required WalletExportBalanceTokenRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_request(req, serializer);
+          sse_encode_box_autoadd_wallet_export_balance_token_request(
+            req,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_mint_url_response,
+          decodeSuccessData: sse_decode_wallet_export_balance_token_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletGetMintUrlConstMeta,
+        constMeta: kCrateApiWalletExportBalanceTokenConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetMintUrlConstMeta =>
-      const TaskConstMeta(debugName: "wallet_get_mint_url", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletExportBalanceTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_export_balance_token",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletNameResponse> crateApiWalletGetName({
-    required WalletRequest req,
+  Future<WalletExportProofsEncryptedResponse>
+  crateApiWalletExportProofsEncrypted({
+    required WalletExportProofsEncryptedRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_request(req, serializer);
+          sse_encode_box_autoadd_wallet_export_proofs_encrypted_request(
+            req,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_name_response,
+          decodeSuccessData: sse_decode_wallet_export_proofs_encrypted_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletGetNameConstMeta,
+        constMeta: kCrateApiWalletExportProofsEncryptedConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetNameConstMeta =>
-      const TaskConstMeta(debugName: "wallet_get_name", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletExportProofsEncryptedConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_export_proofs_encrypted",
+        argNames: ["req"],
+      );
 
   @override
-  Future<StatusResponse> crateApiWalletGetStatus() {
+  Future<WalletExportReceiptResponse> crateApiWalletExportReceipt({
+    required WalletTransactionRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_transaction_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_status_response,
+          decodeSuccessData: sse_decode_wallet_export_receipt_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletGetStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWalletExportReceiptConstMeta,
+        argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetStatusConstMeta =>
-      const TaskConstMeta(debugName: "wallet_get_status", argNames: []);
+  TaskConstMeta get kCrateApiWalletExportReceiptConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_export_receipt",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletTransactionIdsResponse> crateApiWalletGetTransactionIds({
+  Future<WalletBalanceResponse> crateApiWalletGetBalance({
     required WalletRequest req,
   }) {
     return handler.executeNormal(
@@ -1007,31 +1263,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_transaction_ids_response,
+          decodeSuccessData: sse_decode_wallet_balance_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletGetTransactionIdsConstMeta,
+        constMeta: kCrateApiWalletGetBalanceConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetTransactionIdsConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_get_transaction_ids",
-        argNames: ["req"],
-      );
+  TaskConstMeta get kCrateApiWalletGetBalanceConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_balance", argNames: ["req"]);
 
   @override
-  Future<WalletTransactionsResponse> crateApiWalletGetTransactions({
-    required WalletRequest req,
-  }) {
+  Future<WalletDevModeDetailedBalanceResponse>
+  crateApiWalletGetCreditBalanceByKeyset({required WalletRequest req}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1040,149 +1292,153 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_transactions_response,
+          decodeSuccessData:
+              sse_decode_wallet_dev_mode_detailed_balance_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletGetTransactionsConstMeta,
+        constMeta: kCrateApiWalletGetCreditBalanceByKeysetConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletGetTransactionsConstMeta =>
+  TaskConstMeta get kCrateApiWalletGetCreditBalanceByKeysetConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_get_transactions",
+        debugName: "wallet_get_credit_balance_by_keyset",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletTransactionResponse> crateApiWalletLoadTransaction({
-    required WalletTransactionRequest req,
+  Future<WalletCurrencyUnitResponse> crateApiWalletGetCurrencyUnit({
+    required WalletRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_transaction_request(req, serializer);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 30,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_transaction_response,
+          decodeSuccessData: sse_decode_wallet_currency_unit_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletLoadTransactionConstMeta,
+        constMeta: kCrateApiWalletGetCurrencyUnitConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletLoadTransactionConstMeta =>
+  TaskConstMeta get kCrateApiWalletGetCurrencyUnitConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_load_transaction",
+        debugName: "wallet_get_currency_unit",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletTransactionIdResponse> crateApiWalletMelt({
-    required WalletPayRequest req,
-  }) {
+  Future<WalletsIdsResponse> crateApiWalletGetIds() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_pay_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 31,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_transaction_id_response,
+          decodeSuccessData: sse_decode_wallets_ids_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletMeltConstMeta,
-        argValues: [req],
+        constMeta: kCrateApiWalletGetIdsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletMeltConstMeta =>
-      const TaskConstMeta(debugName: "wallet_melt", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletGetIdsConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_ids", argNames: []);
 
   @override
-  Future<MigrateRabidResponse> crateApiWalletMigrateRabid() {
+  Future<WalletKeysetFeesResponse> crateApiWalletGetKeysetFees({
+    required WalletRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 32,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_migrate_rabid_response,
+          decodeSuccessData: sse_decode_wallet_keyset_fees_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletMigrateRabidConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWalletGetKeysetFeesConstMeta,
+        argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletMigrateRabidConstMeta =>
-      const TaskConstMeta(debugName: "wallet_migrate_rabid", argNames: []);
+  TaskConstMeta get kCrateApiWalletGetKeysetFeesConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_get_keyset_fees",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletMintSummaryResponse> crateApiWalletMint({
-    required WalletMintRequest req,
+  Future<WalletMintUrlResponse> crateApiWalletGetMintUrl({
+    required WalletRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_mint_request(req, serializer);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 33,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_mint_summary_response,
+          decodeSuccessData: sse_decode_wallet_mint_url_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletMintConstMeta,
+        constMeta: kCrateApiWalletGetMintUrlConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletMintConstMeta =>
-      const TaskConstMeta(debugName: "wallet_mint", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletGetMintUrlConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_mint_url", argNames: ["req"]);
 
   @override
-  Future<MintIsOfflineResponse> crateApiWalletMintIsOffline({
+  Future<WalletNameResponse> crateApiWalletGetName({
     required WalletRequest req,
   }) {
     return handler.executeNormal(
@@ -1193,29 +1449,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 34,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_mint_is_offline_response,
+          decodeSuccessData: sse_decode_wallet_name_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletMintIsOfflineConstMeta,
+        constMeta: kCrateApiWalletGetNameConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletMintIsOfflineConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_mint_is_offline",
-        argNames: ["req"],
-      );
+  TaskConstMeta get kCrateApiWalletGetNameConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_name", argNames: ["req"]);
 
   @override
-  Future<MintIsRabidResponse> crateApiWalletMintIsRabid({
+  Future<WalletNprofileResponse> crateApiWalletGetNprofile({
     required WalletRequest req,
   }) {
     return handler.executeNormal(
@@ -1226,384 +1479,440 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_mint_is_rabid_response,
+          decodeSuccessData: sse_decode_wallet_nprofile_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletMintIsRabidConstMeta,
+        constMeta: kCrateApiWalletGetNprofileConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletMintIsRabidConstMeta =>
-      const TaskConstMeta(debugName: "wallet_mint_is_rabid", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletGetNprofileConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_nprofile", argNames: ["req"]);
 
   @override
-  Future<WalletTransactionIdResponse> crateApiWalletPay({
-    required WalletPayRequest req,
-  }) {
+  Future<WalletPendingTransactionsResponse>
+  crateApiWalletGetPendingTransactions({required WalletRequest req}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_pay_request(req, serializer);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_transaction_id_response,
+          decodeSuccessData: sse_decode_wallet_pending_transactions_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletPayConstMeta,
+        constMeta: kCrateApiWalletGetPendingTransactionsConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletPayConstMeta =>
-      const TaskConstMeta(debugName: "wallet_pay", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletGetPendingTransactionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_get_pending_transactions",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletPaymentByTokenResponse> crateApiWalletPayByToken({
-    required WalletPaymentByTokenRequest req,
-  }) {
+  Future<StatusResponse> crateApiWalletGetStatus() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_payment_by_token_request(
-            req,
-            serializer,
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
           );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_status_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletGetStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletGetStatusConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_status", argNames: []);
+
+  @override
+  Future<TotalBalanceResponse> crateApiWalletGetTotalBalance() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_payment_by_token_response,
+          decodeSuccessData: sse_decode_total_balance_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletPayByTokenConstMeta,
-        argValues: [req],
+        constMeta: kCrateApiWalletGetTotalBalanceConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletPayByTokenConstMeta =>
-      const TaskConstMeta(debugName: "wallet_pay_by_token", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletGetTotalBalanceConstMeta =>
+      const TaskConstMeta(debugName: "wallet_get_total_balance", argNames: []);
 
   @override
-  Future<WalletPreparePaymentResponse> crateApiWalletPrepareMelt({
-    required WalletPrepareMeltRequest req,
+  Future<WalletTransactionIdsResponse> crateApiWalletGetTransactionIds({
+    required WalletRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_prepare_melt_request(req, serializer);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeSuccessData: sse_decode_wallet_transaction_ids_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletPrepareMeltConstMeta,
+        constMeta: kCrateApiWalletGetTransactionIdsConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletPrepareMeltConstMeta =>
-      const TaskConstMeta(debugName: "wallet_prepare_melt", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletGetTransactionIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_get_transaction_ids",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletPreparePaymentResponse> crateApiWalletPreparePayByToken({
-    required WalletPreparePaymentByTokenRequest req,
+  Future<WalletTransactionTokenResponse> crateApiWalletGetTransactionToken({
+    required WalletTransactionRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_prepare_payment_by_token_request(
-            req,
+          sse_encode_box_autoadd_wallet_transaction_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 40,
+            port: port_,
           );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_transaction_token_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletGetTransactionTokenConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletGetTransactionTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_get_transaction_token",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletTransactionsResponse> crateApiWalletGetTransactions({
+    required WalletRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 41,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeSuccessData: sse_decode_wallet_transactions_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletPreparePayByTokenConstMeta,
+        constMeta: kCrateApiWalletGetTransactionsConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletPreparePayByTokenConstMeta =>
+  TaskConstMeta get kCrateApiWalletGetTransactionsConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_prepare_pay_by_token",
+        debugName: "wallet_get_transactions",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletPreparePaymentResponse> crateApiWalletPreparePayment({
-    required WalletPreparePaymentRequest req,
+  Future<WalletImportProofsEncryptedResponse>
+  crateApiWalletImportProofsEncrypted({
+    required WalletImportProofsEncryptedRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_prepare_payment_request(
+          sse_encode_box_autoadd_wallet_import_proofs_encrypted_request(
             req,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 42,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeSuccessData: sse_decode_wallet_import_proofs_encrypted_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletPreparePaymentConstMeta,
+        constMeta: kCrateApiWalletImportProofsEncryptedConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletPreparePaymentConstMeta =>
+  TaskConstMeta get kCrateApiWalletImportProofsEncryptedConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_prepare_payment",
+        debugName: "wallet_import_proofs_encrypted",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletPreparePaymentReqResponse> crateApiWalletPreparePaymentRequest({
-    required WalletPreparePaymentReqRequest req,
+  Future<WalletInspectPaymentRequestResponse>
+  crateApiWalletInspectPaymentRequest({
+    required WalletPreparePaymentRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_prepare_payment_req_request(
+          sse_encode_box_autoadd_wallet_prepare_payment_request(
             req,
             serializer,
           );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 43,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_prepare_payment_req_response,
+          decodeSuccessData: sse_decode_wallet_inspect_payment_request_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletPreparePaymentRequestConstMeta,
+        constMeta: kCrateApiWalletInspectPaymentRequestConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletPreparePaymentRequestConstMeta =>
+  TaskConstMeta get kCrateApiWalletInspectPaymentRequestConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_prepare_payment_request",
+        debugName: "wallet_inspect_payment_request",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletProtestMeltResponse> crateApiWalletProtestMelt({
-    required WalletProtestMeltRequest req,
+  Future<WalletTransactionsSinceResponse> crateApiWalletListTxSince({
+    required WalletTransactionsSinceRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_protest_melt_request(req, serializer);
+          sse_encode_box_autoadd_wallet_transactions_since_request(
+            req,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 44,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_protest_melt_response,
+          decodeSuccessData: sse_decode_wallet_transactions_since_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletProtestMeltConstMeta,
+        constMeta: kCrateApiWalletListTxSinceConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletProtestMeltConstMeta =>
-      const TaskConstMeta(debugName: "wallet_protest_melt", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletListTxSinceConstMeta =>
+      const TaskConstMeta(debugName: "wallet_list_tx_since", argNames: ["req"]);
 
   @override
-  Future<WalletProtestMintResponse> crateApiWalletProtestMint({
-    required WalletProtestMintRequest req,
+  Future<WalletTransactionResponse> crateApiWalletLoadTransaction({
+    required WalletTransactionRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_protest_mint_request(req, serializer);
+          sse_encode_box_autoadd_wallet_transaction_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 45,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_protest_mint_response,
+          decodeSuccessData: sse_decode_wallet_transaction_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletProtestMintConstMeta,
+        constMeta: kCrateApiWalletLoadTransactionConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletProtestMintConstMeta =>
-      const TaskConstMeta(debugName: "wallet_protest_mint", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletLoadTransactionConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_load_transaction",
+        argNames: ["req"],
+      );
 
   @override
-  Future<WalletProtestSwapResponse> crateApiWalletProtestSwap({
-    required WalletProtestSwapRequest req,
+  Future<WalletTransactionIdResponse> crateApiWalletMelt({
+    required WalletPayRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_protest_swap_request(req, serializer);
+          sse_encode_box_autoadd_wallet_pay_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_protest_swap_response,
+          decodeSuccessData: sse_decode_wallet_transaction_id_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletProtestSwapConstMeta,
+        constMeta: kCrateApiWalletMeltConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletProtestSwapConstMeta =>
-      const TaskConstMeta(debugName: "wallet_protest_swap", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletMeltConstMeta =>
+      const TaskConstMeta(debugName: "wallet_melt", argNames: ["req"]);
 
   @override
-  Future<WalletTransactionIdResponse> crateApiWalletReceive({
-    required WalletReceiveRequest req,
-  }) {
+  Future<MigrateRabidResponse> crateApiWalletMigrateRabid() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_receive_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 47,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_transaction_id_response,
+          decodeSuccessData: sse_decode_migrate_rabid_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletReceiveConstMeta,
-        argValues: [req],
+        constMeta: kCrateApiWalletMigrateRabidConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletReceiveConstMeta =>
-      const TaskConstMeta(debugName: "wallet_receive", argNames: ["req"]);
+  TaskConstMeta get kCrateApiWalletMigrateRabidConstMeta =>
+      const TaskConstMeta(debugName: "wallet_migrate_rabid", argNames: []);
 
   @override
-  Future<WalletReclaimTransactionResponse> crateApiWalletReclaimTransaction({
-    required WalletReclaimTransactionRequest req,
+  Future<WalletTransactionIdResponse> crateApiWalletMint({
+    required WalletMintBolt11Request req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_reclaim_transaction_request(
-            req,
-            serializer,
-          );
+          sse_encode_box_autoadd_wallet_mint_bolt_11_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 48,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_reclaim_transaction_response,
+          decodeSuccessData: sse_decode_wallet_transaction_id_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletReclaimTransactionConstMeta,
+        constMeta: kCrateApiWalletMintConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletReclaimTransactionConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_reclaim_transaction",
-        argNames: ["req"],
-      );
+  TaskConstMeta get kCrateApiWalletMintConstMeta =>
+      const TaskConstMeta(debugName: "wallet_mint", argNames: ["req"]);
 
   @override
-  Future<WalletRecoverStaleTransactionResponse>
-  crateApiWalletRecoverPendingStaleProofs({required WalletRequest req}) {
+  Future<WalletMintCapabilitiesResponse> crateApiWalletMintCapabilities({
+    required WalletRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1612,66 +1921,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 49,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_wallet_recover_stale_transaction_response,
+          decodeSuccessData: sse_decode_wallet_mint_capabilities_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletRecoverPendingStaleProofsConstMeta,
+        constMeta: kCrateApiWalletMintCapabilitiesConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletRecoverPendingStaleProofsConstMeta =>
+  TaskConstMeta get kCrateApiWalletMintCapabilitiesConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_recover_pending_stale_proofs",
+        debugName: "wallet_mint_capabilities",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletRefreshTransactionResponse> crateApiWalletRefreshTransaction({
-    required WalletRefreshTransactionRequest req,
+  Future<MintIsOfflineResponse> crateApiWalletMintIsOffline({
+    required WalletRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_wallet_refresh_transaction_request(
-            req,
-            serializer,
-          );
+          sse_encode_box_autoadd_wallet_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 50,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_refresh_transaction_response,
+          decodeSuccessData: sse_decode_mint_is_offline_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletRefreshTransactionConstMeta,
+        constMeta: kCrateApiWalletMintIsOfflineConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletRefreshTransactionConstMeta =>
+  TaskConstMeta get kCrateApiWalletMintIsOfflineConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_refresh_transaction",
+        debugName: "wallet_mint_is_offline",
         argNames: ["req"],
       );
 
   @override
-  Future<WalletRefreshTransactionsResponse> crateApiWalletRefreshTransactions({
+  Future<MintIsRabidResponse> crateApiWalletMintIsRabid({
     required WalletRequest req,
   }) {
     return handler.executeNormal(
@@ -1682,60 +1987,971 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 51,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_wallet_refresh_transactions_response,
+          decodeSuccessData: sse_decode_mint_is_rabid_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletRefreshTransactionsConstMeta,
+        constMeta: kCrateApiWalletMintIsRabidConstMeta,
         argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletRefreshTransactionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_refresh_transactions",
-        argNames: ["req"],
-      );
+  TaskConstMeta get kCrateApiWalletMintIsRabidConstMeta =>
+      const TaskConstMeta(debugName: "wallet_mint_is_rabid", argNames: ["req"]);
 
   @override
-  Future<RestoreWalletResponse> crateApiWalletRestore() {
+  Future<WalletMintSummaryResponse> crateApiWalletMintOnchain({
+    required WalletMintRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_mint_request(req, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 52,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_restore_wallet_response,
+          decodeSuccessData: sse_decode_wallet_mint_summary_response,
           decodeErrorData: sse_decode_wallet_error,
         ),
-        constMeta: kCrateApiWalletRestoreConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWalletMintOnchainConstMeta,
+        argValues: [req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWalletRestoreConstMeta =>
-      const TaskConstMeta(debugName: "wallet_restore", argNames: []);
+  TaskConstMeta get kCrateApiWalletMintOnchainConstMeta =>
+      const TaskConstMeta(debugName: "wallet_mint_onchain", argNames: ["req"]);
 
-  Future<void> Function(int, dynamic)
-  encode_DartFn_Inputs_wallet_maybe_transaction_id_response_Output_unit_AnyhowException(
-    FutureOr<void> Function(WalletMaybeTransactionIdResponse) raw,
-  ) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_wallet_maybe_transaction_id_response(rawArg0);
+  @override
+  Future<WalletPartialSignProofResponse> crateApiWalletPartialSignProof({
+    required WalletPartialSignProofRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_partial_sign_proof_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_partial_sign_proof_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPartialSignProofConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPartialSignProofConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_partial_sign_proof",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletSendResultResponse> crateApiWalletPay({
+    required WalletPayRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_pay_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_send_result_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPayConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPayConstMeta =>
+      const TaskConstMeta(debugName: "wallet_pay", argNames: ["req"]);
+
+  @override
+  Future<WalletPaymentByTokenResponse> crateApiWalletPayByToken({
+    required WalletPaymentByTokenRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_payment_by_token_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_payment_by_token_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPayByTokenConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPayByTokenConstMeta =>
+      const TaskConstMeta(debugName: "wallet_pay_by_token", argNames: ["req"]);
+
+  @override
+  Future<WalletPreparePaymentResponse> crateApiWalletPrepareMelt({
+    required WalletPrepareMeltRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_prepare_melt_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPrepareMeltConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPrepareMeltConstMeta =>
+      const TaskConstMeta(debugName: "wallet_prepare_melt", argNames: ["req"]);
+
+  @override
+  Future<WalletMintQuoteResponse> crateApiWalletPrepareMint({
+    required WalletMintRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_mint_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_mint_quote_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPrepareMintConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPrepareMintConstMeta =>
+      const TaskConstMeta(debugName: "wallet_prepare_mint", argNames: ["req"]);
+
+  @override
+  Future<WalletPreparePaymentResponse> crateApiWalletPreparePayByToken({
+    required WalletPreparePaymentByTokenRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_prepare_payment_by_token_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPreparePayByTokenConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPreparePayByTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_prepare_pay_by_token",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletPreparePaymentResponse> crateApiWalletPreparePayment({
+    required WalletPreparePaymentRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_prepare_payment_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPreparePaymentConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPreparePaymentConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_prepare_payment",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletPreparePaymentReqResponse> crateApiWalletPreparePaymentRequest({
+    required WalletPreparePaymentReqRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_prepare_payment_req_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_prepare_payment_req_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPreparePaymentRequestConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPreparePaymentRequestConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_prepare_payment_request",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletPreparePaymentResponse> crateApiWalletPrepareSendLocked({
+    required WalletPrepareSendLockedRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_prepare_send_locked_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPrepareSendLockedConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPrepareSendLockedConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_prepare_send_locked",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletPreparePaymentResponse> crateApiWalletPrepareSendMax({
+    required WalletPrepareSendMaxRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_prepare_send_max_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_prepare_payment_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletPrepareSendMaxConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletPrepareSendMaxConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_prepare_send_max",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletProtestMeltResponse> crateApiWalletProtestMelt({
+    required WalletProtestMeltRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_protest_melt_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_protest_melt_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletProtestMeltConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletProtestMeltConstMeta =>
+      const TaskConstMeta(debugName: "wallet_protest_melt", argNames: ["req"]);
+
+  @override
+  Future<WalletProtestMintResponse> crateApiWalletProtestMint({
+    required WalletProtestMintRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_protest_mint_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_protest_mint_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletProtestMintConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletProtestMintConstMeta =>
+      const TaskConstMeta(debugName: "wallet_protest_mint", argNames: ["req"]);
+
+  @override
+  Future<WalletProtestSwapResponse> crateApiWalletProtestSwap({
+    required WalletProtestSwapRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_protest_swap_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_protest_swap_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletProtestSwapConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletProtestSwapConstMeta =>
+      const TaskConstMeta(debugName: "wallet_protest_swap", argNames: ["req"]);
+
+  @override
+  Future<WalletReceiveResponse> crateApiWalletReceive({
+    required WalletReceiveRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_receive_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_receive_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletReceiveConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletReceiveConstMeta =>
+      const TaskConstMeta(debugName: "wallet_receive", argNames: ["req"]);
+
+  @override
+  Future<WalletReceiveMultisigTokenResponse>
+  crateApiWalletReceiveMultisigToken({required WalletReceiveRequest req}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_receive_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_receive_multisig_token_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletReceiveMultisigTokenConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletReceiveMultisigTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_receive_multisig_token",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletRecheckResponse> crateApiWalletRecheck({
+    required WalletRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_recheck_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRecheckConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRecheckConstMeta =>
+      const TaskConstMeta(debugName: "wallet_recheck", argNames: ["req"]);
+
+  @override
+  Future<WalletBalanceResponse> crateApiWalletReclaimFunds({
+    required WalletRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_balance_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletReclaimFundsConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletReclaimFundsConstMeta =>
+      const TaskConstMeta(debugName: "wallet_reclaim_funds", argNames: ["req"]);
+
+  @override
+  Future<WalletReclaimTransactionResponse> crateApiWalletReclaimTransaction({
+    required WalletReclaimTransactionRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_reclaim_transaction_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_reclaim_transaction_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletReclaimTransactionConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletReclaimTransactionConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_reclaim_transaction",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletRecoverStaleTransactionResponse>
+  crateApiWalletRecoverPendingStaleProofs({required WalletRequest req}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_wallet_recover_stale_transaction_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRecoverPendingStaleProofsConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRecoverPendingStaleProofsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_recover_pending_stale_proofs",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletRefreshTransactionResponse> crateApiWalletRefreshTransaction({
+    required WalletRefreshTransactionRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_refresh_transaction_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_refresh_transaction_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRefreshTransactionConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRefreshTransactionConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_refresh_transaction",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletRefreshTransactionsResponse> crateApiWalletRefreshTransactions({
+    required WalletRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_refresh_transactions_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRefreshTransactionsConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRefreshTransactionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_refresh_transactions",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletReleaseReservationsResponse> crateApiWalletReleaseReservations({
+    required WalletRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_release_reservations_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletReleaseReservationsConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletReleaseReservationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_release_reservations",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<void> crateApiWalletRename({required WalletRenameRequest req}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_rename_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRenameConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRenameConstMeta =>
+      const TaskConstMeta(debugName: "wallet_rename", argNames: ["req"]);
+
+  @override
+  Future<RestoreWalletResponse> crateApiWalletRestore() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_restore_wallet_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRestoreConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRestoreConstMeta =>
+      const TaskConstMeta(debugName: "wallet_restore", argNames: []);
+
+  @override
+  Future<WalletRestoreKeysetResponse> crateApiWalletRestoreKeyset({
+    required WalletRestoreKeysetRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_restore_keyset_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_restore_keyset_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRestoreKeysetConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRestoreKeysetConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_restore_keyset",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletRetryPendingTransactionResponse>
+  crateApiWalletRetryPendingTransaction({
+    required WalletTransactionRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_transaction_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_wallet_retry_pending_transaction_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletRetryPendingTransactionConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletRetryPendingTransactionConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_retry_pending_transaction",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<void> crateApiWalletSetKeepSentTokens({
+    required WalletKeepSentTokensRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_keep_sent_tokens_request(
+            req,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletSetKeepSentTokensConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletSetKeepSentTokensConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_set_keep_sent_tokens",
+        argNames: ["req"],
+      );
+
+  @override
+  Future<WalletStatsResponse> crateApiWalletStats({
+    required WalletRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_wallet_request(req, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_stats_response,
+          decodeErrorData: sse_decode_wallet_error,
+        ),
+        constMeta: kCrateApiWalletStatsConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWalletStatsConstMeta =>
+      const TaskConstMeta(debugName: "wallet_stats", argNames: ["req"]);
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_wallet_maybe_transaction_id_response_Output_unit_AnyhowException(
+    FutureOr<void> Function(WalletMaybeTransactionIdResponse) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_wallet_maybe_transaction_id_response(rawArg0);
 
       Box<void>? rawOutput;
       Box<AnyhowException>? rawError;
@@ -1848,6 +3064,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  ExportWalletBackupRequest dco_decode_box_autoadd_export_wallet_backup_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_export_wallet_backup_request(raw);
+  }
+
+  @protected
+  ImportWalletBackupRequest dco_decode_box_autoadd_import_wallet_backup_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_wallet_backup_request(raw);
+  }
+
   @protected
   IsValidTokenRequest dco_decode_box_autoadd_is_valid_token_request(
     dynamic raw,
@@ -1868,6 +3100,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  ValidateMnemonicRequest dco_decode_box_autoadd_validate_mnemonic_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_validate_mnemonic_request(raw);
+  }
+
   @protected
   WalletCheckReceivedPaymentRequest
   dco_decode_box_autoadd_wallet_check_received_payment_request(dynamic raw) {
@@ -1875,18 +3115,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_wallet_check_received_payment_request(raw);
   }
 
+  @protected
+  WalletCleanLocalDbRequest
+  dco_decode_box_autoadd_wallet_clean_local_db_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_clean_local_db_request(raw);
+  }
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  dco_decode_box_autoadd_wallet_combine_partial_signatures_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_combine_partial_signatures_request(raw);
+  }
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  dco_decode_box_autoadd_wallet_confirm_nut_18_delivery_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_confirm_nut_18_delivery_request(raw);
+  }
+
+  @protected
+  WalletExportBalanceTokenRequest
+  dco_decode_box_autoadd_wallet_export_balance_token_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_export_balance_token_request(raw);
+  }
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  dco_decode_box_autoadd_wallet_export_proofs_encrypted_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_export_proofs_encrypted_request(raw);
+  }
+
   @protected
   WalletFfiConfig dco_decode_box_autoadd_wallet_ffi_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_wallet_ffi_config(raw);
   }
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  dco_decode_box_autoadd_wallet_import_proofs_encrypted_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_import_proofs_encrypted_request(raw);
+  }
+
+  @protected
+  WalletKeepSentTokensRequest
+  dco_decode_box_autoadd_wallet_keep_sent_tokens_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_keep_sent_tokens_request(raw);
+  }
+
+  @protected
+  WalletMintBolt11Request dco_decode_box_autoadd_wallet_mint_bolt_11_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_mint_bolt_11_request(raw);
+  }
+
   @protected
   WalletMintRequest dco_decode_box_autoadd_wallet_mint_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_wallet_mint_request(raw);
   }
 
+  @protected
+  WalletPartialSignProofRequest
+  dco_decode_box_autoadd_wallet_partial_sign_proof_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_partial_sign_proof_request(raw);
+  }
+
   @protected
   WalletPayRequest dco_decode_box_autoadd_wallet_pay_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1929,6 +3235,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_wallet_prepare_payment_request(raw);
   }
 
+  @protected
+  WalletPrepareSendLockedRequest
+  dco_decode_box_autoadd_wallet_prepare_send_locked_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_prepare_send_locked_request(raw);
+  }
+
+  @protected
+  WalletPrepareSendMaxRequest
+  dco_decode_box_autoadd_wallet_prepare_send_max_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_prepare_send_max_request(raw);
+  }
+
   @protected
   WalletProtestMeltRequest dco_decode_box_autoadd_wallet_protest_melt_request(
     dynamic raw,
@@ -1975,12 +3295,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_wallet_refresh_transaction_request(raw);
   }
 
+  @protected
+  WalletRenameRequest dco_decode_box_autoadd_wallet_rename_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_rename_request(raw);
+  }
+
   @protected
   WalletRequest dco_decode_box_autoadd_wallet_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_wallet_request(raw);
   }
 
+  @protected
+  WalletRestoreKeysetRequest
+  dco_decode_box_autoadd_wallet_restore_keyset_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_restore_keyset_request(raw);
+  }
+
   @protected
   WalletTransactionRequest dco_decode_box_autoadd_wallet_transaction_request(
     dynamic raw,
@@ -1989,10 +3324,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_wallet_transaction_request(raw);
   }
 
+  @protected
+  WalletTransactionsSinceRequest
+  dco_decode_box_autoadd_wallet_transactions_since_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_transactions_since_request(raw);
+  }
+
+  @protected
+  ExportWalletBackupRequest dco_decode_export_wallet_backup_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ExportWalletBackupRequest(
+      walletId: dco_decode_usize(arr[0]),
+      passphrase: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ExportWalletBackupResponse dco_decode_export_wallet_backup_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ExportWalletBackupResponse(
+      backup: dco_decode_list_prim_u_8_strict(arr[0]),
+    );
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    return raw as int;
+  }
+
+  @protected
+  ImportWalletBackupRequest dco_decode_import_wallet_backup_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportWalletBackupRequest(
+      backup: dco_decode_list_prim_u_8_strict(arr[0]),
+      passphrase: dco_decode_String(arr[1]),
+    );
   }
 
   @protected
@@ -2024,12 +3407,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  KeysetFee dco_decode_keyset_fee(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KeysetFee(
+      kid: dco_decode_String(arr[0]),
+      inputFeePpk: dco_decode_u_64(arr[1]),
+    );
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<KeysetFee> dco_decode_list_keyset_fee(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_keyset_fee).toList();
+  }
+
+  @protected
+  List<PendingTransaction> dco_decode_list_pending_transaction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pending_transaction).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2048,6 +3455,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_transaction).toList();
   }
 
+  @protected
+  List<UnitBalance> dco_decode_list_unit_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_unit_balance).toList();
+  }
+
   @protected
   List<WalletDevModeDetailedBalanceEntry>
   dco_decode_list_wallet_dev_mode_detailed_balance_entry(dynamic raw) {
@@ -2161,6 +3574,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PaymentType.values[raw as int];
   }
 
+  @protected
+  PendingTransaction dco_decode_pending_transaction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PendingTransaction(
+      transaction: dco_decode_transaction(arr[0]),
+      pendingProofs: dco_decode_usize(arr[1]),
+      unsent: dco_decode_bool(arr[2]),
+    );
+  }
+
   @protected
   ProtestStatus dco_decode_protest_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2185,6 +3611,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return StatusResponse(appVersion: dco_decode_String(arr[0]));
   }
 
+  @protected
+  TotalBalanceResponse dco_decode_total_balance_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return TotalBalanceResponse(balances: dco_decode_list_unit_balance(arr[0]));
+  }
+
   @protected
   Transaction dco_decode_transaction(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2242,12 +3677,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  UnitBalance dco_decode_unit_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UnitBalance(
+      unit: dco_decode_String(arr[0]),
+      debit: dco_decode_u_64(arr[1]),
+      credit: dco_decode_u_64(arr[2]),
+    );
+  }
+
   @protected
   BigInt dco_decode_usize(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  ValidateMnemonicRequest dco_decode_validate_mnemonic_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ValidateMnemonicRequest(mnemonic: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  ValidateMnemonicResponse dco_decode_validate_mnemonic_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ValidateMnemonicResponse(
+      valid: dco_decode_bool(arr[0]),
+      wordCount: dco_decode_u_32(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   WalletBalanceResponse dco_decode_wallet_balance_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2280,104 +3750,351 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final arr = raw as List<dynamic>;
     if (arr.length != 3)
       throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WalletCheckReceivedPaymentRequest(
+    return WalletCheckReceivedPaymentRequest(
+      walletId: dco_decode_usize(arr[0]),
+      maxWaitSec: dco_decode_u_64(arr[1]),
+      pId: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  WalletCleanLocalDbRequest dco_decode_wallet_clean_local_db_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletCleanLocalDbRequest(
+      walletId: dco_decode_usize(arr[0]),
+      pruneCounters: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  WalletCleanLocalDbResponse dco_decode_wallet_clean_local_db_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletCleanLocalDbResponse(
+      cleanedProofs: dco_decode_u_32(arr[0]),
+      prunedCounters: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  dco_decode_wallet_combine_partial_signatures_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletCombinePartialSignaturesRequest(
+      walletId: dco_decode_usize(arr[0]),
+      y: dco_decode_String(arr[1]),
+      witnesses: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  WalletCombinePartialSignaturesResponse
+  dco_decode_wallet_combine_partial_signatures_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletCombinePartialSignaturesResponse(
+      spendable: dco_decode_bool(arr[0]),
+    );
+  }
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  dco_decode_wallet_confirm_nut_18_delivery_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletConfirmNut18DeliveryRequest(
+      walletId: dco_decode_usize(arr[0]),
+      txId: dco_decode_String(arr[1]),
+      timeoutSecs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  WalletConfirmNut18DeliveryResponse
+  dco_decode_wallet_confirm_nut_18_delivery_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletConfirmNut18DeliveryResponse(
+      delivered: dco_decode_bool(arr[0]),
+    );
+  }
+
+  @protected
+  WalletCurrencyUnitResponse dco_decode_wallet_currency_unit_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletCurrencyUnitResponse(unit: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  WalletDevModeDetailedBalanceEntry
+  dco_decode_wallet_dev_mode_detailed_balance_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletDevModeDetailedBalanceEntry(
+      kid: dco_decode_String(arr[0]),
+      finalExpiry: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  WalletDevModeDetailedBalanceResponse
+  dco_decode_wallet_dev_mode_detailed_balance_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletDevModeDetailedBalanceResponse(
+      entries: dco_decode_list_wallet_dev_mode_detailed_balance_entry(arr[0]),
+    );
+  }
+
+  @protected
+  WalletError dco_decode_wallet_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletError(
+      kind: dco_decode_wallet_error_kind(arr[0]),
+      code: dco_decode_wallet_error_code(arr[1]),
+      msg: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  WalletErrorCode dco_decode_wallet_error_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WalletErrorCode.values[raw as int];
+  }
+
+  @protected
+  WalletErrorKind dco_decode_wallet_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WalletErrorKind.values[raw as int];
+  }
+
+  @protected
+  WalletExportBalanceTokenRequest
+  dco_decode_wallet_export_balance_token_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletExportBalanceTokenRequest(
+      walletId: dco_decode_usize(arr[0]),
+      unit: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WalletExportBalanceTokenResponse
+  dco_decode_wallet_export_balance_token_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletExportBalanceTokenResponse(token: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  dco_decode_wallet_export_proofs_encrypted_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletExportProofsEncryptedRequest(
+      walletId: dco_decode_usize(arr[0]),
+      passphrase: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WalletExportProofsEncryptedResponse
+  dco_decode_wallet_export_proofs_encrypted_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletExportProofsEncryptedResponse(
+      blob: dco_decode_list_prim_u_8_strict(arr[0]),
+    );
+  }
+
+  @protected
+  WalletExportReceiptResponse dco_decode_wallet_export_receipt_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletExportReceiptResponse(receipt: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  WalletFfiConfig dco_decode_wallet_ffi_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return WalletFfiConfig(
+      dbFolderPath: dco_decode_String(arr[0]),
+      logLevel: dco_decode_String(arr[1]),
+      jobIntervalSecs: dco_decode_u_64(arr[2]),
+      jobInitialDelaySecs: dco_decode_u_64(arr[3]),
+      defaultMintUrl: dco_decode_String(arr[4]),
+      bitcoinNetwork: dco_decode_String(arr[5]),
+      mnemonic: dco_decode_String(arr[6]),
+      passphrase: dco_decode_opt_String(arr[7]),
+      nostrRelays: dco_decode_list_String(arr[8]),
+      swapExpiryMinutes: dco_decode_u_32(arr[9]),
+      devMode: dco_decode_bool(arr[10]),
+    );
+  }
+
+  @protected
+  WalletImportProofsEncryptedRequest
+  dco_decode_wallet_import_proofs_encrypted_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletImportProofsEncryptedRequest(
       walletId: dco_decode_usize(arr[0]),
-      maxWaitSec: dco_decode_u_64(arr[1]),
-      pId: dco_decode_String(arr[2]),
+      blob: dco_decode_list_prim_u_8_strict(arr[1]),
+      passphrase: dco_decode_String(arr[2]),
     );
   }
 
   @protected
-  WalletCurrencyUnitResponse dco_decode_wallet_currency_unit_response(
-    dynamic raw,
-  ) {
+  WalletImportProofsEncryptedResponse
+  dco_decode_wallet_import_proofs_encrypted_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 1)
       throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return WalletCurrencyUnitResponse(unit: dco_decode_String(arr[0]));
+    return WalletImportProofsEncryptedResponse(
+      imported: dco_decode_u_32(arr[0]),
+    );
   }
 
   @protected
-  WalletDevModeDetailedBalanceEntry
-  dco_decode_wallet_dev_mode_detailed_balance_entry(dynamic raw) {
+  WalletInspectPaymentRequestResponse
+  dco_decode_wallet_inspect_payment_request_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WalletDevModeDetailedBalanceEntry(
-      kid: dco_decode_String(arr[0]),
-      finalExpiry: dco_decode_opt_box_autoadd_u_64(arr[1]),
-      amount: dco_decode_u_64(arr[2]),
-    );
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletInspectPaymentRequestResponse(info: dco_decode_String(arr[0]));
   }
 
   @protected
-  WalletDevModeDetailedBalanceResponse
-  dco_decode_wallet_dev_mode_detailed_balance_response(dynamic raw) {
+  WalletKeepSentTokensRequest dco_decode_wallet_keep_sent_tokens_request(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return WalletDevModeDetailedBalanceResponse(
-      entries: dco_decode_list_wallet_dev_mode_detailed_balance_entry(arr[0]),
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletKeepSentTokensRequest(
+      walletId: dco_decode_usize(arr[0]),
+      keep: dco_decode_bool(arr[1]),
     );
   }
 
   @protected
-  WalletError dco_decode_wallet_error(dynamic raw) {
+  WalletKeysetFeesResponse dco_decode_wallet_keyset_fees_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WalletError(
-      kind: dco_decode_wallet_error_kind(arr[0]),
-      code: dco_decode_wallet_error_code(arr[1]),
-      msg: dco_decode_String(arr[2]),
-    );
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletKeysetFeesResponse(fees: dco_decode_list_keyset_fee(arr[0]));
   }
 
   @protected
-  WalletErrorCode dco_decode_wallet_error_code(dynamic raw) {
+  WalletMaybeTransactionIdResponse
+  dco_decode_wallet_maybe_transaction_id_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WalletErrorCode.values[raw as int];
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletMaybeTransactionIdResponse(
+      txId: dco_decode_opt_String(arr[0]),
+    );
   }
 
   @protected
-  WalletErrorKind dco_decode_wallet_error_kind(dynamic raw) {
+  WalletMintBolt11Request dco_decode_wallet_mint_bolt_11_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WalletErrorKind.values[raw as int];
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletMintBolt11Request(
+      walletId: dco_decode_usize(arr[0]),
+      quoteId: dco_decode_String(arr[1]),
+    );
   }
 
   @protected
-  WalletFfiConfig dco_decode_wallet_ffi_config(dynamic raw) {
+  WalletMintCapabilitiesResponse dco_decode_wallet_mint_capabilities_response(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return WalletFfiConfig(
-      dbFolderPath: dco_decode_String(arr[0]),
-      logLevel: dco_decode_String(arr[1]),
-      jobIntervalSecs: dco_decode_u_64(arr[2]),
-      jobInitialDelaySecs: dco_decode_u_64(arr[3]),
-      defaultMintUrl: dco_decode_String(arr[4]),
-      bitcoinNetwork: dco_decode_String(arr[5]),
-      mnemonic: dco_decode_String(arr[6]),
-      nostrRelays: dco_decode_list_String(arr[7]),
-      swapExpiryMinutes: dco_decode_u_32(arr[8]),
-      devMode: dco_decode_bool(arr[9]),
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return WalletMintCapabilitiesResponse(
+      mintEnabled: dco_decode_bool(arr[0]),
+      meltEnabled: dco_decode_bool(arr[1]),
+      tokenStateCheck: dco_decode_bool(arr[2]),
+      restore: dco_decode_bool(arr[3]),
+      p2Pk: dco_decode_bool(arr[4]),
+      dleq: dco_decode_bool(arr[5]),
+      htlc: dco_decode_bool(arr[6]),
+      mpp: dco_decode_bool(arr[7]),
+      websocket: dco_decode_bool(arr[8]),
     );
   }
 
   @protected
-  WalletMaybeTransactionIdResponse
-  dco_decode_wallet_maybe_transaction_id_response(dynamic raw) {
+  WalletMintQuoteResponse dco_decode_wallet_mint_quote_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return WalletMaybeTransactionIdResponse(
-      txId: dco_decode_opt_String(arr[0]),
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WalletMintQuoteResponse(
+      quoteId: dco_decode_String(arr[0]),
+      request: dco_decode_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      unit: dco_decode_String(arr[3]),
+      expiry: dco_decode_opt_box_autoadd_u_64(arr[4]),
     );
   }
 
@@ -2427,6 +4144,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletNameResponse(name: dco_decode_String(arr[0]));
   }
 
+  @protected
+  WalletNprofileResponse dco_decode_wallet_nprofile_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletNprofileResponse(nprofile: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  WalletPartialSignProofRequest dco_decode_wallet_partial_sign_proof_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletPartialSignProofRequest(
+      walletId: dco_decode_usize(arr[0]),
+      y: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WalletPartialSignProofResponse dco_decode_wallet_partial_sign_proof_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletPartialSignProofResponse(witness: dco_decode_String(arr[0]));
+  }
+
   @protected
   WalletPayRequest dco_decode_wallet_pay_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2459,11 +4210,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return WalletPaymentByTokenResponse(
       txId: dco_decode_String(arr[0]),
       token: dco_decode_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      fees: dco_decode_u_64(arr[3]),
+      unit: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  WalletPendingTransactionsResponse
+  dco_decode_wallet_pending_transactions_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletPendingTransactionsResponse(
+      txs: dco_decode_list_pending_transaction(arr[0]),
     );
   }
 
@@ -2550,6 +4316,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WalletPrepareSendLockedRequest dco_decode_wallet_prepare_send_locked_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return WalletPrepareSendLockedRequest(
+      walletId: dco_decode_usize(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      unit: dco_decode_String(arr[2]),
+      pubkey: dco_decode_String(arr[3]),
+      locktime: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      refundKey: dco_decode_opt_String(arr[5]),
+      description: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  WalletPrepareSendMaxRequest dco_decode_wallet_prepare_send_max_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletPrepareSendMaxRequest(
+      walletId: dco_decode_usize(arr[0]),
+      unit: dco_decode_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   WalletProtestMeltRequest dco_decode_wallet_protest_melt_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2628,6 +4428,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WalletReceiveMultisigTokenResponse
+  dco_decode_wallet_receive_multisig_token_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletReceiveMultisigTokenResponse(
+      txId: dco_decode_String(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      ys: dco_decode_list_String(arr[2]),
+    );
+  }
+
   @protected
   WalletReceiveRequest dco_decode_wallet_receive_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2640,6 +4454,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WalletReceiveResponse dco_decode_wallet_receive_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WalletReceiveResponse(
+      txId: dco_decode_String(arr[0]),
+      received: dco_decode_u_64(arr[1]),
+      stored: dco_decode_u_64(arr[2]),
+      fee: dco_decode_u_64(arr[3]),
+      unit: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  WalletRecheckResponse dco_decode_wallet_recheck_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletRecheckResponse(
+      deleted: dco_decode_u_32(arr[0]),
+      restored: dco_decode_u_32(arr[1]),
+      pending: dco_decode_u_32(arr[2]),
+    );
+  }
+
   @protected
   WalletReclaimTransactionRequest dco_decode_wallet_reclaim_transaction_request(
     dynamic raw,
@@ -2710,6 +4552,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletRefreshTransactionsResponse(updated: dco_decode_usize(arr[0]));
   }
 
+  @protected
+  WalletReleaseReservationsResponse
+  dco_decode_wallet_release_reservations_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletReleaseReservationsResponse(released: dco_decode_u_32(arr[0]));
+  }
+
+  @protected
+  WalletRenameRequest dco_decode_wallet_rename_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletRenameRequest(
+      walletId: dco_decode_usize(arr[0]),
+      name: dco_decode_String(arr[1]),
+    );
+  }
+
   @protected
   WalletRequest dco_decode_wallet_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2719,6 +4583,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletRequest(walletId: dco_decode_usize(arr[0]));
   }
 
+  @protected
+  WalletRestoreKeysetRequest dco_decode_wallet_restore_keyset_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletRestoreKeysetRequest(
+      walletId: dco_decode_usize(arr[0]),
+      keysetId: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WalletRestoreKeysetResponse dco_decode_wallet_restore_keyset_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletRestoreKeysetResponse(restored: dco_decode_u_32(arr[0]));
+  }
+
+  @protected
+  WalletRetryPendingTransactionResponse
+  dco_decode_wallet_retry_pending_transaction_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletRetryPendingTransactionResponse(
+      retried: dco_decode_bool(arr[0]),
+    );
+  }
+
+  @protected
+  WalletSendResultResponse dco_decode_wallet_send_result_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WalletSendResultResponse(
+      txId: dco_decode_String(arr[0]),
+      token: dco_decode_opt_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      fees: dco_decode_u_64(arr[3]),
+      unit: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  WalletStatsResponse dco_decode_wallet_stats_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return WalletStatsResponse(
+      unspentProofs: dco_decode_u_32(arr[0]),
+      pendingProofs: dco_decode_u_32(arr[1]),
+      reservedProofs: dco_decode_u_32(arr[2]),
+      totalProofs: dco_decode_u_32(arr[3]),
+      keysets: dco_decode_u_32(arr[4]),
+      randomProofs: dco_decode_u_32(arr[5]),
+      transactions: dco_decode_u_32(arr[6]),
+      oldestTx: dco_decode_opt_box_autoadd_u_64(arr[7]),
+    );
+  }
+
   @protected
   WalletTransactionIdResponse dco_decode_wallet_transaction_id_response(
     dynamic raw,
@@ -2754,27 +4688,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  WalletTransactionResponse dco_decode_wallet_transaction_response(
+  WalletTransactionResponse dco_decode_wallet_transaction_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletTransactionResponse(
+      transaction: dco_decode_transaction(arr[0]),
+    );
+  }
+
+  @protected
+  WalletTransactionTokenResponse dco_decode_wallet_transaction_token_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletTransactionTokenResponse(token: dco_decode_opt_String(arr[0]));
+  }
+
+  @protected
+  WalletTransactionsResponse dco_decode_wallet_transactions_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WalletTransactionsResponse(txs: dco_decode_list_transaction(arr[0]));
+  }
+
+  @protected
+  WalletTransactionsSinceRequest dco_decode_wallet_transactions_since_request(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return WalletTransactionResponse(
-      transaction: dco_decode_transaction(arr[0]),
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WalletTransactionsSinceRequest(
+      walletId: dco_decode_usize(arr[0]),
+      seq: dco_decode_u_64(arr[1]),
     );
   }
 
   @protected
-  WalletTransactionsResponse dco_decode_wallet_transactions_response(
+  WalletTransactionsSinceResponse dco_decode_wallet_transactions_since_response(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return WalletTransactionsResponse(txs: dco_decode_list_transaction(arr[0]));
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WalletTransactionsSinceResponse(
+      txs: dco_decode_list_transaction(arr[0]),
+      deleted: dco_decode_list_String(arr[1]),
+      seq: dco_decode_u_64(arr[2]),
+    );
   }
 
   @protected
@@ -2858,6 +4832,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  ExportWalletBackupRequest sse_decode_box_autoadd_export_wallet_backup_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_wallet_backup_request(deserializer));
+  }
+
+  @protected
+  ImportWalletBackupRequest sse_decode_box_autoadd_import_wallet_backup_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_wallet_backup_request(deserializer));
+  }
+
   @protected
   IsValidTokenRequest sse_decode_box_autoadd_is_valid_token_request(
     SseDeserializer deserializer,
@@ -2880,6 +4870,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  ValidateMnemonicRequest sse_decode_box_autoadd_validate_mnemonic_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_validate_mnemonic_request(deserializer));
+  }
+
   @protected
   WalletCheckReceivedPaymentRequest
   sse_decode_box_autoadd_wallet_check_received_payment_request(
@@ -2889,6 +4887,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_check_received_payment_request(deserializer));
   }
 
+  @protected
+  WalletCleanLocalDbRequest
+  sse_decode_box_autoadd_wallet_clean_local_db_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_clean_local_db_request(deserializer));
+  }
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  sse_decode_box_autoadd_wallet_combine_partial_signatures_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_combine_partial_signatures_request(deserializer));
+  }
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  sse_decode_box_autoadd_wallet_confirm_nut_18_delivery_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_confirm_nut_18_delivery_request(deserializer));
+  }
+
+  @protected
+  WalletExportBalanceTokenRequest
+  sse_decode_box_autoadd_wallet_export_balance_token_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_export_balance_token_request(deserializer));
+  }
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  sse_decode_box_autoadd_wallet_export_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_export_proofs_encrypted_request(deserializer));
+  }
+
   @protected
   WalletFfiConfig sse_decode_box_autoadd_wallet_ffi_config(
     SseDeserializer deserializer,
@@ -2897,6 +4940,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_ffi_config(deserializer));
   }
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  sse_decode_box_autoadd_wallet_import_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_import_proofs_encrypted_request(deserializer));
+  }
+
+  @protected
+  WalletKeepSentTokensRequest
+  sse_decode_box_autoadd_wallet_keep_sent_tokens_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_keep_sent_tokens_request(deserializer));
+  }
+
+  @protected
+  WalletMintBolt11Request sse_decode_box_autoadd_wallet_mint_bolt_11_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_mint_bolt_11_request(deserializer));
+  }
+
   @protected
   WalletMintRequest sse_decode_box_autoadd_wallet_mint_request(
     SseDeserializer deserializer,
@@ -2905,6 +4974,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_mint_request(deserializer));
   }
 
+  @protected
+  WalletPartialSignProofRequest
+  sse_decode_box_autoadd_wallet_partial_sign_proof_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_partial_sign_proof_request(deserializer));
+  }
+
   @protected
   WalletPayRequest sse_decode_box_autoadd_wallet_pay_request(
     SseDeserializer deserializer,
@@ -2957,6 +5035,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_prepare_payment_request(deserializer));
   }
 
+  @protected
+  WalletPrepareSendLockedRequest
+  sse_decode_box_autoadd_wallet_prepare_send_locked_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_prepare_send_locked_request(deserializer));
+  }
+
+  @protected
+  WalletPrepareSendMaxRequest
+  sse_decode_box_autoadd_wallet_prepare_send_max_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_prepare_send_max_request(deserializer));
+  }
+
   @protected
   WalletProtestMeltRequest sse_decode_box_autoadd_wallet_protest_melt_request(
     SseDeserializer deserializer,
@@ -3007,6 +5103,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_refresh_transaction_request(deserializer));
   }
 
+  @protected
+  WalletRenameRequest sse_decode_box_autoadd_wallet_rename_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_rename_request(deserializer));
+  }
+
   @protected
   WalletRequest sse_decode_box_autoadd_wallet_request(
     SseDeserializer deserializer,
@@ -3015,6 +5119,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_request(deserializer));
   }
 
+  @protected
+  WalletRestoreKeysetRequest
+  sse_decode_box_autoadd_wallet_restore_keyset_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_restore_keyset_request(deserializer));
+  }
+
   @protected
   WalletTransactionRequest sse_decode_box_autoadd_wallet_transaction_request(
     SseDeserializer deserializer,
@@ -3023,12 +5136,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_wallet_transaction_request(deserializer));
   }
 
+  @protected
+  WalletTransactionsSinceRequest
+  sse_decode_box_autoadd_wallet_transactions_since_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_transactions_since_request(deserializer));
+  }
+
+  @protected
+  ExportWalletBackupRequest sse_decode_export_wallet_backup_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_passphrase = sse_decode_String(deserializer);
+    return ExportWalletBackupRequest(
+      walletId: var_walletId,
+      passphrase: var_passphrase,
+    );
+  }
+
+  @protected
+  ExportWalletBackupResponse sse_decode_export_wallet_backup_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backup = sse_decode_list_prim_u_8_strict(deserializer);
+    return ExportWalletBackupResponse(backup: var_backup);
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getInt32();
   }
 
+  @protected
+  ImportWalletBackupRequest sse_decode_import_wallet_backup_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backup = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_passphrase = sse_decode_String(deserializer);
+    return ImportWalletBackupRequest(
+      backup: var_backup,
+      passphrase: var_passphrase,
+    );
+  }
+
   @protected
   IsValidTokenRequest sse_decode_is_valid_token_request(
     SseDeserializer deserializer,
@@ -3061,6 +5218,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  KeysetFee sse_decode_keyset_fee(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kid = sse_decode_String(deserializer);
+    var var_inputFeePpk = sse_decode_u_64(deserializer);
+    return KeysetFee(kid: var_kid, inputFeePpk: var_inputFeePpk);
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3073,6 +5238,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<KeysetFee> sse_decode_list_keyset_fee(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KeysetFee>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_keyset_fee(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PendingTransaction> sse_decode_list_pending_transaction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PendingTransaction>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pending_transaction(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3099,6 +5290,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<UnitBalance> sse_decode_list_unit_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UnitBalance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_unit_balance(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<WalletDevModeDetailedBalanceEntry>
   sse_decode_list_wallet_dev_mode_detailed_balance_entry(
@@ -3221,6 +5424,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PaymentType.values[inner];
   }
 
+  @protected
+  PendingTransaction sse_decode_pending_transaction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_transaction = sse_decode_transaction(deserializer);
+    var var_pendingProofs = sse_decode_usize(deserializer);
+    var var_unsent = sse_decode_bool(deserializer);
+    return PendingTransaction(
+      transaction: var_transaction,
+      pendingProofs: var_pendingProofs,
+      unsent: var_unsent,
+    );
+  }
+
   @protected
   ProtestStatus sse_decode_protest_status(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3244,6 +5462,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return StatusResponse(appVersion: var_appVersion);
   }
 
+  @protected
+  TotalBalanceResponse sse_decode_total_balance_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_balances = sse_decode_list_unit_balance(deserializer);
+    return TotalBalanceResponse(balances: var_balances);
+  }
+
   @protected
   Transaction sse_decode_transaction(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3314,12 +5541,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  UnitBalance sse_decode_unit_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_unit = sse_decode_String(deserializer);
+    var var_debit = sse_decode_u_64(deserializer);
+    var var_credit = sse_decode_u_64(deserializer);
+    return UnitBalance(unit: var_unit, debit: var_debit, credit: var_credit);
+  }
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  ValidateMnemonicRequest sse_decode_validate_mnemonic_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mnemonic = sse_decode_String(deserializer);
+    return ValidateMnemonicRequest(mnemonic: var_mnemonic);
+  }
+
+  @protected
+  ValidateMnemonicResponse sse_decode_validate_mnemonic_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_valid = sse_decode_bool(deserializer);
+    var var_wordCount = sse_decode_u_32(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ValidateMnemonicResponse(
+      valid: var_valid,
+      wordCount: var_wordCount,
+      error: var_error,
+    );
+  }
+
   @protected
   WalletBalanceResponse sse_decode_wallet_balance_response(
     SseDeserializer deserializer,
@@ -3359,6 +5619,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WalletCleanLocalDbRequest sse_decode_wallet_clean_local_db_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_pruneCounters = sse_decode_bool(deserializer);
+    return WalletCleanLocalDbRequest(
+      walletId: var_walletId,
+      pruneCounters: var_pruneCounters,
+    );
+  }
+
+  @protected
+  WalletCleanLocalDbResponse sse_decode_wallet_clean_local_db_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cleanedProofs = sse_decode_u_32(deserializer);
+    var var_prunedCounters = sse_decode_u_32(deserializer);
+    return WalletCleanLocalDbResponse(
+      cleanedProofs: var_cleanedProofs,
+      prunedCounters: var_prunedCounters,
+    );
+  }
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  sse_decode_wallet_combine_partial_signatures_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_y = sse_decode_String(deserializer);
+    var var_witnesses = sse_decode_list_String(deserializer);
+    return WalletCombinePartialSignaturesRequest(
+      walletId: var_walletId,
+      y: var_y,
+      witnesses: var_witnesses,
+    );
+  }
+
+  @protected
+  WalletCombinePartialSignaturesResponse
+  sse_decode_wallet_combine_partial_signatures_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_spendable = sse_decode_bool(deserializer);
+    return WalletCombinePartialSignaturesResponse(spendable: var_spendable);
+  }
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  sse_decode_wallet_confirm_nut_18_delivery_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_txId = sse_decode_String(deserializer);
+    var var_timeoutSecs = sse_decode_u_64(deserializer);
+    return WalletConfirmNut18DeliveryRequest(
+      walletId: var_walletId,
+      txId: var_txId,
+      timeoutSecs: var_timeoutSecs,
+    );
+  }
+
+  @protected
+  WalletConfirmNut18DeliveryResponse
+  sse_decode_wallet_confirm_nut_18_delivery_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_delivered = sse_decode_bool(deserializer);
+    return WalletConfirmNut18DeliveryResponse(delivered: var_delivered);
+  }
+
   @protected
   WalletCurrencyUnitResponse sse_decode_wallet_currency_unit_response(
     SseDeserializer deserializer,
@@ -3419,6 +5757,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletErrorKind.values[inner];
   }
 
+  @protected
+  WalletExportBalanceTokenRequest
+  sse_decode_wallet_export_balance_token_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    return WalletExportBalanceTokenRequest(
+      walletId: var_walletId,
+      unit: var_unit,
+    );
+  }
+
+  @protected
+  WalletExportBalanceTokenResponse
+  sse_decode_wallet_export_balance_token_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_token = sse_decode_String(deserializer);
+    return WalletExportBalanceTokenResponse(token: var_token);
+  }
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  sse_decode_wallet_export_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_passphrase = sse_decode_String(deserializer);
+    return WalletExportProofsEncryptedRequest(
+      walletId: var_walletId,
+      passphrase: var_passphrase,
+    );
+  }
+
+  @protected
+  WalletExportProofsEncryptedResponse
+  sse_decode_wallet_export_proofs_encrypted_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blob = sse_decode_list_prim_u_8_strict(deserializer);
+    return WalletExportProofsEncryptedResponse(blob: var_blob);
+  }
+
+  @protected
+  WalletExportReceiptResponse sse_decode_wallet_export_receipt_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_receipt = sse_decode_String(deserializer);
+    return WalletExportReceiptResponse(receipt: var_receipt);
+  }
+
   @protected
   WalletFfiConfig sse_decode_wallet_ffi_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3429,6 +5822,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_defaultMintUrl = sse_decode_String(deserializer);
     var var_bitcoinNetwork = sse_decode_String(deserializer);
     var var_mnemonic = sse_decode_String(deserializer);
+    var var_passphrase = sse_decode_opt_String(deserializer);
     var var_nostrRelays = sse_decode_list_String(deserializer);
     var var_swapExpiryMinutes = sse_decode_u_32(deserializer);
     var var_devMode = sse_decode_bool(deserializer);
@@ -3440,12 +5834,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       defaultMintUrl: var_defaultMintUrl,
       bitcoinNetwork: var_bitcoinNetwork,
       mnemonic: var_mnemonic,
+      passphrase: var_passphrase,
       nostrRelays: var_nostrRelays,
       swapExpiryMinutes: var_swapExpiryMinutes,
       devMode: var_devMode,
     );
   }
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  sse_decode_wallet_import_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_blob = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_passphrase = sse_decode_String(deserializer);
+    return WalletImportProofsEncryptedRequest(
+      walletId: var_walletId,
+      blob: var_blob,
+      passphrase: var_passphrase,
+    );
+  }
+
+  @protected
+  WalletImportProofsEncryptedResponse
+  sse_decode_wallet_import_proofs_encrypted_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imported = sse_decode_u_32(deserializer);
+    return WalletImportProofsEncryptedResponse(imported: var_imported);
+  }
+
+  @protected
+  WalletInspectPaymentRequestResponse
+  sse_decode_wallet_inspect_payment_request_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_info = sse_decode_String(deserializer);
+    return WalletInspectPaymentRequestResponse(info: var_info);
+  }
+
+  @protected
+  WalletKeepSentTokensRequest sse_decode_wallet_keep_sent_tokens_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_keep = sse_decode_bool(deserializer);
+    return WalletKeepSentTokensRequest(walletId: var_walletId, keep: var_keep);
+  }
+
+  @protected
+  WalletKeysetFeesResponse sse_decode_wallet_keyset_fees_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fees = sse_decode_list_keyset_fee(deserializer);
+    return WalletKeysetFeesResponse(fees: var_fees);
+  }
+
   @protected
   WalletMaybeTransactionIdResponse
   sse_decode_wallet_maybe_transaction_id_response(
@@ -3456,6 +5906,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletMaybeTransactionIdResponse(txId: var_txId);
   }
 
+  @protected
+  WalletMintBolt11Request sse_decode_wallet_mint_bolt_11_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_quoteId = sse_decode_String(deserializer);
+    return WalletMintBolt11Request(
+      walletId: var_walletId,
+      quoteId: var_quoteId,
+    );
+  }
+
+  @protected
+  WalletMintCapabilitiesResponse sse_decode_wallet_mint_capabilities_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mintEnabled = sse_decode_bool(deserializer);
+    var var_meltEnabled = sse_decode_bool(deserializer);
+    var var_tokenStateCheck = sse_decode_bool(deserializer);
+    var var_restore = sse_decode_bool(deserializer);
+    var var_p2Pk = sse_decode_bool(deserializer);
+    var var_dleq = sse_decode_bool(deserializer);
+    var var_htlc = sse_decode_bool(deserializer);
+    var var_mpp = sse_decode_bool(deserializer);
+    var var_websocket = sse_decode_bool(deserializer);
+    return WalletMintCapabilitiesResponse(
+      mintEnabled: var_mintEnabled,
+      meltEnabled: var_meltEnabled,
+      tokenStateCheck: var_tokenStateCheck,
+      restore: var_restore,
+      p2Pk: var_p2Pk,
+      dleq: var_dleq,
+      htlc: var_htlc,
+      mpp: var_mpp,
+      websocket: var_websocket,
+    );
+  }
+
+  @protected
+  WalletMintQuoteResponse sse_decode_wallet_mint_quote_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_quoteId = sse_decode_String(deserializer);
+    var var_request = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_expiry = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return WalletMintQuoteResponse(
+      quoteId: var_quoteId,
+      request: var_request,
+      amount: var_amount,
+      unit: var_unit,
+      expiry: var_expiry,
+    );
+  }
+
   @protected
   WalletMintRequest sse_decode_wallet_mint_request(
     SseDeserializer deserializer,
@@ -3501,6 +6010,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletNameResponse(name: var_name);
   }
 
+  @protected
+  WalletNprofileResponse sse_decode_wallet_nprofile_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nprofile = sse_decode_String(deserializer);
+    return WalletNprofileResponse(nprofile: var_nprofile);
+  }
+
+  @protected
+  WalletPartialSignProofRequest sse_decode_wallet_partial_sign_proof_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_y = sse_decode_String(deserializer);
+    return WalletPartialSignProofRequest(walletId: var_walletId, y: var_y);
+  }
+
+  @protected
+  WalletPartialSignProofResponse sse_decode_wallet_partial_sign_proof_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_witness = sse_decode_String(deserializer);
+    return WalletPartialSignProofResponse(witness: var_witness);
+  }
+
   @protected
   WalletPayRequest sse_decode_wallet_pay_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3526,7 +6063,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_txId = sse_decode_String(deserializer);
     var var_token = sse_decode_String(deserializer);
-    return WalletPaymentByTokenResponse(txId: var_txId, token: var_token);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fees = sse_decode_u_64(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    return WalletPaymentByTokenResponse(
+      txId: var_txId,
+      token: var_token,
+      amount: var_amount,
+      fees: var_fees,
+      unit: var_unit,
+    );
+  }
+
+  @protected
+  WalletPendingTransactionsResponse
+  sse_decode_wallet_pending_transactions_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txs = sse_decode_list_pending_transaction(deserializer);
+    return WalletPendingTransactionsResponse(txs: var_txs);
   }
 
   @protected
@@ -3609,6 +6165,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletPreparePaymentResponse(paymentSummary: var_paymentSummary);
   }
 
+  @protected
+  WalletPrepareSendLockedRequest sse_decode_wallet_prepare_send_locked_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_pubkey = sse_decode_String(deserializer);
+    var var_locktime = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_refundKey = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    return WalletPrepareSendLockedRequest(
+      walletId: var_walletId,
+      amount: var_amount,
+      unit: var_unit,
+      pubkey: var_pubkey,
+      locktime: var_locktime,
+      refundKey: var_refundKey,
+      description: var_description,
+    );
+  }
+
+  @protected
+  WalletPrepareSendMaxRequest sse_decode_wallet_prepare_send_max_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    return WalletPrepareSendMaxRequest(
+      walletId: var_walletId,
+      unit: var_unit,
+      description: var_description,
+    );
+  }
+
   @protected
   WalletProtestMeltRequest sse_decode_wallet_protest_melt_request(
     SseDeserializer deserializer,
@@ -3678,6 +6272,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletProtestSwapResponse(status: var_status, amount: var_amount);
   }
 
+  @protected
+  WalletReceiveMultisigTokenResponse
+  sse_decode_wallet_receive_multisig_token_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txId = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_ys = sse_decode_list_String(deserializer);
+    return WalletReceiveMultisigTokenResponse(
+      txId: var_txId,
+      amount: var_amount,
+      ys: var_ys,
+    );
+  }
+
   @protected
   WalletReceiveRequest sse_decode_wallet_receive_request(
     SseDeserializer deserializer,
@@ -3688,6 +6298,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletReceiveRequest(walletId: var_walletId, token: var_token);
   }
 
+  @protected
+  WalletReceiveResponse sse_decode_wallet_receive_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txId = sse_decode_String(deserializer);
+    var var_received = sse_decode_u_64(deserializer);
+    var var_stored = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_u_64(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    return WalletReceiveResponse(
+      txId: var_txId,
+      received: var_received,
+      stored: var_stored,
+      fee: var_fee,
+      unit: var_unit,
+    );
+  }
+
+  @protected
+  WalletRecheckResponse sse_decode_wallet_recheck_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deleted = sse_decode_u_32(deserializer);
+    var var_restored = sse_decode_u_32(deserializer);
+    var var_pending = sse_decode_u_32(deserializer);
+    return WalletRecheckResponse(
+      deleted: var_deleted,
+      restored: var_restored,
+      pending: var_pending,
+    );
+  }
+
   @protected
   WalletReclaimTransactionRequest sse_decode_wallet_reclaim_transaction_request(
     SseDeserializer deserializer,
@@ -3750,6 +6394,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletRefreshTransactionsResponse(updated: var_updated);
   }
 
+  @protected
+  WalletReleaseReservationsResponse
+  sse_decode_wallet_release_reservations_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_released = sse_decode_u_32(deserializer);
+    return WalletReleaseReservationsResponse(released: var_released);
+  }
+
+  @protected
+  WalletRenameRequest sse_decode_wallet_rename_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    return WalletRenameRequest(walletId: var_walletId, name: var_name);
+  }
+
   @protected
   WalletRequest sse_decode_wallet_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3757,6 +6421,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletRequest(walletId: var_walletId);
   }
 
+  @protected
+  WalletRestoreKeysetRequest sse_decode_wallet_restore_keyset_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_keysetId = sse_decode_String(deserializer);
+    return WalletRestoreKeysetRequest(
+      walletId: var_walletId,
+      keysetId: var_keysetId,
+    );
+  }
+
+  @protected
+  WalletRestoreKeysetResponse sse_decode_wallet_restore_keyset_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_restored = sse_decode_u_32(deserializer);
+    return WalletRestoreKeysetResponse(restored: var_restored);
+  }
+
+  @protected
+  WalletRetryPendingTransactionResponse
+  sse_decode_wallet_retry_pending_transaction_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_retried = sse_decode_bool(deserializer);
+    return WalletRetryPendingTransactionResponse(retried: var_retried);
+  }
+
+  @protected
+  WalletSendResultResponse sse_decode_wallet_send_result_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txId = sse_decode_String(deserializer);
+    var var_token = sse_decode_opt_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fees = sse_decode_u_64(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    return WalletSendResultResponse(
+      txId: var_txId,
+      token: var_token,
+      amount: var_amount,
+      fees: var_fees,
+      unit: var_unit,
+    );
+  }
+
+  @protected
+  WalletStatsResponse sse_decode_wallet_stats_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_unspentProofs = sse_decode_u_32(deserializer);
+    var var_pendingProofs = sse_decode_u_32(deserializer);
+    var var_reservedProofs = sse_decode_u_32(deserializer);
+    var var_totalProofs = sse_decode_u_32(deserializer);
+    var var_keysets = sse_decode_u_32(deserializer);
+    var var_randomProofs = sse_decode_u_32(deserializer);
+    var var_transactions = sse_decode_u_32(deserializer);
+    var var_oldestTx = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return WalletStatsResponse(
+      unspentProofs: var_unspentProofs,
+      pendingProofs: var_pendingProofs,
+      reservedProofs: var_reservedProofs,
+      totalProofs: var_totalProofs,
+      keysets: var_keysets,
+      randomProofs: var_randomProofs,
+      transactions: var_transactions,
+      oldestTx: var_oldestTx,
+    );
+  }
+
   @protected
   WalletTransactionIdResponse sse_decode_wallet_transaction_id_response(
     SseDeserializer deserializer,
@@ -3794,6 +6534,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletTransactionResponse(transaction: var_transaction);
   }
 
+  @protected
+  WalletTransactionTokenResponse sse_decode_wallet_transaction_token_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_token = sse_decode_opt_String(deserializer);
+    return WalletTransactionTokenResponse(token: var_token);
+  }
+
   @protected
   WalletTransactionsResponse sse_decode_wallet_transactions_response(
     SseDeserializer deserializer,
@@ -3803,6 +6552,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return WalletTransactionsResponse(txs: var_txs);
   }
 
+  @protected
+  WalletTransactionsSinceRequest sse_decode_wallet_transactions_since_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_walletId = sse_decode_usize(deserializer);
+    var var_seq = sse_decode_u_64(deserializer);
+    return WalletTransactionsSinceRequest(walletId: var_walletId, seq: var_seq);
+  }
+
+  @protected
+  WalletTransactionsSinceResponse sse_decode_wallet_transactions_since_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txs = sse_decode_list_transaction(deserializer);
+    var var_deleted = sse_decode_list_String(deserializer);
+    var var_seq = sse_decode_u_64(deserializer);
+    return WalletTransactionsSinceResponse(
+      txs: var_txs,
+      deleted: var_deleted,
+      seq: var_seq,
+    );
+  }
+
   @protected
   WalletsIdsResponse sse_decode_wallets_ids_response(
     SseDeserializer deserializer,
@@ -3911,6 +6685,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_export_wallet_backup_request(
+    ExportWalletBackupRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_export_wallet_backup_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_import_wallet_backup_request(
+    ImportWalletBackupRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_wallet_backup_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_is_valid_token_request(
     IsValidTokenRequest self,
@@ -3935,6 +6727,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_validate_mnemonic_request(
+    ValidateMnemonicRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_validate_mnemonic_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_check_received_payment_request(
     WalletCheckReceivedPaymentRequest self,
@@ -3944,6 +6745,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_check_received_payment_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_clean_local_db_request(
+    WalletCleanLocalDbRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_clean_local_db_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_combine_partial_signatures_request(
+    WalletCombinePartialSignaturesRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_combine_partial_signatures_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_confirm_nut_18_delivery_request(
+    WalletConfirmNut18DeliveryRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_confirm_nut_18_delivery_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_export_balance_token_request(
+    WalletExportBalanceTokenRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_export_balance_token_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_export_proofs_encrypted_request(
+    WalletExportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_export_proofs_encrypted_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_ffi_config(
     WalletFfiConfig self,
@@ -3953,6 +6799,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_ffi_config(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_import_proofs_encrypted_request(
+    WalletImportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_import_proofs_encrypted_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_keep_sent_tokens_request(
+    WalletKeepSentTokensRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_keep_sent_tokens_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_mint_bolt_11_request(
+    WalletMintBolt11Request self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_mint_bolt_11_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_mint_request(
     WalletMintRequest self,
@@ -3962,6 +6835,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_mint_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_partial_sign_proof_request(
+    WalletPartialSignProofRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_partial_sign_proof_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_pay_request(
     WalletPayRequest self,
@@ -4016,6 +6898,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_prepare_payment_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_prepare_send_locked_request(
+    WalletPrepareSendLockedRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_prepare_send_locked_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wallet_prepare_send_max_request(
+    WalletPrepareSendMaxRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_prepare_send_max_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_protest_melt_request(
     WalletProtestMeltRequest self,
@@ -4070,6 +6970,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_refresh_transaction_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_rename_request(
+    WalletRenameRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_rename_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_request(
     WalletRequest self,
@@ -4079,6 +6988,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_restore_keyset_request(
+    WalletRestoreKeysetRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_restore_keyset_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_wallet_transaction_request(
     WalletTransactionRequest self,
@@ -4088,12 +7006,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_wallet_transaction_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wallet_transactions_since_request(
+    WalletTransactionsSinceRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_transactions_since_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_export_wallet_backup_request(
+    ExportWalletBackupRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.passphrase, serializer);
+  }
+
+  @protected
+  void sse_encode_export_wallet_backup_response(
+    ExportWalletBackupResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.backup, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putInt32(self);
   }
 
+  @protected
+  void sse_encode_import_wallet_backup_request(
+    ImportWalletBackupRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.backup, serializer);
+    sse_encode_String(self.passphrase, serializer);
+  }
+
   @protected
   void sse_encode_is_valid_token_request(
     IsValidTokenRequest self,
@@ -4121,6 +7077,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_keyset_fee(KeysetFee self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kid, serializer);
+    sse_encode_u_64(self.inputFeePpk, serializer);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4130,6 +7093,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_keyset_fee(
+    List<KeysetFee> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_keyset_fee(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_pending_transaction(
+    List<PendingTransaction> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pending_transaction(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -4162,6 +7149,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_unit_balance(
+    List<UnitBalance> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_unit_balance(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_wallet_dev_mode_detailed_balance_entry(
     List<WalletDevModeDetailedBalanceEntry> self,
@@ -4277,6 +7276,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_pending_transaction(
+    PendingTransaction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transaction(self.transaction, serializer);
+    sse_encode_usize(self.pendingProofs, serializer);
+    sse_encode_bool(self.unsent, serializer);
+  }
+
   @protected
   void sse_encode_protest_status(ProtestStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4301,6 +7311,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.appVersion, serializer);
   }
 
+  @protected
+  void sse_encode_total_balance_response(
+    TotalBalanceResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_unit_balance(self.balances, serializer);
+  }
+
   @protected
   void sse_encode_transaction(Transaction self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4348,51 +7367,139 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
+  }
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  void sse_encode_unit_balance(UnitBalance self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.unit, serializer);
+    sse_encode_u_64(self.debit, serializer);
+    sse_encode_u_64(self.credit, serializer);
+  }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_validate_mnemonic_request(
+    ValidateMnemonicRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mnemonic, serializer);
+  }
+
+  @protected
+  void sse_encode_validate_mnemonic_response(
+    ValidateMnemonicResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.valid, serializer);
+    sse_encode_u_32(self.wordCount, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_balance_response(
+    WalletBalanceResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.debit, serializer);
+    sse_encode_u_64(self.credit, serializer);
+    sse_encode_u_64(self.total, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_check_pending_mints_response(
+    WalletCheckPendingMintsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.txIds, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_check_received_payment_request(
+    WalletCheckReceivedPaymentRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_u_64(self.maxWaitSec, serializer);
+    sse_encode_String(self.pId, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_clean_local_db_request(
+    WalletCleanLocalDbRequest self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_bool(self.pruneCounters, serializer);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_wallet_clean_local_db_response(
+    WalletCleanLocalDbResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.cleanedProofs, serializer);
+    sse_encode_u_32(self.prunedCounters, serializer);
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_wallet_combine_partial_signatures_request(
+    WalletCombinePartialSignaturesRequest self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.y, serializer);
+    sse_encode_list_String(self.witnesses, serializer);
   }
 
   @protected
-  void sse_encode_wallet_balance_response(
-    WalletBalanceResponse self,
+  void sse_encode_wallet_combine_partial_signatures_response(
+    WalletCombinePartialSignaturesResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.debit, serializer);
-    sse_encode_u_64(self.credit, serializer);
-    sse_encode_u_64(self.total, serializer);
+    sse_encode_bool(self.spendable, serializer);
   }
 
   @protected
-  void sse_encode_wallet_check_pending_mints_response(
-    WalletCheckPendingMintsResponse self,
+  void sse_encode_wallet_confirm_nut_18_delivery_request(
+    WalletConfirmNut18DeliveryRequest self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_String(self.txIds, serializer);
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.txId, serializer);
+    sse_encode_u_64(self.timeoutSecs, serializer);
   }
 
   @protected
-  void sse_encode_wallet_check_received_payment_request(
-    WalletCheckReceivedPaymentRequest self,
+  void sse_encode_wallet_confirm_nut_18_delivery_response(
+    WalletConfirmNut18DeliveryResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.walletId, serializer);
-    sse_encode_u_64(self.maxWaitSec, serializer);
-    sse_encode_String(self.pId, serializer);
+    sse_encode_bool(self.delivered, serializer);
   }
 
   @protected
@@ -4453,6 +7560,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_export_balance_token_request(
+    WalletExportBalanceTokenRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.unit, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_export_balance_token_response(
+    WalletExportBalanceTokenResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.token, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_export_proofs_encrypted_request(
+    WalletExportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.passphrase, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_export_proofs_encrypted_response(
+    WalletExportProofsEncryptedResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.blob, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_export_receipt_response(
+    WalletExportReceiptResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.receipt, serializer);
+  }
+
   @protected
   void sse_encode_wallet_ffi_config(
     WalletFfiConfig self,
@@ -4466,11 +7620,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.defaultMintUrl, serializer);
     sse_encode_String(self.bitcoinNetwork, serializer);
     sse_encode_String(self.mnemonic, serializer);
+    sse_encode_opt_String(self.passphrase, serializer);
     sse_encode_list_String(self.nostrRelays, serializer);
     sse_encode_u_32(self.swapExpiryMinutes, serializer);
     sse_encode_bool(self.devMode, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_import_proofs_encrypted_request(
+    WalletImportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_list_prim_u_8_strict(self.blob, serializer);
+    sse_encode_String(self.passphrase, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_import_proofs_encrypted_response(
+    WalletImportProofsEncryptedResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.imported, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_inspect_payment_request_response(
+    WalletInspectPaymentRequestResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.info, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_keep_sent_tokens_request(
+    WalletKeepSentTokensRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_bool(self.keep, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_keyset_fees_response(
+    WalletKeysetFeesResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_keyset_fee(self.fees, serializer);
+  }
+
   @protected
   void sse_encode_wallet_maybe_transaction_id_response(
     WalletMaybeTransactionIdResponse self,
@@ -4480,6 +7683,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.txId, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_mint_bolt_11_request(
+    WalletMintBolt11Request self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.quoteId, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_mint_capabilities_response(
+    WalletMintCapabilitiesResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.mintEnabled, serializer);
+    sse_encode_bool(self.meltEnabled, serializer);
+    sse_encode_bool(self.tokenStateCheck, serializer);
+    sse_encode_bool(self.restore, serializer);
+    sse_encode_bool(self.p2Pk, serializer);
+    sse_encode_bool(self.dleq, serializer);
+    sse_encode_bool(self.htlc, serializer);
+    sse_encode_bool(self.mpp, serializer);
+    sse_encode_bool(self.websocket, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_mint_quote_response(
+    WalletMintQuoteResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.quoteId, serializer);
+    sse_encode_String(self.request, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_String(self.unit, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.expiry, serializer);
+  }
+
   @protected
   void sse_encode_wallet_mint_request(
     WalletMintRequest self,
@@ -4520,6 +7763,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.name, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_nprofile_response(
+    WalletNprofileResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.nprofile, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_partial_sign_proof_request(
+    WalletPartialSignProofRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.y, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_partial_sign_proof_response(
+    WalletPartialSignProofResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.witness, serializer);
+  }
+
   @protected
   void sse_encode_wallet_pay_request(
     WalletPayRequest self,
@@ -4548,6 +7819,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.txId, serializer);
     sse_encode_String(self.token, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.fees, serializer);
+    sse_encode_String(self.unit, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_pending_transactions_response(
+    WalletPendingTransactionsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_pending_transaction(self.txs, serializer);
   }
 
   @protected
@@ -4613,6 +7896,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment_summary(self.paymentSummary, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_prepare_send_locked_request(
+    WalletPrepareSendLockedRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_String(self.unit, serializer);
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.locktime, serializer);
+    sse_encode_opt_String(self.refundKey, serializer);
+    sse_encode_opt_String(self.description, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_prepare_send_max_request(
+    WalletPrepareSendMaxRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.unit, serializer);
+    sse_encode_opt_String(self.description, serializer);
+  }
+
   @protected
   void sse_encode_wallet_protest_melt_request(
     WalletProtestMeltRequest self,
@@ -4673,6 +7982,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.amount, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_receive_multisig_token_response(
+    WalletReceiveMultisigTokenResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.txId, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_list_String(self.ys, serializer);
+  }
+
   @protected
   void sse_encode_wallet_receive_request(
     WalletReceiveRequest self,
@@ -4683,6 +8003,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.token, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_receive_response(
+    WalletReceiveResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.txId, serializer);
+    sse_encode_u_64(self.received, serializer);
+    sse_encode_u_64(self.stored, serializer);
+    sse_encode_u_64(self.fee, serializer);
+    sse_encode_String(self.unit, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_recheck_response(
+    WalletRecheckResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.deleted, serializer);
+    sse_encode_u_32(self.restored, serializer);
+    sse_encode_u_32(self.pending, serializer);
+  }
+
   @protected
   void sse_encode_wallet_reclaim_transaction_request(
     WalletReclaimTransactionRequest self,
@@ -4739,12 +8083,88 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize(self.updated, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_release_reservations_response(
+    WalletReleaseReservationsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.released, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_rename_request(
+    WalletRenameRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.name, serializer);
+  }
+
   @protected
   void sse_encode_wallet_request(WalletRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(self.walletId, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_restore_keyset_request(
+    WalletRestoreKeysetRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_String(self.keysetId, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_restore_keyset_response(
+    WalletRestoreKeysetResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.restored, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_retry_pending_transaction_response(
+    WalletRetryPendingTransactionResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.retried, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_send_result_response(
+    WalletSendResultResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.txId, serializer);
+    sse_encode_opt_String(self.token, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.fees, serializer);
+    sse_encode_String(self.unit, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_stats_response(
+    WalletStatsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.unspentProofs, serializer);
+    sse_encode_u_32(self.pendingProofs, serializer);
+    sse_encode_u_32(self.reservedProofs, serializer);
+    sse_encode_u_32(self.totalProofs, serializer);
+    sse_encode_u_32(self.keysets, serializer);
+    sse_encode_u_32(self.randomProofs, serializer);
+    sse_encode_u_32(self.transactions, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.oldestTx, serializer);
+  }
+
   @protected
   void sse_encode_wallet_transaction_id_response(
     WalletTransactionIdResponse self,
@@ -4782,6 +8202,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_transaction(self.transaction, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_transaction_token_response(
+    WalletTransactionTokenResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.token, serializer);
+  }
+
   @protected
   void sse_encode_wallet_transactions_response(
     WalletTransactionsResponse self,
@@ -4791,6 +8220,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_transaction(self.txs, serializer);
   }
 
+  @protected
+  void sse_encode_wallet_transactions_since_request(
+    WalletTransactionsSinceRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.walletId, serializer);
+    sse_encode_u_64(self.seq, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_transactions_since_response(
+    WalletTransactionsSinceResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_transaction(self.txs, serializer);
+    sse_encode_list_String(self.deleted, serializer);
+    sse_encode_u_64(self.seq, serializer);
+  }
+
   @protected
   void sse_encode_wallets_ids_response(
     WalletsIdsResponse self,
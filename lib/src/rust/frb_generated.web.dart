@@ -63,6 +63,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ExportWalletBackupRequest dco_decode_box_autoadd_export_wallet_backup_request(
+    dynamic raw,
+  );
+
+  @protected
+  ImportWalletBackupRequest dco_decode_box_autoadd_import_wallet_backup_request(
+    dynamic raw,
+  );
+
   @protected
   IsValidTokenRequest dco_decode_box_autoadd_is_valid_token_request(
     dynamic raw,
@@ -74,16 +84,58 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  ValidateMnemonicRequest dco_decode_box_autoadd_validate_mnemonic_request(
+    dynamic raw,
+  );
+
   @protected
   WalletCheckReceivedPaymentRequest
   dco_decode_box_autoadd_wallet_check_received_payment_request(dynamic raw);
 
+  @protected
+  WalletCleanLocalDbRequest
+  dco_decode_box_autoadd_wallet_clean_local_db_request(dynamic raw);
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  dco_decode_box_autoadd_wallet_combine_partial_signatures_request(dynamic raw);
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  dco_decode_box_autoadd_wallet_confirm_nut_18_delivery_request(dynamic raw);
+
+  @protected
+  WalletExportBalanceTokenRequest
+  dco_decode_box_autoadd_wallet_export_balance_token_request(dynamic raw);
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  dco_decode_box_autoadd_wallet_export_proofs_encrypted_request(dynamic raw);
+
   @protected
   WalletFfiConfig dco_decode_box_autoadd_wallet_ffi_config(dynamic raw);
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  dco_decode_box_autoadd_wallet_import_proofs_encrypted_request(dynamic raw);
+
+  @protected
+  WalletKeepSentTokensRequest
+  dco_decode_box_autoadd_wallet_keep_sent_tokens_request(dynamic raw);
+
+  @protected
+  WalletMintBolt11Request dco_decode_box_autoadd_wallet_mint_bolt_11_request(
+    dynamic raw,
+  );
+
   @protected
   WalletMintRequest dco_decode_box_autoadd_wallet_mint_request(dynamic raw);
 
+  @protected
+  WalletPartialSignProofRequest
+  dco_decode_box_autoadd_wallet_partial_sign_proof_request(dynamic raw);
+
   @protected
   WalletPayRequest dco_decode_box_autoadd_wallet_pay_request(dynamic raw);
 
@@ -108,6 +160,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   WalletPreparePaymentRequest
   dco_decode_box_autoadd_wallet_prepare_payment_request(dynamic raw);
 
+  @protected
+  WalletPrepareSendLockedRequest
+  dco_decode_box_autoadd_wallet_prepare_send_locked_request(dynamic raw);
+
+  @protected
+  WalletPrepareSendMaxRequest
+  dco_decode_box_autoadd_wallet_prepare_send_max_request(dynamic raw);
+
   @protected
   WalletProtestMeltRequest dco_decode_box_autoadd_wallet_protest_melt_request(
     dynamic raw,
@@ -136,17 +196,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   WalletRefreshTransactionRequest
   dco_decode_box_autoadd_wallet_refresh_transaction_request(dynamic raw);
 
+  @protected
+  WalletRenameRequest dco_decode_box_autoadd_wallet_rename_request(dynamic raw);
+
   @protected
   WalletRequest dco_decode_box_autoadd_wallet_request(dynamic raw);
 
+  @protected
+  WalletRestoreKeysetRequest
+  dco_decode_box_autoadd_wallet_restore_keyset_request(dynamic raw);
+
   @protected
   WalletTransactionRequest dco_decode_box_autoadd_wallet_transaction_request(
     dynamic raw,
   );
 
+  @protected
+  WalletTransactionsSinceRequest
+  dco_decode_box_autoadd_wallet_transactions_since_request(dynamic raw);
+
+  @protected
+  ExportWalletBackupRequest dco_decode_export_wallet_backup_request(
+    dynamic raw,
+  );
+
+  @protected
+  ExportWalletBackupResponse dco_decode_export_wallet_backup_response(
+    dynamic raw,
+  );
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  ImportWalletBackupRequest dco_decode_import_wallet_backup_request(
+    dynamic raw,
+  );
+
   @protected
   IsValidTokenRequest dco_decode_is_valid_token_request(dynamic raw);
 
@@ -156,9 +242,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_isize(dynamic raw);
 
+  @protected
+  KeysetFee dco_decode_keyset_fee(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<KeysetFee> dco_decode_list_keyset_fee(dynamic raw);
+
+  @protected
+  List<PendingTransaction> dco_decode_list_pending_transaction(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -168,6 +263,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Transaction> dco_decode_list_transaction(dynamic raw);
 
+  @protected
+  List<UnitBalance> dco_decode_list_unit_balance(dynamic raw);
+
   @protected
   List<WalletDevModeDetailedBalanceEntry>
   dco_decode_list_wallet_dev_mode_detailed_balance_entry(dynamic raw);
@@ -205,6 +303,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentType dco_decode_payment_type(dynamic raw);
 
+  @protected
+  PendingTransaction dco_decode_pending_transaction(dynamic raw);
+
   @protected
   ProtestStatus dco_decode_protest_status(dynamic raw);
 
@@ -214,6 +315,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StatusResponse dco_decode_status_response(dynamic raw);
 
+  @protected
+  TotalBalanceResponse dco_decode_total_balance_response(dynamic raw);
+
   @protected
   Transaction dco_decode_transaction(dynamic raw);
 
@@ -235,9 +339,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UnitBalance dco_decode_unit_balance(dynamic raw);
+
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  ValidateMnemonicRequest dco_decode_validate_mnemonic_request(dynamic raw);
+
+  @protected
+  ValidateMnemonicResponse dco_decode_validate_mnemonic_response(dynamic raw);
+
   @protected
   WalletBalanceResponse dco_decode_wallet_balance_response(dynamic raw);
 
@@ -249,6 +362,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   WalletCheckReceivedPaymentRequest
   dco_decode_wallet_check_received_payment_request(dynamic raw);
 
+  @protected
+  WalletCleanLocalDbRequest dco_decode_wallet_clean_local_db_request(
+    dynamic raw,
+  );
+
+  @protected
+  WalletCleanLocalDbResponse dco_decode_wallet_clean_local_db_response(
+    dynamic raw,
+  );
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  dco_decode_wallet_combine_partial_signatures_request(dynamic raw);
+
+  @protected
+  WalletCombinePartialSignaturesResponse
+  dco_decode_wallet_combine_partial_signatures_response(dynamic raw);
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  dco_decode_wallet_confirm_nut_18_delivery_request(dynamic raw);
+
+  @protected
+  WalletConfirmNut18DeliveryResponse
+  dco_decode_wallet_confirm_nut_18_delivery_response(dynamic raw);
+
   @protected
   WalletCurrencyUnitResponse dco_decode_wallet_currency_unit_response(
     dynamic raw,
@@ -271,13 +410,65 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WalletErrorKind dco_decode_wallet_error_kind(dynamic raw);
 
+  @protected
+  WalletExportBalanceTokenRequest
+  dco_decode_wallet_export_balance_token_request(dynamic raw);
+
+  @protected
+  WalletExportBalanceTokenResponse
+  dco_decode_wallet_export_balance_token_response(dynamic raw);
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  dco_decode_wallet_export_proofs_encrypted_request(dynamic raw);
+
+  @protected
+  WalletExportProofsEncryptedResponse
+  dco_decode_wallet_export_proofs_encrypted_response(dynamic raw);
+
+  @protected
+  WalletExportReceiptResponse dco_decode_wallet_export_receipt_response(
+    dynamic raw,
+  );
+
   @protected
   WalletFfiConfig dco_decode_wallet_ffi_config(dynamic raw);
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  dco_decode_wallet_import_proofs_encrypted_request(dynamic raw);
+
+  @protected
+  WalletImportProofsEncryptedResponse
+  dco_decode_wallet_import_proofs_encrypted_response(dynamic raw);
+
+  @protected
+  WalletInspectPaymentRequestResponse
+  dco_decode_wallet_inspect_payment_request_response(dynamic raw);
+
+  @protected
+  WalletKeepSentTokensRequest dco_decode_wallet_keep_sent_tokens_request(
+    dynamic raw,
+  );
+
+  @protected
+  WalletKeysetFeesResponse dco_decode_wallet_keyset_fees_response(dynamic raw);
+
   @protected
   WalletMaybeTransactionIdResponse
   dco_decode_wallet_maybe_transaction_id_response(dynamic raw);
 
+  @protected
+  WalletMintBolt11Request dco_decode_wallet_mint_bolt_11_request(dynamic raw);
+
+  @protected
+  WalletMintCapabilitiesResponse dco_decode_wallet_mint_capabilities_response(
+    dynamic raw,
+  );
+
+  @protected
+  WalletMintQuoteResponse dco_decode_wallet_mint_quote_response(dynamic raw);
+
   @protected
   WalletMintRequest dco_decode_wallet_mint_request(dynamic raw);
 
@@ -292,6 +483,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WalletNameResponse dco_decode_wallet_name_response(dynamic raw);
 
+  @protected
+  WalletNprofileResponse dco_decode_wallet_nprofile_response(dynamic raw);
+
+  @protected
+  WalletPartialSignProofRequest dco_decode_wallet_partial_sign_proof_request(
+    dynamic raw,
+  );
+
+  @protected
+  WalletPartialSignProofResponse dco_decode_wallet_partial_sign_proof_response(
+    dynamic raw,
+  );
+
   @protected
   WalletPayRequest dco_decode_wallet_pay_request(dynamic raw);
 
@@ -305,6 +509,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  WalletPendingTransactionsResponse
+  dco_decode_wallet_pending_transactions_response(dynamic raw);
+
   @protected
   WalletPrepareMeltRequest dco_decode_wallet_prepare_melt_request(dynamic raw);
 
@@ -331,6 +539,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  WalletPrepareSendLockedRequest dco_decode_wallet_prepare_send_locked_request(
+    dynamic raw,
+  );
+
+  @protected
+  WalletPrepareSendMaxRequest dco_decode_wallet_prepare_send_max_request(
+    dynamic raw,
+  );
+
   @protected
   WalletProtestMeltRequest dco_decode_wallet_protest_melt_request(dynamic raw);
 
@@ -355,9 +573,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  WalletReceiveMultisigTokenResponse
+  dco_decode_wallet_receive_multisig_token_response(dynamic raw);
+
   @protected
   WalletReceiveRequest dco_decode_wallet_receive_request(dynamic raw);
 
+  @protected
+  WalletReceiveResponse dco_decode_wallet_receive_response(dynamic raw);
+
+  @protected
+  WalletRecheckResponse dco_decode_wallet_recheck_response(dynamic raw);
+
   @protected
   WalletReclaimTransactionRequest dco_decode_wallet_reclaim_transaction_request(
     dynamic raw,
@@ -384,9 +612,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   WalletRefreshTransactionsResponse
   dco_decode_wallet_refresh_transactions_response(dynamic raw);
 
+  @protected
+  WalletReleaseReservationsResponse
+  dco_decode_wallet_release_reservations_response(dynamic raw);
+
+  @protected
+  WalletRenameRequest dco_decode_wallet_rename_request(dynamic raw);
+
   @protected
   WalletRequest dco_decode_wallet_request(dynamic raw);
 
+  @protected
+  WalletRestoreKeysetRequest dco_decode_wallet_restore_keyset_request(
+    dynamic raw,
+  );
+
+  @protected
+  WalletRestoreKeysetResponse dco_decode_wallet_restore_keyset_response(
+    dynamic raw,
+  );
+
+  @protected
+  WalletRetryPendingTransactionResponse
+  dco_decode_wallet_retry_pending_transaction_response(dynamic raw);
+
+  @protected
+  WalletSendResultResponse dco_decode_wallet_send_result_response(dynamic raw);
+
+  @protected
+  WalletStatsResponse dco_decode_wallet_stats_response(dynamic raw);
+
   @protected
   WalletTransactionIdResponse dco_decode_wallet_transaction_id_response(
     dynamic raw,
@@ -403,11 +658,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WalletTransactionResponse dco_decode_wallet_transaction_response(dynamic raw);
 
+  @protected
+  WalletTransactionTokenResponse dco_decode_wallet_transaction_token_response(
+    dynamic raw,
+  );
+
   @protected
   WalletTransactionsResponse dco_decode_wallet_transactions_response(
     dynamic raw,
   );
 
+  @protected
+  WalletTransactionsSinceRequest dco_decode_wallet_transactions_since_request(
+    dynamic raw,
+  );
+
+  @protected
+  WalletTransactionsSinceResponse dco_decode_wallet_transactions_since_response(
+    dynamic raw,
+  );
+
   @protected
   WalletsIdsResponse dco_decode_wallets_ids_response(dynamic raw);
 
@@ -446,6 +716,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  ExportWalletBackupRequest sse_decode_box_autoadd_export_wallet_backup_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImportWalletBackupRequest sse_decode_box_autoadd_import_wallet_backup_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   IsValidTokenRequest sse_decode_box_autoadd_is_valid_token_request(
     SseDeserializer deserializer,
@@ -459,22 +739,80 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  ValidateMnemonicRequest sse_decode_box_autoadd_validate_mnemonic_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletCheckReceivedPaymentRequest
   sse_decode_box_autoadd_wallet_check_received_payment_request(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletCleanLocalDbRequest
+  sse_decode_box_autoadd_wallet_clean_local_db_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  sse_decode_box_autoadd_wallet_combine_partial_signatures_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  sse_decode_box_autoadd_wallet_confirm_nut_18_delivery_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletExportBalanceTokenRequest
+  sse_decode_box_autoadd_wallet_export_balance_token_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  sse_decode_box_autoadd_wallet_export_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletFfiConfig sse_decode_box_autoadd_wallet_ffi_config(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  sse_decode_box_autoadd_wallet_import_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletKeepSentTokensRequest
+  sse_decode_box_autoadd_wallet_keep_sent_tokens_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletMintBolt11Request sse_decode_box_autoadd_wallet_mint_bolt_11_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletMintRequest sse_decode_box_autoadd_wallet_mint_request(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletPartialSignProofRequest
+  sse_decode_box_autoadd_wallet_partial_sign_proof_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletPayRequest sse_decode_box_autoadd_wallet_pay_request(
     SseDeserializer deserializer,
@@ -509,6 +847,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletPrepareSendLockedRequest
+  sse_decode_box_autoadd_wallet_prepare_send_locked_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletPrepareSendMaxRequest
+  sse_decode_box_autoadd_wallet_prepare_send_max_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletProtestMeltRequest sse_decode_box_autoadd_wallet_protest_melt_request(
     SseDeserializer deserializer,
@@ -541,19 +891,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletRenameRequest sse_decode_box_autoadd_wallet_rename_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletRequest sse_decode_box_autoadd_wallet_request(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletRestoreKeysetRequest
+  sse_decode_box_autoadd_wallet_restore_keyset_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletTransactionRequest sse_decode_box_autoadd_wallet_transaction_request(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletTransactionsSinceRequest
+  sse_decode_box_autoadd_wallet_transactions_since_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportWalletBackupRequest sse_decode_export_wallet_backup_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportWalletBackupResponse sse_decode_export_wallet_backup_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  ImportWalletBackupRequest sse_decode_import_wallet_backup_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   IsValidTokenRequest sse_decode_is_valid_token_request(
     SseDeserializer deserializer,
@@ -567,9 +949,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
 
+  @protected
+  KeysetFee sse_decode_keyset_fee(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<KeysetFee> sse_decode_list_keyset_fee(SseDeserializer deserializer);
+
+  @protected
+  List<PendingTransaction> sse_decode_list_pending_transaction(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -579,6 +972,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Transaction> sse_decode_list_transaction(SseDeserializer deserializer);
 
+  @protected
+  List<UnitBalance> sse_decode_list_unit_balance(SseDeserializer deserializer);
+
   @protected
   List<WalletDevModeDetailedBalanceEntry>
   sse_decode_list_wallet_dev_mode_detailed_balance_entry(
@@ -624,6 +1020,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentType sse_decode_payment_type(SseDeserializer deserializer);
 
+  @protected
+  PendingTransaction sse_decode_pending_transaction(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ProtestStatus sse_decode_protest_status(SseDeserializer deserializer);
 
@@ -635,6 +1036,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StatusResponse sse_decode_status_response(SseDeserializer deserializer);
 
+  @protected
+  TotalBalanceResponse sse_decode_total_balance_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Transaction sse_decode_transaction(SseDeserializer deserializer);
 
@@ -658,9 +1064,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UnitBalance sse_decode_unit_balance(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  ValidateMnemonicRequest sse_decode_validate_mnemonic_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ValidateMnemonicResponse sse_decode_validate_mnemonic_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletBalanceResponse sse_decode_wallet_balance_response(
     SseDeserializer deserializer,
@@ -676,6 +1095,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletCleanLocalDbRequest sse_decode_wallet_clean_local_db_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletCleanLocalDbResponse sse_decode_wallet_clean_local_db_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletCombinePartialSignaturesRequest
+  sse_decode_wallet_combine_partial_signatures_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletCombinePartialSignaturesResponse
+  sse_decode_wallet_combine_partial_signatures_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletConfirmNut18DeliveryRequest
+  sse_decode_wallet_confirm_nut_18_delivery_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletConfirmNut18DeliveryResponse
+  sse_decode_wallet_confirm_nut_18_delivery_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletCurrencyUnitResponse sse_decode_wallet_currency_unit_response(
     SseDeserializer deserializer,
@@ -702,13 +1155,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   WalletErrorKind sse_decode_wallet_error_kind(SseDeserializer deserializer);
 
+  @protected
+  WalletExportBalanceTokenRequest
+  sse_decode_wallet_export_balance_token_request(SseDeserializer deserializer);
+
+  @protected
+  WalletExportBalanceTokenResponse
+  sse_decode_wallet_export_balance_token_response(SseDeserializer deserializer);
+
+  @protected
+  WalletExportProofsEncryptedRequest
+  sse_decode_wallet_export_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletExportProofsEncryptedResponse
+  sse_decode_wallet_export_proofs_encrypted_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletExportReceiptResponse sse_decode_wallet_export_receipt_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletFfiConfig sse_decode_wallet_ffi_config(SseDeserializer deserializer);
 
+  @protected
+  WalletImportProofsEncryptedRequest
+  sse_decode_wallet_import_proofs_encrypted_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletImportProofsEncryptedResponse
+  sse_decode_wallet_import_proofs_encrypted_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletInspectPaymentRequestResponse
+  sse_decode_wallet_inspect_payment_request_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletKeepSentTokensRequest sse_decode_wallet_keep_sent_tokens_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletKeysetFeesResponse sse_decode_wallet_keyset_fees_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletMaybeTransactionIdResponse
   sse_decode_wallet_maybe_transaction_id_response(SseDeserializer deserializer);
 
+  @protected
+  WalletMintBolt11Request sse_decode_wallet_mint_bolt_11_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletMintCapabilitiesResponse sse_decode_wallet_mint_capabilities_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletMintQuoteResponse sse_decode_wallet_mint_quote_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletMintRequest sse_decode_wallet_mint_request(
     SseDeserializer deserializer,
@@ -729,6 +1250,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletNprofileResponse sse_decode_wallet_nprofile_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletPartialSignProofRequest sse_decode_wallet_partial_sign_proof_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletPartialSignProofResponse sse_decode_wallet_partial_sign_proof_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletPayRequest sse_decode_wallet_pay_request(SseDeserializer deserializer);
 
@@ -742,6 +1278,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletPendingTransactionsResponse
+  sse_decode_wallet_pending_transactions_response(SseDeserializer deserializer);
+
   @protected
   WalletPrepareMeltRequest sse_decode_wallet_prepare_melt_request(
     SseDeserializer deserializer,
@@ -772,6 +1312,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletPrepareSendLockedRequest sse_decode_wallet_prepare_send_locked_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletPrepareSendMaxRequest sse_decode_wallet_prepare_send_max_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletProtestMeltRequest sse_decode_wallet_protest_melt_request(
     SseDeserializer deserializer,
@@ -802,11 +1352,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletReceiveMultisigTokenResponse
+  sse_decode_wallet_receive_multisig_token_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletReceiveRequest sse_decode_wallet_receive_request(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletReceiveResponse sse_decode_wallet_receive_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletRecheckResponse sse_decode_wallet_recheck_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletReclaimTransactionRequest sse_decode_wallet_reclaim_transaction_request(
     SseDeserializer deserializer,
@@ -835,9 +1401,44 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   WalletRefreshTransactionsResponse
   sse_decode_wallet_refresh_transactions_response(SseDeserializer deserializer);
 
+  @protected
+  WalletReleaseReservationsResponse
+  sse_decode_wallet_release_reservations_response(SseDeserializer deserializer);
+
+  @protected
+  WalletRenameRequest sse_decode_wallet_rename_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletRequest sse_decode_wallet_request(SseDeserializer deserializer);
 
+  @protected
+  WalletRestoreKeysetRequest sse_decode_wallet_restore_keyset_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletRestoreKeysetResponse sse_decode_wallet_restore_keyset_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletRetryPendingTransactionResponse
+  sse_decode_wallet_retry_pending_transaction_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletSendResultResponse sse_decode_wallet_send_result_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletStatsResponse sse_decode_wallet_stats_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletTransactionIdResponse sse_decode_wallet_transaction_id_response(
     SseDeserializer deserializer,
@@ -858,11 +1459,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletTransactionTokenResponse sse_decode_wallet_transaction_token_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletTransactionsResponse sse_decode_wallet_transactions_response(
     SseDeserializer deserializer,
   );
 
+  @protected
+  WalletTransactionsSinceRequest sse_decode_wallet_transactions_since_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WalletTransactionsSinceResponse sse_decode_wallet_transactions_since_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WalletsIdsResponse sse_decode_wallets_ids_response(
     SseDeserializer deserializer,
@@ -889,58 +1505,124 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void
-  sse_encode_DartFn_Inputs_wallet_maybe_transaction_id_response_Output_unit_AnyhowException(
-    FutureOr<void> Function(WalletMaybeTransactionIdResponse) self,
+  void
+  sse_encode_DartFn_Inputs_wallet_maybe_transaction_id_response_Output_unit_AnyhowException(
+    FutureOr<void> Function(WalletMaybeTransactionIdResponse) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWalletPaymentCheckHandle(
+    WalletPaymentCheckHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_add_wallet_response(
+    AddWalletResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_export_wallet_backup_request(
+    ExportWalletBackupRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_import_wallet_backup_request(
+    ImportWalletBackupRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_is_valid_token_request(
+    IsValidTokenRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_mnemonic_request(
+    MnemonicRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_validate_mnemonic_request(
+    ValidateMnemonicRequest self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+  void sse_encode_box_autoadd_wallet_check_received_payment_request(
+    WalletCheckReceivedPaymentRequest self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWalletPaymentCheckHandle(
-    WalletPaymentCheckHandle self,
+  void sse_encode_box_autoadd_wallet_clean_local_db_request(
+    WalletCleanLocalDbRequest self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_box_autoadd_wallet_combine_partial_signatures_request(
+    WalletCombinePartialSignaturesRequest self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_add_wallet_response(
-    AddWalletResponse self,
+  void sse_encode_box_autoadd_wallet_confirm_nut_18_delivery_request(
+    WalletConfirmNut18DeliveryRequest self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_box_autoadd_wallet_export_balance_token_request(
+    WalletExportBalanceTokenRequest self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_is_valid_token_request(
-    IsValidTokenRequest self,
+  void sse_encode_box_autoadd_wallet_export_proofs_encrypted_request(
+    WalletExportProofsEncryptedRequest self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_mnemonic_request(
-    MnemonicRequest self,
+  void sse_encode_box_autoadd_wallet_ffi_config(
+    WalletFfiConfig self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_box_autoadd_wallet_import_proofs_encrypted_request(
+    WalletImportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_wallet_check_received_payment_request(
-    WalletCheckReceivedPaymentRequest self,
+  void sse_encode_box_autoadd_wallet_keep_sent_tokens_request(
+    WalletKeepSentTokensRequest self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_wallet_ffi_config(
-    WalletFfiConfig self,
+  void sse_encode_box_autoadd_wallet_mint_bolt_11_request(
+    WalletMintBolt11Request self,
     SseSerializer serializer,
   );
 
@@ -950,6 +1632,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_wallet_partial_sign_proof_request(
+    WalletPartialSignProofRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_wallet_pay_request(
     WalletPayRequest self,
@@ -986,6 +1674,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_wallet_prepare_send_locked_request(
+    WalletPrepareSendLockedRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_wallet_prepare_send_max_request(
+    WalletPrepareSendMaxRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_wallet_protest_melt_request(
     WalletProtestMeltRequest self,
@@ -1022,21 +1722,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_wallet_rename_request(
+    WalletRenameRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_wallet_request(
     WalletRequest self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_wallet_restore_keyset_request(
+    WalletRestoreKeysetRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_wallet_transaction_request(
     WalletTransactionRequest self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_wallet_transactions_since_request(
+    WalletTransactionsSinceRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_export_wallet_backup_request(
+    ExportWalletBackupRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_export_wallet_backup_response(
+    ExportWalletBackupResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_import_wallet_backup_request(
+    ImportWalletBackupRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_is_valid_token_request(
     IsValidTokenRequest self,
@@ -1052,9 +1788,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_keyset_fee(KeysetFee self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_keyset_fee(
+    List<KeysetFee> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_pending_transaction(
+    List<PendingTransaction> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -1073,6 +1824,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_unit_balance(
+    List<UnitBalance> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_wallet_dev_mode_detailed_balance_entry(
     List<WalletDevModeDetailedBalanceEntry> self,
@@ -1133,6 +1890,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_payment_type(PaymentType self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_pending_transaction(
+    PendingTransaction self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_protest_status(ProtestStatus self, SseSerializer serializer);
 
@@ -1148,6 +1911,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_total_balance_response(
+    TotalBalanceResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_transaction(Transaction self, SseSerializer serializer);
 
@@ -1175,9 +1944,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_unit_balance(UnitBalance self, SseSerializer serializer);
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_validate_mnemonic_request(
+    ValidateMnemonicRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_validate_mnemonic_response(
+    ValidateMnemonicResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_balance_response(
     WalletBalanceResponse self,
@@ -1196,6 +1980,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_clean_local_db_request(
+    WalletCleanLocalDbRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_clean_local_db_response(
+    WalletCleanLocalDbResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_combine_partial_signatures_request(
+    WalletCombinePartialSignaturesRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_combine_partial_signatures_response(
+    WalletCombinePartialSignaturesResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_confirm_nut_18_delivery_request(
+    WalletConfirmNut18DeliveryRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_confirm_nut_18_delivery_response(
+    WalletConfirmNut18DeliveryResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_currency_unit_response(
     WalletCurrencyUnitResponse self,
@@ -1229,18 +2049,96 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_export_balance_token_request(
+    WalletExportBalanceTokenRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_export_balance_token_response(
+    WalletExportBalanceTokenResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_export_proofs_encrypted_request(
+    WalletExportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_export_proofs_encrypted_response(
+    WalletExportProofsEncryptedResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_export_receipt_response(
+    WalletExportReceiptResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_ffi_config(
     WalletFfiConfig self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_import_proofs_encrypted_request(
+    WalletImportProofsEncryptedRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_import_proofs_encrypted_response(
+    WalletImportProofsEncryptedResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_inspect_payment_request_response(
+    WalletInspectPaymentRequestResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_keep_sent_tokens_request(
+    WalletKeepSentTokensRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_keyset_fees_response(
+    WalletKeysetFeesResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_maybe_transaction_id_response(
     WalletMaybeTransactionIdResponse self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_mint_bolt_11_request(
+    WalletMintBolt11Request self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_mint_capabilities_response(
+    WalletMintCapabilitiesResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_mint_quote_response(
+    WalletMintQuoteResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_mint_request(
     WalletMintRequest self,
@@ -1265,6 +2163,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_nprofile_response(
+    WalletNprofileResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_partial_sign_proof_request(
+    WalletPartialSignProofRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_partial_sign_proof_response(
+    WalletPartialSignProofResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_pay_request(
     WalletPayRequest self,
@@ -1283,6 +2199,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_pending_transactions_response(
+    WalletPendingTransactionsResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_prepare_melt_request(
     WalletPrepareMeltRequest self,
@@ -1319,6 +2241,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_prepare_send_locked_request(
+    WalletPrepareSendLockedRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_prepare_send_max_request(
+    WalletPrepareSendMaxRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_protest_melt_request(
     WalletProtestMeltRequest self,
@@ -1355,12 +2289,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_receive_multisig_token_response(
+    WalletReceiveMultisigTokenResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_receive_request(
     WalletReceiveRequest self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_receive_response(
+    WalletReceiveResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_recheck_response(
+    WalletRecheckResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_reclaim_transaction_request(
     WalletReclaimTransactionRequest self,
@@ -1397,9 +2349,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_release_reservations_response(
+    WalletReleaseReservationsResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_rename_request(
+    WalletRenameRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_request(WalletRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_wallet_restore_keyset_request(
+    WalletRestoreKeysetRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_restore_keyset_response(
+    WalletRestoreKeysetResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_retry_pending_transaction_response(
+    WalletRetryPendingTransactionResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_send_result_response(
+    WalletSendResultResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_stats_response(
+    WalletStatsResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_transaction_id_response(
     WalletTransactionIdResponse self,
@@ -1424,12 +2418,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_transaction_token_response(
+    WalletTransactionTokenResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallet_transactions_response(
     WalletTransactionsResponse self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_wallet_transactions_since_request(
+    WalletTransactionsSinceRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wallet_transactions_since_response(
+    WalletTransactionsSinceResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wallets_ids_response(
     WalletsIdsResponse self,